@@ -0,0 +1,43 @@
+//! Compares `relative_luminance_batch` with calling `relative_luminance` on every color, on
+//! palettes as big as the audits of a design system. Run with `cargo bench --bench luminance`.
+
+use std::hint::black_box;
+
+use contrast_checker::{relative_luminance, relative_luminance_batch, Color};
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion, Throughput};
+
+/// A palette spread over the whole sRGB cube, without needing a random number generator.
+fn palette(size: usize) -> Vec<Color> {
+    (0..size)
+        .map(|index| {
+            let value = index.wrapping_mul(2_654_435_761) as u32;
+            let [red, green, blue, _] = value.to_le_bytes();
+            Color::new(red, green, blue)
+        })
+        .collect()
+}
+
+fn luminance(c: &mut Criterion) {
+    let mut group = c.benchmark_group("luminance");
+
+    for size in [1_000, 10_000, 100_000] {
+        let colors = palette(size);
+        group.throughput(Throughput::Elements(size as u64));
+        group.bench_with_input(BenchmarkId::new("per color", size), &colors, |b, colors| {
+            b.iter(|| {
+                black_box(colors)
+                    .iter()
+                    .map(relative_luminance)
+                    .collect::<Vec<f32>>()
+            })
+        });
+        group.bench_with_input(BenchmarkId::new("batch", size), &colors, |b, colors| {
+            b.iter(|| relative_luminance_batch(black_box(colors)))
+        });
+    }
+
+    group.finish();
+}
+
+criterion_group!(benches, luminance);
+criterion_main!(benches);
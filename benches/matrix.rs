@@ -0,0 +1,113 @@
+//! Compares `contrast_matrix` with computing the luminance of both colors with `powf` for every
+//! pair, which is what it used to do, and `contrast_ratios` and `pairwise_contrast` with calling
+//! `contrast_ratio` for every pair. Run with `cargo bench --bench matrix`.
+
+use std::hint::black_box;
+
+use contrast_checker::{
+    contrast_matrix, contrast_ratio, contrast_ratios, pairwise_contrast, srgb_to_linear, Color,
+};
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion};
+
+fn luminance_with_powf(color: &Color) -> f32 {
+    let [red, green, blue] = color.normalized().map(srgb_to_linear);
+    0.2126 * red + 0.7152 * green + 0.0722 * blue
+}
+
+fn matrix_with_powf(colors: &[Color]) -> Vec<Vec<f32>> {
+    colors
+        .iter()
+        .map(|foreground| {
+            colors
+                .iter()
+                .map(|background| {
+                    let foreground = luminance_with_powf(foreground);
+                    let background = luminance_with_powf(background);
+                    (foreground.max(background) + 0.05) / (foreground.min(background) + 0.05)
+                })
+                .collect()
+        })
+        .collect()
+}
+
+/// A palette spread over the whole sRGB cube, without needing a random number generator.
+fn palette(size: usize) -> Vec<Color> {
+    (0..size)
+        .map(|index| {
+            let value = index.wrapping_mul(2_654_435_761) as u32;
+            let [red, green, blue, _] = value.to_le_bytes();
+            Color::new(red, green, blue)
+        })
+        .collect()
+}
+
+fn matrix(c: &mut Criterion) {
+    let mut group = c.benchmark_group("matrix");
+    group.sample_size(10);
+
+    for size in [100, 1000] {
+        let colors = palette(size);
+        group.bench_with_input(
+            BenchmarkId::new("powf for every pair", size),
+            &colors,
+            |b, colors| b.iter(|| matrix_with_powf(black_box(colors))),
+        );
+        group.bench_with_input(
+            BenchmarkId::new("contrast_matrix", size),
+            &colors,
+            |b, colors| b.iter(|| contrast_matrix(black_box(colors))),
+        );
+    }
+
+    group.finish();
+}
+
+fn batches(c: &mut Criterion) {
+    let mut group = c.benchmark_group("batches");
+    group.sample_size(10);
+
+    let background = Color::new(0xfa, 0xfa, 0xfa);
+    for size in [1_000, 100_000] {
+        let colors = palette(size);
+        group.bench_with_input(
+            BenchmarkId::new("contrast_ratio for every foreground", size),
+            &colors,
+            |b, colors| {
+                b.iter(|| {
+                    black_box(colors)
+                        .iter()
+                        .map(|foreground| contrast_ratio(foreground, &background))
+                        .collect::<Vec<f32>>()
+                })
+            },
+        );
+        group.bench_with_input(
+            BenchmarkId::new("contrast_ratios", size),
+            &colors,
+            |b, colors| b.iter(|| contrast_ratios(black_box(colors), &background)),
+        );
+    }
+
+    let (foregrounds, backgrounds) = (palette(1000), palette(50));
+    group.bench_function("contrast_ratio for every pair of 1000x50", |b| {
+        b.iter(|| {
+            black_box(&foregrounds)
+                .iter()
+                .map(|foreground| {
+                    backgrounds
+                        .iter()
+                        .map(|background| contrast_ratio(foreground, background))
+                        .collect::<Vec<f32>>()
+                })
+                .collect::<Vec<_>>()
+        })
+    });
+    group.bench_function("pairwise_contrast of 1000x50", |b| {
+        b.iter(|| pairwise_contrast(black_box(&foregrounds), &backgrounds))
+    });
+
+    group.finish();
+}
+
+criterion_group!(benches, matrix, batches);
+criterion_main!(benches);
@@ -0,0 +1,16 @@
+//! `cargo +nightly fuzz run parse_color`, colors that parse must survive a round trip through hex.
+
+#![no_main]
+
+use contrast_checker::{parse_color, split_colors_iter, GamutMapping};
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|input: &str| {
+    if let Ok(color) = parse_color(input) {
+        assert_eq!(parse_color(&color.to_hex()), Ok(color));
+    }
+    let _ = contrast_checker::Color::from_css_with(input, GamutMapping::Clip);
+    for color in split_colors_iter(input) {
+        let _ = parse_color(color);
+    }
+});
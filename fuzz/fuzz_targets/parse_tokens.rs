@@ -0,0 +1,12 @@
+//! `cargo +nightly fuzz run parse_tokens`
+
+#![no_main]
+
+use contrast_checker::tokens::parse_tokens;
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|json: &str| {
+    if let Ok(file) = parse_tokens(json) {
+        let _ = file.checked_pairs();
+    }
+});
@@ -0,0 +1,14 @@
+//! `cargo +nightly fuzz run scan`, every parser of `scan` on the same input.
+
+#![no_main]
+
+use contrast_checker::scan::{css_in_js_rules, css_rules, postcss_rules, sfc_rules, svg_elements};
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|source: &str| {
+    let _ = css_rules(source);
+    let _ = css_in_js_rules(source);
+    let _ = sfc_rules(source);
+    let _ = svg_elements(source);
+    let _ = postcss_rules(source);
+});
@@ -0,0 +1,90 @@
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+
+use crate::contrast::visible_colors;
+use crate::{apca_contrast, contrast_ratio, relative_luminance, Color, TextSize, WcagLevel};
+
+/// Combinations that can pass the WCAG 2 ratio but are still known to be hard to read.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum AdvisoryKind {
+    /// Saturated blue text on a very dark background. The eye can't focus blue light as sharply
+    /// as other colors, so thin strokes blur.
+    SaturatedBlueOnDark,
+    /// Saturated red and blue next to each other seem to sit at different depths and vibrate.
+    RedBlueVibration,
+    /// The pair passes WCAG 2 AA but APCA, which models dark colors more accurately, rates it too
+    /// low for text.
+    LowApcaContrast,
+}
+
+/// An informational note about a pair, not a failure.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct Advisory {
+    pub kind: AdvisoryKind,
+    pub message: String,
+    /// Where to read more about the issue.
+    pub reference: &'static str,
+}
+
+/// Minimum APCA lightness contrast recommended for text that isn't body text.
+const APCA_MINIMUM_TEXT_LC: f32 = 60.0;
+
+fn is_vivid(color: &Color) -> bool {
+    let (_, saturation, lightness) = color.to_hsl();
+    saturation >= 0.8 && (0.25..=0.75).contains(&lightness)
+}
+
+fn is_blue(color: &Color) -> bool {
+    let (hue, _, _) = color.to_hsl();
+    is_vivid(color) && (210.0..=270.0).contains(&hue)
+}
+
+fn is_red(color: &Color) -> bool {
+    let (hue, _, _) = color.to_hsl();
+    is_vivid(color) && !(15.0..345.0).contains(&hue)
+}
+
+/// Looks for combinations that are hard to read at small sizes even when the numbers look fine.
+/// The notes are advisory, they don't change whether a pair passes.
+pub fn advisories(foreground: &Color, background: &Color) -> Vec<Advisory> {
+    let (visible_foreground, visible_background) = visible_colors(foreground, background);
+    let mut advisories = Vec::new();
+
+    if is_blue(&visible_foreground) && relative_luminance(&visible_background) < 0.05 {
+        advisories.push(Advisory {
+            kind: AdvisoryKind::SaturatedBlueOnDark,
+            message: "saturated blue text on a very dark background blurs at small sizes"
+                .to_string(),
+            reference: "https://en.wikipedia.org/wiki/Chromatic_aberration",
+        });
+    }
+
+    let red_and_blue = (is_red(&visible_foreground) && is_blue(&visible_background))
+        || (is_blue(&visible_foreground) && is_red(&visible_background));
+    if red_and_blue {
+        advisories.push(Advisory {
+            kind: AdvisoryKind::RedBlueVibration,
+            message: "saturated red and blue together appear to vibrate".to_string(),
+            reference: "https://en.wikipedia.org/wiki/Chromostereopsis",
+        });
+    }
+
+    let passes_aa =
+        contrast_ratio(foreground, background) >= WcagLevel::AA.minimum_ratio(TextSize::Normal);
+    let lc = apca_contrast(foreground, background);
+    if passes_aa && lc.abs() < APCA_MINIMUM_TEXT_LC {
+        advisories.push(Advisory {
+            kind: AdvisoryKind::LowApcaContrast,
+            message: format!(
+                "passes WCAG 2 AA but APCA rates it Lc {:.1}, below the Lc {APCA_MINIMUM_TEXT_LC} recommended for text",
+                lc.abs()
+            ),
+            reference: "https://github.com/Myndex/apca-w3",
+        });
+    }
+
+    advisories
+}
@@ -0,0 +1,207 @@
+use alloc::boxed::Box;
+use alloc::vec::Vec;
+
+#[cfg(feature = "rayon")]
+use rayon::prelude::*;
+
+use crate::contrast::visible_colors;
+use crate::{apca_contrast, contrast_matrix, contrast_ratio, relative_luminance, Color};
+
+/// A way of measuring contrast. The audits, reports and matrices of the crate work with any of
+/// them, and library users can implement their own:
+///
+/// ```
+/// use contrast_checker::audit::Audit;
+/// use contrast_checker::{relative_luminance, Color, ContrastAlgorithm};
+///
+/// /// Contrast as the difference of the luminances in percent.
+/// struct Percent;
+///
+/// impl ContrastAlgorithm for Percent {
+///     fn name(&self) -> &'static str {
+///         "percent"
+///     }
+///
+///     fn contrast(&self, foreground: &Color, background: &Color) -> f32 {
+///         (relative_luminance(foreground) - relative_luminance(background)).abs() * 100.0
+///     }
+///
+///     fn default_threshold(&self) -> f32 {
+///         50.0
+///     }
+/// }
+///
+/// let report = Audit::new().algorithm(Percent).pair("#777", "white").run().unwrap();
+/// assert_eq!(report.failures(), 0);
+/// ```
+pub trait ContrastAlgorithm: Send + Sync {
+    /// Lowercase name written in reports, like `wcag2`.
+    fn name(&self) -> &'static str;
+
+    /// Contrast of text in `foreground` on `background`.
+    fn contrast(&self, foreground: &Color, background: &Color) -> f32;
+
+    /// The threshold used when none is given, what the algorithm asks of body text.
+    fn default_threshold(&self) -> f32;
+
+    /// Whether a contrast reaches a threshold. Algorithms with signed results, like APCA, compare
+    /// magnitudes.
+    fn passes(&self, contrast: f32, threshold: f32) -> bool {
+        contrast >= threshold
+    }
+
+    /// The contrast of every combination of a palette, `matrix[i][j]` is `colors[i]` on
+    /// `colors[j]`. With the `rayon` feature the rows are computed in parallel.
+    fn contrast_matrix(&self, colors: &[Color]) -> Vec<Vec<f32>> {
+        let row = |foreground: &Color| -> Vec<f32> {
+            colors
+                .iter()
+                .map(|background| self.contrast(foreground, background))
+                .collect()
+        };
+
+        #[cfg(feature = "rayon")]
+        return colors.par_iter().map(row).collect();
+        #[cfg(not(feature = "rayon"))]
+        colors.iter().map(row).collect()
+    }
+}
+
+impl<A: ContrastAlgorithm + ?Sized> ContrastAlgorithm for &A {
+    fn name(&self) -> &'static str {
+        (**self).name()
+    }
+
+    fn contrast(&self, foreground: &Color, background: &Color) -> f32 {
+        (**self).contrast(foreground, background)
+    }
+
+    fn default_threshold(&self) -> f32 {
+        (**self).default_threshold()
+    }
+
+    fn passes(&self, contrast: f32, threshold: f32) -> bool {
+        (**self).passes(contrast, threshold)
+    }
+
+    fn contrast_matrix(&self, colors: &[Color]) -> Vec<Vec<f32>> {
+        (**self).contrast_matrix(colors)
+    }
+}
+
+impl<A: ContrastAlgorithm + ?Sized> ContrastAlgorithm for Box<A> {
+    fn name(&self) -> &'static str {
+        (**self).name()
+    }
+
+    fn contrast(&self, foreground: &Color, background: &Color) -> f32 {
+        (**self).contrast(foreground, background)
+    }
+
+    fn default_threshold(&self) -> f32 {
+        (**self).default_threshold()
+    }
+
+    fn passes(&self, contrast: f32, threshold: f32) -> bool {
+        (**self).passes(contrast, threshold)
+    }
+
+    fn contrast_matrix(&self, colors: &[Color]) -> Vec<Vec<f32>> {
+        (**self).contrast_matrix(colors)
+    }
+}
+
+/// The WCAG 2 contrast ratio of [`contrast_ratio`], from 1 to 21.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Wcag2;
+
+impl ContrastAlgorithm for Wcag2 {
+    fn name(&self) -> &'static str {
+        "wcag2"
+    }
+
+    fn contrast(&self, foreground: &Color, background: &Color) -> f32 {
+        contrast_ratio(foreground, background)
+    }
+
+    /// 4.5:1, AA for normal text.
+    fn default_threshold(&self) -> f32 {
+        4.5
+    }
+
+    fn contrast_matrix(&self, colors: &[Color]) -> Vec<Vec<f32>> {
+        contrast_matrix(colors)
+    }
+}
+
+/// The APCA lightness contrast (Lc) of [`apca_contrast`], positive for dark text on a light
+/// background and negative the other way around.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Apca;
+
+impl ContrastAlgorithm for Apca {
+    fn name(&self) -> &'static str {
+        "apca"
+    }
+
+    fn contrast(&self, foreground: &Color, background: &Color) -> f32 {
+        apca_contrast(foreground, background)
+    }
+
+    /// Lc 60, the minimum the APCA guidelines give for content text.
+    fn default_threshold(&self) -> f32 {
+        60.0
+    }
+
+    fn passes(&self, contrast: f32, threshold: f32) -> bool {
+        contrast.abs() >= threshold
+    }
+}
+
+/// The difference of the relative luminances, from 0 to 1. Simple, but it ignores that the eye
+/// tells dark colors apart better than light ones, so it has no standard threshold: the default
+/// of 0.5 is only a starting point.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct LuminanceDifference;
+
+impl ContrastAlgorithm for LuminanceDifference {
+    fn name(&self) -> &'static str {
+        "luminance-difference"
+    }
+
+    fn contrast(&self, foreground: &Color, background: &Color) -> f32 {
+        let (foreground, background) = visible_colors(foreground, background);
+        (relative_luminance(&foreground) - relative_luminance(&background)).abs()
+    }
+
+    fn default_threshold(&self) -> f32 {
+        0.5
+    }
+}
+
+/// Weber contrast, the luminance difference relative to the darker color, with the same 0.05
+/// flare WCAG adds for ambient light. It's the WCAG 2 ratio minus 1, which WCAG is built on.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Weber;
+
+impl ContrastAlgorithm for Weber {
+    fn name(&self) -> &'static str {
+        "weber"
+    }
+
+    fn contrast(&self, foreground: &Color, background: &Color) -> f32 {
+        let (foreground, background) = visible_colors(foreground, background);
+        let (foreground, background) = (
+            relative_luminance(&foreground),
+            relative_luminance(&background),
+        );
+
+        (foreground.max(background) - foreground.min(background))
+            / (foreground.min(background) + 0.05)
+    }
+
+    /// 3.5, the same bar as the 4.5:1 of AA for normal text.
+    fn default_threshold(&self) -> f32 {
+        3.5
+    }
+}
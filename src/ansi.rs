@@ -0,0 +1,116 @@
+#[cfg(not(any(feature = "std", test)))]
+use crate::math::Float;
+use crate::Color;
+
+/// The 16 ANSI colors as xterm shows them by default. Terminal themes usually change them, so
+/// contrast computed with these is only as accurate as the theme is close to xterm's.
+const ANSI16: [[u8; 3]; 16] = [
+    [0, 0, 0],
+    [205, 0, 0],
+    [0, 205, 0],
+    [205, 205, 0],
+    [0, 0, 238],
+    [205, 0, 205],
+    [0, 205, 205],
+    [229, 229, 229],
+    [127, 127, 127],
+    [255, 0, 0],
+    [0, 255, 0],
+    [255, 255, 0],
+    [92, 92, 255],
+    [255, 0, 255],
+    [0, 255, 255],
+    [255, 255, 255],
+];
+
+/// Channel values of the 6x6x6 color cube, indices 16 to 231.
+const CUBE_LEVELS: [u8; 6] = [0, 95, 135, 175, 215, 255];
+
+/// Index of the palette color closest to `color`, with the distance measured in OKLab so the pick
+/// is the one that looks closest rather than the closest channel values.
+fn nearest(color: &Color, indices: impl Iterator<Item = u8>) -> u8 {
+    let (lightness, a, b) = color.to_oklab();
+    let distance = |index: &u8| {
+        let (other_lightness, other_a, other_b) = Color::from_ansi256(*index).to_oklab();
+        (lightness - other_lightness).powi(2) + (a - other_a).powi(2) + (b - other_b).powi(2)
+    };
+
+    indices
+        .min_by(|first, second| distance(first).total_cmp(&distance(second)))
+        .unwrap_or_default()
+}
+
+impl Color {
+    /// The color of an xterm 256 color palette index: the 16 ANSI colors (with xterm's default
+    /// values), then a 6x6x6 color cube and 24 grays.
+    ///
+    /// ```
+    /// use contrast_checker::Color;
+    ///
+    /// assert_eq!(Color::from_ansi256(196), Color::new(255, 0, 0));
+    /// assert_eq!(Color::from_ansi256(244), Color::new(128, 128, 128));
+    /// ```
+    pub fn from_ansi256(index: u8) -> Color {
+        match index {
+            0..=15 => {
+                let [red, green, blue] = ANSI16[usize::from(index)];
+                Color::new(red, green, blue)
+            }
+            16..=231 => {
+                let cube = usize::from(index - 16);
+                Color::new(
+                    CUBE_LEVELS[cube / 36],
+                    CUBE_LEVELS[cube / 6 % 6],
+                    CUBE_LEVELS[cube % 6],
+                )
+            }
+            _ => {
+                let gray = 8 + (index - 232) * 10;
+                Color::new(gray, gray, gray)
+            }
+        }
+    }
+
+    /// The closest color of the xterm 256 color palette, for TUIs that can't use true color. Only
+    /// the color cube and the grays (16 to 255) are considered, the first 16 colors depend on
+    /// the terminal theme. Alpha is ignored.
+    ///
+    /// ```
+    /// use contrast_checker::Color;
+    ///
+    /// let pink = Color::from_hex("#f26ca7").unwrap();
+    /// assert_eq!(pink.to_ansi256(), 205);
+    /// assert_eq!(Color::from_ansi256(205).to_hex(), "#ff5faf");
+    /// ```
+    pub fn to_ansi256(&self) -> u8 {
+        nearest(self, 16..=255)
+    }
+
+    /// The closest of the 16 ANSI colors, as xterm shows them by default, see
+    /// [`Color::from_ansi256`]. Alpha is ignored.
+    ///
+    /// ```
+    /// use contrast_checker::Color;
+    ///
+    /// assert_eq!(Color::new(250, 20, 10).to_ansi16(), 9);
+    /// assert_eq!(Color::new(0x77, 0x77, 0x77).to_ansi16(), 8);
+    /// ```
+    pub fn to_ansi16(&self) -> u8 {
+        nearest(self, 0..=15)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn palette_colors_map_to_themselves() {
+        for index in 16..=255 {
+            assert_eq!(Color::from_ansi256(index).to_ansi256(), index);
+        }
+        for index in 0..16 {
+            assert_eq!(Color::from_ansi256(index).to_ansi16(), index);
+        }
+    }
+}
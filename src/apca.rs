@@ -0,0 +1,111 @@
+use crate::contrast::visible_colors;
+#[cfg(not(any(feature = "std", test)))]
+use crate::math::Float;
+use crate::Color;
+
+// Constants for APCA-W3 0.0.98G-4g, obtained from https://github.com/Myndex/apca-w3
+const MAIN_TRC: f32 = 2.4;
+const RED_COEFFICIENT: f32 = 0.2126729;
+const GREEN_COEFFICIENT: f32 = 0.7151522;
+const BLUE_COEFFICIENT: f32 = 0.0721750;
+
+const NORMAL_BACKGROUND_EXPONENT: f32 = 0.56;
+const NORMAL_TEXT_EXPONENT: f32 = 0.57;
+const REVERSE_TEXT_EXPONENT: f32 = 0.62;
+const REVERSE_BACKGROUND_EXPONENT: f32 = 0.65;
+
+const BLACK_THRESHOLD: f32 = 0.022;
+const BLACK_CLAMP: f32 = 1.414;
+const SCALE: f32 = 1.14;
+const LOW_OFFSET: f32 = 0.027;
+const LOW_CLIP: f32 = 0.1;
+const DELTA_Y_MINIMUM: f32 = 0.0005;
+
+/// APCA uses a simple power curve instead of the piecewise sRGB transfer function, and soft
+/// clamps very dark colors to account for flare.
+fn screen_luminance(color: &Color) -> f32 {
+    let [red, green, blue] = color.normalized();
+    let luminance = RED_COEFFICIENT * red.powf(MAIN_TRC)
+        + GREEN_COEFFICIENT * green.powf(MAIN_TRC)
+        + BLUE_COEFFICIENT * blue.powf(MAIN_TRC);
+
+    if luminance < BLACK_THRESHOLD {
+        luminance + (BLACK_THRESHOLD - luminance).powf(BLACK_CLAMP)
+    } else {
+        luminance
+    }
+}
+
+/// Lightness contrast (Lc) of the APCA algorithm used by the WCAG 3 drafts.
+/// Formula obtained from https://github.com/Myndex/apca-w3
+///
+/// Unlike [`contrast_ratio`](crate::contrast_ratio) the order of the arguments matters. The result
+/// goes roughly from -108 to 106: it's positive for dark text on a light background and negative
+/// for light text on a dark background. Values close to 0 mean there's no usable contrast.
+/// Translucent colors are composited the same way [`contrast_ratio`](crate::contrast_ratio) does.
+pub fn apca_contrast(text: &Color, background: &Color) -> f32 {
+    let (text, background) = visible_colors(text, background);
+    let text_luminance = screen_luminance(&text);
+    let background_luminance = screen_luminance(&background);
+
+    if (background_luminance - text_luminance).abs() < DELTA_Y_MINIMUM {
+        return 0.0;
+    }
+
+    let contrast = if background_luminance > text_luminance {
+        let contrast = (background_luminance.powf(NORMAL_BACKGROUND_EXPONENT)
+            - text_luminance.powf(NORMAL_TEXT_EXPONENT))
+            * SCALE;
+
+        if contrast < LOW_CLIP {
+            0.0
+        } else {
+            contrast - LOW_OFFSET
+        }
+    } else {
+        let contrast = (background_luminance.powf(REVERSE_BACKGROUND_EXPONENT)
+            - text_luminance.powf(REVERSE_TEXT_EXPONENT))
+            * SCALE;
+
+        if contrast > -LOW_CLIP {
+            0.0
+        } else {
+            contrast + LOW_OFFSET
+        }
+    };
+
+    contrast * 100.0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_lc(text: &str, background: &str, expected: f32) {
+        let text = Color::from_hex(text).unwrap();
+        let background = Color::from_hex(background).unwrap();
+        let contrast = apca_contrast(&text, &background);
+        assert!(
+            (contrast - expected).abs() < 0.01,
+            "expected Lc {expected}, got {contrast}"
+        );
+    }
+
+    /// Values of the test suite of https://github.com/Myndex/apca-w3
+    #[test]
+    fn matches_the_reference_implementation() {
+        assert_lc("#888888", "#ffffff", 63.056);
+        assert_lc("#ffffff", "#888888", -68.541);
+        assert_lc("#000000", "#aaaaaa", 58.146);
+        assert_lc("#aaaaaa", "#000000", -56.241);
+        assert_lc("#112233", "#ddeeff", 91.668);
+        assert_lc("#ddeeff", "#112233", -93.068);
+    }
+
+    #[test]
+    fn close_colors_have_no_contrast() {
+        assert_lc("#777777", "#777777", 0.0);
+        // A difference of luminance below the low clip
+        assert_lc("#777777", "#7a7a7a", 0.0);
+    }
+}
@@ -0,0 +1,445 @@
+use core::fmt;
+use std::collections::HashMap;
+
+#[cfg(feature = "rayon")]
+use rayon::prelude::*;
+
+use crate::report::{BatchReport, PairResult, SeverityLevels};
+use crate::suggest::closest_lightness;
+use crate::{
+    split_colors_iter, Color, ContrastAlgorithm, ParseColorError, RoundingPolicy, TextSize, Wcag2,
+    WcagLevel,
+};
+
+/// A foreground and background pair as written in the input, before it's parsed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PairInput {
+    pub foreground: String,
+    pub background: String,
+    /// Line the pair was read from, if it came from text.
+    pub line: Option<usize>,
+    /// Fields written after the colors, like a component name or a ticket, which the results
+    /// carry through untouched.
+    pub metadata: Vec<String>,
+}
+
+/// A pair whose colors have been parsed and is ready to be checked.
+#[derive(Debug, Clone)]
+pub struct ResolvedPair {
+    pub input: PairInput,
+    pub foreground: Color,
+    pub background: Color,
+}
+
+/// A new color for a foreground that fails somewhere in an audit, see [`Audit::replacements`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct Replacement {
+    /// Every way the foreground is written in the report, like a token name or a literal.
+    pub inputs: Vec<String>,
+    pub original: Color,
+    /// The closest color by OKLCH lightness that passes on every background the original is used
+    /// on, `None` when no lightness does.
+    pub replacement: Option<Color>,
+    /// The backgrounds the original is used on in the report, the passing ones included.
+    pub backgrounds: Vec<Color>,
+}
+
+/// Which color of a pair an error refers to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PairRole {
+    Foreground,
+    Background,
+}
+
+/// The fields of the metadata written after the colors of a line, separated by whitespace, commas
+/// or semicolons unless they're quoted. A quote that isn't closed runs to the end of the line.
+fn metadata_fields(text: &str) -> Vec<String> {
+    let mut fields = Vec::new();
+    let mut chars = text.chars().peekable();
+    while let Some(c) = chars.next() {
+        if c.is_whitespace() || c == ',' || c == ';' {
+            continue;
+        }
+
+        let mut field = String::new();
+        if c == '"' {
+            while let Some(c) = chars.next() {
+                match c {
+                    '"' if chars.next_if_eq(&'"').is_some() => field.push('"'),
+                    '"' => break,
+                    c => field.push(c),
+                }
+            }
+        } else {
+            field.push(c);
+            while let Some(c) = chars.next_if(|&c| !(c.is_whitespace() || c == ',' || c == ';')) {
+                field.push(c);
+            }
+        }
+        fields.push(field);
+    }
+
+    fields
+}
+
+/// Errors returned while reading or resolving the inputs of an [`Audit`].
+#[derive(Debug)]
+pub enum AuditError {
+    /// A line didn't contain two colors.
+    MalformedLine { line: usize },
+    InvalidColor {
+        line: Option<usize>,
+        role: PairRole,
+        input: String,
+        error: ParseColorError,
+    },
+}
+
+impl fmt::Display for AuditError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AuditError::MalformedLine { line } => {
+                write!(
+                    f,
+                    "line {line}: expected a foreground and a background color"
+                )
+            }
+            AuditError::InvalidColor {
+                line, role, error, ..
+            } => {
+                if let Some(line) = line {
+                    write!(f, "line {line}: ")?;
+                }
+
+                let role = match role {
+                    PairRole::Foreground => "foreground",
+                    PairRole::Background => "background",
+                };
+                // The error already quotes the input
+                write!(f, "invalid {role} color {error}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for AuditError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            AuditError::InvalidColor { error, .. } => Some(error),
+            AuditError::MalformedLine { .. } => None,
+        }
+    }
+}
+
+/// Checks a set of pairs against a threshold: inputs are resolved into colors, every pair is
+/// checked and the results are collected into a [`BatchReport`] that any
+/// [`ReportWriter`](crate::report::ReportWriter) can render.
+///
+/// ```
+/// use contrast_checker::audit::Audit;
+///
+/// let report = Audit::new()
+///     .threshold(4.5)
+///     .pair("#777", "white")
+///     .pairs_from_lines("tomato black\nrgb(0 0 0) #fff")
+///     .unwrap()
+///     .run()
+///     .unwrap();
+///
+/// assert_eq!(report.results.len(), 3);
+/// assert_eq!(report.failures(), 1);
+/// ```
+///
+/// Pairs are checked with the WCAG 2 ratio unless another [`ContrastAlgorithm`] is picked with
+/// [`Audit::algorithm`].
+#[derive(Debug, Clone)]
+pub struct Audit<A = Wcag2> {
+    threshold: f32,
+    rounding: RoundingPolicy,
+    algorithm: A,
+    severities: Option<SeverityLevels>,
+    inputs: Vec<PairInput>,
+}
+
+impl Default for Audit {
+    fn default() -> Self {
+        Audit::new()
+    }
+}
+
+impl Audit {
+    /// Creates an audit without pairs that requires AA for normal text, comparing unrounded
+    /// ratios.
+    pub fn new() -> Audit {
+        Audit {
+            threshold: WcagLevel::AA.minimum_ratio(TextSize::Normal),
+            rounding: RoundingPolicy::Exact,
+            algorithm: Wcag2,
+            severities: None,
+            inputs: Vec::new(),
+        }
+    }
+}
+
+impl<A: ContrastAlgorithm> Audit<A> {
+    /// Measures contrast with another algorithm. The threshold becomes the default of the
+    /// algorithm, call [`Audit::threshold`] after this one to change it.
+    pub fn algorithm<B: ContrastAlgorithm>(self, algorithm: B) -> Audit<B> {
+        Audit {
+            threshold: algorithm.default_threshold(),
+            rounding: self.rounding,
+            algorithm,
+            severities: self.severities,
+            inputs: self.inputs,
+        }
+    }
+
+    /// Sets the contrast every pair has to reach, a ratio unless another algorithm is used.
+    pub fn threshold(mut self, threshold: f32) -> Audit<A> {
+        self.threshold = threshold;
+        self
+    }
+
+    /// Sets which ratio is compared against the threshold.
+    pub fn rounding(mut self, rounding: RoundingPolicy) -> Audit<A> {
+        self.rounding = rounding;
+        self
+    }
+
+    /// Classifies every pair with severity levels, which then decide which pairs fail the report,
+    /// see [`BatchReport::errors`].
+    pub fn severities(mut self, severities: SeverityLevels) -> Audit<A> {
+        self.severities = Some(severities);
+        self
+    }
+
+    /// Adds a pair written in any notation supported by [`Color::from_css`].
+    pub fn pair(
+        mut self,
+        foreground: impl Into<String>,
+        background: impl Into<String>,
+    ) -> Audit<A> {
+        self.inputs.push(PairInput {
+            foreground: foreground.into(),
+            background: background.into(),
+            line: None,
+            metadata: Vec::new(),
+        });
+        self
+    }
+
+    /// Adds one pair per line of `text`, with the colors separated like
+    /// [`split_colors`](crate::split_colors) expects. Empty lines are skipped. Anything after
+    /// the two colors, separated the same way, is the [`PairInput::metadata`] of the pair. Fields
+    /// in double quotes are taken as they are, separators included, with `""` for a quote like in
+    /// CSV:
+    ///
+    /// ```
+    /// use contrast_checker::audit::Audit;
+    ///
+    /// let audit = Audit::new()
+    ///     .pairs_from_lines("#777 white Button JIRA-12\n#777,white,\"Button, primary\",JIRA-1")
+    ///     .unwrap();
+    /// let report = audit.run().unwrap();
+    /// assert_eq!(report.results[0].metadata, ["Button", "JIRA-12"]);
+    /// assert_eq!(report.results[1].metadata, ["Button, primary", "JIRA-1"]);
+    /// ```
+    pub fn pairs_from_lines(mut self, text: &str) -> Result<Audit<A>, AuditError> {
+        for (index, line) in text.lines().enumerate() {
+            if line.trim().is_empty() {
+                continue;
+            }
+
+            let line_number = index + 1;
+            let mut colors = split_colors_iter(line);
+            let (Some(foreground), Some(background)) = (colors.next(), colors.next()) else {
+                return Err(AuditError::MalformedLine { line: line_number });
+            };
+
+            self.inputs.push(PairInput {
+                foreground: foreground.to_string(),
+                background: background.to_string(),
+                line: Some(line_number),
+                metadata: metadata_fields(colors.rest()),
+            });
+        }
+
+        Ok(self)
+    }
+
+    pub fn inputs(&self) -> &[PairInput] {
+        &self.inputs
+    }
+
+    /// Parses the colors of every pair, the error is always the one of the first invalid pair.
+    /// Every distinct spelling is only parsed once, compiled stylesheets and palettes repeat the
+    /// same few colors over thousands of pairs.
+    pub fn resolve(&self) -> Result<Vec<ResolvedPair>, AuditError> {
+        let mut parsed: HashMap<&str, Result<Color, ParseColorError>> = HashMap::new();
+        for input in &self.inputs {
+            for color in [&input.foreground, &input.background] {
+                parsed
+                    .entry(color.as_str())
+                    .or_insert_with(|| Color::from_css(color));
+            }
+        }
+
+        let resolve = |input: &PairInput| {
+            let parse = |role, color: &str| {
+                parsed[color]
+                    .clone()
+                    .map_err(|error| AuditError::InvalidColor {
+                        line: input.line,
+                        role,
+                        input: color.to_string(),
+                        error,
+                    })
+            };
+
+            Ok(ResolvedPair {
+                foreground: parse(PairRole::Foreground, &input.foreground)?,
+                background: parse(PairRole::Background, &input.background)?,
+                input: input.clone(),
+            })
+        };
+
+        // In parallel any pair can fail first, so every result is kept in order before picking
+        // the error
+        #[cfg(feature = "rayon")]
+        return self
+            .inputs
+            .par_iter()
+            .map(resolve)
+            .collect::<Vec<_>>()
+            .into_iter()
+            .collect();
+
+        #[cfg(not(feature = "rayon"))]
+        self.inputs.iter().map(resolve).collect()
+    }
+
+    /// Checks a single resolved pair with the configuration of the audit, for callers that
+    /// stream results instead of collecting a report, like every combination of two long lists.
+    ///
+    /// ```
+    /// use contrast_checker::audit::{Audit, PairInput, ResolvedPair};
+    /// use contrast_checker::Color;
+    ///
+    /// let pair = ResolvedPair {
+    ///     input: PairInput {
+    ///         foreground: "#777".into(),
+    ///         background: "white".into(),
+    ///         line: None,
+    ///         metadata: Vec::new(),
+    ///     },
+    ///     foreground: Color::new(0x77, 0x77, 0x77),
+    ///     background: Color::new(255, 255, 255),
+    /// };
+    /// let result = Audit::new().threshold(3.0).check_pair(pair);
+    ///
+    /// assert!(result.meets_threshold);
+    /// ```
+    pub fn check_pair(&self, pair: ResolvedPair) -> PairResult {
+        let mut result = PairResult::with_algorithm(
+            &pair.input.foreground,
+            &pair.input.background,
+            pair.foreground,
+            pair.background,
+            self.threshold,
+            self.rounding,
+            &self.algorithm,
+        );
+        if let Some(severities) = &self.severities {
+            let contrast = self.rounding.apply(result.contrast);
+            result.severity = severities.classify(contrast, &self.algorithm);
+        }
+        result.metadata = pair.input.metadata;
+
+        result
+    }
+
+    /// Checks already resolved pairs with the configuration of the audit. With the `rayon`
+    /// feature the pairs are checked in parallel, the results stay in the order of the pairs.
+    pub fn check(&self, pairs: Vec<ResolvedPair>) -> BatchReport {
+        let check = |pair| self.check_pair(pair);
+
+        #[cfg(feature = "rayon")]
+        let results = pairs.into_par_iter().map(check).collect();
+        #[cfg(not(feature = "rayon"))]
+        let results = pairs.into_iter().map(check).collect();
+
+        BatchReport {
+            threshold: self.threshold,
+            algorithm: self.algorithm.name().to_string(),
+            results,
+            severities: self.severities.clone(),
+        }
+    }
+
+    /// Resolves and checks every pair.
+    pub fn run(&self) -> Result<BatchReport, AuditError> {
+        Ok(self.check(self.resolve()?))
+    }
+
+    /// Proposes a new color for every foreground that fails in a report of this audit. A color
+    /// gets a single replacement however many pairs it's in, and however it's written, so
+    /// applying them keeps a palette consistent: the replacement has to pass on all the
+    /// backgrounds the color is used on, including the ones it already passes on.
+    ///
+    /// ```
+    /// use contrast_checker::audit::Audit;
+    ///
+    /// let audit = Audit::new().pairs_from_lines("#777 white\n#777777 #eee\nblack white").unwrap();
+    /// let report = audit.run().unwrap();
+    ///
+    /// let replacements = audit.replacements(&report);
+    /// assert_eq!(replacements.len(), 1);
+    /// assert_eq!(replacements[0].inputs, ["#777", "#777777"]);
+    /// // #777 passes neither, and the darker gray it needs on #eee works on white too
+    /// assert_eq!(replacements[0].replacement.unwrap().to_hex(), "#6c6c6c");
+    /// ```
+    pub fn replacements(&self, report: &BatchReport) -> Vec<Replacement> {
+        let mut replacements: Vec<Replacement> = Vec::new();
+        for result in report
+            .results
+            .iter()
+            .filter(|result| !result.meets_threshold)
+        {
+            if !replacements
+                .iter()
+                .any(|replacement| replacement.original == result.foreground)
+            {
+                replacements.push(Replacement {
+                    inputs: Vec::new(),
+                    original: result.foreground,
+                    replacement: None,
+                    backgrounds: Vec::new(),
+                });
+            }
+        }
+
+        for replacement in &mut replacements {
+            for result in &report.results {
+                if result.foreground != replacement.original {
+                    continue;
+                }
+                if !replacement.inputs.contains(&result.foreground_input) {
+                    replacement.inputs.push(result.foreground_input.clone());
+                }
+                if !replacement.backgrounds.contains(&result.background) {
+                    replacement.backgrounds.push(result.background);
+                }
+            }
+
+            replacement.replacement = closest_lightness(&replacement.original, |color| {
+                replacement.backgrounds.iter().all(|background| {
+                    let contrast = self.algorithm.contrast(color, background);
+                    self.algorithm
+                        .passes(self.rounding.apply(contrast), self.threshold)
+                })
+            });
+        }
+
+        replacements
+    }
+}
@@ -0,0 +1,392 @@
+pub mod axe;
+pub mod batch;
+#[cfg(feature = "blame")]
+pub mod blame;
+pub mod capabilities;
+pub mod check;
+#[cfg(feature = "clipboard")]
+pub mod clipboard;
+pub mod completions;
+pub mod component;
+pub mod config;
+pub mod convert;
+pub mod cross;
+pub mod diff;
+pub mod duplicates;
+pub mod filter;
+pub mod fix;
+pub mod flash;
+pub mod focus;
+pub mod generate;
+pub mod gradient;
+#[cfg(feature = "image")]
+pub mod image;
+pub mod interactive;
+pub mod link;
+#[cfg(feature = "screen")]
+pub mod live;
+pub mod matrix;
+#[cfg(feature = "cdp")]
+pub mod page;
+pub mod palette_file;
+pub mod pick;
+pub mod plugin_host;
+pub mod preview;
+pub mod range;
+pub mod replacements;
+pub mod rewrite;
+#[cfg(feature = "scripting")]
+pub mod rules;
+pub mod same_luminance;
+pub mod scale;
+pub mod scan;
+#[cfg(feature = "screen")]
+pub mod screen;
+#[cfg(feature = "image")]
+pub mod screenshot;
+#[cfg(feature = "server")]
+pub mod serve;
+#[cfg(feature = "server")]
+pub mod sessions;
+pub mod site;
+pub mod sort;
+pub mod suggest;
+pub mod surface;
+pub mod tokens;
+#[cfg(feature = "tray")]
+pub mod tray;
+pub mod trend;
+pub mod verbose;
+pub mod watch;
+
+use std::fmt;
+use std::fs;
+use std::io::{self, Read};
+use std::path::PathBuf;
+use std::sync::OnceLock;
+
+use clap::ValueEnum;
+use contrast_checker::palettes::PaletteRegistry;
+use contrast_checker::report::{CsvDialect, TableStyle, COMPLIANCE_COLUMNS};
+use contrast_checker::{
+    Apca, Color, ColorFormat, ComplianceReport, ContrastAlgorithm, ContrastContext, ExitStatus,
+    GamutMapping, LuminanceDifference, ParseColorError, RoundingPolicy, Wcag2, WcagLevel, Weber,
+};
+
+/// Why a command failed, with the status the tool exits with. Plain messages are invalid input.
+#[derive(Debug)]
+pub struct Error {
+    pub status: ExitStatus,
+    pub message: String,
+}
+
+impl Error {
+    pub fn new(status: ExitStatus, message: impl Into<String>) -> Error {
+        Error {
+            status,
+            message: message.into(),
+        }
+    }
+
+    /// A file or stream that couldn't be read or written.
+    pub fn io(message: impl Into<String>) -> Error {
+        Error::new(ExitStatus::Io, message)
+    }
+
+    /// An invalid configuration file.
+    pub fn config(message: impl Into<String>) -> Error {
+        Error::new(ExitStatus::Config, message)
+    }
+}
+
+impl From<String> for Error {
+    fn from(message: String) -> Self {
+        Error::new(ExitStatus::Parse, message)
+    }
+}
+
+impl From<&str> for Error {
+    fn from(message: &str) -> Self {
+        Error::new(ExitStatus::Parse, message)
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.message)
+    }
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+pub enum Rounding {
+    /// Compare the unrounded ratio, so 4.4956 fails AA even though it's displayed as 4.50
+    Exact,
+    /// Compare the ratio rounded to two decimals, the way it's displayed
+    TwoDecimals,
+}
+
+impl From<Rounding> for RoundingPolicy {
+    fn from(rounding: Rounding) -> Self {
+        match rounding {
+            Rounding::Exact => RoundingPolicy::Exact,
+            Rounding::TwoDecimals => RoundingPolicy::TwoDecimals,
+        }
+    }
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+pub enum Style {
+    /// A column of pass and fail for every level
+    Columns,
+    /// A line of ✅ AA and ❌ AAA badges for every pair, for commit statuses and narrow terminals
+    Badges,
+}
+
+impl Style {
+    /// The style of the table, `--ascii` swaps the emoji of the badges for [PASS] and [FAIL].
+    pub fn table_style(self, ascii: bool) -> TableStyle {
+        match (self, ascii) {
+            (Style::Columns, _) => TableStyle::Columns,
+            (Style::Badges, false) => TableStyle::Badges,
+            (Style::Badges, true) => TableStyle::AsciiBadges,
+        }
+    }
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+pub enum Algorithm {
+    /// The WCAG 2 contrast ratio, from 1 to 21, 4.5 by default
+    Wcag2,
+    /// The APCA lightness contrast (Lc) of the WCAG 3 drafts, compared by magnitude, 60 by default
+    Apca,
+    /// The difference of the relative luminances, from 0 to 1, 0.5 by default
+    LuminanceDifference,
+    /// Weber contrast with the WCAG flare, the WCAG 2 ratio minus 1, 3.5 by default
+    Weber,
+}
+
+impl Algorithm {
+    pub fn boxed(self) -> Box<dyn ContrastAlgorithm> {
+        match self {
+            Algorithm::Wcag2 => Box::new(Wcag2),
+            Algorithm::Apca => Box::new(Apca),
+            Algorithm::LuminanceDifference => Box::new(LuminanceDifference),
+            Algorithm::Weber => Box::new(Weber),
+        }
+    }
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+pub enum Context {
+    /// Normal text, SC 1.4.3 and 1.4.6 with 4.5:1 for AA and 7:1 for AAA
+    Text,
+    /// Text of at least 18pt or 14pt bold, SC 1.4.3 and 1.4.6 with 3:1 for AA and 4.5:1 for AAA
+    LargeText,
+    /// Graphical objects and UI component boundaries, SC 1.4.11 with 3:1 for AA
+    Ui,
+}
+
+impl From<Context> for ContrastContext {
+    fn from(context: Context) -> Self {
+        match context {
+            Context::Text => ContrastContext::Text,
+            Context::LargeText => ContrastContext::LargeText,
+            Context::Ui => ContrastContext::Ui,
+        }
+    }
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+pub enum Gamut {
+    /// Reduce the chroma until the color fits in sRGB, keeping lightness and hue like CSS does
+    ReduceChroma,
+    /// Clamp every channel to the sRGB range
+    Clip,
+}
+
+impl From<Gamut> for GamutMapping {
+    fn from(gamut: Gamut) -> Self {
+        match gamut {
+            Gamut::ReduceChroma => GamutMapping::ReduceChroma,
+            Gamut::Clip => GamutMapping::Clip,
+        }
+    }
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+pub enum Notation {
+    /// #f26ca7
+    Hex,
+    /// rgb(242 108 167)
+    Rgb,
+    /// hsl(333.58 83.75% 68.63%)
+    Hsl,
+    /// oklch(0.70967 0.17512 354.927)
+    Oklch,
+}
+
+impl From<Notation> for ColorFormat {
+    fn from(notation: Notation) -> Self {
+        match notation {
+            Notation::Hex => ColorFormat::Hex,
+            Notation::Rgb => ColorFormat::Rgb,
+            Notation::Hsl => ColorFormat::Hsl,
+            Notation::Oklch => ColorFormat::Oklch,
+        }
+    }
+}
+
+/// The threshold given to `--require`: a WCAG level, which means its normal text ratio, or a
+/// plain ratio like 3.
+#[derive(Clone, Copy)]
+pub enum Requirement {
+    Level(WcagLevel),
+    Ratio(f32),
+}
+
+impl Requirement {
+    /// The ratio needed in a context, levels without a requirement for it are an error.
+    pub fn minimum_ratio(self, context: ContrastContext) -> Result<f32, String> {
+        match self {
+            Requirement::Level(level) => context.minimum_ratio(level).ok_or_else(|| {
+                format!(
+                    "{level:?} has no contrast requirement for {}",
+                    context.name()
+                )
+            }),
+            Requirement::Ratio(ratio) => Ok(ratio),
+        }
+    }
+}
+
+pub fn parse_requirement(input: &str) -> Result<Requirement, String> {
+    match input.to_ascii_uppercase().as_str() {
+        "AA" => Ok(Requirement::Level(WcagLevel::AA)),
+        "AAA" => Ok(Requirement::Level(WcagLevel::AAA)),
+        _ => match input.parse::<f32>() {
+            Ok(ratio) if (1.0..=21.0).contains(&ratio) => Ok(Requirement::Ratio(ratio)),
+            _ => Err("expected AA, AAA or a ratio between 1 and 21".to_string()),
+        },
+    }
+}
+
+fn describe_error(description: &str, err: ParseColorError) -> Error {
+    format!("invalid {description} {err}\n  hint: {}", err.hint()).into()
+}
+
+static PALETTES: OnceLock<PaletteRegistry> = OnceLock::new();
+
+/// Sets the palettes `palette:color` arguments are looked up in, once at startup.
+pub fn set_palettes(registry: PaletteRegistry) {
+    // Only fails when they're already set, which never happens in the binary
+    let _ = PALETTES.set(registry);
+}
+
+pub fn palettes() -> &'static PaletteRegistry {
+    PALETTES.get_or_init(PaletteRegistry::new)
+}
+
+/// Looks up references like `tailwind:sky-500`, `None` when the input isn't one. CSS colors
+/// never have a colon, so there's no ambiguity.
+fn palette_color(description: &str, input: &str) -> Option<Result<Color, String>> {
+    let (palette, color) = input.trim().split_once(':')?;
+
+    Some(match palettes().palette(palette.trim()) {
+        Some(found) => found.get(color.trim()).ok_or_else(|| {
+            format!("invalid {description} {input:?}: palette {palette} has no color {color}")
+        }),
+        None => {
+            let hint = if cfg!(feature = "palettes") {
+                "the built-in palettes are tailwind, material, open-color and xkcd"
+            } else {
+                "build with the palettes feature for tailwind, material, open-color and xkcd"
+            };
+            Err(format!(
+                "invalid {description} {input:?}: unknown palette {palette}\n  hint: {hint}"
+            ))
+        }
+    })
+}
+
+/// Parses a color argument with [`contrast_checker::parse_color`], `description` says what the
+/// color is for in the error message. References to palettes like `tailwind:sky-500` work too.
+pub fn parse_color(description: &str, input: &str) -> Result<Color, Error> {
+    if let Some(color) = palette_color(description, input) {
+        return color.map_err(Error::from);
+    }
+
+    contrast_checker::parse_color(input).map_err(|err| describe_error(description, err))
+}
+
+/// Same as [`parse_color`] with a choice of how `color(display-p3 ...)` colors are mapped.
+pub fn parse_color_with(
+    description: &str,
+    input: &str,
+    mapping: GamutMapping,
+) -> Result<Color, Error> {
+    if let Some(color) = palette_color(description, input) {
+        return color.map_err(Error::from);
+    }
+
+    Color::from_css_with(input, mapping).map_err(|err| describe_error(description, err))
+}
+
+pub fn print_compliance(report: &ComplianceReport) {
+    for (name, level, size) in COMPLIANCE_COLUMNS {
+        let verdict = if report.passes(level, size) {
+            "pass"
+        } else {
+            "fail"
+        };
+        println!(
+            "{name:<16} {verdict} (needs {}:1)",
+            level.minimum_ratio(size)
+        );
+    }
+}
+
+/// Prints the verdict of every level for a context, along with the success criterion it comes
+/// from.
+pub fn print_context_compliance(report: &ComplianceReport, context: ContrastContext) {
+    println!("context: {}", context.name());
+    for level in [WcagLevel::AA, WcagLevel::AAA] {
+        let name = format!("{level:?}");
+        match (
+            report.passes_in(level, context),
+            context.minimum_ratio(level),
+            context.criterion(level),
+        ) {
+            (Some(passes), Some(ratio), Some(criterion)) => println!(
+                "{name:<4} {} (needs {ratio}:1, SC {} {})",
+                if passes { "pass" } else { "fail" },
+                criterion.number,
+                criterion.name
+            ),
+            _ => println!("{name:<4} no requirement for {}", context.name()),
+        }
+    }
+}
+
+/// The dialect of CSV output, `--decimal-comma` is an error with the other formats.
+pub fn csv_dialect(decimal_comma: bool, is_csv: bool) -> Result<CsvDialect, Error> {
+    match (decimal_comma, is_csv) {
+        (false, _) => Ok(CsvDialect::RFC_4180),
+        (true, true) => Ok(CsvDialect::DECIMAL_COMMA),
+        (true, false) => Err("--decimal-comma only applies to --format csv".into()),
+    }
+}
+
+/// Reads a whole input file, or stdin when there's no path or it's "-".
+pub fn read_input(input: Option<&PathBuf>) -> Result<String, Error> {
+    match input {
+        Some(path) if path.as_os_str() != "-" => fs::read_to_string(path)
+            .map_err(|err| Error::io(format!("could not read {}: {err}", path.display()))),
+        _ => {
+            let mut contents = String::new();
+            io::stdin()
+                .read_to_string(&mut contents)
+                .map_err(|err| Error::io(format!("could not read stdin: {err}")))?;
+            Ok(contents)
+        }
+    }
+}
@@ -0,0 +1,289 @@
+//! `axe`, the color contrast results of [axe-core](https://github.com/dequelabs/axe-core) checked
+//! again with this crate: the WCAG 2 ratio of every node, and its APCA contrast, next to what axe
+//! decided.
+
+use std::path::PathBuf;
+use std::process::ExitCode;
+
+use clap::{Args, ValueEnum};
+use contrast_checker::report::escape_json;
+use contrast_checker::{apca_contrast, check, Color};
+use serde_json::Value;
+
+use super::{read_input, Error};
+
+#[derive(Args)]
+pub struct AxeArgs {
+    /// JSON results of axe-core, the object axe.run() resolves with or the array of them the axe
+    /// CLI writes. "-" reads from stdin
+    input: PathBuf,
+
+    /// APCA contrast (Lc) normal text has to reach
+    #[arg(long, default_value_t = 60.0)]
+    apca: f32,
+
+    /// APCA contrast large text has to reach, 24px or 18.66px and bold
+    #[arg(long, default_value_t = 45.0)]
+    apca_large: f32,
+
+    /// Also list the nodes everything agrees on
+    #[arg(long)]
+    all: bool,
+
+    /// How the reconciled results are printed
+    #[arg(long, value_enum, default_value_t = AxeFormat::Text)]
+    format: AxeFormat,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, ValueEnum)]
+enum AxeFormat {
+    /// A line per node and the disagreements under it
+    Text,
+    /// A JSON object with every node
+    Json,
+}
+
+/// The rules of axe that check the contrast of text, AA and AAA.
+const RULES: [&str; 2] = ["color-contrast", "color-contrast-enhanced"];
+
+/// A node axe checked the contrast of, and what this crate makes of it.
+struct Node {
+    target: String,
+    foreground: Color,
+    background: Color,
+    /// Whether axe reported the node as a violation or a pass.
+    axe_passes: bool,
+    axe_ratio: f32,
+    expected: f32,
+    large: bool,
+    ratio: f32,
+    apca: f32,
+    apca_threshold: f32,
+}
+
+impl Node {
+    fn passes(&self) -> bool {
+        self.ratio >= self.expected
+    }
+
+    fn apca_passes(&self) -> bool {
+        self.apca.abs() >= self.apca_threshold
+    }
+
+    /// What doesn't agree about the node: the verdict of axe, its ratio beyond the rounding to
+    /// two decimals it reports, and the verdict of APCA.
+    fn disagreements(&self) -> Vec<String> {
+        let verdict = |passes| if passes { "passes" } else { "fails" };
+        let mut disagreements = Vec::new();
+        if self.passes() != self.axe_passes {
+            disagreements.push(format!(
+                "axe says it {}, it {} {}:1",
+                verdict(self.axe_passes),
+                verdict(self.passes()),
+                self.expected
+            ));
+        }
+        if (self.ratio - self.axe_ratio).abs() > 0.01 {
+            disagreements.push(format!(
+                "axe computed {:.2}:1 instead of {:.2}:1",
+                self.axe_ratio, self.ratio
+            ));
+        }
+        if self.apca_passes() != self.passes() {
+            disagreements.push(format!(
+                "APCA {} Lc {}, Lc {:.1}",
+                verdict(self.apca_passes()),
+                self.apca_threshold,
+                self.apca
+            ));
+        }
+
+        disagreements
+    }
+}
+
+/// Reads size like axe writes it, `12.0pt (16px)`, in pixels.
+fn font_size(data: &Value) -> Option<f32> {
+    let size = data["fontSize"].as_str()?;
+    let pixels = size.split_once('(')?.1.strip_suffix("px)")?;
+    pixels.trim().parse().ok()
+}
+
+/// The nodes of a result of axe checked by the contrast rules, with whether axe passed them.
+/// Incomplete nodes, whose background axe couldn't tell, are counted instead.
+fn nodes(args: &AxeArgs, result: &Value, nodes: &mut Vec<Node>, incomplete: &mut usize) {
+    let url = result["url"].as_str();
+    let contrast_rules = |group: &str| {
+        result[group]
+            .as_array()
+            .into_iter()
+            .flatten()
+            .filter(|rule| RULES.contains(&rule["id"].as_str().unwrap_or("")))
+            .collect::<Vec<_>>()
+    };
+    for rule in contrast_rules("incomplete") {
+        *incomplete += rule["nodes"].as_array().map_or(0, Vec::len);
+    }
+
+    for (group, axe_passes) in [("violations", false), ("passes", true)] {
+        for rule in contrast_rules(group) {
+            for node in rule["nodes"].as_array().into_iter().flatten() {
+                let checks = node["any"].as_array().into_iter().flatten();
+                let Some(data) = checks
+                    .filter(|check| RULES.contains(&check["id"].as_str().unwrap_or("")))
+                    .map(|check| &check["data"])
+                    .next()
+                else {
+                    continue;
+                };
+
+                let colors = (
+                    data["fgColor"].as_str().map(contrast_checker::parse_color),
+                    data["bgColor"].as_str().map(contrast_checker::parse_color),
+                );
+                let (Some(Ok(foreground)), Some(Ok(background))) = colors else {
+                    *incomplete += 1;
+                    continue;
+                };
+
+                let target = node["target"]
+                    .as_array()
+                    .into_iter()
+                    .flatten()
+                    .filter_map(Value::as_str)
+                    .collect::<Vec<_>>()
+                    .join(" ");
+                let target = match url {
+                    Some(url) => format!("{url} {target}"),
+                    None => target,
+                };
+                let size = font_size(data).unwrap_or(16.0);
+                let bold = data["fontWeight"].as_str().is_some_and(|weight| {
+                    weight == "bold" || weight.parse::<u32>().is_ok_and(|weight| weight >= 700)
+                });
+                let large = size >= 24.0 || (size >= 18.66 && bold);
+                let expected = data["expectedContrastRatio"]
+                    .as_str()
+                    .and_then(|ratio| ratio.strip_suffix(":1"))
+                    .and_then(|ratio| ratio.parse().ok())
+                    .unwrap_or(if large { 3.0 } else { 4.5 });
+
+                let report = check(&foreground, &background);
+                nodes.push(Node {
+                    target,
+                    foreground,
+                    background,
+                    axe_passes,
+                    axe_ratio: data["contrastRatio"].as_f64().unwrap_or(0.0) as f32,
+                    expected,
+                    large,
+                    ratio: report.ratio,
+                    apca: apca_contrast(&foreground, &background),
+                    apca_threshold: if large { args.apca_large } else { args.apca },
+                });
+            }
+        }
+    }
+}
+
+fn print_text(args: &AxeArgs, nodes: &[Node], incomplete: usize) {
+    let mut disagreeing = 0;
+    for node in nodes {
+        let disagreements = node.disagreements();
+        disagreeing += usize::from(!disagreements.is_empty());
+        if disagreements.is_empty() && !args.all {
+            continue;
+        }
+
+        println!(
+            "{}: {} on {} is {:.2}:1, Lc {:.1}{}",
+            node.target,
+            node.foreground.to_hex(),
+            node.background.to_hex(),
+            node.ratio,
+            node.apca,
+            if node.large { ", large text" } else { "" }
+        );
+        for disagreement in disagreements {
+            println!("  {disagreement}");
+        }
+    }
+
+    if disagreeing > 0 || args.all {
+        println!();
+    }
+    println!(
+        "{disagreeing} of {} nodes disagree with axe or APCA",
+        nodes.len()
+    );
+    if incomplete > 0 {
+        println!("{incomplete} incomplete nodes skipped, axe couldn't tell their background");
+    }
+}
+
+fn print_json(nodes: &[Node], incomplete: usize) {
+    let nodes: Vec<String> = nodes
+        .iter()
+        .map(|node| {
+            let disagreements: Vec<String> = node
+                .disagreements()
+                .iter()
+                .map(|disagreement| format!("\"{}\"", escape_json(disagreement)))
+                .collect();
+            format!(
+                "{{\"target\":\"{}\",\"foreground\":\"{}\",\"background\":\"{}\",\"large\":{},\
+                 \"axe\":{{\"passes\":{},\"ratio\":{},\"expected\":{}}},\"ratio\":{:.2},\
+                 \"passes\":{},\"apca\":{:.1},\"apca_passes\":{},\"disagreements\":[{}]}}",
+                escape_json(&node.target),
+                node.foreground.to_hex(),
+                node.background.to_hex(),
+                node.large,
+                node.axe_passes,
+                node.axe_ratio,
+                node.expected,
+                node.ratio,
+                node.passes(),
+                node.apca,
+                node.apca_passes(),
+                disagreements.join(",")
+            )
+        })
+        .collect();
+    println!(
+        "{{\"nodes\":[{}],\"incomplete\":{incomplete}}}",
+        nodes.join(",")
+    );
+}
+
+pub fn run(args: &AxeArgs) -> Result<ExitCode, Error> {
+    let contents = read_input(Some(&args.input))?;
+    let results: Value =
+        serde_json::from_str(&contents).map_err(|err| format!("invalid axe results, {err}"))?;
+    let results = match results {
+        Value::Array(results) => results,
+        result => vec![result],
+    };
+
+    let mut found = Vec::new();
+    let mut incomplete = 0;
+    for result in &results {
+        nodes(args, result, &mut found, &mut incomplete);
+    }
+    if found.is_empty() && incomplete == 0 {
+        return Err("the results have no color-contrast nodes"
+            .to_string()
+            .into());
+    }
+
+    match args.format {
+        AxeFormat::Text => print_text(args, &found, incomplete),
+        AxeFormat::Json => print_json(&found, incomplete),
+    }
+
+    let disagree = found.iter().any(|node| !node.disagreements().is_empty());
+    Ok(if disagree {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    })
+}
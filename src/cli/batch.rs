@@ -0,0 +1,238 @@
+use std::io;
+use std::path::PathBuf;
+use std::process::ExitCode;
+
+use clap::{Args, ValueEnum};
+use contrast_checker::audit::Audit;
+use contrast_checker::report::{
+    BatchReport, CsvDialect, CsvWriter, HtmlWriter, JsonWriter, PdfWriter, ReportWriter,
+    ScoreWeights, SpreadsheetFormat, SpreadsheetWriter, TableStyle, TableWriter, TsvWriter,
+};
+use contrast_checker::ContrastAlgorithm;
+
+use super::config::Config;
+use super::{csv_dialect, read_input, replacements, trend, Algorithm, Error, Rounding, Style};
+
+#[derive(Args)]
+pub struct BatchArgs {
+    /// Files with one foreground and background pair per line, in any of the supported notations.
+    /// Reads from stdin when missing or "-". Spreadsheets get a sheet for each
+    inputs: Vec<PathBuf>,
+
+    /// Contrast every pair has to reach, the ratio of the configuration, 4.5:1 (AA for normal
+    /// text) or the default of the algorithm
+    #[arg(long)]
+    ratio: Option<f32>,
+
+    /// How contrast is measured, the WCAG 2 verdicts are reported either way
+    #[arg(long, value_enum, default_value_t = Algorithm::Wcag2)]
+    algorithm: Algorithm,
+
+    /// Which ratio is compared against the thresholds
+    #[arg(long, value_enum, default_value_t = Rounding::Exact)]
+    rounding: Rounding,
+
+    /// How results are printed
+    #[arg(long, value_enum, default_value_t = BatchFormat::Table)]
+    format: BatchFormat,
+
+    /// How the table shows the verdicts
+    #[arg(long, value_enum, default_value_t = Style::Columns)]
+    style: Style,
+
+    /// Show the badges as [PASS] and [FAIL] instead of emoji
+    #[arg(long)]
+    ascii: bool,
+
+    /// With --format csv, separate the fields with ";" and write decimals with a comma, for
+    /// spreadsheets in locales like German or French
+    #[arg(long)]
+    decimal_comma: bool,
+
+    /// Also write a replacement for every failing foreground to this file, as CSS custom
+    /// properties when it ends in .css and as a JSON object of old to new colors otherwise
+    #[arg(long, value_name = "FILE")]
+    export_fixes: Option<PathBuf>,
+
+    /// Also print the score of all the pairs, from 0 to 100, weighted by the [score] table of the
+    /// configuration. It goes to stderr unless the results are a table
+    #[arg(long)]
+    score: bool,
+
+    /// Append the score and the failures of the run to a history file, which trend reads
+    #[arg(long, value_name = "HISTORY", num_args = 0..=1, default_missing_value = trend::DEFAULT_HISTORY)]
+    record: Option<PathBuf>,
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+pub enum BatchFormat {
+    /// Aligned columns for reading in a terminal
+    Table,
+    /// Comma separated values with a header row, for spreadsheets
+    Csv,
+    /// Tab separated values with a header row
+    Tsv,
+    /// A single JSON object with every result
+    Json,
+    /// A standalone HTML page with rendered samples and pass/fail badges
+    Html,
+    /// A paginated PDF with a summary, rendered samples, badges and hints, redirect it to a file
+    Pdf,
+    /// An Excel workbook with a sheet per input, verdicts are booleans and ratios numbers
+    Xlsx,
+    /// An OpenDocument spreadsheet like the Excel workbook, for LibreOffice
+    Ods,
+}
+
+/// The name of the sheet of an input, the file name without its extension.
+pub fn sheet_name(input: Option<&PathBuf>) -> String {
+    input
+        .filter(|path| path.as_os_str() != "-")
+        .and_then(|path| path.file_stem())
+        .map_or("results".to_string(), |stem| {
+            stem.to_string_lossy().into_owned()
+        })
+}
+
+/// The inputs of a command that reads several files, stdin when it's given none.
+pub fn inputs(paths: &[PathBuf]) -> Vec<Option<&PathBuf>> {
+    if paths.is_empty() {
+        vec![None]
+    } else {
+        paths.iter().map(Some).collect()
+    }
+}
+
+pub fn run(args: &BatchArgs, config: &Config) -> Result<ExitCode, Error> {
+    let dialect = csv_dialect(args.decimal_comma, matches!(args.format, BatchFormat::Csv))?;
+    let inputs = inputs(&args.inputs);
+
+    let mut reports = Vec::new();
+    let mut fixes = Vec::new();
+    for input in &inputs {
+        let contents = read_input(*input)?;
+        // Files get the settings of their directory, stdin the ones of the whole configuration
+        let config = match input {
+            Some(path) if path.as_os_str() != "-" => config.for_path(path),
+            _ => config.clone(),
+        };
+        let algorithm = args.algorithm.boxed();
+        let threshold = args
+            .ratio
+            .or(config.ratio)
+            .unwrap_or(algorithm.default_threshold());
+        // With several files the errors say which one they are in
+        let in_file = |err: String| match input {
+            Some(path) if inputs.len() > 1 => format!("{}: {err}", path.display()),
+            _ => err,
+        };
+        let mut audit = Audit::new()
+            .algorithm(algorithm)
+            .threshold(threshold)
+            .rounding(args.rounding.into())
+            .pairs_from_lines(&contents)
+            .map_err(|err| in_file(err.to_string()))?;
+        if let Some(severities) = &config.severities {
+            audit = audit.severities(severities.clone());
+        }
+        let report = audit.run().map_err(|err| in_file(err.to_string()))?;
+
+        if args.export_fixes.is_some() {
+            fixes.extend(audit.replacements(&report));
+        }
+        reports.push((sheet_name(*input), report));
+    }
+
+    if let (Some(path), Some((_, report))) = (&args.export_fixes, reports.first()) {
+        replacements::export(path, &fixes, &report.threshold_label())?;
+    }
+    let code = write_reports(
+        &reports,
+        args.format,
+        args.style.table_style(args.ascii),
+        dialect,
+    )?;
+
+    if args.score {
+        let score = format!("score: {:.1} of 100", score(&reports, &config.score));
+        match args.format {
+            BatchFormat::Table => println!("{score}"),
+            _ => eprintln!("{score}"),
+        }
+    }
+    if let Some(history) = &args.record {
+        trend::record(history, &reports, &config.score)?;
+    }
+    Ok(code)
+}
+
+/// The score of the pairs of every report together, each report weighing as many pairs as it
+/// has.
+pub fn score(reports: &[(String, BatchReport)], weights: &ScoreWeights) -> f32 {
+    let total: usize = reports.iter().map(|(_, report)| report.results.len()).sum();
+    if total == 0 {
+        return 100.0;
+    }
+    let weighted: f32 = reports
+        .iter()
+        .map(|(_, report)| report.score(weights) * report.results.len() as f32)
+        .sum();
+
+    weighted / total as f32
+}
+
+/// Prints the reports of several inputs, spreadsheets get a sheet for each and the other formats
+/// one report with every pair. The style only applies to the table and the dialect to CSV. The
+/// exit code is 1 when any pair fails, or has an error severity when the configuration has
+/// severity levels.
+pub fn write_reports(
+    reports: &[(String, BatchReport)],
+    format: BatchFormat,
+    style: TableStyle,
+    dialect: CsvDialect,
+) -> Result<ExitCode, Error> {
+    let merged;
+    let report = match reports {
+        [(_, report)] => report,
+        _ => {
+            let (_, first) = reports.first().ok_or("there is nothing to report")?;
+            merged = BatchReport {
+                threshold: first.threshold,
+                algorithm: first.algorithm.clone(),
+                severities: first.severities.clone(),
+                results: reports
+                    .iter()
+                    .flat_map(|(_, report)| report.results.iter().cloned())
+                    .collect(),
+            };
+            &merged
+        }
+    };
+
+    let sheets: Vec<(&str, &BatchReport)> = reports
+        .iter()
+        .map(|(name, report)| (name.as_str(), report))
+        .collect();
+    let stdout = io::stdout().lock();
+    let written = match format {
+        BatchFormat::Xlsx => {
+            SpreadsheetWriter::new(stdout, SpreadsheetFormat::Xlsx).write_sheets(&sheets)
+        }
+        BatchFormat::Ods => {
+            SpreadsheetWriter::new(stdout, SpreadsheetFormat::Ods).write_sheets(&sheets)
+        }
+        BatchFormat::Table => TableWriter::new(stdout).style(style).write_report(report),
+        BatchFormat::Csv => CsvWriter::new(stdout).dialect(dialect).write_report(report),
+        BatchFormat::Tsv => TsvWriter::new(stdout).write_report(report),
+        BatchFormat::Json => JsonWriter::new(stdout).write_report(report),
+        BatchFormat::Html => HtmlWriter::new(stdout).write_report(report),
+        BatchFormat::Pdf => PdfWriter::new(stdout).write_report(report),
+    };
+    written.map_err(|err| Error::io(format!("could not write the report: {err}")))?;
+
+    Ok(if report.errors() > 0 {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    })
+}
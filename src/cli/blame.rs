@@ -0,0 +1,109 @@
+//! `scan --blame`, the commit that wrote the line of every failure, read with libgit2 so fixes
+//! can go to whoever introduced the color.
+
+use std::collections::HashMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use git2::{ErrorCode, Oid, Repository};
+
+use super::trend::date;
+use super::Error;
+
+/// Lines of a file written by the same commit, or not committed yet.
+struct Hunk {
+    start: usize,
+    lines: usize,
+    note: String,
+}
+
+/// The blame of the files of a repository, read once per file.
+pub struct Blame {
+    repository: Repository,
+    files: HashMap<PathBuf, Vec<Hunk>>,
+}
+
+impl Blame {
+    /// Opens the repository `path` is in.
+    pub fn open(path: &Path) -> Result<Blame, Error> {
+        let repository = Repository::discover(path)
+            .map_err(|err| format!("--blame needs a git repository, {}", err.message()))?;
+        Ok(Blame {
+            repository,
+            files: HashMap::new(),
+        })
+    }
+
+    /// What wrote a line of the working tree, like `written in 3f7bea0 by Jane Doe on 2026-10-12:
+    /// Darken the links`. `None` for files outside the repository or that can't be blamed.
+    pub fn line(&mut self, path: &Path, line: usize) -> Option<String> {
+        let path = fs::canonicalize(path).ok()?;
+        if !self.files.contains_key(&path) {
+            let hunks = self.hunks(&path);
+            if let Err(err) = &hunks {
+                log::info!("could not blame {}: {err}", path.display());
+            }
+            self.files.insert(path.clone(), hunks.unwrap_or_default());
+        }
+
+        self.files[&path]
+            .iter()
+            .find(|hunk| (hunk.start..hunk.start + hunk.lines).contains(&line))
+            .map(|hunk| hunk.note.clone())
+    }
+
+    fn hunks(&self, path: &Path) -> Result<Vec<Hunk>, Error> {
+        let workdir = self
+            .repository
+            .workdir()
+            .ok_or("the repository has no working tree")?;
+        let workdir = fs::canonicalize(workdir).map_err(|err| err.to_string())?;
+        let relative = path
+            .strip_prefix(&workdir)
+            .map_err(|_| "the file is outside the repository".to_string())?;
+        let contents = fs::read(path).map_err(|err| err.to_string())?;
+
+        // The blame of HEAD, moved to the lines of the file as it is now. Files HEAD doesn't have
+        // are new, every line of them is
+        let committed = match self.repository.blame_file(relative, None) {
+            Ok(committed) => committed,
+            Err(err) if err.code() == ErrorCode::NotFound => {
+                return Ok(vec![Hunk {
+                    start: 1,
+                    lines: usize::MAX - 1,
+                    note: self.note(Oid::zero()),
+                }])
+            }
+            Err(err) => return Err(err.message().to_string().into()),
+        };
+        let blame = committed
+            .blame_buffer(&contents)
+            .map_err(|err| err.message().to_string())?;
+
+        Ok(blame
+            .iter()
+            .map(|hunk| Hunk {
+                start: hunk.final_start_line(),
+                lines: hunk.lines_in_hunk(),
+                note: self.note(hunk.final_commit_id()),
+            })
+            .collect())
+    }
+
+    fn note(&self, id: Oid) -> String {
+        let Some(commit) = (!id.is_zero())
+            .then(|| self.repository.find_commit(id).ok())
+            .flatten()
+        else {
+            return "not committed yet".to_string();
+        };
+        let short = id.to_string()[..7].to_string();
+        let author = commit.author();
+        format!(
+            "written in {short} by {} on {}: {}",
+            author.name().unwrap_or("unknown"),
+            date(author.when().seconds().max(0) as u64),
+            commit.summary().unwrap_or_default()
+        )
+    }
+}
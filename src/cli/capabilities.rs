@@ -0,0 +1,141 @@
+//! `--capabilities`, what this build of the tool supports as JSON, so wrappers can adapt to the
+//! installed version instead of parsing `--help`. The commands, their options and the values those
+//! take come from the clap command, so they always match the flags.
+
+use clap::{Command, ValueEnum};
+use serde_json::{json, Map, Value};
+
+use super::scan::{PROJECT_FILES, SCRIPTS};
+use super::{Algorithm, Error};
+
+/// The features of the crate, and whether this build has them.
+const FEATURES: [(&str, bool); 19] = [
+    ("std", cfg!(feature = "std")),
+    ("cli", cfg!(feature = "cli")),
+    ("serde", cfg!(feature = "serde")),
+    ("tokens", cfg!(feature = "tokens")),
+    ("source-maps", cfg!(feature = "source-maps")),
+    ("postcss", cfg!(feature = "postcss")),
+    ("rayon", cfg!(feature = "rayon")),
+    ("server", cfg!(feature = "server")),
+    ("clipboard", cfg!(feature = "clipboard")),
+    ("palettes", cfg!(feature = "palettes")),
+    ("screen", cfg!(feature = "screen")),
+    ("tray", cfg!(feature = "tray")),
+    ("cdp", cfg!(feature = "cdp")),
+    ("blame", cfg!(feature = "blame")),
+    ("image", cfg!(feature = "image")),
+    ("scripting", cfg!(feature = "scripting")),
+    ("wasm-plugins", cfg!(feature = "wasm-plugins")),
+    ("fixed-point", cfg!(feature = "fixed-point")),
+    ("pyo3", cfg!(feature = "pyo3")),
+];
+
+/// The notations colors can be written in, wherever the tool reads one.
+const NOTATIONS: [&str; 7] = ["hex", "name", "rgb", "hsl", "oklab", "oklch", "color"];
+
+/// The options of a command with their values, when they take one of a list.
+fn options(command: &Command) -> Vec<Value> {
+    command
+        .get_arguments()
+        .filter(|argument| !argument.is_hide_set())
+        .map(|argument| {
+            let mut option = Map::new();
+            match argument.get_long() {
+                Some(long) => option.insert("name".into(), json!(format!("--{long}"))),
+                None => option.insert("name".into(), json!(argument.get_id().as_str())),
+            };
+            option.insert("positional".into(), json!(argument.is_positional()));
+            let values: Vec<String> = argument
+                .get_possible_values()
+                .iter()
+                .filter(|value| !value.is_hide_set())
+                .map(|value| value.get_name().to_string())
+                .collect();
+            if !values.is_empty() {
+                option.insert("values".into(), json!(values));
+            }
+            Value::Object(option)
+        })
+        .collect()
+}
+
+/// The values of the `--format` option of a command.
+fn output_formats(command: &Command) -> Option<Vec<String>> {
+    let format = command
+        .get_arguments()
+        .find(|argument| argument.get_long() == Some("format"))?;
+    let values: Vec<String> = format
+        .get_possible_values()
+        .iter()
+        .map(|value| value.get_name().to_string())
+        .collect();
+    (!values.is_empty()).then_some(values)
+}
+
+/// The capabilities of `command`, the whole command line interface.
+fn capabilities(command: &Command) -> Value {
+    let algorithms: Vec<Value> = Algorithm::value_variants()
+        .iter()
+        .filter_map(ValueEnum::to_possible_value)
+        .map(|value| {
+            json!({
+                "name": value.get_name(),
+                "description": value.get_help().map(|help| help.to_string()),
+            })
+        })
+        .collect();
+
+    // The check without a subcommand has the options of the command itself
+    let mut output = Map::new();
+    if let Some(formats) = output_formats(command) {
+        output.insert("check".into(), json!(formats));
+    }
+    let mut commands = Vec::new();
+    for subcommand in command.get_subcommands() {
+        if let Some(formats) = output_formats(subcommand) {
+            output.insert(subcommand.get_name().into(), json!(formats));
+        }
+        commands.push(json!({
+            "name": subcommand.get_name(),
+            "about": subcommand.get_about().map(|about| about.to_string()),
+            "options": options(subcommand),
+        }));
+    }
+
+    let mut scan: Vec<&str> = PROJECT_FILES.iter().chain(&SCRIPTS).copied().collect();
+    if cfg!(feature = "postcss") {
+        scan.push("json");
+    }
+    let mut input = json!({
+        "colors": NOTATIONS,
+        "batch": ["text"],
+        "scan": scan,
+        "tokens": ["w3c-design-tokens", "json"],
+    });
+    if cfg!(feature = "image") {
+        input["image"] = json!(["png", "jpeg", "webp"]);
+    }
+
+    json!({
+        "version": env!("CARGO_PKG_VERSION"),
+        "features": FEATURES
+            .iter()
+            .filter(|(_, enabled)| *enabled)
+            .map(|(name, _)| name)
+            .collect::<Vec<_>>(),
+        "algorithms": algorithms,
+        "input_formats": input,
+        "output_formats": output,
+        "commands": commands,
+    })
+}
+
+/// Prints the capabilities of `command` as pretty printed JSON.
+pub fn run(command: &Command) -> Result<(), Error> {
+    let json = serde_json::to_string_pretty(&capabilities(command))
+        .map_err(|err| Error::io(format!("could not write the capabilities: {err}")))?;
+    println!("{json}");
+
+    Ok(())
+}
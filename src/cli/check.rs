@@ -0,0 +1,913 @@
+use std::process::ExitCode;
+
+use clap::{Args, ValueEnum};
+use contrast_checker::report::{escape_json, JsonValue, Measurement, PairJson};
+use contrast_checker::{
+    advisories, apca_contrast, check, compliance_gap, contrast_ratio, cvd_contrast, delta_e_2000,
+    suggest_foreground, worst_case_contrast, Color, ComplianceGap, ComplianceReport,
+    ContrastAlgorithm, ContrastContext, ContrastReport, DeltaEBand, DisplayProfile, LightnessFix,
+    Polarity, RoundingPolicy, TextSize, WcagLevel, INDISTINGUISHABLE_RATIO,
+};
+
+use super::config::Config;
+use super::preview::{swatch, Preview};
+use super::{
+    parse_color_with, parse_requirement, print_compliance, print_context_compliance, Algorithm,
+    Context, Error, Gamut, Requirement, Rounding,
+};
+
+#[derive(Args)]
+pub struct CheckArgs {
+    /// Foreground (text) color as hex, rgb(), hsl() or a CSS name, e.g. #F26CA7 or
+    /// "rgb(242 108 167)". Translucent colors are composited over the background
+    #[cfg_attr(not(feature = "clipboard"), arg(required = true))]
+    #[cfg_attr(feature = "clipboard", arg(required_unless_present = "from_clipboard"))]
+    foreground: Option<String>,
+
+    /// Background color as hex, rgb(), hsl() or a CSS name, e.g. white. When several backgrounds
+    /// are given the worst case is reported. Defaults to the background of the configuration file
+    backgrounds: Vec<String>,
+
+    /// Another background, can be repeated, e.g. for the dominant colors of a pattern or texture.
+    /// Checked along with the positional backgrounds
+    #[arg(long = "bg", value_name = "COLOR")]
+    bg: Vec<String>,
+
+    /// Check the first two colors found in the clipboard instead of the arguments
+    #[cfg(feature = "clipboard")]
+    #[arg(long, conflicts_with_all = ["foreground", "backgrounds", "bg"])]
+    from_clipboard: bool,
+
+    /// Background used with --from-clipboard when the clipboard only has one color
+    #[cfg(feature = "clipboard")]
+    #[arg(long, default_value = "white", requires = "from_clipboard")]
+    fallback_background: String,
+
+    /// Also report the APCA lightness contrast (Lc) from the WCAG 3 drafts
+    #[arg(long)]
+    apca: bool,
+
+    /// How contrast is measured for --require and the threshold of the JSON output, the WCAG 2
+    /// ratio and verdicts are reported either way
+    #[arg(long, value_enum, default_value_t = Algorithm::Wcag2)]
+    algorithm: Algorithm,
+
+    /// What the foreground is, which picks the success criterion and thresholds the verdict uses.
+    /// Every level and text size is reported when it isn't given
+    #[arg(long, value_enum)]
+    context: Option<Context>,
+
+    /// How Display P3 colors that sRGB can't show are mapped into it before being checked
+    #[arg(long, value_enum, default_value_t = Gamut::ReduceChroma)]
+    gamut: Gamut,
+
+    /// Check the colors as a display in other conditions shows them: standard, dimmed, night,
+    /// sunlight, or settings like brightness=0.3,gamma=1.1,warmth=0.5,glare=0.1 (which can follow a
+    /// preset). Glare is experimental
+    #[arg(long, value_parser = parse_profile)]
+    profile: Option<DisplayProfile>,
+
+    /// Check the colors a terminal without true color shows: the nearest ones of the xterm 256
+    /// color palette or of the 16 ANSI colors. The true color ratio is reported too
+    #[arg(long, value_enum)]
+    terminal_palette: Option<TerminalPalette>,
+
+    /// Which ratio is compared against the WCAG thresholds
+    #[arg(long, value_enum, default_value_t = Rounding::Exact)]
+    rounding: Rounding,
+
+    /// How results are printed, text unless the configuration file says otherwise
+    #[arg(long, value_enum)]
+    format: Option<CheckFormat>,
+
+    /// Don't print notes about combinations that pass but are known to be hard to read
+    #[arg(long)]
+    no_advisories: bool,
+
+    /// When to show a sample of the text on the background, using 24-bit terminal colors
+    #[arg(long, value_enum, default_value_t = Preview::Auto)]
+    preview: Preview,
+
+    /// Also report how different the colors look (CIEDE2000), which takes hue into account
+    #[arg(long)]
+    delta_e: bool,
+
+    /// Also report the contrast as seen with protanopia, deuteranopia and tritanopia
+    #[arg(long)]
+    simulate_cvd: bool,
+
+    /// Exit with 1 when the contrast is below AA, AAA (the normal text ratios) or a given ratio,
+    /// against the worst background. With another --algorithm it's a threshold of that
+    /// algorithm, like 60 for APCA. Invalid input always exits with 2. Defaults to the level of
+    /// the configuration file
+    #[arg(long, value_parser = parse_check_requirement)]
+    require: Option<Requirement>,
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+enum TerminalPalette {
+    /// The color cube and grays of xterm's 256 colors
+    #[value(name = "256")]
+    Ansi256,
+    /// The 16 ANSI colors, with xterm's default values
+    #[value(name = "16")]
+    Ansi16,
+}
+
+impl TerminalPalette {
+    fn index(self, color: &Color) -> u8 {
+        match self {
+            TerminalPalette::Ansi256 => color.to_ansi256(),
+            TerminalPalette::Ansi16 => color.to_ansi16(),
+        }
+    }
+
+    fn name(self) -> &'static str {
+        match self {
+            TerminalPalette::Ansi256 => "256",
+            TerminalPalette::Ansi16 => "16",
+        }
+    }
+
+    fn colors(self) -> usize {
+        match self {
+            TerminalPalette::Ansi256 => 256,
+            TerminalPalette::Ansi16 => 16,
+        }
+    }
+}
+
+/// A pair as a terminal shows it with `--terminal-palette`, and its ratio in true color.
+struct Quantized {
+    foreground: u8,
+    background: u8,
+    true_color_ratio: f32,
+}
+
+impl Quantized {
+    fn new(palette: TerminalPalette, foreground: &Color, background: &Color) -> Quantized {
+        Quantized {
+            foreground: palette.index(foreground),
+            background: palette.index(background),
+            true_color_ratio: contrast_ratio(foreground, background),
+        }
+    }
+
+    fn describe(&self, palette: TerminalPalette) -> String {
+        format!(
+            "terminal ({} colors): {} {} on {} {}, {:.2}:1 in true color",
+            palette.name(),
+            self.foreground,
+            Color::from_ansi256(self.foreground).to_hex(),
+            self.background,
+            Color::from_ansi256(self.background).to_hex(),
+            self.true_color_ratio
+        )
+    }
+
+    fn json(&self, palette: TerminalPalette) -> JsonValue {
+        JsonValue::object([
+            ("palette", palette.colors().into()),
+            ("foreground", usize::from(self.foreground).into()),
+            ("background", usize::from(self.background).into()),
+            ("true_color_ratio", self.true_color_ratio.into()),
+        ])
+    }
+}
+
+/// Parses `--require` like [`parse_requirement`], but any positive number is taken as a threshold
+/// so the other algorithms can be given theirs. [`required_ratio`] checks the range of WCAG 2
+/// ratios.
+fn parse_check_requirement(input: &str) -> Result<Requirement, String> {
+    match input.parse::<f32>() {
+        Ok(threshold) if threshold > 0.0 => Ok(Requirement::Ratio(threshold)),
+        _ => parse_requirement(input),
+    }
+}
+
+/// Parses the presets and settings of `--profile`, separated by commas and applied in order.
+fn parse_profile(input: &str) -> Result<DisplayProfile, String> {
+    let mut profile = DisplayProfile::STANDARD;
+
+    for part in input.split(',').map(str::trim) {
+        let Some((name, value)) = part.split_once('=') else {
+            profile = match part.to_ascii_lowercase().as_str() {
+                "standard" => DisplayProfile::STANDARD,
+                "dimmed" => DisplayProfile::DIMMED,
+                "night" => DisplayProfile::NIGHT,
+                "sunlight" => DisplayProfile::SUNLIGHT,
+                _ => {
+                    return Err(format!(
+                        "unknown profile {part}, expected standard, dimmed, night or sunlight"
+                    ))
+                }
+            };
+            continue;
+        };
+
+        let value: f32 = value
+            .trim()
+            .parse()
+            .map_err(|_| format!("{name} must be a number"))?;
+        match name.trim() {
+            "brightness" if (0.0..=1.0).contains(&value) => profile.brightness = value,
+            "warmth" if (0.0..=1.0).contains(&value) => profile.warmth = value,
+            "glare" if (0.0..=1.0).contains(&value) => profile.glare = value,
+            "gamma" if (0.1..=10.0).contains(&value) => profile.gamma = value,
+            "brightness" | "warmth" | "glare" => {
+                return Err(format!("{name} must be between 0 and 1"))
+            }
+            "gamma" => return Err("gamma must be between 0.1 and 10".to_string()),
+            _ => {
+                return Err(format!(
+                    "unknown setting {name}, expected brightness, gamma, warmth or glare"
+                ))
+            }
+        }
+    }
+
+    Ok(profile)
+}
+
+fn describe_profile(profile: &DisplayProfile) -> String {
+    let mut description = format!(
+        "brightness {:.0}%, gamma {}, night light {:.0}%",
+        profile.brightness * 100.0,
+        profile.gamma,
+        profile.warmth * 100.0
+    );
+    if profile.glare > 0.0 {
+        description.push_str(&format!(", glare {:.0}%", profile.glare * 100.0));
+    }
+    description
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum CheckFormat {
+    /// Human readable text
+    Text,
+    /// A JSON object with the normalized colors, luminances, ratio and verdicts
+    Json,
+    /// Script filter JSON for Raycast and Alfred: the ratio and grade of every background, and
+    /// the closest passing foreground to copy when it fails
+    #[value(alias = "alfred")]
+    Raycast,
+}
+
+fn print_apca(foreground: &Color, background: &Color) {
+    let lc = apca_contrast(foreground, background);
+    let polarity = if lc < 0.0 {
+        "light text on dark background"
+    } else {
+        "dark text on light background"
+    };
+
+    println!("APCA contrast: Lc {lc:.1} ({polarity})");
+}
+
+fn print_delta_e(foreground: &Color, background: &Color) {
+    let delta_e = delta_e_2000(foreground, background);
+    println!(
+        "delta E (CIEDE2000): {delta_e:.2}, {}",
+        DeltaEBand::from_delta_e(delta_e).name()
+    );
+}
+
+fn print_cvd(args: &CheckArgs, foreground: &Color, background: &Color) {
+    if !args.simulate_cvd {
+        return;
+    }
+
+    for simulation in cvd_contrast(foreground, background) {
+        let report = ComplianceReport::with_rounding(simulation.ratio, args.rounding.into());
+        let verdict = match report.highest_level(TextSize::Normal) {
+            Some(level) => format!("passes {level:?}"),
+            None => "fails AA".to_string(),
+        };
+        println!(
+            "{:<16} {:.2}:1, {verdict} for normal text",
+            simulation.deficiency.name(),
+            simulation.ratio
+        );
+    }
+}
+
+fn cvd_json(foreground: &Color, background: &Color) -> JsonValue {
+    let simulations: Vec<JsonValue> = cvd_contrast(foreground, background)
+        .iter()
+        .map(|simulation| {
+            JsonValue::object([
+                ("deficiency", simulation.deficiency.name().into()),
+                ("foreground", simulation.foreground.to_hex().into()),
+                ("background", simulation.background.to_hex().into()),
+                ("ratio", simulation.ratio.into()),
+            ])
+        })
+        .collect();
+
+    simulations.into()
+}
+
+fn print_advisories(args: &CheckArgs, foreground: &Color, background: &Color) {
+    if args.no_advisories {
+        return;
+    }
+
+    for advisory in advisories(foreground, background) {
+        println!("info: {} (see {})", advisory.message, advisory.reference);
+    }
+}
+
+fn advisories_json(foreground: &Color, background: &Color) -> JsonValue {
+    let advisories: Vec<JsonValue> = advisories(foreground, background)
+        .iter()
+        .map(|advisory| {
+            JsonValue::object([
+                ("kind", format!("{:?}", advisory.kind).into()),
+                ("message", advisory.message.as_str().into()),
+                ("reference", advisory.reference.into()),
+            ])
+        })
+        .collect();
+
+    advisories.into()
+}
+
+fn print_verdicts(args: &CheckArgs, ratio: f32) {
+    let report = ComplianceReport::with_rounding(ratio, args.rounding.into());
+    if report.indistinguishable {
+        println!(
+            "warning: indistinguishable, below {INDISTINGUISHABLE_RATIO}:1 the colors are the same \
+             or too close for text to be seen"
+        );
+    }
+    match args.context {
+        Some(context) => print_context_compliance(&report, context.into()),
+        None => print_compliance(&report),
+    }
+}
+
+fn context_json(context: Context, compliance: &ComplianceReport) -> JsonValue {
+    // The value of the flag, like large-text, is easier to match on than the description
+    let name = context
+        .to_possible_value()
+        .map(|value| value.get_name().to_string());
+    let context: ContrastContext = context.into();
+    let level_json = |level: WcagLevel| match (
+        compliance.passes_in(level, context),
+        context.minimum_ratio(level),
+        context.criterion(level),
+    ) {
+        (Some(passes), Some(ratio), Some(criterion)) => JsonValue::object([
+            ("passes", passes.into()),
+            ("needs", ratio.into()),
+            ("criterion", criterion.number.into()),
+            ("url", criterion.url.into()),
+        ]),
+        _ => JsonValue::Null,
+    };
+
+    JsonValue::object([
+        ("name", name.unwrap_or_default().into()),
+        ("aa", level_json(WcagLevel::AA)),
+        ("aaa", level_json(WcagLevel::AAA)),
+    ])
+}
+
+/// The ratio a pair fails when it's below it: the one of `--require`, or else AA in the context
+/// of `--context`. Without either there's nothing to fail, and the thresholds of other algorithms
+/// aren't ratios.
+fn target_ratio(args: &CheckArgs, required: Option<f32>) -> Option<f32> {
+    if !matches!(args.algorithm, Algorithm::Wcag2) {
+        return None;
+    }
+    required.or_else(|| {
+        let context: ContrastContext = args.context?.into();
+        context.minimum_ratio(WcagLevel::AA)
+    })
+}
+
+/// How far the pair is from the target ratio, when it fails it.
+fn gap(
+    args: &CheckArgs,
+    required: Option<f32>,
+    foreground: &Color,
+    background: &Color,
+) -> Option<ComplianceGap> {
+    let target = target_ratio(args, required)?;
+    let rounding: RoundingPolicy = args.rounding.into();
+    if rounding.apply(contrast_ratio(foreground, background)) >= target {
+        return None;
+    }
+
+    compliance_gap(foreground, background, target)
+}
+
+fn print_gap(gap: &ComplianceGap) {
+    println!("{}", gap.hint());
+    println!("the closest fixes to reach {}:1 are", gap.required_ratio);
+    let print_fix = |name: &str, fix: &Option<LightnessFix>| match fix {
+        Some(fix) => println!(
+            "  {name}: {} ({:.2}:1, OKLCH L {:+.3}, HSL L {:+.1}%)",
+            fix.color.to_hex(),
+            fix.ratio,
+            fix.oklch_lightness_change,
+            fix.hsl_lightness_change * 100.0
+        ),
+        None => {
+            println!("  {name}: no lightness reaches it without swapping which color is lighter")
+        }
+    };
+    print_fix("foreground", &gap.foreground_fix);
+    print_fix("background", &gap.background_fix);
+}
+
+fn gap_json(gap: &ComplianceGap) -> JsonValue {
+    let fix_json = |fix: &Option<LightnessFix>| match fix {
+        Some(fix) => JsonValue::object([
+            ("color", fix.color.to_hex().into()),
+            ("ratio", fix.ratio.into()),
+            ("oklch_lightness_change", fix.oklch_lightness_change.into()),
+            ("hsl_lightness_change", fix.hsl_lightness_change.into()),
+        ]),
+        None => JsonValue::Null,
+    };
+
+    JsonValue::object([
+        ("required", gap.required_ratio.into()),
+        ("achieved", gap.achieved_ratio.into()),
+        ("hint", gap.hint().into()),
+        ("foreground", fix_json(&gap.foreground_fix)),
+        ("background", fix_json(&gap.background_fix)),
+    ])
+}
+
+/// What `--verbose` shows about a pair, from the values its ratio is computed with.
+fn log_details(report: &ContrastReport) {
+    log::debug!(
+        "foreground {} has a relative luminance of {}",
+        report.foreground.to_hex(),
+        report.foreground_luminance
+    );
+    log::debug!(
+        "background {} has a relative luminance of {}",
+        report.background.to_hex(),
+        report.background_luminance
+    );
+    let polarity = match report.polarity {
+        Polarity::DarkOnLight => "dark text on a light background",
+        Polarity::LightOnDark => "light text on a dark background",
+    };
+    log::debug!("{polarity}, contrast ratio {}:1", report.ratio);
+}
+
+/// The colors to check as they were written, a foreground and at least one background.
+struct Inputs {
+    foreground: String,
+    backgrounds: Vec<String>,
+}
+
+impl Inputs {
+    fn new(args: &CheckArgs, config: &Config) -> Result<Inputs, Error> {
+        #[cfg(feature = "clipboard")]
+        if args.from_clipboard {
+            let (foreground, backgrounds) =
+                super::clipboard::read_colors(&args.fallback_background)?;
+            return Ok(Inputs {
+                foreground,
+                backgrounds,
+            });
+        }
+
+        let given: Vec<String> = args.backgrounds.iter().chain(&args.bg).cloned().collect();
+        let backgrounds = match (given.as_slice(), &config.background) {
+            ([], Some(background)) => vec![background.clone()],
+            ([], None) => {
+                return Err(
+                    "missing the background color, pass one or set a background in the configuration file"
+                        .to_string().into(),
+                )
+            }
+            (backgrounds, _) => backgrounds.to_vec(),
+        };
+
+        Ok(Inputs {
+            // clap requires it when the colors don't come from the clipboard
+            foreground: args.foreground.clone().unwrap(),
+            backgrounds,
+        })
+    }
+}
+
+fn print_json(
+    args: &CheckArgs,
+    algorithm: &dyn ContrastAlgorithm,
+    inputs: &Inputs,
+    required: Option<f32>,
+    foreground: &Color,
+    backgrounds: &[Color],
+    quantized: &[Quantized],
+) {
+    let pairs: Vec<String> = inputs
+        .backgrounds
+        .iter()
+        .zip(backgrounds)
+        .enumerate()
+        .map(|(index, (input, background))| {
+            let rounding: RoundingPolicy = args.rounding.into();
+            let ratio = contrast_ratio(foreground, background);
+            let compliance = ComplianceReport::with_rounding(ratio, rounding);
+            let threshold = threshold(args, algorithm, required);
+            let contrast = algorithm.contrast(foreground, background);
+            let measurement = Measurement {
+                algorithm: algorithm.name(),
+                contrast,
+                threshold,
+                meets_threshold: algorithm.passes(rounding.apply(contrast), threshold),
+            };
+
+            let mut json = PairJson::new(
+                (&inputs.foreground, foreground),
+                (input, background),
+                &compliance,
+                measurement,
+            );
+            if let Some(context) = args.context {
+                json = json.field("context", context_json(context, &compliance));
+            }
+            if let Some(profile) = &args.profile {
+                let profile = JsonValue::object([
+                    ("brightness", profile.brightness.into()),
+                    ("gamma", profile.gamma.into()),
+                    ("warmth", profile.warmth.into()),
+                    ("glare", profile.glare.into()),
+                ]);
+                json = json.field("profile", profile);
+            }
+            if let (Some(palette), Some(pair)) = (args.terminal_palette, quantized.get(index)) {
+                json = json.field("terminal", pair.json(palette));
+            }
+            if !args.no_advisories {
+                json = json.field("advisories", advisories_json(foreground, background));
+            }
+            if args.simulate_cvd {
+                json = json.field("cvd", cvd_json(foreground, background));
+            }
+            if let Some(gap) = gap(args, required, foreground, background) {
+                json = json.field("gap", gap_json(&gap));
+            }
+            if args.delta_e {
+                let delta_e = delta_e_2000(foreground, background);
+                json = json
+                    .field("delta_e", delta_e)
+                    .field("delta_e_band", DeltaEBand::from_delta_e(delta_e).name());
+            }
+            if args.apca {
+                json = json.field("apca", apca_contrast(foreground, background));
+            }
+
+            json.to_string()
+        })
+        .collect();
+
+    if let [pair] = pairs.as_slice() {
+        println!("{pair}");
+    } else {
+        // There's always at least one background, Inputs makes sure of that
+        let worst = worst_case_contrast(foreground, backgrounds).unwrap();
+        println!(
+            r#"{{"results":[{}],"worst":{}}}"#,
+            pairs.join(","),
+            worst.index
+        );
+    }
+}
+
+/// The best verdict of a pair, like "AA" when it's enough for normal text at AA but not AAA.
+pub fn grade(compliance: &ComplianceReport) -> &'static str {
+    match compliance {
+        ComplianceReport {
+            aaa_normal_text: true,
+            ..
+        } => "AAA",
+        ComplianceReport {
+            aa_normal_text: true,
+            ..
+        } => "AA",
+        ComplianceReport {
+            aa_large_text: true,
+            ..
+        } => "AA large text only",
+        _ => "fails AA",
+    }
+}
+
+/// A script filter item, `arg` is what the launcher copies or passes on.
+fn script_filter_item(title: &str, subtitle: &str, arg: &str) -> String {
+    let [title, subtitle, arg] = [title, subtitle, arg].map(escape_json);
+    format!(
+        r#"{{"title":"{title}","subtitle":"{subtitle}","arg":"{arg}","text":{{"copy":"{arg}","largetype":"{title}"}}}}"#
+    )
+}
+
+fn print_raycast(
+    args: &CheckArgs,
+    inputs: &Inputs,
+    required: Option<f32>,
+    foreground: &Color,
+    backgrounds: &[Color],
+) {
+    // A launcher has nothing to fail against, so without --require the fix aims at AA
+    let target =
+        target_ratio(args, required).unwrap_or(WcagLevel::AA.minimum_ratio(TextSize::Normal));
+    let mut items = Vec::new();
+
+    for (input, background) in inputs.backgrounds.iter().zip(backgrounds) {
+        let ratio = contrast_ratio(foreground, background);
+        let compliance = ComplianceReport::with_rounding(ratio, args.rounding.into());
+        items.push(script_filter_item(
+            &format!("{ratio:.2}:1, {}", grade(&compliance)),
+            &format!("{} on {input}", inputs.foreground),
+            &format!("{ratio:.2}:1"),
+        ));
+
+        let rounding: RoundingPolicy = args.rounding.into();
+        if rounding.apply(ratio) >= target {
+            continue;
+        }
+        match suggest_foreground(foreground, background, target) {
+            Some(suggestion) => items.push(script_filter_item(
+                &format!(
+                    "Use {} instead, {:.2}:1",
+                    suggestion.color.to_hex(),
+                    suggestion.ratio
+                ),
+                &format!("closest lightness reaching {target}:1 on {input}, copy it with enter"),
+                &suggestion.color.to_hex(),
+            )),
+            None => items.push(script_filter_item(
+                &format!("No lightness of {} reaches {target}:1", inputs.foreground),
+                &format!("on {input}"),
+                &inputs.foreground,
+            )),
+        }
+    }
+
+    println!(r#"{{"items":[{}]}}"#, items.join(","));
+}
+
+/// The threshold asked for with `--require`, if any: a ratio in the context of the check for
+/// the WCAG 2 ratio, or a plain threshold of another algorithm. WCAG levels are only thresholds
+/// of the ratio, so the configuration's doesn't apply to the other algorithms.
+fn required_ratio(
+    args: &CheckArgs,
+    config: &Config,
+    algorithm: &dyn ContrastAlgorithm,
+) -> Result<Option<f32>, Error> {
+    if !matches!(args.algorithm, Algorithm::Wcag2) {
+        return match args.require {
+            Some(Requirement::Level(level)) => Err(format!(
+                "{level:?} is a level of the WCAG 2 ratio, {} needs a threshold like --require {}",
+                algorithm.name(),
+                algorithm.default_threshold()
+            )
+            .into()),
+            Some(Requirement::Ratio(threshold)) => Ok(Some(threshold)),
+            None => Ok(None),
+        };
+    }
+
+    if let Some(Requirement::Ratio(ratio)) = args.require {
+        if !(1.0..=21.0).contains(&ratio) {
+            return Err("expected AA, AAA or a ratio between 1 and 21".into());
+        }
+    }
+    let context = args
+        .context
+        .map_or(ContrastContext::Text, ContrastContext::from);
+    args.require
+        .or(config.level)
+        .map(|requirement| requirement.minimum_ratio(context))
+        .transpose()
+        .map_err(Error::from)
+}
+
+/// The threshold pairs are measured against in the JSON output: the required one, or else AA in
+/// the context of the check for the WCAG 2 ratio and the default of the other algorithms.
+fn threshold(args: &CheckArgs, algorithm: &dyn ContrastAlgorithm, required: Option<f32>) -> f32 {
+    match args.algorithm {
+        Algorithm::Wcag2 => {
+            target_ratio(args, required).unwrap_or(WcagLevel::AA.minimum_ratio(TextSize::Normal))
+        }
+        _ => required.unwrap_or(algorithm.default_threshold()),
+    }
+}
+
+/// The contrast of the worst background, the one closest to 0. For the WCAG 2 ratio it's the
+/// worst case ratio.
+fn worst_contrast(
+    algorithm: &dyn ContrastAlgorithm,
+    foreground: &Color,
+    backgrounds: &[Color],
+) -> f32 {
+    backgrounds
+        .iter()
+        .map(|background| algorithm.contrast(foreground, background))
+        .min_by(|first, second| first.abs().total_cmp(&second.abs()))
+        .unwrap_or(0.0)
+}
+
+/// Turns the worst contrast of the check into the exit code asked for with `--require`.
+fn exit_code(
+    args: &CheckArgs,
+    algorithm: &dyn ContrastAlgorithm,
+    required: Option<f32>,
+    contrast: f32,
+) -> ExitCode {
+    let Some(required) = required else {
+        return ExitCode::SUCCESS;
+    };
+
+    let rounding: RoundingPolicy = args.rounding.into();
+    if algorithm.passes(rounding.apply(contrast), required) {
+        return ExitCode::SUCCESS;
+    }
+    match args.algorithm {
+        Algorithm::Wcag2 => {
+            eprintln!("contrast ratio {contrast:.2}:1 is below the required {required}:1")
+        }
+        _ => eprintln!(
+            "{} contrast {contrast:.2} is below the required {required}",
+            algorithm.name()
+        ),
+    }
+    ExitCode::FAILURE
+}
+
+/// The contrast of `--algorithm` when it isn't the WCAG 2 ratio, which is printed anyway.
+fn print_algorithm(
+    args: &CheckArgs,
+    algorithm: &dyn ContrastAlgorithm,
+    required: Option<f32>,
+    foreground: &Color,
+    background: &Color,
+) {
+    if matches!(args.algorithm, Algorithm::Wcag2) {
+        return;
+    }
+
+    let threshold = threshold(args, algorithm, required);
+    let contrast = algorithm.contrast(foreground, background);
+    let rounding: RoundingPolicy = args.rounding.into();
+    let verdict = if algorithm.passes(rounding.apply(contrast), threshold) {
+        "passes"
+    } else {
+        "below"
+    };
+    println!(
+        "{} contrast: {contrast:.2} ({verdict} {threshold})",
+        algorithm.name()
+    );
+}
+
+pub fn run(args: &CheckArgs, config: &Config) -> Result<ExitCode, Error> {
+    let inputs = Inputs::new(args, config)?;
+    let algorithm = args.algorithm.boxed();
+    let algorithm = algorithm.as_ref();
+    let required = required_ratio(args, config, algorithm)?;
+    let mapping = args.gamut.into();
+    let foreground = parse_color_with("foreground color", &inputs.foreground, mapping)?;
+    let backgrounds = inputs
+        .backgrounds
+        .iter()
+        .map(|input| parse_color_with("background color", input, mapping))
+        .collect::<Result<Vec<_>, _>>()?;
+    // Everything after this point sees the colors the way the display shows them
+    let (foreground, backgrounds) = match &args.profile {
+        Some(profile) => (
+            profile.apply(&foreground),
+            backgrounds
+                .iter()
+                .map(|color| profile.apply(color))
+                .collect(),
+        ),
+        None => (foreground, backgrounds),
+    };
+    // And after this one the way the terminal does, the pairs remember their true color ratio
+    let quantized: Vec<Quantized> = match args.terminal_palette {
+        Some(palette) => backgrounds
+            .iter()
+            .map(|background| Quantized::new(palette, &foreground, background))
+            .collect(),
+        None => Vec::new(),
+    };
+    let (foreground, backgrounds) = match quantized.first() {
+        Some(first) => (
+            Color::from_ansi256(first.foreground),
+            quantized
+                .iter()
+                .map(|pair| Color::from_ansi256(pair.background))
+                .collect(),
+        ),
+        None => (foreground, backgrounds),
+    };
+
+    // There's always at least one background, Inputs makes sure of that
+    let worst = worst_case_contrast(&foreground, &backgrounds).unwrap();
+    let worst_contrast = worst_contrast(algorithm, &foreground, &backgrounds);
+
+    if args.format.or(config.format) == Some(CheckFormat::Raycast) {
+        print_raycast(args, &inputs, required, &foreground, &backgrounds);
+        return Ok(exit_code(args, algorithm, required, worst_contrast));
+    }
+    if args.format.or(config.format) == Some(CheckFormat::Json) {
+        print_json(
+            args,
+            algorithm,
+            &inputs,
+            required,
+            &foreground,
+            &backgrounds,
+            &quantized,
+        );
+        return Ok(exit_code(args, algorithm, required, worst_contrast));
+    }
+
+    let preview = args.preview.enabled();
+    if let Some(profile) = &args.profile {
+        println!("display: {}", describe_profile(profile));
+    }
+    if let Some(palette) = args.terminal_palette {
+        for pair in &quantized {
+            println!("{}", pair.describe(palette));
+        }
+    }
+
+    if let [background] = backgrounds.as_slice() {
+        let report = check(&foreground, background);
+        log_details(&report);
+        let ratio = report.ratio;
+        if preview {
+            println!("{}", swatch(&foreground, background));
+        }
+        println!("contrast ratio: {ratio:.2}:1");
+        print_verdicts(args, ratio);
+        print_algorithm(args, algorithm, required, &foreground, background);
+        if let Some(gap) = gap(args, required, &foreground, background) {
+            print_gap(&gap);
+        }
+        if args.apca {
+            print_apca(&foreground, background);
+        }
+        if args.delta_e {
+            print_delta_e(&foreground, background);
+        }
+        print_cvd(args, &foreground, background);
+        print_advisories(args, &foreground, background);
+        return Ok(exit_code(args, algorithm, required, worst_contrast));
+    }
+
+    let rounding: RoundingPolicy = args.rounding.into();
+    for (input, background) in inputs.backgrounds.iter().zip(&backgrounds) {
+        let sample = if preview {
+            format!("{} ", swatch(&foreground, background))
+        } else {
+            String::new()
+        };
+        let report = check(&foreground, background);
+        log_details(&report);
+        let verdict = match target_ratio(args, required) {
+            _ if rounding.apply(report.ratio) < INDISTINGUISHABLE_RATIO => {
+                " (indistinguishable)".to_string()
+            }
+            Some(target) if rounding.apply(report.ratio) < target => {
+                format!(" (below {target}:1)")
+            }
+            _ => String::new(),
+        };
+        println!(
+            "{sample}contrast ratio against {input}: {:.2}:1{verdict}",
+            report.ratio
+        );
+        print_algorithm(args, algorithm, required, &foreground, background);
+        if args.apca {
+            print_apca(&foreground, background);
+        }
+        if args.delta_e {
+            print_delta_e(&foreground, background);
+        }
+        print_cvd(args, &foreground, background);
+        print_advisories(args, &foreground, background);
+    }
+
+    println!(
+        "worst contrast ratio: {:.2}:1 against {}",
+        worst.ratio, inputs.backgrounds[worst.index]
+    );
+    print_verdicts(args, worst.ratio);
+    if let Some(gap) = gap(args, required, &foreground, &backgrounds[worst.index]) {
+        print_gap(&gap);
+    }
+
+    Ok(exit_code(args, algorithm, required, worst_contrast))
+}
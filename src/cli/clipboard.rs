@@ -0,0 +1,30 @@
+use arboard::Clipboard;
+use contrast_checker::{parse_color, split_colors_iter};
+
+use super::Error;
+
+/// Finds the colors in a piece of text, like `color: #777; background: white`, skipping the
+/// words around them.
+fn colors_in(text: &str) -> Vec<&str> {
+    text.split([':', '"', '\''])
+        .flat_map(split_colors_iter)
+        .filter(|candidate| parse_color(candidate).is_ok())
+        .collect()
+}
+
+/// Reads the foreground and the background from the clipboard. When it only has one color,
+/// `fallback_background` is used as the background.
+pub fn read_colors(fallback_background: &str) -> Result<(String, Vec<String>), Error> {
+    let text = Clipboard::new()
+        .and_then(|mut clipboard| clipboard.get_text())
+        .map_err(|err| Error::io(format!("could not read the clipboard: {err}")))?;
+
+    match colors_in(&text)[..] {
+        [] => Err("the clipboard doesn't contain any color".to_string().into()),
+        [foreground] => Ok((
+            foreground.to_string(),
+            vec![fallback_background.to_string()],
+        )),
+        [foreground, background, ..] => Ok((foreground.to_string(), vec![background.to_string()])),
+    }
+}
@@ -0,0 +1,33 @@
+use std::io;
+
+use clap::{Args, Command};
+use clap_complete::Shell;
+use clap_mangen::Man;
+
+use super::Error;
+
+#[derive(Args)]
+pub struct CompletionsArgs {
+    /// Shell to generate the completions for
+    #[arg(value_enum, required_unless_present = "man")]
+    shell: Option<Shell>,
+
+    /// Print a man page in roff instead, e.g. to install as contrast-checker.1
+    #[arg(long, conflicts_with = "shell")]
+    man: bool,
+}
+
+/// Writes the completions or the man page of `command`, the whole command line interface, so they
+/// always match the flags.
+pub fn run(args: &CompletionsArgs, mut command: Command) -> Result<(), Error> {
+    let name = command.get_name().to_string();
+
+    match args.shell {
+        Some(shell) => clap_complete::generate(shell, &mut command, name, &mut io::stdout()),
+        None => Man::new(command)
+            .render(&mut io::stdout())
+            .map_err(|err| Error::io(format!("could not write the man page: {err}")))?,
+    }
+
+    Ok(())
+}
@@ -0,0 +1,152 @@
+use std::process::ExitCode;
+
+use clap::{Args, ValueEnum};
+use contrast_checker::report::escape_json;
+use contrast_checker::{Component, ComponentReport, WcagLevel};
+
+use super::config::Config;
+use super::{parse_color, parse_requirement, Error, Requirement, Rounding};
+
+#[derive(Args)]
+#[command(group = clap::ArgGroup::new("roles").required(true).multiple(true))]
+pub struct ComponentArgs {
+    /// Background of the component. Defaults to the background of the configuration file
+    #[arg(long)]
+    background: Option<String>,
+
+    /// Color of the label, checked as text
+    #[arg(long, group = "roles")]
+    text: Option<String>,
+
+    /// Color of the boundary, checked as a UI component (3:1)
+    #[arg(long, group = "roles")]
+    border: Option<String>,
+
+    /// Color of an icon, checked as a graphical object (3:1)
+    #[arg(long, group = "roles")]
+    icon: Option<String>,
+
+    /// The label is at least 18pt, or 14pt and bold
+    #[arg(long, requires = "text")]
+    large_text: bool,
+
+    /// Level the component has to reach, AA unless the configuration file says otherwise
+    #[arg(long, value_parser = parse_level)]
+    level: Option<WcagLevel>,
+
+    /// Which ratio is compared against the thresholds
+    #[arg(long, value_enum, default_value_t = Rounding::Exact)]
+    rounding: Rounding,
+
+    /// How the verdict is printed
+    #[arg(long, value_enum, default_value_t = ComponentFormat::Text)]
+    format: ComponentFormat,
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+enum ComponentFormat {
+    /// A line per role and the verdict of the component
+    Text,
+    /// A JSON object with every role and the verdict
+    Json,
+}
+
+pub fn parse_level(input: &str) -> Result<WcagLevel, String> {
+    match parse_requirement(input)? {
+        Requirement::Level(level) => Ok(level),
+        Requirement::Ratio(_) => {
+            Err("expected AA or AAA, the ratios come from the success criteria".to_string())
+        }
+    }
+}
+
+fn print_text(report: &ComponentReport, inputs: &[(&str, &str)], background: &str) {
+    for (check, (_, input)) in report.checks.iter().zip(inputs) {
+        let verdict = if check.passes { "pass" } else { "fail" };
+        println!(
+            "{:<7} {input} on {background}: {:.2}:1, needs {}:1 (SC {} {})  {verdict}",
+            check.role.name(),
+            check.ratio,
+            check.required_ratio,
+            check.criterion.number,
+            check.criterion.name
+        );
+    }
+
+    let failing: Vec<&str> = report.failures().map(|check| check.role.name()).collect();
+    if failing.is_empty() {
+        println!("component passes {:?}", report.level);
+    } else {
+        println!("component fails {:?}: {}", report.level, failing.join(", "));
+    }
+}
+
+fn print_json(report: &ComponentReport, inputs: &[(&str, &str)], background: &str) {
+    let checks: Vec<String> = report
+        .checks
+        .iter()
+        .zip(inputs)
+        .map(|(check, (_, input))| {
+            format!(
+                r#"{{"role":"{}","input":"{}","hex":"{}","ratio":{},"required_ratio":{},"criterion":"{}","passes":{}}}"#,
+                check.role.name(),
+                escape_json(input),
+                check.color.to_hex(),
+                check.ratio,
+                check.required_ratio,
+                check.criterion.number,
+                check.passes
+            )
+        })
+        .collect();
+
+    println!(
+        r#"{{"background":"{}","level":"{:?}","passes":{},"checks":[{}]}}"#,
+        escape_json(background),
+        report.level,
+        report.passes(),
+        checks.join(",")
+    );
+}
+
+pub fn run(args: &ComponentArgs, config: &Config) -> Result<ExitCode, Error> {
+    let background_input = args
+        .background
+        .as_deref()
+        .or(config.background.as_deref())
+        .ok_or("missing the background, pass --background or set it in the configuration file")?;
+    let level = args.level.unwrap_or(match config.level {
+        Some(Requirement::Level(level)) => level,
+        _ => WcagLevel::AA,
+    });
+
+    let mut component = Component::new(parse_color("background color", background_input)?)
+        .large_text(args.large_text)
+        .rounding(args.rounding.into());
+    // In the order the report checks them
+    let mut inputs = Vec::new();
+    if let Some(input) = &args.text {
+        component = component.text(parse_color("text color", input)?);
+        inputs.push(("text", input.as_str()));
+    }
+    if let Some(input) = &args.border {
+        component = component.border(parse_color("border color", input)?);
+        inputs.push(("border", input.as_str()));
+    }
+    if let Some(input) = &args.icon {
+        component = component.icon(parse_color("icon color", input)?);
+        inputs.push(("icon", input.as_str()));
+    }
+
+    let report = component.check(level);
+    match args.format {
+        ComponentFormat::Text => print_text(&report, &inputs, background_input),
+        ComponentFormat::Json => print_json(&report, &inputs, background_input),
+    }
+
+    Ok(if report.passes() {
+        ExitCode::SUCCESS
+    } else {
+        ExitCode::FAILURE
+    })
+}
@@ -0,0 +1,454 @@
+//! Defaults read from `~/.config/contrast-checker/config.toml`, or the file given with
+//! `--config`, so the same flags don't have to be repeated on every invocation:
+//!
+//! ```toml
+//! background = "#fafafa"
+//! level = "AAA"
+//! format = "json"
+//! notation = "oklch"
+//! # Used as --ratio of batch and scan
+//! ratio = 4.5
+//!
+//! [palettes]
+//! brand = ["#f26ca7", "#112233", "white"]
+//! # Colors of named palettes can be used as arguments, like ui:accent
+//! ui = { accent = "#f26ca7", surface = "white" }
+//! # A design tokens JSON file, relative to this one
+//! tokens = "tokens.json"
+//! # Read by the plugin of its extension
+//! theme = "brand.sketchpalette"
+//!
+//! # WebAssembly modules that read palette files in other formats, by extension, with the
+//! # wasm-plugins feature
+//! [plugins]
+//! sketchpalette = "plugins/sketch.wasm"
+//!
+//! # Severity of the pairs below each contrast in batch and tokens, only errors fail them
+//! [severity]
+//! error = 3
+//! warning = 4.5
+//! info = 7
+//!
+//! # How much a pair of each severity takes off the score of batch --score
+//! [score]
+//! error = 1.0
+//! warning = 0.4
+//! info = 0.1
+//!
+//! # Settings of the files in a directory, relative to this file. Inner directories win
+//! [directories."apps/marketing"]
+//! background = "#fff"
+//! ratio = 3
+//! severity = { error = 2, warning = 3 }
+//! ```
+//!
+//! Flags given on the command line always win over the file.
+
+use std::env;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use clap::ValueEnum;
+use contrast_checker::palettes::PaletteRegistry;
+use contrast_checker::report::{ScoreWeights, Severity, SeverityLevels};
+use contrast_checker::ColorFormat;
+use toml::{Table, Value};
+
+use super::check::CheckFormat;
+use super::{parse_requirement, Error, Notation, Requirement};
+
+#[derive(Clone, Default)]
+pub struct Config {
+    /// Background used when the check doesn't get one.
+    pub background: Option<String>,
+    /// Used as `--require` when it isn't given.
+    pub level: Option<Requirement>,
+    /// Used as `--format` of the check when it isn't given.
+    pub format: Option<CheckFormat>,
+    /// Used as `--notation` of the commands that print colors when it isn't given.
+    pub notation: Option<Notation>,
+    /// Palettes that `--palette` can refer to by name, in the order they're written.
+    pub palettes: Vec<(String, Vec<String>)>,
+    /// The palettes whose colors have names, which `palette:color` arguments can refer to.
+    pub named_palettes: Vec<(String, Vec<(String, String)>)>,
+    /// Classifies the pairs of audits, which then only fail on errors.
+    pub severities: Option<SeverityLevels>,
+    /// How much the pairs of every severity take off the score of an audit.
+    pub score: ScoreWeights,
+    /// Used as `--ratio` of batch and scan when it isn't given.
+    pub ratio: Option<f32>,
+    /// The settings of the files in some directories, outer directories first.
+    directories: Vec<(PathBuf, Overrides)>,
+}
+
+/// The settings a `[directories."path"]` table changes for the files in it.
+#[derive(Clone, Default)]
+struct Overrides {
+    background: Option<String>,
+    ratio: Option<f32>,
+    severities: Option<SeverityLevels>,
+}
+
+impl Config {
+    /// Reads the file given with `--config`, which must exist, or the default one if there's any.
+    pub fn load(path: Option<&Path>) -> Result<Config, Error> {
+        let (path, required) = match path {
+            Some(path) => (path.to_path_buf(), true),
+            None => match default_path() {
+                Some(path) => (path, false),
+                None => return Ok(Config::default()),
+            },
+        };
+
+        let contents = match fs::read_to_string(&path) {
+            Ok(contents) => contents,
+            Err(err) if err.kind() == io::ErrorKind::NotFound && !required => {
+                return Ok(Config::default())
+            }
+            Err(err) => {
+                return Err(Error::io(format!(
+                    "could not read {}: {err}",
+                    path.display()
+                )))
+            }
+        };
+        log::debug!("reading the configuration from {}", path.display());
+
+        let directory = path.parent().unwrap_or(Path::new(""));
+        Config::parse(&contents, directory)
+            .map_err(|err| Error::config(format!("{}: {err}", path.display())))
+    }
+
+    /// Reads the settings of a file, `directory` is where the file is, which the paths in it are
+    /// relative to.
+    fn parse(contents: &str, directory: &Path) -> Result<Config, String> {
+        let table: Table = contents
+            .parse()
+            .map_err(|err| format!("invalid TOML: {err}"))?;
+        let mut config = Config::default();
+        // Palettes of any table can be read by the plugins, wherever the plugins table is
+        let plugins = match table.get("plugins") {
+            Some(value) => plugins(value, directory)?,
+            None => Vec::new(),
+        };
+
+        for (key, value) in &table {
+            match key.as_str() {
+                "background" => config.background = Some(string(key, value)?.to_string()),
+                "level" => {
+                    let level = parse_requirement(string(key, value)?)
+                        .map_err(|err| format!("invalid level, {err}"))?;
+                    config.level = Some(level);
+                }
+                "format" => {
+                    let format = CheckFormat::from_str(string(key, value)?, true)
+                        .map_err(|_| "invalid format, expected text or json".to_string())?;
+                    config.format = Some(format);
+                }
+                "notation" => {
+                    let notation = Notation::from_str(string(key, value)?, true).map_err(|_| {
+                        "invalid notation, expected hex, rgb, hsl or oklch".to_string()
+                    })?;
+                    config.notation = Some(notation);
+                }
+                "palettes" => {
+                    for (name, value) in value.as_table().ok_or(
+                        "palettes must be a table of names to lists of colors, tables of colors or \
+                         tokens files",
+                    )? {
+                        let palette = palette(name, value, directory, &plugins)?;
+                        config
+                            .palettes
+                            .push((name.clone(), palette.iter().map(|(_, color)| color.clone()).collect()));
+                        if !matches!(value, Value::Array(_)) {
+                            config.named_palettes.push((name.clone(), palette));
+                        }
+                    }
+                }
+                "severity" => config.severities = Some(severities(value)?),
+                "score" => config.score = score(value)?,
+                "ratio" => config.ratio = Some(number(key, value)?),
+                "plugins" => {}
+                "directories" => {
+                    for (name, value) in value
+                        .as_table()
+                        .ok_or("directories must be a table of paths to settings")?
+                    {
+                        let overrides = overrides(value)
+                            .map_err(|err| format!("directories.\"{name}\": {err}"))?;
+                        config
+                            .directories
+                            .push((absolute(&directory.join(name)), overrides));
+                    }
+                    // Inner directories are applied after the ones they're in
+                    config
+                        .directories
+                        .sort_by_key(|(path, _)| path.components().count());
+                }
+                _ => return Err(format!("unknown setting {key}")),
+            }
+        }
+
+        Ok(config)
+    }
+
+    /// The notation colors are printed in, the flag wins over the file and hex is the default.
+    pub fn color_format(&self, flag: Option<Notation>) -> ColorFormat {
+        flag.or(self.notation)
+            .map_or(ColorFormat::Hex, ColorFormat::from)
+    }
+
+    /// Returns the colors of the palette called `name`, from the configuration or else the
+    /// registered palettes, whose colors are returned as references like `tailwind:sky-500`.
+    pub fn palette(&self, name: &str) -> Result<Vec<String>, String> {
+        if let Some((_, colors)) = self.palettes.iter().find(|(palette, _)| palette == name) {
+            return Ok(colors.clone());
+        }
+
+        let palette = super::palettes()
+            .palette(name)
+            .ok_or_else(|| format!("there's no palette called {name}"))?;
+        Ok(palette
+            .colors()
+            .map(|(color, _)| format!("{}:{color}", palette.name()))
+            .collect())
+    }
+
+    /// The built-in palettes, if they're enabled, along with the named ones of the file.
+    pub fn registry(&self) -> Result<PaletteRegistry, String> {
+        #[cfg(feature = "palettes")]
+        let mut registry = PaletteRegistry::with_builtins();
+        #[cfg(not(feature = "palettes"))]
+        let mut registry = PaletteRegistry::new();
+
+        for (name, colors) in &self.named_palettes {
+            let colors = colors
+                .iter()
+                .map(|(color, value)| {
+                    let parsed = contrast_checker::parse_color(value)
+                        .map_err(|err| format!("palette {name}: invalid color {err}"))?;
+                    Ok((color.clone(), parsed))
+                })
+                .collect::<Result<_, String>>()?;
+            registry.register(name, colors);
+        }
+
+        Ok(registry)
+    }
+
+    /// The settings of a file: those of the directories it's in, from the outermost one in, over
+    /// the ones of the whole file.
+    pub fn for_path(&self, path: &Path) -> Config {
+        let mut config = self.clone();
+        let path = absolute(path);
+        for (directory, overrides) in &self.directories {
+            if !path.starts_with(directory) {
+                continue;
+            }
+            log::debug!(
+                "using the settings of {} for {}",
+                directory.display(),
+                path.display()
+            );
+            let overrides = overrides.clone();
+            config.background = overrides.background.or(config.background);
+            config.ratio = overrides.ratio.or(config.ratio);
+            config.severities = overrides.severities.or(config.severities);
+        }
+
+        config
+    }
+}
+
+/// `$XDG_CONFIG_HOME/contrast-checker/config.toml`, falling back to `~/.config`.
+fn default_path() -> Option<PathBuf> {
+    let directory = env::var_os("XDG_CONFIG_HOME")
+        .filter(|directory| !directory.is_empty())
+        .map(PathBuf::from)
+        .or_else(|| env::var_os("HOME").map(|home| Path::new(&home).join(".config")))?;
+
+    Some(directory.join("contrast-checker").join("config.toml"))
+}
+
+/// A path from the root, relative ones are relative to the current directory. The directories
+/// are compared by their components, so `./packages/app` is in `packages`.
+fn absolute(path: &Path) -> PathBuf {
+    let path = std::path::absolute(path).unwrap_or_else(|_| path.to_path_buf());
+    path.components().collect()
+}
+
+/// Reads the settings of a `[directories."path"]` table.
+fn overrides(value: &Value) -> Result<Overrides, String> {
+    let table = value
+        .as_table()
+        .ok_or("the settings of a directory must be a table")?;
+
+    let mut overrides = Overrides::default();
+    for (key, value) in table {
+        match key.as_str() {
+            "background" => overrides.background = Some(string(key, value)?.to_string()),
+            "ratio" => overrides.ratio = Some(number(key, value)?),
+            "severity" => overrides.severities = Some(severities(value)?),
+            _ => {
+                return Err(format!(
+                    "unknown setting {key}, directories can set background, ratio and severity"
+                ))
+            }
+        }
+    }
+
+    Ok(overrides)
+}
+
+fn number(key: &str, value: &Value) -> Result<f32, String> {
+    match value {
+        Value::Integer(number) => Ok(*number as f32),
+        Value::Float(number) => Ok(*number as f32),
+        _ => Err(format!("{key} must be a number")),
+    }
+}
+
+fn string<'a>(key: &str, value: &'a Value) -> Result<&'a str, String> {
+    value
+        .as_str()
+        .ok_or_else(|| format!("{key} must be a string"))
+}
+
+/// Reads the contrast below which pairs get each severity.
+fn score(value: &Value) -> Result<ScoreWeights, String> {
+    let table = value
+        .as_table()
+        .ok_or("score must be a table of severities to weights")?;
+
+    let mut weights = ScoreWeights::default();
+    for (name, weight) in table {
+        let field = match name.as_str() {
+            "error" => &mut weights.error,
+            "warning" => &mut weights.warning,
+            "info" => &mut weights.info,
+            _ => {
+                return Err(format!(
+                    "unknown severity {name} in score, expected error, warning or info"
+                ))
+            }
+        };
+        *field = match weight {
+            Value::Integer(weight) => *weight as f32,
+            Value::Float(weight) => *weight as f32,
+            _ => return Err(format!("the {name} weight of score must be a number")),
+        };
+    }
+
+    Ok(weights)
+}
+
+fn severities(value: &Value) -> Result<SeverityLevels, String> {
+    let table = value
+        .as_table()
+        .ok_or("severity must be a table of severities to contrasts")?;
+
+    let mut severities = SeverityLevels::new();
+    for (name, limit) in table {
+        let severity = match name.as_str() {
+            "error" => Severity::Error,
+            "warning" => Severity::Warning,
+            "info" => Severity::Info,
+            _ => {
+                return Err(format!(
+                    "unknown severity {name}, expected error, warning or info"
+                ))
+            }
+        };
+        let limit = match limit {
+            Value::Integer(limit) => *limit as f32,
+            Value::Float(limit) => *limit as f32,
+            _ => return Err(format!("the {name} severity must be a number")),
+        };
+        severities = severities.level(severity, limit);
+    }
+
+    Ok(severities)
+}
+
+/// Reads a palette: a list of colors, a table of names to colors or the path of a tokens file.
+/// Reads the `[plugins]` table, file extensions to the WebAssembly modules that read them.
+fn plugins(value: &Value, directory: &Path) -> Result<Vec<(String, PathBuf)>, String> {
+    if cfg!(not(feature = "wasm-plugins")) {
+        return Err("plugins need a build with the wasm-plugins feature".to_string());
+    }
+    value
+        .as_table()
+        .ok_or("plugins must be a table of file extensions to WebAssembly modules")?
+        .iter()
+        .map(|(extension, path)| {
+            let path = string(&format!("plugins.{extension}"), path)?;
+            Ok((extension.to_ascii_lowercase(), directory.join(path)))
+        })
+        .collect()
+}
+
+/// The colors a plugin reads from a palette file.
+#[cfg(feature = "wasm-plugins")]
+fn plugin_palette(module: &Path, path: &Path) -> Result<Vec<(String, String)>, String> {
+    use contrast_checker::palettes::WasmPaletteSource;
+
+    let wasm =
+        fs::read(module).map_err(|err| format!("could not read {}: {err}", module.display()))?;
+    let source =
+        WasmPaletteSource::new(&wasm).map_err(|err| format!("{}: {err}", module.display()))?;
+    let input =
+        fs::read(path).map_err(|err| format!("could not read {}: {err}", path.display()))?;
+    let colors = source
+        .read(&input)
+        .map_err(|err| format!("{} reading {}: {err}", module.display(), path.display()))?;
+
+    Ok(colors
+        .into_iter()
+        .map(|(name, color)| (name, color.to_hex()))
+        .collect())
+}
+
+#[cfg(not(feature = "wasm-plugins"))]
+fn plugin_palette(_: &Path, _: &Path) -> Result<Vec<(String, String)>, String> {
+    Err("plugins need a build with the wasm-plugins feature".to_string())
+}
+
+fn palette(
+    name: &str,
+    value: &Value,
+    directory: &Path,
+    plugins: &[(String, PathBuf)],
+) -> Result<Vec<(String, String)>, String> {
+    match value {
+        Value::Array(colors) => colors
+            .iter()
+            .enumerate()
+            .map(|(index, color)| Some(((index + 1).to_string(), color.as_str()?.to_string())))
+            .collect::<Option<_>>()
+            .ok_or_else(|| format!("palette {name} must be a list of colors")),
+        Value::Table(colors) => colors
+            .iter()
+            .map(|(color, value)| Some((color.clone(), value.as_str()?.to_string())))
+            .collect::<Option<_>>()
+            .ok_or_else(|| format!("the colors of palette {name} must be strings")),
+        Value::String(path) => {
+            let path = directory.join(path);
+            let extension = path
+                .extension()
+                .map(|extension| extension.to_string_lossy().to_ascii_lowercase());
+            let plugin = plugins
+                .iter()
+                .find(|(plugin, _)| Some(plugin) == extension.as_ref());
+            if let Some((_, module)) = plugin {
+                return plugin_palette(module, &path)
+                    .map_err(|err| format!("palette {name}: {err}"));
+            }
+
+            super::palette_file::read(&path).map_err(|err| format!("palette {name}: {err}"))
+        }
+        _ => Err(format!(
+            "palette {name} must be a list of colors, a table of colors or a tokens file"
+        )),
+    }
+}
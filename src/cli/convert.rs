@@ -0,0 +1,50 @@
+use clap::Args;
+use contrast_checker::ColorFormat;
+
+use super::config::Config;
+use super::{parse_color, Error, Notation};
+
+#[derive(Args)]
+pub struct ConvertArgs {
+    /// Colors to convert, in any of the supported notations
+    #[arg(required = true)]
+    colors: Vec<String>,
+
+    /// Only print this notation, one color per line. Every notation is printed when it isn't
+    /// given, unless the configuration file sets one
+    #[arg(long, value_enum)]
+    to: Option<Notation>,
+}
+
+const NOTATIONS: [(&str, ColorFormat); 4] = [
+    ("hex", ColorFormat::Hex),
+    ("rgb", ColorFormat::Rgb),
+    ("hsl", ColorFormat::Hsl),
+    ("oklch", ColorFormat::Oklch),
+];
+
+pub fn run(args: &ConvertArgs, config: &Config) -> Result<(), Error> {
+    let colors = args
+        .colors
+        .iter()
+        .map(|input| parse_color("color", input))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    if let Some(notation) = args.to.or(config.notation) {
+        for color in &colors {
+            println!("{}", color.format_as(notation.into()));
+        }
+        return Ok(());
+    }
+
+    for (index, color) in colors.iter().enumerate() {
+        if index > 0 {
+            println!();
+        }
+        for (name, format) in NOTATIONS {
+            println!("{name:<5}  {}", color.format_as(format));
+        }
+    }
+
+    Ok(())
+}
@@ -0,0 +1,207 @@
+//! `cross`, every foreground of a list on every background of another, for policies like "any
+//! text token on any surface token". Results are printed as they're checked, the lists can be as
+//! long as they need.
+
+use std::io::{self, Write};
+use std::path::PathBuf;
+use std::process::ExitCode;
+
+use clap::{Args, ValueEnum};
+use contrast_checker::audit::{Audit, PairInput, ResolvedPair};
+use contrast_checker::report::{threshold_label, CsvDialect, PairResult, Severity};
+use contrast_checker::{split_colors_iter, Color};
+
+use super::config::Config;
+use super::{csv_dialect, parse_color, read_input, Algorithm, Error, Rounding};
+
+#[derive(Args)]
+pub struct CrossArgs {
+    /// File with the foregrounds, separated by whitespace, commas or new lines. "-" reads from
+    /// stdin
+    foregrounds: PathBuf,
+
+    /// File with the backgrounds, like the foregrounds
+    backgrounds: PathBuf,
+
+    /// Contrast every pair has to reach, 4.5:1 (AA for normal text) or the default of the
+    /// algorithm
+    #[arg(long)]
+    ratio: Option<f32>,
+
+    /// How contrast is measured, the WCAG 2 verdicts are reported either way
+    #[arg(long, value_enum, default_value_t = Algorithm::Wcag2)]
+    algorithm: Algorithm,
+
+    /// Which ratio is compared against the thresholds
+    #[arg(long, value_enum, default_value_t = Rounding::Exact)]
+    rounding: Rounding,
+
+    /// Only print the pairs that fail
+    #[arg(long)]
+    failures: bool,
+
+    /// How the pairs are printed
+    #[arg(long, value_enum, default_value_t = CrossFormat::Text)]
+    format: CrossFormat,
+
+    /// With --format csv, separate the fields with ";" and write decimals with a comma, for
+    /// spreadsheets in locales like German or French
+    #[arg(long)]
+    decimal_comma: bool,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, ValueEnum)]
+enum CrossFormat {
+    /// A line per pair, then the failure rate
+    Text,
+    /// Comma separated values with a header row, the failure rate goes to stderr
+    Csv,
+}
+
+/// The colors of a list, as they're written and parsed.
+fn colors(path: &PathBuf, description: &str) -> Result<Vec<(String, Color)>, Error> {
+    let contents = read_input(Some(path))?;
+    let colors = split_colors_iter(&contents)
+        .map(|input| Ok((input.to_string(), parse_color(description, input)?)))
+        .collect::<Result<Vec<_>, Error>>()?;
+    if colors.is_empty() {
+        return Err(format!("{} has no colors", path.display()).into());
+    }
+
+    Ok(colors)
+}
+
+fn write_pair(
+    output: &mut impl Write,
+    args: &CrossArgs,
+    dialect: CsvDialect,
+    result: &PairResult,
+    widths: (usize, usize),
+) -> io::Result<()> {
+    let verdict = if result.meets_threshold {
+        "pass"
+    } else {
+        "fail"
+    };
+    match args.format {
+        CrossFormat::Text => {
+            write!(
+                output,
+                "{:fg$}  on  {:bg$}  {:>5.2}:1",
+                result.foreground_input,
+                result.background_input,
+                result.compliance.ratio,
+                fg = widths.0,
+                bg = widths.1
+            )?;
+            if !matches!(args.algorithm, Algorithm::Wcag2) {
+                write!(output, "  {:>6.2}", result.contrast)?;
+            }
+            writeln!(output, "  {verdict}")
+        }
+        CrossFormat::Csv => {
+            let fields = [
+                dialect.field(&result.foreground_input),
+                dialect.field(&result.background_input),
+                dialect.decimal(result.compliance.ratio),
+                dialect.decimal(result.contrast),
+                verdict.to_string(),
+            ];
+            write!(
+                output,
+                "{}\r\n",
+                fields.join(&dialect.delimiter.to_string())
+            )
+        }
+    }
+}
+
+pub fn run(args: &CrossArgs, config: &Config) -> Result<ExitCode, Error> {
+    let dialect = csv_dialect(args.decimal_comma, args.format == CrossFormat::Csv)?;
+    if args.foregrounds.as_os_str() == "-" && args.backgrounds.as_os_str() == "-" {
+        return Err("only one of the lists can be read from stdin"
+            .to_string()
+            .into());
+    }
+    let foregrounds = colors(&args.foregrounds, "foreground color")?;
+    let backgrounds = colors(&args.backgrounds, "background color")?;
+
+    let algorithm = args.algorithm.boxed();
+    let threshold = args.ratio.unwrap_or(algorithm.default_threshold());
+    let label = threshold_label(algorithm.name(), threshold);
+    let mut audit = Audit::new()
+        .algorithm(algorithm)
+        .threshold(threshold)
+        .rounding(args.rounding.into());
+    if let Some(severities) = &config.severities {
+        audit = audit.severities(severities.clone());
+    }
+
+    let width = |colors: &[(String, Color)]| {
+        colors
+            .iter()
+            .map(|(input, _)| input.chars().count())
+            .max()
+            .unwrap_or(0)
+    };
+    let widths = (width(&foregrounds), width(&backgrounds));
+
+    let stdout = io::stdout();
+    let mut output = stdout.lock();
+    let write_error = |err: io::Error| Error::io(format!("could not write the results: {err}"));
+    if args.format == CrossFormat::Csv {
+        let header = ["foreground", "background", "ratio", "contrast", &label];
+        write!(
+            output,
+            "{}\r\n",
+            header.join(&dialect.delimiter.to_string())
+        )
+        .map_err(write_error)?;
+    }
+
+    let (mut failures, mut foregrounds_passing) = (0, 0);
+    for (foreground_input, foreground) in &foregrounds {
+        let mut passes_everywhere = true;
+        for (background_input, background) in &backgrounds {
+            let result = audit.check_pair(ResolvedPair {
+                input: PairInput {
+                    foreground: foreground_input.clone(),
+                    background: background_input.clone(),
+                    line: None,
+                    metadata: Vec::new(),
+                },
+                foreground: *foreground,
+                background: *background,
+            });
+            // Like BatchReport::errors, severities decide which pairs fail when there are some
+            let fails = match config.severities {
+                Some(_) => result.severity == Some(Severity::Error),
+                None => !result.meets_threshold,
+            };
+            failures += usize::from(fails);
+            passes_everywhere &= !fails;
+            if fails || !args.failures {
+                write_pair(&mut output, args, dialect, &result, widths).map_err(write_error)?;
+            }
+        }
+        foregrounds_passing += usize::from(passes_everywhere);
+    }
+
+    let total = foregrounds.len() * backgrounds.len();
+    let summary = format!(
+        "{failures} of {total} pairs fail {label} ({:.1}%), {foregrounds_passing} of {} \
+         foregrounds pass on every background",
+        failures as f64 * 100.0 / total as f64,
+        foregrounds.len()
+    );
+    match args.format {
+        CrossFormat::Text => writeln!(output, "\n{summary}").map_err(write_error)?,
+        CrossFormat::Csv => eprintln!("{summary}"),
+    }
+
+    Ok(if failures > 0 {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    })
+}
@@ -0,0 +1,121 @@
+use std::path::{Path, PathBuf};
+use std::process::{Command, ExitCode};
+
+use clap::Args;
+use contrast_checker::tokens::{diff_tokens, parse_tokens, TokenFile};
+use contrast_checker::RoundingPolicy;
+
+use super::{read_input, Error, Rounding};
+
+#[derive(Args)]
+pub struct DiffArgs {
+    /// Design tokens or palette JSON file before the change, or the file to compare with its
+    /// version at --diff-base
+    old: PathBuf,
+
+    /// The same file after the change, "-" reads it from stdin
+    #[arg(required_unless_present = "diff_base", conflicts_with = "diff_base")]
+    new: Option<PathBuf>,
+
+    /// Compare the file with its version at a git revision, like origin/main, so only what the
+    /// change made worse is reported
+    #[arg(long, value_name = "REVISION")]
+    diff_base: Option<String>,
+
+    /// Contrast ratio pairs have to keep, 4.5 is AA for normal text
+    #[arg(long, default_value_t = 4.5)]
+    ratio: f32,
+
+    /// Which ratio is compared against the threshold
+    #[arg(long, value_enum, default_value_t = Rounding::Exact)]
+    rounding: Rounding,
+
+    /// Also list the pairs whose contrast went up or stayed the same
+    #[arg(long)]
+    all: bool,
+}
+
+fn read_tokens(path: &PathBuf) -> Result<TokenFile, Error> {
+    parse_tokens(&read_input(Some(path))?)
+        .map_err(|err| format!("{}: {err}", path.display()).into())
+}
+
+/// The tokens of a file as they are at a git revision.
+fn revision_tokens(revision: &str, path: &Path) -> Result<TokenFile, Error> {
+    // `./` makes git read the path from the current directory instead of the repository root
+    let object = format!("{revision}:./{}", path.display());
+    let output = Command::new("git")
+        .args(["show", &object])
+        .output()
+        .map_err(|err| Error::io(format!("could not run git: {err}")))?;
+    if !output.status.success() {
+        return Err(format!(
+            "could not read {} at {revision}: {}",
+            path.display(),
+            String::from_utf8_lossy(&output.stderr).trim()
+        )
+        .into());
+    }
+
+    let contents = String::from_utf8_lossy(&output.stdout);
+    parse_tokens(&contents).map_err(|err| format!("{} at {revision}: {err}", path.display()).into())
+}
+
+pub fn run(args: &DiffArgs) -> Result<ExitCode, Error> {
+    let (old, new) = match (&args.diff_base, &args.new) {
+        (Some(revision), _) => (
+            revision_tokens(revision, &args.old)?,
+            read_tokens(&args.old)?,
+        ),
+        (None, Some(new)) => (read_tokens(&args.old)?, read_tokens(new)?),
+        (None, None) => {
+            return Err("give the file after the change, or --diff-base"
+                .to_string()
+                .into())
+        }
+    };
+    let rounding: RoundingPolicy = args.rounding.into();
+
+    let changes = diff_tokens(&old, &new);
+    let names: Vec<String> = changes
+        .iter()
+        .map(|change| format!("{} on {}", change.foreground, change.background))
+        .collect();
+    let width = names.iter().map(String::len).max().unwrap_or(0);
+
+    let mut dropped = 0;
+    let mut newly_failing = 0;
+    for (change, name) in changes.iter().zip(&names) {
+        let newly_fails = change.newly_fails(args.ratio, rounding);
+        dropped += usize::from(change.dropped());
+        newly_failing += usize::from(newly_fails);
+        if !change.dropped() && !args.all {
+            continue;
+        }
+
+        let note = if newly_fails {
+            format!("  newly fails {}:1", args.ratio)
+        } else {
+            String::new()
+        };
+        println!(
+            "{name:<width$}  {:>6.2}:1 -> {:>6.2}:1{note}",
+            change.old_ratio, change.new_ratio
+        );
+    }
+
+    if dropped > 0 || args.all {
+        println!();
+    }
+    println!(
+        "{dropped} of {} shared pairs lost contrast, {newly_failing} newly fail {}:1",
+        changes.len(),
+        args.ratio
+    );
+
+    Ok(if newly_failing > 0 {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    })
+}
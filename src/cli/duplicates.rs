@@ -0,0 +1,125 @@
+use std::path::PathBuf;
+use std::process::ExitCode;
+
+use clap::{Args, ValueEnum};
+use contrast_checker::report::escape_json;
+use contrast_checker::{near_duplicates, split_colors, DuplicateGroup, DUPLICATE_DELTA_E};
+
+use super::config::Config;
+use super::{parse_color, read_input, Error};
+
+#[derive(Args)]
+pub struct DuplicatesArgs {
+    /// Palette colors, in any of the supported notations
+    #[arg(required_unless_present_any = ["file", "palette"])]
+    colors: Vec<String>,
+
+    /// Read the palette from a file instead, with colors separated by whitespace, commas or new
+    /// lines. Use "-" for stdin
+    #[arg(long, conflicts_with = "colors")]
+    file: Option<PathBuf>,
+
+    /// Use a palette of the configuration file, or a built-in one like tailwind, instead
+    #[arg(long, conflicts_with_all = ["colors", "file"])]
+    palette: Option<String>,
+
+    /// CIEDE2000 difference below which colors are near duplicates, 2 is where the difference
+    /// starts to be seen at a glance
+    #[arg(long, default_value_t = DUPLICATE_DELTA_E)]
+    delta_e: f32,
+
+    /// How the groups are printed
+    #[arg(long, value_enum, default_value_t = DuplicatesFormat::Text)]
+    format: DuplicatesFormat,
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+enum DuplicatesFormat {
+    /// A line per group with the color to merge it into
+    Text,
+    /// A JSON object with the groups, their canonical color and their largest difference
+    Json,
+}
+
+/// The colors of a group that aren't its canonical one.
+fn merged<'a>(group: &DuplicateGroup, labels: &'a [String]) -> Vec<&'a str> {
+    group
+        .members
+        .iter()
+        .filter(|member| **member != group.canonical)
+        .map(|member| labels[*member].as_str())
+        .collect()
+}
+
+fn print_text(groups: &[DuplicateGroup], labels: &[String], delta_e: f32) {
+    for group in groups {
+        println!(
+            "merge {} into {} (ΔE2000 up to {:.2})",
+            merged(group, labels).join(", "),
+            labels[group.canonical],
+            group.max_delta_e
+        );
+    }
+
+    let colors: usize = groups.iter().map(|group| group.members.len()).sum();
+    let groups = match groups.len() {
+        0 => {
+            println!(
+                "no near duplicates in {} colors (ΔE2000 below {delta_e})",
+                labels.len()
+            );
+            return;
+        }
+        1 => "1 group".to_string(),
+        count => format!("{count} groups"),
+    };
+    println!(
+        "{colors} of {} colors are near duplicates, in {groups}",
+        labels.len()
+    );
+}
+
+fn print_json(groups: &[DuplicateGroup], labels: &[String], delta_e: f32) {
+    let string = |label: &str| format!("\"{}\"", escape_json(label));
+    let groups: Vec<String> = groups
+        .iter()
+        .map(|group| {
+            let merged: Vec<String> = merged(group, labels).into_iter().map(string).collect();
+            format!(
+                r#"{{"canonical":{},"merge":[{}],"max_delta_e":{}}}"#,
+                string(&labels[group.canonical]),
+                merged.join(","),
+                group.max_delta_e
+            )
+        })
+        .collect();
+
+    println!(r#"{{"delta_e":{delta_e},"groups":[{}]}}"#, groups.join(","));
+}
+
+pub fn run(args: &DuplicatesArgs, config: &Config) -> Result<ExitCode, Error> {
+    let labels: Vec<String> = match (&args.file, &args.palette) {
+        (Some(path), _) => split_colors(&read_input(Some(path))?)
+            .into_iter()
+            .map(str::to_string)
+            .collect(),
+        (None, Some(name)) => config.palette(name)?,
+        (None, None) => args.colors.clone(),
+    };
+    let colors = labels
+        .iter()
+        .map(|input| parse_color("color", input))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let groups = near_duplicates(&colors, args.delta_e);
+    match args.format {
+        DuplicatesFormat::Text => print_text(&groups, &labels, args.delta_e),
+        DuplicatesFormat::Json => print_json(&groups, &labels, args.delta_e),
+    }
+
+    Ok(if groups.is_empty() {
+        ExitCode::SUCCESS
+    } else {
+        ExitCode::FAILURE
+    })
+}
@@ -0,0 +1,50 @@
+use std::path::PathBuf;
+
+use clap::Args;
+use contrast_checker::{contrast_ratio, split_colors_iter, RoundingPolicy};
+
+use super::{parse_color, read_input, Error, Rounding};
+
+#[derive(Args)]
+pub struct FilterArgs {
+    /// Background the candidates are checked against, in any of the supported notations
+    background: String,
+
+    /// File with the candidate foregrounds, separated by whitespace, commas or new lines. Reads
+    /// from stdin when missing or "-"
+    input: Option<PathBuf>,
+
+    /// Contrast ratio a candidate needs to be kept, 4.5 is AA for normal text
+    #[arg(long, default_value_t = 4.5)]
+    ratio: f32,
+
+    /// Which ratio is compared against the threshold
+    #[arg(long, value_enum, default_value_t = Rounding::Exact)]
+    rounding: Rounding,
+}
+
+pub fn run(args: &FilterArgs) -> Result<(), Error> {
+    let background = parse_color("background color", &args.background)?;
+    let contents = read_input(args.input.as_ref())?;
+    let rounding: RoundingPolicy = args.rounding.into();
+
+    let mut passing = Vec::new();
+    for input in split_colors_iter(&contents) {
+        let ratio = contrast_ratio(&parse_color("foreground color", input)?, &background);
+        if rounding.apply(ratio) >= args.ratio {
+            passing.push((input, ratio));
+        }
+    }
+    passing.sort_by(|first, second| second.1.total_cmp(&first.1));
+
+    let width = passing
+        .iter()
+        .map(|(input, _)| input.chars().count())
+        .max()
+        .unwrap_or(0);
+    for (input, ratio) in passing {
+        println!("{input:width$}  {ratio:>5.2}:1");
+    }
+
+    Ok(())
+}
@@ -0,0 +1,76 @@
+use clap::Args;
+use contrast_checker::{contrast_ratio, suggest_foreground, Color, ColorFormat, ContrastContext};
+
+use super::config::Config;
+use super::preview::{swatch, Preview};
+use super::{parse_color, parse_requirement, Context, Error, Notation, Requirement};
+
+#[derive(Args)]
+pub struct FixArgs {
+    /// Foreground (text) color to fix
+    foreground: String,
+
+    /// Background color the foreground has to contrast with
+    background: String,
+
+    /// Level or ratio to reach, AA unless the configuration file says otherwise
+    #[arg(long, value_parser = parse_requirement)]
+    target: Option<Requirement>,
+
+    /// What the foreground is, which picks the ratio the level needs
+    #[arg(long, value_enum, default_value_t = Context::Text)]
+    context: Context,
+
+    /// Notation of the fixed color, hex unless the configuration file says otherwise
+    #[arg(long, value_enum)]
+    notation: Option<Notation>,
+
+    /// When to show samples of the text before and after, using 24-bit terminal colors
+    #[arg(long, value_enum, default_value_t = Preview::Auto)]
+    preview: Preview,
+}
+
+fn print_color(label: &str, color: &Color, background: &Color, format: ColorFormat, preview: bool) {
+    let sample = if preview {
+        format!("{} ", swatch(color, background))
+    } else {
+        String::new()
+    };
+    let (lightness, chroma, hue) = color.to_oklch();
+
+    println!(
+        "{label:<7}{sample}{}  {:.2}:1  (OKLCH L {lightness:.3}, C {chroma:.3}, H {hue:.1})",
+        color.format_as(format),
+        contrast_ratio(color, background)
+    );
+}
+
+pub fn run(args: &FixArgs, config: &Config) -> Result<(), Error> {
+    let foreground = parse_color("foreground color", &args.foreground)?;
+    let background = parse_color("background color", &args.background)?;
+    let context: ContrastContext = args.context.into();
+    let ratio = args
+        .target
+        .or(config.level)
+        .unwrap_or(Requirement::Level(contrast_checker::WcagLevel::AA))
+        .minimum_ratio(context)?;
+    let format = config.color_format(args.notation);
+    let preview = args.preview.enabled();
+
+    print_color("before", &foreground, &background, format, preview);
+    if contrast_ratio(&foreground, &background) >= ratio {
+        println!("already reaches {ratio}:1, nothing to fix");
+        return Ok(());
+    }
+
+    let Some(suggestion) = suggest_foreground(&foreground, &background, ratio) else {
+        return Err(format!(
+            "no lightness of {} reaches {ratio}:1 against {}",
+            args.foreground, args.background
+        )
+        .into());
+    };
+    print_color("after", &suggestion.color, &background, format, preview);
+
+    Ok(())
+}
@@ -0,0 +1,124 @@
+use std::path::PathBuf;
+use std::process::ExitCode;
+
+use clap::{Args, ValueEnum};
+use contrast_checker::report::escape_json;
+use contrast_checker::{check_flashes, split_colors, FlashCheck, MAX_FLASHES_PER_SECOND};
+
+use super::{parse_color, read_input, Error};
+
+#[derive(Args)]
+pub struct FlashArgs {
+    /// Colors of the keyframes, in order, in any of the supported notations
+    #[arg(required_unless_present = "file")]
+    keyframes: Vec<String>,
+
+    /// Read the keyframes from a file instead, with colors separated by whitespace, commas or new
+    /// lines. Use "-" for stdin
+    #[arg(long, conflicts_with = "keyframes")]
+    file: Option<PathBuf>,
+
+    /// Seconds the animation takes to go through every keyframe once, like animation-duration
+    #[arg(long, default_value_t = 1.0)]
+    duration: f32,
+
+    /// The animation loops, going from the last keyframe back to the first one
+    #[arg(long)]
+    repeat: bool,
+
+    /// What translucent keyframes are drawn over
+    #[arg(long, default_value = "white")]
+    background: String,
+
+    /// How the keyframes and flashes are printed
+    #[arg(long, value_enum, default_value_t = FlashFormat::Text)]
+    format: FlashFormat,
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+enum FlashFormat {
+    /// A line per keyframe with its luminance, then the largest jump and the flashes
+    Text,
+    /// A JSON object with the luminances, the largest jump and the flashes
+    Json,
+}
+
+fn print_text(check: &FlashCheck, labels: &[String]) {
+    println!("keyframe  color      luminance");
+    for (index, (label, luminance)) in labels.iter().zip(&check.luminances).enumerate() {
+        println!("{:>8}  {label:<9}  {luminance:>9.3}", index + 1);
+    }
+
+    if let Some(jump) = &check.largest_jump {
+        println!(
+            "\nlargest jump: {:+.3} from keyframe {} to {}",
+            jump.delta,
+            jump.from + 1,
+            jump.to + 1
+        );
+    }
+    let verdict = if check.passes() { "pass" } else { "fail" };
+    println!(
+        "flashes: {} in the worst second, {verdict} (at most {MAX_FLASHES_PER_SECOND})",
+        check.flashes
+    );
+}
+
+fn print_json(check: &FlashCheck, labels: &[String], args: &FlashArgs) {
+    let keyframes: Vec<String> = labels
+        .iter()
+        .zip(&check.luminances)
+        .map(|(label, luminance)| {
+            format!(
+                r#"{{"color":"{}","luminance":{luminance}}}"#,
+                escape_json(label)
+            )
+        })
+        .collect();
+    let largest_jump = match &check.largest_jump {
+        Some(jump) => format!(
+            r#"{{"from":{},"to":{},"delta":{}}}"#,
+            jump.from, jump.to, jump.delta
+        ),
+        None => "null".to_string(),
+    };
+
+    println!(
+        r#"{{"duration":{},"repeat":{},"keyframes":[{}],"largest_jump":{largest_jump},"flashes":{},"pass":{}}}"#,
+        args.duration,
+        args.repeat,
+        keyframes.join(","),
+        check.flashes,
+        check.passes()
+    );
+}
+
+pub fn run(args: &FlashArgs) -> Result<ExitCode, Error> {
+    if args.duration.is_nan() || args.duration <= 0.0 {
+        return Err("the duration must be more than 0 seconds".into());
+    }
+    let labels: Vec<String> = match &args.file {
+        Some(path) => split_colors(&read_input(Some(path))?)
+            .into_iter()
+            .map(str::to_string)
+            .collect(),
+        None => args.keyframes.clone(),
+    };
+    let background = parse_color("background color", &args.background)?;
+    let keyframes = labels
+        .iter()
+        .map(|input| Ok(parse_color("keyframe", input)?.composite_over(&background)))
+        .collect::<Result<Vec<_>, Error>>()?;
+
+    let check = check_flashes(&keyframes, args.duration, args.repeat);
+    match args.format {
+        FlashFormat::Text => print_text(&check, &labels),
+        FlashFormat::Json => print_json(&check, &labels, args),
+    }
+
+    Ok(if check.passes() {
+        ExitCode::SUCCESS
+    } else {
+        ExitCode::FAILURE
+    })
+}
@@ -0,0 +1,140 @@
+use std::process::ExitCode;
+
+use clap::{Args, ValueEnum};
+use contrast_checker::report::escape_json;
+use contrast_checker::{FocusIndicator, FocusReport, WcagLevel};
+
+use super::component::parse_level;
+use super::config::Config;
+use super::{parse_color, Error, Requirement, Rounding};
+
+#[derive(Args)]
+pub struct FocusArgs {
+    /// Color of the focus indicator, like the color of the focus ring
+    indicator: String,
+
+    /// Fill of the focused component, checked when the indicator touches it
+    #[arg(long)]
+    component: Option<String>,
+
+    /// What's around the component. Defaults to the background of the configuration file
+    #[arg(long)]
+    background: Option<String>,
+
+    /// Level the indicator has to reach, AA (SC 1.4.11) unless the configuration file says
+    /// otherwise. AAA is SC 2.4.13 of WCAG 2.2
+    #[arg(long, value_parser = parse_level)]
+    level: Option<WcagLevel>,
+
+    /// Which ratio is compared against the thresholds
+    #[arg(long, value_enum, default_value_t = Rounding::Exact)]
+    rounding: Rounding,
+
+    /// How the verdict is printed
+    #[arg(long, value_enum, default_value_t = FocusFormat::Text)]
+    format: FocusFormat,
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+enum FocusFormat {
+    /// A line per adjacent color and the verdict of the indicator
+    Text,
+    /// A JSON object with every adjacent color and the verdict
+    Json,
+}
+
+fn print_text(report: &FocusReport, indicator: &str, inputs: &[&str]) {
+    for (check, input) in report.checks.iter().zip(inputs) {
+        let verdict = if check.passes { "pass" } else { "fail" };
+        println!(
+            "{:<10} {indicator} next to {input}: {:.2}:1, needs {}:1 (SC {} {})  {verdict}",
+            check.adjacency.name(),
+            check.ratio,
+            check.required_ratio,
+            check.criterion.number,
+            check.criterion.name
+        );
+    }
+
+    let failing: Vec<&str> = report
+        .failures()
+        .map(|check| check.adjacency.name())
+        .collect();
+    if failing.is_empty() {
+        println!("focus indicator passes {:?}", report.level);
+    } else {
+        println!(
+            "focus indicator fails {:?} next to the {}",
+            report.level,
+            failing.join(" and ")
+        );
+    }
+}
+
+fn print_json(report: &FocusReport, indicator: &str, inputs: &[&str]) {
+    let checks: Vec<String> = report
+        .checks
+        .iter()
+        .zip(inputs)
+        .map(|(check, input)| {
+            format!(
+                r#"{{"adjacency":"{}","input":"{}","hex":"{}","ratio":{},"required_ratio":{},"criterion":"{}","passes":{}}}"#,
+                check.adjacency.name(),
+                escape_json(input),
+                check.color.to_hex(),
+                check.ratio,
+                check.required_ratio,
+                check.criterion.number,
+                check.passes
+            )
+        })
+        .collect();
+
+    println!(
+        r#"{{"indicator":"{}","level":"{:?}","passes":{},"checks":[{}]}}"#,
+        escape_json(indicator),
+        report.level,
+        report.passes(),
+        checks.join(",")
+    );
+}
+
+pub fn run(args: &FocusArgs, config: &Config) -> Result<ExitCode, Error> {
+    let background_input = args.background.as_deref().or(config.background.as_deref());
+    if args.component.is_none() && background_input.is_none() {
+        return Err(
+            "missing the adjacent colors, pass --component, --background or both"
+                .to_string()
+                .into(),
+        );
+    }
+    let level = args.level.unwrap_or(match config.level {
+        Some(Requirement::Level(level)) => level,
+        _ => WcagLevel::AA,
+    });
+
+    let mut indicator = FocusIndicator::new(parse_color("indicator color", &args.indicator)?)
+        .rounding(args.rounding.into());
+    // In the order the report checks them
+    let mut inputs = Vec::new();
+    if let Some(input) = &args.component {
+        indicator = indicator.component(parse_color("component color", input)?);
+        inputs.push(input.as_str());
+    }
+    if let Some(input) = background_input {
+        indicator = indicator.background(parse_color("background color", input)?);
+        inputs.push(input);
+    }
+
+    let report = indicator.check(level);
+    match args.format {
+        FocusFormat::Text => print_text(&report, &args.indicator, &inputs),
+        FocusFormat::Json => print_json(&report, &args.indicator, &inputs),
+    }
+
+    Ok(if report.passes() {
+        ExitCode::SUCCESS
+    } else {
+        ExitCode::FAILURE
+    })
+}
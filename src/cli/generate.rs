@@ -0,0 +1,116 @@
+use std::ops::RangeInclusive;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use clap::Args;
+use contrast_checker::{contrast_ratio, generate_accessible, ExitStatus};
+
+use super::config::Config;
+use super::{parse_color, Error, Notation};
+
+#[derive(Args)]
+pub struct GenerateArgs {
+    /// Background the colors have to contrast with
+    background: String,
+
+    /// How many colors to generate
+    #[arg(long, default_value_t = 8, value_parser = clap::value_parser!(u16).range(1..=256))]
+    count: u16,
+
+    /// Contrast ratio every color has to reach, 3 is AA for chart elements and large text
+    #[arg(long, default_value_t = 3.0)]
+    ratio: f32,
+
+    /// Only generate hues in this range of OKLCH degrees, e.g. 200..260. A start above the end
+    /// goes through 0, so 330..30 are the reds
+    #[arg(long, value_parser = parse_hue_range)]
+    hue: Option<RangeInclusive<f32>>,
+
+    /// Seed of the random generator, to get the same colors every time
+    #[arg(long)]
+    seed: Option<u64>,
+
+    /// Notation of the generated colors, hex unless the configuration file says otherwise
+    #[arg(long, value_enum)]
+    notation: Option<Notation>,
+
+    /// Exit successfully when fewer than --count colors reach the ratio, only warning about it
+    #[arg(long)]
+    allow_fewer: bool,
+}
+
+fn parse_hue_range(value: &str) -> Result<RangeInclusive<f32>, String> {
+    let (start, end) = value
+        .split_once("..")
+        .ok_or("expected a range of degrees like 200..260")?;
+    let degrees = |value: &str| match value.trim().parse::<f32>() {
+        Ok(degrees) if (0.0..=360.0).contains(&degrees) => Ok(degrees),
+        _ => Err(format!("{value} isn't a hue between 0 and 360")),
+    };
+
+    Ok(degrees(start)?..=degrees(end)?)
+}
+
+/// A xorshift generator, random enough to pick colors without a dependency.
+fn random_numbers(seed: u64) -> impl FnMut() -> f32 {
+    // Xorshift gets stuck on 0
+    let mut state = seed.max(1);
+    move || {
+        state ^= state << 13;
+        state ^= state >> 7;
+        state ^= state << 17;
+        (state >> 40) as f32 / (1u64 << 24) as f32
+    }
+}
+
+pub fn run(args: &GenerateArgs, config: &Config) -> Result<(), Error> {
+    let background = parse_color("background color", &args.background)?;
+    let format = config.color_format(args.notation);
+    let seed = args.seed.unwrap_or_else(|| {
+        SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_or(1, |time| time.as_nanos() as u64)
+    });
+    log::debug!("generating colors with seed {seed}");
+
+    let count = usize::from(args.count);
+    let colors = generate_accessible(
+        &background,
+        args.ratio,
+        count,
+        args.hue.clone(),
+        random_numbers(seed),
+    );
+    if colors.is_empty() {
+        return Err(Error::new(
+            ExitStatus::Threshold,
+            format!(
+                "no color reaches {}:1 against {}",
+                args.ratio, args.background
+            ),
+        ));
+    }
+
+    for color in &colors {
+        println!(
+            "{}  {:.2}:1",
+            color.format_as(format),
+            contrast_ratio(color, &background)
+        );
+    }
+    if colors.len() < count {
+        let found = match colors.len() {
+            1 => "1 different color reaches".to_string(),
+            found => format!("{found} different colors reach"),
+        };
+        let message = format!(
+            "only {found} {}:1 against {}, {count} were asked for",
+            args.ratio, args.background
+        );
+        if !args.allow_fewer {
+            return Err(Error::new(ExitStatus::Threshold, message));
+        }
+        eprintln!("warning: {message}");
+    }
+
+    Ok(())
+}
@@ -0,0 +1,73 @@
+use std::process::ExitCode;
+
+use clap::Args;
+use contrast_checker::{contrast_along_gradient, RoundingPolicy};
+
+use super::{parse_color, Error, Rounding};
+
+#[derive(Args)]
+pub struct GradientArgs {
+    /// Text color, in any of the supported notations
+    text: String,
+
+    /// First stop of the gradient
+    start: String,
+
+    /// Second stop of the gradient
+    end: String,
+
+    /// How many evenly spaced points to check, both stops included
+    #[arg(long, default_value_t = 11, value_parser = clap::value_parser!(u16).range(2..=1000))]
+    steps: u16,
+
+    /// Contrast ratio every point has to reach, 4.5 is AA for normal text
+    #[arg(long, default_value_t = 4.5)]
+    ratio: f32,
+
+    /// Which ratio is compared against the threshold
+    #[arg(long, value_enum, default_value_t = Rounding::Exact)]
+    rounding: Rounding,
+}
+
+pub fn run(args: &GradientArgs) -> Result<ExitCode, Error> {
+    let text = parse_color("text color", &args.text)?;
+    let start = parse_color("start color", &args.start)?;
+    let end = parse_color("end color", &args.end)?;
+    let rounding: RoundingPolicy = args.rounding.into();
+
+    let gradient = contrast_along_gradient(&text, &start, &end, usize::from(args.steps));
+    println!("position  color      ratio");
+    for sample in &gradient.samples {
+        let verdict = if rounding.apply(sample.ratio) >= args.ratio {
+            "pass"
+        } else {
+            "fail"
+        };
+        println!(
+            "{:>7.1}%  {:<9}  {:>5.2}  {verdict}",
+            sample.position * 100.0,
+            sample.color.to_hex(),
+            sample.ratio
+        );
+    }
+
+    if let Some(minimum) = gradient.minimum() {
+        println!(
+            "\nminimum: {:.2}:1 at {:.1}%",
+            minimum.ratio,
+            minimum.position * 100.0
+        );
+    }
+
+    Ok(match gradient.first_failure(args.ratio, rounding) {
+        Some(failure) => {
+            println!(
+                "first below {}:1 at {:.1}%",
+                args.ratio,
+                failure.position * 100.0
+            );
+            ExitCode::FAILURE
+        }
+        None => ExitCode::SUCCESS,
+    })
+}
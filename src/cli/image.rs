@@ -0,0 +1,158 @@
+use std::io::{self, Read};
+use std::path::PathBuf;
+
+use clap::Args;
+use contrast_checker::{overlay_contrast, Color};
+use image::{DynamicImage, ImageReader};
+
+use super::{parse_color, Error};
+
+/// A rectangle of the image, in pixels.
+#[derive(Clone, Copy)]
+pub struct Region {
+    pub x: u32,
+    pub y: u32,
+    pub width: u32,
+    pub height: u32,
+}
+
+pub fn parse_region(input: &str) -> Result<Region, String> {
+    let values = input
+        .split(',')
+        .map(|value| value.trim().parse::<u32>())
+        .collect::<Result<Vec<_>, _>>();
+
+    match values.as_deref() {
+        Ok(&[x, y, width, height]) if width > 0 && height > 0 => Ok(Region {
+            x,
+            y,
+            width,
+            height,
+        }),
+        _ => Err("expected x,y,width,height in pixels, like 0,0,640,120".to_string()),
+    }
+}
+
+/// Parses a pixel as x,y, the region of that single pixel.
+pub fn parse_point(input: &str) -> Result<Region, String> {
+    match input
+        .split_once(',')
+        .map(|(x, y)| (x.trim().parse(), y.trim().parse()))
+    {
+        Some((Ok(x), Ok(y))) => Ok(Region {
+            x,
+            y,
+            width: 1,
+            height: 1,
+        }),
+        _ => Err("expected x,y in pixels, like 100,200".to_string()),
+    }
+}
+
+#[derive(Args)]
+pub struct ImageArgs {
+    /// PNG, JPEG or WebP image the text is drawn over, "-" reads it from stdin like
+    /// `wl-paste | contrast-checker image - white`
+    image: PathBuf,
+
+    /// Text color, in any of the supported notations
+    text: String,
+
+    /// Only sample the part of the image behind the text, as x,y,width,height in pixels
+    #[arg(long, value_parser = parse_region)]
+    region: Option<Region>,
+
+    /// Only sample the pixel at x,y
+    #[arg(long, value_parser = parse_point, conflicts_with = "region")]
+    at: Option<Region>,
+
+    /// Contrast ratio the worst case has to reach, 4.5 is AA for normal text
+    #[arg(long, default_value_t = 4.5)]
+    ratio: f32,
+}
+
+/// Reads an image file, or the bytes piped to stdin for "-", whose format is told from them.
+fn open(path: &PathBuf) -> Result<DynamicImage, Error> {
+    if path.as_os_str() != "-" {
+        return image::open(path)
+            .map_err(|err| Error::io(format!("could not read {}: {err}", path.display())));
+    }
+
+    let mut bytes = Vec::new();
+    io::stdin()
+        .read_to_end(&mut bytes)
+        .map_err(|err| Error::io(format!("could not read stdin: {err}")))?;
+    let format = image::guess_format(&bytes)
+        .map_err(|_| "stdin isn't a PNG, JPEG or WebP image".to_string())?;
+    ImageReader::with_format(io::Cursor::new(bytes), format)
+        .decode()
+        .map_err(|err| format!("could not decode the image on stdin: {err}").into())
+}
+
+/// Reads the pixels of a region of an image, the whole image without one, and returns the region
+/// they come from. The image is read from stdin when `path` is "-".
+pub fn region_pixels(
+    path: &PathBuf,
+    region: Option<Region>,
+) -> Result<(Region, Vec<Color>), Error> {
+    let image = open(path)?.to_rgba8();
+
+    let region = region.unwrap_or(Region {
+        x: 0,
+        y: 0,
+        width: image.width(),
+        height: image.height(),
+    });
+    if region.x.saturating_add(region.width) > image.width()
+        || region.y.saturating_add(region.height) > image.height()
+    {
+        return Err(format!(
+            "the region doesn't fit in the image, which is {}x{}",
+            image.width(),
+            image.height()
+        )
+        .into());
+    }
+
+    let pixels = (region.y..region.y + region.height)
+        .flat_map(|y| {
+            let image = &image;
+            (region.x..region.x + region.width).map(move |x| {
+                let [red, green, blue, alpha] = image.get_pixel(x, y).0;
+                Color::new_with_alpha(red, green, blue, f32::from(alpha) / 255.0)
+            })
+        })
+        .collect();
+
+    Ok((region, pixels))
+}
+
+pub fn run(args: &ImageArgs) -> Result<(), Error> {
+    let text = parse_color("text color", &args.text)?;
+    let (region, pixels) = region_pixels(&args.image, args.region.or(args.at))?;
+    let contrast = overlay_contrast(&text, pixels)
+        .ok_or_else(|| format!("{} has no pixels", args.image.display()))?;
+
+    println!(
+        "sampled {}x{} pixels at {},{}",
+        region.width, region.height, region.x, region.y
+    );
+    println!(
+        "background luminance: {:.3} to {:.3}, {:.3} on average",
+        contrast.min_luminance, contrast.max_luminance, contrast.average_luminance
+    );
+    println!(
+        "contrast ratio: {:.2}:1 worst, {:.2}:1 average, {:.2}:1 best",
+        contrast.worst_ratio, contrast.average_ratio, contrast.best_ratio
+    );
+    let verdict = if contrast.worst_ratio >= args.ratio {
+        "passes"
+    } else if contrast.average_ratio >= args.ratio {
+        "only passes on average"
+    } else {
+        "fails"
+    };
+    println!("{verdict} {}:1", args.ratio);
+
+    Ok(())
+}
@@ -0,0 +1,293 @@
+use std::io::{self, Write};
+
+use clap::Args;
+use contrast_checker::report::COMPLIANCE_COLUMNS;
+use contrast_checker::{
+    contrast_ratio, required_lightness_change, Color, ComplianceReport, TextSize, WcagLevel,
+};
+use crossterm::event::{self, Event, KeyCode, KeyEvent, KeyEventKind, KeyModifiers};
+use crossterm::style::Print;
+use crossterm::terminal::{self, Clear, ClearType, EnterAlternateScreen, LeaveAlternateScreen};
+use crossterm::{cursor, execute, queue};
+
+use super::preview::swatch;
+use super::{parse_color, Error};
+
+#[derive(Args)]
+pub struct InteractiveArgs {
+    /// Foreground (text) color to start with
+    #[arg(default_value = "#777777")]
+    foreground: String,
+
+    /// Background color to start with
+    #[arg(default_value = "white")]
+    background: String,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Mode {
+    Rgb,
+    Hsl,
+}
+
+/// One of the two colors being edited. The HSL values are kept next to the color because
+/// converting back from RGB loses the hue of grays, which would make the hue slider jump.
+struct Slot {
+    name: &'static str,
+    color: Color,
+    hsl: (f32, f32, f32),
+}
+
+impl Slot {
+    fn new(name: &'static str, color: Color) -> Slot {
+        Slot {
+            name,
+            hsl: color.to_hsl(),
+            color,
+        }
+    }
+
+    fn channels(&self, mode: Mode) -> [(&'static str, f32, f32); 3] {
+        match mode {
+            Mode::Rgb => [
+                ("red", f32::from(self.color.red), 255.0),
+                ("green", f32::from(self.color.green), 255.0),
+                ("blue", f32::from(self.color.blue), 255.0),
+            ],
+            Mode::Hsl => [
+                ("hue", self.hsl.0, 360.0),
+                ("saturation", self.hsl.1 * 100.0, 100.0),
+                ("lightness", self.hsl.2 * 100.0, 100.0),
+            ],
+        }
+    }
+
+    fn adjust(&mut self, mode: Mode, channel: usize, step: f32) {
+        match mode {
+            Mode::Rgb => {
+                let value = match channel {
+                    0 => &mut self.color.red,
+                    1 => &mut self.color.green,
+                    _ => &mut self.color.blue,
+                };
+                *value = (f32::from(*value) + step).clamp(0.0, 255.0) as u8;
+                self.hsl = self.color.to_hsl();
+            }
+            Mode::Hsl => {
+                let (hue, saturation, lightness) = &mut self.hsl;
+                match channel {
+                    0 => *hue = (hue.round() + step).rem_euclid(360.0),
+                    1 => {
+                        *saturation =
+                            ((*saturation * 100.0).round() + step).clamp(0.0, 100.0) / 100.0
+                    }
+                    _ => {
+                        *lightness = ((*lightness * 100.0).round() + step).clamp(0.0, 100.0) / 100.0
+                    }
+                }
+                self.color = Color {
+                    alpha: self.color.alpha,
+                    ..Color::from_hsl(self.hsl.0, self.hsl.1, self.hsl.2)
+                };
+            }
+        }
+    }
+}
+
+struct State {
+    slots: [Slot; 2],
+    selected_slot: usize,
+    selected_channel: usize,
+    mode: Mode,
+}
+
+/// Restores the terminal even when drawing fails halfway.
+struct RawModeGuard;
+
+impl RawModeGuard {
+    fn enter() -> io::Result<RawModeGuard> {
+        terminal::enable_raw_mode()?;
+        execute!(io::stdout(), EnterAlternateScreen, cursor::Hide)?;
+        Ok(RawModeGuard)
+    }
+}
+
+impl Drop for RawModeGuard {
+    fn drop(&mut self) {
+        let _ = execute!(io::stdout(), cursor::Show, LeaveAlternateScreen);
+        let _ = terminal::disable_raw_mode();
+    }
+}
+
+fn bar(value: f32, max: f32) -> String {
+    let width = 24;
+    let filled = ((value / max) * width as f32).round() as usize;
+    format!(
+        "{}{}",
+        "█".repeat(filled),
+        "░".repeat(width - filled.min(width))
+    )
+}
+
+fn render(state: &State) -> Vec<String> {
+    let [foreground, background] = &state.slots;
+    let ratio = contrast_ratio(&foreground.color, &background.color);
+    let report = ComplianceReport::from_ratio(ratio);
+    let mut lines = Vec::new();
+
+    for (index, slot) in state.slots.iter().enumerate() {
+        let marker = if index == state.selected_slot {
+            '>'
+        } else {
+            ' '
+        };
+        lines.push(format!(
+            "{marker} {:<11} {}",
+            slot.name,
+            slot.color.to_hex()
+        ));
+
+        for (channel, (name, value, max)) in slot.channels(state.mode).into_iter().enumerate() {
+            let marker = if index == state.selected_slot && channel == state.selected_channel {
+                '>'
+            } else {
+                ' '
+            };
+            lines.push(format!(
+                "  {marker} {name:<10} {} {value:.0}",
+                bar(value, max)
+            ));
+        }
+        lines.push(String::new());
+    }
+
+    lines.push(swatch(&foreground.color, &background.color));
+    lines.push(String::new());
+    let note = if report.indistinguishable {
+        " (indistinguishable)"
+    } else {
+        ""
+    };
+    lines.push(format!("contrast ratio: {ratio:.2}:1{note}"));
+    for (name, level, size) in COMPLIANCE_COLUMNS {
+        let verdict = if report.passes(level, size) {
+            "pass"
+        } else {
+            "fail"
+        };
+        lines.push(format!(
+            "{name:<16} {verdict} (needs {}:1)",
+            level.minimum_ratio(size)
+        ));
+    }
+
+    // How far the lightness sliders are from the normal text levels that fail
+    let change = |change: Option<f32>| match change {
+        Some(change) => format!("{change:+.3}"),
+        None => "none".to_string(),
+    };
+    for level in [WcagLevel::AA, WcagLevel::AAA] {
+        let target = level.minimum_ratio(TextSize::Normal);
+        if ratio < target {
+            let delta = required_lightness_change(&foreground.color, &background.color, target);
+            lines.push(format!(
+                "{level:?} normal text needs OKLCH L {} on the foreground or {} on the background",
+                change(delta.foreground),
+                change(delta.background)
+            ));
+        }
+    }
+    lines.push(String::new());
+    lines.push(
+        "tab: switch color  up/down: channel  left/right: adjust (shift for 10)  m: rgb/hsl  q: quit"
+            .to_string(),
+    );
+
+    lines
+}
+
+fn draw(state: &State) -> io::Result<()> {
+    let mut stdout = io::stdout();
+    queue!(stdout, cursor::MoveTo(0, 0), Clear(ClearType::All))?;
+    for line in render(state) {
+        // Raw mode doesn't translate new lines into carriage returns
+        queue!(stdout, Print(line), Print("\r\n"))?;
+    }
+    stdout.flush()
+}
+
+/// Applies a key press, returns false when the user wants to quit.
+fn handle_key(state: &mut State, key: KeyEvent) -> bool {
+    let step = if key.modifiers.contains(KeyModifiers::SHIFT) {
+        10.0
+    } else {
+        1.0
+    };
+
+    match key.code {
+        KeyCode::Char('q') | KeyCode::Esc => return false,
+        KeyCode::Char('c') if key.modifiers.contains(KeyModifiers::CONTROL) => return false,
+        KeyCode::Tab | KeyCode::BackTab => state.selected_slot = 1 - state.selected_slot,
+        KeyCode::Char('m') => {
+            state.mode = match state.mode {
+                Mode::Rgb => Mode::Hsl,
+                Mode::Hsl => Mode::Rgb,
+            }
+        }
+        KeyCode::Up => state.selected_channel = (state.selected_channel + 2) % 3,
+        KeyCode::Down => state.selected_channel = (state.selected_channel + 1) % 3,
+        KeyCode::Left => {
+            state.slots[state.selected_slot].adjust(state.mode, state.selected_channel, -step)
+        }
+        KeyCode::Right => {
+            state.slots[state.selected_slot].adjust(state.mode, state.selected_channel, step)
+        }
+        _ => {}
+    }
+
+    true
+}
+
+fn event_loop(state: &mut State) -> io::Result<()> {
+    loop {
+        draw(state)?;
+
+        if let Event::Key(key) = event::read()? {
+            // Windows reports releases too
+            if key.kind == KeyEventKind::Press && !handle_key(state, key) {
+                return Ok(());
+            }
+        }
+    }
+}
+
+pub fn run(args: &InteractiveArgs) -> Result<(), Error> {
+    let foreground = parse_color("foreground color", &args.foreground)?;
+    let background = parse_color("background color", &args.background)?;
+
+    let mut state = State {
+        slots: [
+            Slot::new("foreground", foreground),
+            Slot::new("background", background),
+        ],
+        selected_slot: 0,
+        selected_channel: 0,
+        mode: Mode::Rgb,
+    };
+
+    let guard = RawModeGuard::enter()
+        .map_err(|err| Error::io(format!("could not open the terminal: {err}")))?;
+    let result = event_loop(&mut state);
+    drop(guard);
+    result.map_err(|err| format!("terminal error: {err}"))?;
+
+    let [foreground, background] = &state.slots;
+    println!(
+        "{} on {}: {:.2}:1",
+        foreground.color.to_hex(),
+        background.color.to_hex(),
+        contrast_ratio(&foreground.color, &background.color)
+    );
+
+    Ok(())
+}
@@ -0,0 +1,122 @@
+use std::process::ExitCode;
+
+use clap::{Args, ValueEnum};
+use contrast_checker::report::escape_json;
+use contrast_checker::{check_link, LinkReport, WcagLevel};
+
+use super::component::parse_level;
+use super::config::Config;
+use super::{parse_color, Error, Requirement, Rounding};
+
+#[derive(Args)]
+pub struct LinkArgs {
+    /// Color of the links
+    link: String,
+
+    /// Color of the body text the links are in
+    text: String,
+
+    /// Background of the text. Defaults to the background of the configuration file
+    #[arg(long)]
+    background: Option<String>,
+
+    /// Level the text and the link need on the background, AA unless the configuration file says
+    /// otherwise. The link always needs 3:1 against the text
+    #[arg(long, value_parser = parse_level)]
+    level: Option<WcagLevel>,
+
+    /// Which ratio is compared against the thresholds
+    #[arg(long, value_enum, default_value_t = Rounding::Exact)]
+    rounding: Rounding,
+
+    /// How the verdict is printed
+    #[arg(long, value_enum, default_value_t = LinkFormat::Text)]
+    format: LinkFormat,
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+enum LinkFormat {
+    /// A line per pair and the verdict of the link color
+    Text,
+    /// A JSON object with every pair and the verdict
+    Json,
+}
+
+fn print_text(report: &LinkReport) {
+    for check in &report.checks {
+        let verdict = if check.passes { "pass" } else { "fail" };
+        println!(
+            "{:<18}  {:>5.2}:1, needs {}:1  {verdict}",
+            check.pair.name(),
+            check.ratio,
+            check.required_ratio
+        );
+    }
+
+    let failing: Vec<&str> = report.failures().map(|check| check.pair.name()).collect();
+    if failing.is_empty() {
+        println!(
+            "links can be told apart by color alone at {:?}",
+            report.level
+        );
+    } else {
+        println!("links fail {:?}: {}", report.level, failing.join(", "));
+    }
+    if !report.checks[0].passes && report.checks[1..].iter().all(|check| check.passes) {
+        println!("  hint: underline the links, or give them another cue than color");
+    }
+}
+
+fn print_json(report: &LinkReport, inputs: [&str; 3]) {
+    let checks: Vec<String> = report
+        .checks
+        .iter()
+        .map(|check| {
+            format!(
+                r#"{{"pair":"{}","ratio":{},"required_ratio":{},"passes":{}}}"#,
+                check.pair.name(),
+                check.ratio,
+                check.required_ratio,
+                check.passes
+            )
+        })
+        .collect();
+    let [link, text, background] = inputs.map(escape_json);
+
+    println!(
+        r#"{{"link":"{link}","text":"{text}","background":"{background}","level":"{:?}","passes":{},"checks":[{}]}}"#,
+        report.level,
+        report.passes(),
+        checks.join(",")
+    );
+}
+
+pub fn run(args: &LinkArgs, config: &Config) -> Result<ExitCode, Error> {
+    let background_input = args
+        .background
+        .as_deref()
+        .or(config.background.as_deref())
+        .ok_or("missing the background, pass --background or set it in the configuration file")?;
+    let level = args.level.unwrap_or(match config.level {
+        Some(Requirement::Level(level)) => level,
+        _ => WcagLevel::AA,
+    });
+
+    let report = check_link(
+        &parse_color("link color", &args.link)?,
+        &parse_color("text color", &args.text)?,
+        &parse_color("background color", background_input)?,
+        level,
+        args.rounding.into(),
+    );
+    match args.format {
+        LinkFormat::Text => print_text(&report),
+        LinkFormat::Json => print_json(&report, [&args.link, &args.text, background_input]),
+    }
+
+    Ok(if report.passes() {
+        ExitCode::SUCCESS
+    } else {
+        ExitCode::FAILURE
+    })
+}
@@ -0,0 +1,82 @@
+//! `live`, a readout of the contrast under the pointer for apps that can't be inspected. The
+//! region around the pointer is sampled continuously, the text and background colors are estimated
+//! from it and the result is shown in an overlay next to the pointer.
+
+use std::thread;
+use std::time::Duration;
+
+use clap::Args;
+use contrast_checker::{check_compliance, estimate_text_colors, Color};
+
+use super::check::grade;
+use super::screen::{Screen, OFFSET};
+use super::Error;
+
+fn parse_size(input: &str) -> Result<u16, String> {
+    match input.parse::<u16>() {
+        Ok(size) if (2..=2 * OFFSET as u16).contains(&size) => Ok(size),
+        _ => Err(format!("expected a size from 2 to {} pixels", 2 * OFFSET)),
+    }
+}
+
+#[derive(Args)]
+pub struct LiveArgs {
+    /// Width and height of the square sampled around the pointer, in pixels, big enough for a
+    /// couple of letters of the text
+    #[arg(long, default_value_t = 32, value_parser = parse_size)]
+    size: u16,
+
+    /// How often the region is sampled again, in milliseconds
+    #[arg(long, default_value_t = 100)]
+    interval: u64,
+}
+
+/// The lines of the overlay for a region of the screen.
+fn readout(pixels: &[Color]) -> Vec<String> {
+    match estimate_text_colors(pixels.iter().copied()) {
+        Some(colors) => {
+            let compliance = check_compliance(&colors.text, &colors.background);
+            vec![
+                format!("{:.2}:1, {}", compliance.ratio, grade(&compliance)),
+                format!("{} on {}", colors.text.to_hex(), colors.background.to_hex()),
+            ]
+        }
+        None => vec![
+            "no text under the pointer".to_string(),
+            pixels
+                .first()
+                .map(|color| color.to_hex())
+                .unwrap_or_default(),
+        ],
+    }
+}
+
+pub fn run(args: &LiveArgs) -> Result<(), Error> {
+    let screen = Screen::connect()?;
+    let overlay = screen.overlay()?;
+    let interval = Duration::from_millis(args.interval);
+    let mut last = None;
+
+    loop {
+        let (x, y) = screen.pointer()?;
+        // The square is moved inside the screen near its edges, the reading is still close by
+        let corner = |pointer: i16, screen: u16| {
+            let start = i32::from(pointer) - i32::from(args.size / 2);
+            start.clamp(0, i32::from(screen.saturating_sub(args.size))) as i16
+        };
+        let pixels = screen.capture(
+            corner(x, screen.width),
+            corner(y, screen.height),
+            args.size,
+            args.size,
+        )?;
+
+        let lines = readout(&pixels);
+        if last.as_ref() != Some(&(x, y, lines.clone())) {
+            overlay.show(x, y, &lines)?;
+            last = Some((x, y, lines));
+        }
+
+        thread::sleep(interval);
+    }
+}
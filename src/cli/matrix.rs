@@ -0,0 +1,377 @@
+use std::path::PathBuf;
+
+use clap::{Args, ValueEnum};
+use contrast_checker::report::{
+    distance_matrix_html, escape_json, matrix_html_with, tsv_field, CsvDialect,
+};
+use contrast_checker::{
+    best_text_color, delta_e_matrix, split_colors, Color, ContrastAlgorithm, RoundingPolicy,
+};
+
+use super::config::Config;
+use super::{csv_dialect, parse_color, read_input, Algorithm, Error, Rounding};
+
+#[derive(Args)]
+pub struct MatrixArgs {
+    /// Palette colors, in any of the supported notations
+    #[arg(required_unless_present_any = ["file", "palette"])]
+    colors: Vec<String>,
+
+    /// Read the palette from a file instead, with colors separated by whitespace, commas or new
+    /// lines. Use "-" for stdin
+    #[arg(long, conflicts_with = "colors")]
+    file: Option<PathBuf>,
+
+    /// Use a palette of the configuration file, or a built-in one like tailwind, instead
+    #[arg(long, conflicts_with_all = ["colors", "file"])]
+    palette: Option<String>,
+
+    /// Contrast a combination needs to pass, 4.5:1 (AA for normal text) or the default of the
+    /// algorithm
+    #[arg(long)]
+    ratio: Option<f32>,
+
+    /// How contrast is measured
+    #[arg(long, value_enum, default_value_t = Algorithm::Wcag2)]
+    algorithm: Algorithm,
+
+    /// Which ratio is compared against the threshold
+    #[arg(long, value_enum, default_value_t = Rounding::Exact)]
+    rounding: Rounding,
+
+    /// Print the CIEDE2000 difference of every two colors instead of their contrast, for
+    /// clustering or multidimensional scaling of the palette
+    #[arg(long, conflicts_with_all = ["ratio", "algorithm", "rounding"])]
+    delta_e: bool,
+
+    /// How the matrix is printed
+    #[arg(long, value_enum, default_value_t = MatrixFormat::Text)]
+    format: MatrixFormat,
+
+    /// With --format csv, separate the fields with ";" and write decimals with a comma, for
+    /// spreadsheets in locales like German or French
+    #[arg(long)]
+    decimal_comma: bool,
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+enum MatrixFormat {
+    /// A grid with foregrounds as rows and backgrounds as columns, passing cells are marked
+    Text,
+    /// A JSON object with the colors, the threshold and the ratios
+    Json,
+    /// The grid as comma separated values, for spreadsheets
+    Csv,
+    /// The grid as tab separated values
+    Tsv,
+    /// A standalone HTML page with rendered samples of every combination and pass/fail badges
+    Html,
+    /// A Graphviz graph of the colors, with an edge for every combination that passes
+    Dot,
+    /// A Mermaid flowchart of the colors, with an edge for every combination that passes
+    Mermaid,
+}
+
+/// Prints the grid, every cell but the diagonal written by `cell`.
+fn print_text(labels: &[String], matrix: &[Vec<f32>], cell: impl Fn(f32) -> String) {
+    // Wide enough for cells like "21.00 ✓", or "-108.00 ✓" with APCA
+    let cell_width = matrix
+        .iter()
+        .flatten()
+        .map(|value| cell(*value).chars().count())
+        .fold(7, usize::max);
+    let widths: Vec<usize> = labels
+        .iter()
+        .map(|label| label.chars().count().max(cell_width))
+        .collect();
+    let corner = "fg \\ bg";
+    let label_width = labels
+        .iter()
+        .map(|label| label.chars().count())
+        .fold(corner.len(), usize::max);
+
+    let mut header = format!("{corner:label_width$}");
+    for (label, width) in labels.iter().zip(&widths) {
+        header.push_str(&format!("  {label:>width$}"));
+    }
+    println!("{}", header.trim_end());
+
+    for (index, (label, row)) in labels.iter().zip(matrix).enumerate() {
+        let mut line = format!("{label:label_width$}");
+        for (column, (value, width)) in row.iter().zip(&widths).enumerate() {
+            let cell = if column == index {
+                "-".to_string()
+            } else {
+                cell(*value)
+            };
+            line.push_str(&format!("  {cell:>width$}"));
+        }
+        println!("{line}");
+    }
+}
+
+/// Prints the grid with a header row of backgrounds and a foreground first in every row, each
+/// label made safe by `field`, each value written by `number` and the fields separated by
+/// `separator`.
+fn print_separated(
+    labels: &[String],
+    matrix: &[Vec<f32>],
+    field: impl Fn(&str) -> String,
+    number: impl Fn(f32) -> String,
+    separator: &str,
+    line_end: &str,
+) {
+    let header: Vec<String> = std::iter::once("fg \\ bg")
+        .chain(labels.iter().map(String::as_str))
+        .map(&field)
+        .collect();
+    print!("{}{line_end}", header.join(separator));
+
+    for (label, row) in labels.iter().zip(matrix) {
+        let fields: Vec<String> = std::iter::once(field(label))
+            .chain(row.iter().map(|ratio| number(*ratio)))
+            .collect();
+        print!("{}{line_end}", fields.join(separator));
+    }
+}
+
+/// The colors and the rows of the matrix as JSON arrays.
+fn json_arrays(labels: &[String], matrix: &[Vec<f32>]) -> (String, String) {
+    let colors: Vec<String> = labels
+        .iter()
+        .map(|label| format!("\"{}\"", escape_json(label)))
+        .collect();
+    let rows: Vec<String> = matrix
+        .iter()
+        .map(|row| {
+            let values: Vec<String> = row.iter().map(f32::to_string).collect();
+            format!("[{}]", values.join(","))
+        })
+        .collect();
+
+    (
+        format!("[{}]", colors.join(",")),
+        format!("[{}]", rows.join(",")),
+    )
+}
+
+fn print_json(labels: &[String], matrix: &[Vec<f32>], algorithm: &str, threshold: f32) {
+    let (colors, ratios) = json_arrays(labels, matrix);
+    println!(
+        r#"{{"colors":{colors},"algorithm":"{algorithm}","threshold":{threshold},"ratios":{ratios}}}"#
+    );
+}
+
+fn print_distances_json(labels: &[String], matrix: &[Vec<f32>]) {
+    let (colors, distances) = json_arrays(labels, matrix);
+    println!(r#"{{"colors":{colors},"metric":"ciede2000","distances":{distances}}}"#);
+}
+
+/// Prints the grid as CSV in `dialect`.
+fn print_csv(labels: &[String], matrix: &[Vec<f32>], dialect: CsvDialect) {
+    print_separated(
+        labels,
+        matrix,
+        |label| dialect.field(label),
+        |value| dialect.decimal(value),
+        &dialect.delimiter.to_string(),
+        "\r\n",
+    );
+}
+
+/// Prints the grid as TSV.
+fn print_tsv(labels: &[String], matrix: &[Vec<f32>]) {
+    print_separated(
+        labels,
+        matrix,
+        tsv_field,
+        |value| format!("{value:.2}"),
+        "\t",
+        "\n",
+    );
+}
+
+/// Prints the CIEDE2000 differences of the palette with the writers of the contrast matrix.
+fn print_distances(
+    labels: &[String],
+    colors: &[Color],
+    format: MatrixFormat,
+    dialect: CsvDialect,
+) -> Result<(), Error> {
+    let matrix = delta_e_matrix(colors);
+    match format {
+        MatrixFormat::Text => print_text(labels, &matrix, |delta_e| format!("{delta_e:.2}")),
+        MatrixFormat::Json => print_distances_json(labels, &matrix),
+        MatrixFormat::Csv => print_csv(labels, &matrix, dialect),
+        MatrixFormat::Tsv => print_tsv(labels, &matrix),
+        MatrixFormat::Html => println!("{}", distance_matrix_html(labels, colors)),
+        MatrixFormat::Dot | MatrixFormat::Mermaid => {
+            return Err(
+                "graphs show the pairs that pass, they can't be drawn with --delta-e".into(),
+            )
+        }
+    }
+
+    Ok(())
+}
+
+/// The combinations that pass, as `(foreground, background, contrast)` indices. With a symmetric
+/// algorithm, like the WCAG ratio, a pair is only listed once and the graph is undirected.
+fn passing_edges(
+    matrix: &[Vec<f32>],
+    passes: impl Fn(f32) -> bool,
+) -> (bool, Vec<(usize, usize, f32)>) {
+    let symmetric = matrix.iter().enumerate().all(|(row, ratios)| {
+        ratios
+            .iter()
+            .enumerate()
+            .all(|(column, ratio)| *ratio == matrix[column][row])
+    });
+
+    let edges = matrix
+        .iter()
+        .enumerate()
+        .flat_map(|(row, ratios)| {
+            ratios
+                .iter()
+                .enumerate()
+                .filter(move |(column, _)| {
+                    if symmetric {
+                        row < *column
+                    } else {
+                        row != *column
+                    }
+                })
+                .map(move |(column, ratio)| (row, column, *ratio))
+        })
+        .filter(|(_, _, ratio)| passes(*ratio))
+        .collect();
+
+    (symmetric, edges)
+}
+
+/// Black or white, whichever reads better on a node filled with `color`.
+fn node_text(color: &Color) -> String {
+    let candidates = [Color::new(0, 0, 0), Color::new(255, 255, 255)];
+    // There are always two candidates
+    candidates[best_text_color(color, &candidates).unwrap().index].to_hex()
+}
+
+fn dot_string(value: &str) -> String {
+    format!("\"{}\"", value.replace('\\', "\\\\").replace('"', "\\\""))
+}
+
+fn print_dot(
+    labels: &[String],
+    colors: &[Color],
+    edges: (bool, Vec<(usize, usize, f32)>),
+    description: &str,
+) {
+    let (symmetric, edges) = edges;
+    let (graph, arrow) = if symmetric {
+        ("graph", "--")
+    } else {
+        ("digraph", "->")
+    };
+
+    println!("{graph} contrast {{");
+    println!("  // {description}");
+    println!("  node [shape=box, style=filled];");
+    for (label, color) in labels.iter().zip(colors) {
+        println!(
+            "  {} [fillcolor=\"{}\", fontcolor=\"{}\"];",
+            dot_string(label),
+            color.to_hex(),
+            node_text(color)
+        );
+    }
+    for (foreground, background, contrast) in edges {
+        println!(
+            "  {} {arrow} {} [label=\"{contrast:.2}\"];",
+            dot_string(&labels[foreground]),
+            dot_string(&labels[background])
+        );
+    }
+    println!("}}");
+}
+
+fn print_mermaid(
+    labels: &[String],
+    colors: &[Color],
+    edges: (bool, Vec<(usize, usize, f32)>),
+    description: &str,
+) {
+    let (symmetric, edges) = edges;
+    let arrow = if symmetric { "---" } else { "-->" };
+
+    println!("graph LR");
+    println!("  %% {description}");
+    for (index, label) in labels.iter().enumerate() {
+        // Quotes can't be escaped with a backslash in Mermaid labels
+        println!("  c{index}[\"{}\"]", label.replace('"', "#quot;"));
+    }
+    for (foreground, background, contrast) in edges {
+        println!("  c{foreground} {arrow}|\"{contrast:.2}\"| c{background}");
+    }
+    for (index, color) in colors.iter().enumerate() {
+        println!(
+            "  style c{index} fill:{},color:{}",
+            color.to_hex(),
+            node_text(color)
+        );
+    }
+}
+
+pub fn run(args: &MatrixArgs, config: &Config) -> Result<(), Error> {
+    let dialect = csv_dialect(args.decimal_comma, matches!(args.format, MatrixFormat::Csv))?;
+    let labels: Vec<String> = match (&args.file, &args.palette) {
+        (Some(path), _) => split_colors(&read_input(Some(path))?)
+            .into_iter()
+            .map(str::to_string)
+            .collect(),
+        (None, Some(name)) => config.palette(name)?,
+        (None, None) => args.colors.clone(),
+    };
+
+    let colors = labels
+        .iter()
+        .map(|input| parse_color("color", input))
+        .collect::<Result<Vec<_>, _>>()?;
+    if args.delta_e {
+        return print_distances(&labels, &colors, args.format, dialect);
+    }
+    let algorithm = args.algorithm.boxed();
+    let threshold = args.ratio.unwrap_or(algorithm.default_threshold());
+    let matrix = algorithm.contrast_matrix(&colors);
+
+    let rounding: RoundingPolicy = args.rounding.into();
+    let passes = |contrast: f32| algorithm.passes(rounding.apply(contrast), threshold);
+    let description = format!("pairs that reach {threshold} with {}", algorithm.name());
+    match args.format {
+        MatrixFormat::Text => print_text(&labels, &matrix, |ratio| {
+            let mark = if passes(ratio) { '✓' } else { '✗' };
+            format!("{ratio:.2} {mark}")
+        }),
+        MatrixFormat::Json => print_json(&labels, &matrix, algorithm.name(), threshold),
+        MatrixFormat::Csv => print_csv(&labels, &matrix, dialect),
+        MatrixFormat::Tsv => print_tsv(&labels, &matrix),
+        MatrixFormat::Html => println!(
+            "{}",
+            matrix_html_with(&labels, &colors, threshold, rounding, &algorithm)
+        ),
+        MatrixFormat::Dot => print_dot(
+            &labels,
+            &colors,
+            passing_edges(&matrix, passes),
+            &description,
+        ),
+        MatrixFormat::Mermaid => print_mermaid(
+            &labels,
+            &colors,
+            passing_edges(&matrix, passes),
+            &description,
+        ),
+    }
+
+    Ok(())
+}
@@ -0,0 +1,280 @@
+//! `page`, the rendered contrast of a page open in Chrome, read through the
+//! [DevTools protocol](https://chromedevtools.github.io/devtools-protocol/): the computed colors
+//! of every visible text and the backgrounds behind it, as the browser resolved them.
+
+use std::io::{Read, Write};
+use std::net::TcpStream;
+use std::process::ExitCode;
+
+use clap::Args;
+use contrast_checker::audit::{Audit, PairInput, ResolvedPair};
+use contrast_checker::Color;
+use serde_json::{json, Value};
+use tungstenite::Message;
+
+use super::batch::{write_reports, BatchFormat};
+use super::config::Config;
+use super::{csv_dialect, Error, Rounding, Style};
+
+#[derive(Args)]
+pub struct PageArgs {
+    /// Part of the URL of the tab to audit, the first page tab when missing
+    target: Option<String>,
+
+    /// Port Chrome listens on, the one given to --remote-debugging-port
+    #[arg(long, default_value_t = 9222)]
+    port: u16,
+
+    /// Host Chrome listens on
+    #[arg(long, default_value = "127.0.0.1")]
+    host: String,
+
+    /// Contrast normal text has to reach
+    #[arg(long, default_value_t = 4.5)]
+    ratio: f32,
+
+    /// Contrast large text has to reach, 24px or 18.66px and bold
+    #[arg(long, default_value_t = 3.0)]
+    large_ratio: f32,
+
+    /// Which ratio is compared against the thresholds
+    #[arg(long, value_enum, default_value_t = Rounding::Exact)]
+    rounding: Rounding,
+
+    /// How results are printed
+    #[arg(long, value_enum, default_value_t = BatchFormat::Table)]
+    format: BatchFormat,
+
+    /// How the table shows the verdicts
+    #[arg(long, value_enum, default_value_t = Style::Columns)]
+    style: Style,
+
+    /// Show the badges as [PASS] and [FAIL] instead of emoji
+    #[arg(long)]
+    ascii: bool,
+
+    /// With --format csv, separate the fields with ";" and write decimals with a comma, for
+    /// spreadsheets in locales like German or French
+    #[arg(long)]
+    decimal_comma: bool,
+}
+
+/// Runs in the page: every element with visible text, its computed color, its font, and the
+/// background colors from it up to the first opaque one. Backgrounds with an image can't be
+/// known from styles, those texts are flagged.
+const SCRIPT: &str = r##"(() => {
+  const texts = [];
+  const seen = new Set();
+  const walker = document.createTreeWalker(document.body, NodeFilter.SHOW_TEXT);
+  for (let node = walker.nextNode(); node; node = walker.nextNode()) {
+    const element = node.parentElement;
+    if (!element || seen.has(element) || !node.textContent.trim()) continue;
+    seen.add(element);
+    const style = getComputedStyle(element);
+    if (style.visibility !== "visible" || element.getClientRects().length === 0) continue;
+
+    const backgrounds = [];
+    let image = false;
+    for (let ancestor = element; ancestor; ancestor = ancestor.parentElement) {
+      const ancestorStyle = getComputedStyle(ancestor);
+      if (ancestorStyle.backgroundImage !== "none") { image = true; break; }
+      const color = ancestorStyle.backgroundColor;
+      backgrounds.push(color);
+      if (!color.startsWith("rgba") && !color.includes("/")) break;
+    }
+
+    let name = element.tagName.toLowerCase();
+    if (element.id) name += "#" + element.id;
+    for (const className of element.classList) name += "." + className;
+    texts.push({
+      name,
+      text: node.textContent.trim().replace(/\s+/g, " ").slice(0, 30),
+      color: style.color,
+      backgrounds,
+      image,
+      fontSize: parseFloat(style.fontSize),
+      fontWeight: parseInt(style.fontWeight, 10),
+    });
+  }
+  return texts;
+})()"##;
+
+/// Reads a JSON response of the HTTP endpoints of Chrome, like `/json/list`.
+fn http_json(args: &PageArgs, path: &str) -> Result<Value, Error> {
+    let address = format!("{}:{}", args.host, args.port);
+    let unreachable = |err: std::io::Error| {
+        Error::io(format!("could not reach Chrome at {address}, is it running with --remote-debugging-port? {err}"))
+    };
+    let mut stream = TcpStream::connect(&address).map_err(unreachable)?;
+    write!(
+        stream,
+        "GET {path} HTTP/1.1\r\nHost: {address}\r\nConnection: close\r\n\r\n"
+    )
+    .map_err(unreachable)?;
+    let mut response = String::new();
+    stream.read_to_string(&mut response).map_err(unreachable)?;
+
+    let (_, body) = response
+        .split_once("\r\n\r\n")
+        .ok_or("Chrome sent an invalid HTTP response")?;
+    serde_json::from_str(body).map_err(|err| format!("Chrome sent invalid JSON, {err}").into())
+}
+
+/// The DevTools WebSocket of the tab to audit.
+fn target_url(args: &PageArgs) -> Result<String, Error> {
+    let targets = http_json(args, "/json/list")?;
+    let target = targets
+        .as_array()
+        .into_iter()
+        .flatten()
+        .filter(|target| target["type"] == "page")
+        .find(|target| match &args.target {
+            Some(part) => target["url"].as_str().is_some_and(|url| url.contains(part)),
+            None => true,
+        })
+        .ok_or_else(|| match &args.target {
+            Some(part) => format!("no tab of Chrome has \"{part}\" in its URL"),
+            None => "Chrome has no tab open".to_string(),
+        })?;
+
+    target["webSocketDebuggerUrl"]
+        .as_str()
+        .map(str::to_string)
+        .ok_or_else(|| "the tab is already being debugged by another client".into())
+}
+
+/// Evaluates [`SCRIPT`] in the tab and returns what it found.
+fn evaluate(args: &PageArgs, url: &str) -> Result<Vec<Value>, Error> {
+    let stream = TcpStream::connect(format!("{}:{}", args.host, args.port))
+        .map_err(|err| Error::io(format!("could not connect to the tab, {err}")))?;
+    let (mut socket, _) = tungstenite::client(url, stream)
+        .map_err(|err| Error::io(format!("could not connect to the tab, {err}")))?;
+    let request = json!({
+        "id": 1,
+        "method": "Runtime.evaluate",
+        "params": { "expression": SCRIPT, "returnByValue": true },
+    });
+    socket
+        .send(Message::text(request.to_string()))
+        .map_err(|err| Error::io(format!("could not send to the tab, {err}")))?;
+
+    // Events of the protocol can come before the response
+    loop {
+        let message = socket
+            .read()
+            .map_err(|err| Error::io(format!("could not read from the tab, {err}")))?;
+        let Message::Text(text) = message else {
+            continue;
+        };
+        let response: Value = serde_json::from_str(text.as_str())
+            .map_err(|err| format!("the tab sent invalid JSON, {err}"))?;
+        if response["id"] != 1 {
+            continue;
+        }
+        let _ = socket.close(None);
+
+        if let Some(error) = response["error"]["message"].as_str() {
+            return Err(format!("Chrome could not run the audit, {error}").into());
+        }
+        if let Some(exception) = response["result"]["exceptionDetails"]["text"].as_str() {
+            return Err(format!("the audit failed in the page, {exception}").into());
+        }
+        return Ok(response["result"]["result"]["value"]
+            .as_array()
+            .cloned()
+            .unwrap_or_default());
+    }
+}
+
+/// A text of the page, the color it's rendered with and whether it's large.
+struct Text {
+    pair: ResolvedPair,
+    large: bool,
+}
+
+/// The colors a text is rendered with: its color on the backgrounds composited from the opaque
+/// one up, over the white of the page when none is opaque.
+fn rendered(text: &Value) -> Option<Text> {
+    let color = contrast_checker::parse_color(text["color"].as_str()?).ok()?;
+    let mut background = Color::from_hex("#ffffff").ok()?;
+    let layers = text["backgrounds"].as_array()?;
+    for layer in layers.iter().rev() {
+        let layer = contrast_checker::parse_color(layer.as_str()?).ok()?;
+        background = layer.composite_over(&background);
+    }
+    let foreground = color.composite_over(&background);
+
+    let size = text["fontSize"].as_f64().unwrap_or(16.0);
+    let weight = text["fontWeight"].as_i64().unwrap_or(400);
+    let large = size >= 24.0 || (size >= 18.66 && weight >= 700);
+    Some(Text {
+        pair: ResolvedPair {
+            input: PairInput {
+                foreground: format!(
+                    "{} \"{}\" ({}{})",
+                    text["name"].as_str().unwrap_or("element"),
+                    text["text"].as_str().unwrap_or_default(),
+                    text["color"].as_str().unwrap_or_default(),
+                    if large { ", large" } else { "" }
+                ),
+                background: background.to_hex(),
+                line: None,
+                metadata: Vec::new(),
+            },
+            foreground,
+            background,
+        },
+        large,
+    })
+}
+
+pub fn run(args: &PageArgs, config: &Config) -> Result<ExitCode, Error> {
+    let dialect = csv_dialect(args.decimal_comma, matches!(args.format, BatchFormat::Csv))?;
+    let url = target_url(args)?;
+    let found = evaluate(args, &url)?;
+
+    let mut texts = Vec::new();
+    let mut over_images = 0;
+    for text in &found {
+        if text["image"].as_bool() == Some(true) {
+            over_images += 1;
+            continue;
+        }
+        texts.extend(rendered(text));
+    }
+    if over_images > 0 {
+        eprintln!("{over_images} texts skipped, their background has an image");
+    }
+
+    let mut reports = Vec::new();
+    for (name, large, threshold) in [
+        ("text", false, args.ratio),
+        ("large text", true, args.large_ratio),
+    ] {
+        let pairs: Vec<ResolvedPair> = texts
+            .iter()
+            .filter(|text| text.large == large)
+            .map(|text| text.pair.clone())
+            .collect();
+        if pairs.is_empty() {
+            continue;
+        }
+        let mut audit = Audit::new()
+            .threshold(threshold)
+            .rounding(args.rounding.into());
+        if let Some(severities) = &config.severities {
+            audit = audit.severities(severities.clone());
+        }
+        reports.push((name.to_string(), audit.check(pairs)));
+    }
+    if reports.is_empty() {
+        return Err("the page has no visible text".to_string().into());
+    }
+
+    write_reports(
+        &reports,
+        args.format,
+        args.style.table_style(args.ascii),
+        dialect,
+    )
+}
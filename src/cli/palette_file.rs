@@ -0,0 +1,438 @@
+//! Palette files the configuration points to, in whatever format they're written: the format is
+//! told from the contents, or the extension when they don't say, so no setting names it.
+
+use std::fs;
+use std::path::Path;
+
+use contrast_checker::tokens::parse_tokens;
+use contrast_checker::Color;
+use yaml_rust2::{Yaml, YamlLoader};
+
+/// The formats a palette file can be in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum PaletteFormat {
+    /// GIMP and Inkscape palettes, `R G B name` lines after a `GIMP Palette` header.
+    Gpl,
+    /// Adobe Swatch Exchange, the binary swatches of Photoshop and Illustrator.
+    Ase,
+    /// Base16 and Base24 schemes, YAML with `base00` to `base0F` keys.
+    Base16,
+    /// Design tokens JSON, like `tokens` reads.
+    Tokens,
+    /// CSS custom properties, like `--accent: #f26ca7;`.
+    Css,
+}
+
+const SUPPORTED: &str = "GIMP palettes (.gpl), Adobe Swatch Exchange (.ase), Base16 schemes \
+                         (.yaml), design tokens (.json) and CSS custom properties (.css)";
+
+/// Whether a key is one of the colors of a Base16 scheme, `base00` to `base0F`, or the
+/// `base10` to `base17` Base24 adds.
+fn is_base16_key(key: &str) -> bool {
+    key.strip_prefix("base").is_some_and(|digits| {
+        digits.len() == 2
+            && digits.starts_with(['0', '1'])
+            && u8::from_str_radix(digits, 16).is_ok()
+    })
+}
+
+/// Whether a line of YAML sets one of the colors of a Base16 scheme.
+fn sets_base16_color(line: &str) -> bool {
+    line.split_once(':')
+        .is_some_and(|(key, _)| is_base16_key(key.trim().trim_matches(['"', '\''])))
+}
+
+/// The custom properties declared in CSS, as their names and values.
+fn custom_properties(css: &str) -> impl Iterator<Item = (&str, &str)> {
+    css.split(['{', '}', ';']).filter_map(|declaration| {
+        let (name, value) = declaration.split_once(':')?;
+        let name = name.trim();
+        let is_name = name.len() > 2
+            && name.starts_with("--")
+            && name
+                .chars()
+                .all(|c| c.is_alphanumeric() || c == '-' || c == '_');
+        is_name.then_some((name, value.trim()))
+    })
+}
+
+fn sniff(path: &Path, bytes: &[u8]) -> Option<PaletteFormat> {
+    if bytes.starts_with(b"ASEF") {
+        return Some(PaletteFormat::Ase);
+    }
+    if let Ok(text) = std::str::from_utf8(bytes) {
+        let text = text.trim_start_matches('\u{feff}').trim_start();
+        if text.starts_with("GIMP Palette") {
+            return Some(PaletteFormat::Gpl);
+        }
+        if text.starts_with('{') {
+            return Some(PaletteFormat::Tokens);
+        }
+        if text.lines().any(sets_base16_color) {
+            return Some(PaletteFormat::Base16);
+        }
+        if custom_properties(text).next().is_some() {
+            return Some(PaletteFormat::Css);
+        }
+    }
+
+    let extension = path.extension()?.to_string_lossy().to_ascii_lowercase();
+    match extension.as_str() {
+        "gpl" => Some(PaletteFormat::Gpl),
+        "ase" => Some(PaletteFormat::Ase),
+        "yaml" | "yml" => Some(PaletteFormat::Base16),
+        "json" => Some(PaletteFormat::Tokens),
+        "css" => Some(PaletteFormat::Css),
+        _ => None,
+    }
+}
+
+fn gpl(text: &str) -> Result<Vec<(String, String)>, String> {
+    let mut colors = Vec::new();
+    for (index, line) in text.lines().enumerate().skip(1) {
+        let line = line.trim();
+        // Comments and the Name and Columns of the palette
+        if line.is_empty() || line.starts_with('#') || line.starts_with(char::is_alphabetic) {
+            continue;
+        }
+
+        let mut fields = line.split_whitespace();
+        let channels: Vec<u8> = fields
+            .by_ref()
+            .take(3)
+            .filter_map(|channel| channel.parse().ok())
+            .collect();
+        let [red, green, blue] = channels[..] else {
+            return Err(format!(
+                "line {}: expected red, green and blue from 0 to 255",
+                index + 1
+            ));
+        };
+        let name: Vec<&str> = fields.collect();
+        let name = if name.is_empty() {
+            (colors.len() + 1).to_string()
+        } else {
+            name.join(" ")
+        };
+        colors.push((name, Color::new(red, green, blue).to_hex()));
+    }
+
+    Ok(colors)
+}
+
+/// Reads the big endian numbers and strings of an ASE file.
+struct AseReader<'a> {
+    bytes: &'a [u8],
+}
+
+impl<'a> AseReader<'a> {
+    fn take(&mut self, len: usize) -> Result<&'a [u8], String> {
+        if self.bytes.len() < len {
+            return Err("the swatches end in the middle of a block".to_string());
+        }
+        let (taken, rest) = self.bytes.split_at(len);
+        self.bytes = rest;
+        Ok(taken)
+    }
+
+    fn u16(&mut self) -> Result<u16, String> {
+        let bytes = self.take(2)?;
+        Ok(u16::from_be_bytes([bytes[0], bytes[1]]))
+    }
+
+    fn u32(&mut self) -> Result<u32, String> {
+        let bytes = self.take(4)?;
+        Ok(u32::from_be_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]))
+    }
+
+    fn f32(&mut self) -> Result<f32, String> {
+        Ok(f32::from_bits(self.u32()?))
+    }
+
+    /// A UTF-16 string prefixed with its length, which counts the terminating zero.
+    fn name(&mut self) -> Result<String, String> {
+        let len = self.u16()? as usize;
+        let units = (0..len)
+            .map(|_| self.u16())
+            .collect::<Result<Vec<u16>, String>>()?;
+        Ok(String::from_utf16_lossy(&units)
+            .trim_end_matches('\0')
+            .to_string())
+    }
+}
+
+fn channel(value: f32) -> u8 {
+    (value.clamp(0.0, 1.0) * 255.0).round() as u8
+}
+
+fn ase(bytes: &[u8]) -> Result<Vec<(String, String)>, String> {
+    let Some(bytes) = bytes.strip_prefix(b"ASEF") else {
+        return Err("not an Adobe Swatch Exchange file, it doesn't start with ASEF".to_string());
+    };
+    // The version
+    let mut reader = AseReader { bytes };
+    reader.take(4)?;
+    let blocks = reader.u32()?;
+
+    let mut colors = Vec::new();
+    for _ in 0..blocks {
+        let kind = reader.u16()?;
+        let len = reader.u32()? as usize;
+        let mut block = AseReader {
+            bytes: reader.take(len)?,
+        };
+        // Only color entries matter, groups just nest them
+        if kind != 0x0001 {
+            continue;
+        }
+
+        let name = block.name()?;
+        let color = match block.take(4)? {
+            b"RGB " => Color::new(
+                channel(block.f32()?),
+                channel(block.f32()?),
+                channel(block.f32()?),
+            ),
+            b"Gray" => {
+                let gray = channel(block.f32()?);
+                Color::new(gray, gray, gray)
+            }
+            b"CMYK" => {
+                let [cyan, magenta, yellow, black] =
+                    [block.f32()?, block.f32()?, block.f32()?, block.f32()?];
+                Color::new(
+                    channel((1.0 - cyan) * (1.0 - black)),
+                    channel((1.0 - magenta) * (1.0 - black)),
+                    channel((1.0 - yellow) * (1.0 - black)),
+                )
+            }
+            model => {
+                return Err(format!(
+                    "swatch {name} is in the {} color model, only RGB, CMYK and gray are supported",
+                    String::from_utf8_lossy(model).trim()
+                ))
+            }
+        };
+        let name = if name.is_empty() {
+            (colors.len() + 1).to_string()
+        } else {
+            name
+        };
+        colors.push((name, color.to_hex()));
+    }
+
+    Ok(colors)
+}
+
+fn base16(text: &str) -> Result<Vec<(String, String)>, String> {
+    let documents =
+        YamlLoader::load_from_str(text).map_err(|err| format!("invalid YAML, {err}"))?;
+    let Some(Yaml::Hash(scheme)) = documents.first() else {
+        return Err("a Base16 scheme must be a mapping of base00 to base0F".to_string());
+    };
+    // Newer schemes keep the colors in a palette mapping
+    let scheme = match scheme.get(&Yaml::String("palette".to_string())) {
+        Some(Yaml::Hash(palette)) => palette,
+        _ => scheme,
+    };
+
+    let colors: Vec<(String, String)> = scheme
+        .iter()
+        .filter_map(|(key, value)| {
+            let key = key.as_str().filter(|key| is_base16_key(key))?;
+            let value = value.as_str()?;
+            Some((
+                key.to_string(),
+                format!("#{}", value.trim_start_matches('#')),
+            ))
+        })
+        .collect();
+    if colors.is_empty() {
+        return Err("the scheme has no base00 to base0F colors".to_string());
+    }
+
+    Ok(colors)
+}
+
+fn tokens(text: &str) -> Result<Vec<(String, String)>, String> {
+    let file = parse_tokens(text).map_err(|err| err.to_string())?;
+    Ok(file
+        .tokens
+        .into_iter()
+        .map(|token| (token.name, token.color.to_hex()))
+        .collect())
+}
+
+/// The custom properties whose value is a color, the ones built from other properties with
+/// `var()` are left out.
+fn css(text: &str) -> Result<Vec<(String, String)>, String> {
+    let colors: Vec<(String, String)> = custom_properties(text)
+        .filter_map(|(name, value)| {
+            let color = Color::from_css(value.trim_end_matches("!important").trim()).ok()?;
+            Some((name.to_string(), color.to_hex()))
+        })
+        .collect();
+    if colors.is_empty() {
+        return Err("none of the custom properties are colors".to_string());
+    }
+
+    Ok(colors)
+}
+
+/// Reads the colors of a palette file as names and hex colors, in the order of the file.
+pub fn read(path: &Path) -> Result<Vec<(String, String)>, String> {
+    let bytes =
+        fs::read(path).map_err(|err| format!("could not read {}: {err}", path.display()))?;
+    let format = sniff(path, &bytes).ok_or_else(|| {
+        format!(
+            "could not tell the format of {}, palettes can be {SUPPORTED}",
+            path.display()
+        )
+    })?;
+
+    let text = || {
+        std::str::from_utf8(&bytes)
+            .map(|text| text.trim_start_matches('\u{feff}'))
+            .map_err(|_| "the file isn't UTF-8 text".to_string())
+    };
+    match format {
+        PaletteFormat::Gpl => gpl(text()?),
+        PaletteFormat::Ase => ase(&bytes),
+        PaletteFormat::Base16 => base16(text()?),
+        PaletteFormat::Tokens => tokens(text()?),
+        PaletteFormat::Css => css(text()?),
+    }
+    .map_err(|err| format!("{}: {err}", path.display()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// An ASE file with the swatches, as names, color models and channels.
+    fn ase_file(swatches: &[(&str, &[u8; 4], &[f32])]) -> Vec<u8> {
+        let mut bytes = b"ASEF\0\x01\0\0".to_vec();
+        bytes.extend((swatches.len() as u32).to_be_bytes());
+        for (name, model, channels) in swatches {
+            let mut block = Vec::new();
+            let units: Vec<u16> = name.encode_utf16().chain([0]).collect();
+            block.extend((units.len() as u16).to_be_bytes());
+            block.extend(units.iter().flat_map(|unit| unit.to_be_bytes()));
+            block.extend(*model);
+            block.extend(channels.iter().flat_map(|channel| channel.to_be_bytes()));
+            // Global swatch
+            block.extend([0, 0]);
+
+            bytes.extend([0, 1]);
+            bytes.extend((block.len() as u32).to_be_bytes());
+            bytes.extend(block);
+        }
+        bytes
+    }
+
+    #[test]
+    fn formats_are_told_from_the_contents_first() {
+        let path = Path::new("palette.txt");
+        assert_eq!(sniff(path, b"ASEF"), Some(PaletteFormat::Ase));
+        assert_eq!(sniff(path, b"GIMP Palette\n"), Some(PaletteFormat::Gpl));
+        assert_eq!(sniff(path, b"\xef\xbb\xbf{}"), Some(PaletteFormat::Tokens));
+        assert_eq!(
+            sniff(path, b"base00: \"181818\""),
+            Some(PaletteFormat::Base16)
+        );
+        assert_eq!(
+            sniff(path, b":root { --a: red; }"),
+            Some(PaletteFormat::Css)
+        );
+        assert_eq!(sniff(path, b"red"), None);
+
+        assert_eq!(sniff(Path::new("brand.ASE"), b""), Some(PaletteFormat::Ase));
+        assert_eq!(
+            sniff(Path::new("brand.yml"), b""),
+            Some(PaletteFormat::Base16)
+        );
+        assert_eq!(
+            sniff(Path::new("brand.css"), b"GIMP Palette"),
+            Some(PaletteFormat::Gpl)
+        );
+    }
+
+    #[test]
+    fn gpl_palettes() {
+        let text = "GIMP Palette\nName: Brand\nColumns: 2\n# comment\n255 0 0 Brand red\n0 0 255\n";
+        assert_eq!(
+            gpl(text).unwrap(),
+            [
+                ("Brand red".to_string(), "#ff0000".to_string()),
+                ("2".to_string(), "#0000ff".to_string()),
+            ]
+        );
+        assert_eq!(
+            gpl("GIMP Palette\n255 0\n").unwrap_err(),
+            "line 2: expected red, green and blue from 0 to 255"
+        );
+    }
+
+    #[test]
+    fn ase_swatches() {
+        let bytes = ase_file(&[
+            ("Brand red", b"RGB ", &[1.0, 0.0, 0.0]),
+            ("", b"Gray", &[0.5]),
+            ("Ink", b"CMYK", &[0.0, 0.0, 0.0, 1.0]),
+        ]);
+        assert_eq!(
+            ase(&bytes).unwrap(),
+            [
+                ("Brand red".to_string(), "#ff0000".to_string()),
+                ("2".to_string(), "#808080".to_string()),
+                ("Ink".to_string(), "#000000".to_string()),
+            ]
+        );
+
+        let lab = ase_file(&[("Paper", b"LAB ", &[100.0, 0.0, 0.0])]);
+        assert_eq!(
+            ase(&lab).unwrap_err(),
+            "swatch Paper is in the LAB color model, only RGB, CMYK and gray are supported"
+        );
+    }
+
+    #[test]
+    fn truncated_ase_files_are_errors() {
+        let bytes = ase_file(&[("Brand red", b"RGB ", &[1.0, 0.0, 0.0])]);
+        for len in 0..bytes.len() {
+            assert!(ase(&bytes[..len]).is_err(), "{len} bytes");
+        }
+    }
+
+    #[test]
+    fn ase_files_must_start_with_the_signature() {
+        let mut bytes = ase_file(&[("Brand red", b"RGB ", &[1.0, 0.0, 0.0])]);
+        bytes[..4].copy_from_slice(b"ASE ");
+        assert_eq!(
+            ase(&bytes).unwrap_err(),
+            "not an Adobe Swatch Exchange file, it doesn't start with ASEF"
+        );
+    }
+
+    #[test]
+    fn base16_schemes() {
+        let flat = "scheme: Default Dark\nbase00: \"181818\"\nbase0F: \"#a16946\"\n";
+        assert_eq!(
+            base16(flat).unwrap(),
+            [
+                ("base00".to_string(), "#181818".to_string()),
+                ("base0F".to_string(), "#a16946".to_string()),
+            ]
+        );
+
+        let nested = "system: base24\npalette:\n  base17: \"ff0000\"\n";
+        assert_eq!(
+            base16(nested).unwrap(),
+            [("base17".to_string(), "#ff0000".to_string())]
+        );
+        assert_eq!(
+            base16("scheme: Empty\n").unwrap_err(),
+            "the scheme has no base00 to base0F colors"
+        );
+    }
+}
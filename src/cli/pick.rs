@@ -0,0 +1,64 @@
+use clap::Args;
+use contrast_checker::{best_text_color, Color};
+
+use super::config::Config;
+use super::{parse_color, Error};
+
+#[derive(Args)]
+pub struct PickArgs {
+    /// Background the text goes on, in any of the supported notations. With --at, an image to
+    /// take it from, "-" for stdin like `wl-paste | contrast-checker pick - --at 100,200`
+    background: String,
+
+    /// Candidate text colors, black and white when none are given
+    candidates: Vec<String>,
+
+    /// Use a palette of the configuration file, or a built-in one, as the candidates
+    #[arg(long, conflicts_with = "candidates")]
+    palette: Option<String>,
+
+    /// Take the background from the pixel at x,y of the image given as background
+    #[cfg(feature = "image")]
+    #[arg(long, value_parser = super::image::parse_point)]
+    at: Option<super::image::Region>,
+}
+
+/// The background color, read from a pixel of an image with --at.
+fn background(args: &PickArgs) -> Result<Color, Error> {
+    #[cfg(feature = "image")]
+    if let Some(at) = args.at {
+        let (_, pixels) = super::image::region_pixels(&args.background.as_str().into(), Some(at))?;
+        // The region is a single pixel that fits in the image
+        return Ok(pixels[0]);
+    }
+    #[cfg(feature = "image")]
+    if args.background == "-" {
+        return Err("reading the background from an image on stdin needs --at x,y".into());
+    }
+
+    parse_color("background color", &args.background)
+}
+
+pub fn run(args: &PickArgs, config: &Config) -> Result<(), Error> {
+    let background = background(args)?;
+    let inputs = match &args.palette {
+        Some(name) => config.palette(name)?,
+        None if args.candidates.is_empty() => vec!["black".to_string(), "white".to_string()],
+        None => args.candidates.clone(),
+    };
+    let candidates = inputs
+        .iter()
+        .map(|input| parse_color("candidate color", input))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    // Only a palette of the configuration can leave no candidates
+    let best = best_text_color(&background, &candidates).ok_or_else(|| {
+        Error::config(format!(
+            "palette {} has no colors to pick from",
+            args.palette.as_deref().unwrap_or_default()
+        ))
+    })?;
+    println!("{}  {:.2}:1", inputs[best.index], best.ratio);
+
+    Ok(())
+}
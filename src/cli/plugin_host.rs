@@ -0,0 +1,189 @@
+//! `plugin-host`, JSON-RPC 2.0 over stdio for editor and design tool plugins that can spawn a
+//! process but can't link the library. Requests and responses are one JSON object per line.
+
+use std::io::{self, BufRead, Write};
+
+use clap::Args;
+use contrast_checker::report::{escape_json, Measurement, PairJson};
+use contrast_checker::{check_compliance, contrast_matrix, suggest_foreground, Color};
+use serde_json::Value;
+
+use super::{parse_color, Error};
+
+#[derive(Args)]
+pub struct PluginHostArgs {
+    /// Ratio suggest and palette use when a request doesn't have one, 4.5 is AA for normal text
+    #[arg(long, default_value_t = 4.5)]
+    ratio: f32,
+}
+
+/// The error codes of the JSON-RPC 2.0 specification.
+const PARSE_ERROR: i32 = -32700;
+const INVALID_REQUEST: i32 = -32600;
+const METHOD_NOT_FOUND: i32 = -32601;
+const INVALID_PARAMS: i32 = -32602;
+
+/// An error response: the code and the message.
+type RpcError = (i32, String);
+
+fn string_param<'a>(params: &'a Value, name: &str) -> Result<&'a str, RpcError> {
+    params.get(name).and_then(Value::as_str).ok_or_else(|| {
+        (
+            INVALID_PARAMS,
+            format!("missing the string parameter {name}"),
+        )
+    })
+}
+
+fn color_param(params: &Value, name: &str, description: &str) -> Result<(String, Color), RpcError> {
+    let input = string_param(params, name)?;
+    let color = parse_color(description, input).map_err(|err| (INVALID_PARAMS, err.message))?;
+    Ok((input.to_string(), color))
+}
+
+fn ratio_param(params: &Value, default: f32) -> Result<f32, RpcError> {
+    match params.get("ratio") {
+        None => Ok(default),
+        Some(ratio) => ratio
+            .as_f64()
+            .map(|ratio| ratio as f32)
+            .ok_or_else(|| (INVALID_PARAMS, "ratio has to be a number".to_string())),
+    }
+}
+
+/// `check {fg, bg, ratio?}`: the same object as `--format json`.
+fn check(params: &Value, default_ratio: f32) -> Result<String, RpcError> {
+    let (foreground_input, foreground) = color_param(params, "fg", "foreground color")?;
+    let (background_input, background) = color_param(params, "bg", "background color")?;
+    let ratio = ratio_param(params, default_ratio)?;
+
+    let compliance = check_compliance(&foreground, &background);
+    Ok(PairJson::new(
+        (&foreground_input, &foreground),
+        (&background_input, &background),
+        &compliance,
+        Measurement::wcag2(&compliance, ratio),
+    )
+    .to_string())
+}
+
+/// `suggest {fg, bg, ratio?}`: the closest foreground by lightness, `null` when none passes.
+fn suggest(params: &Value, default_ratio: f32) -> Result<String, RpcError> {
+    let (_, foreground) = color_param(params, "fg", "foreground color")?;
+    let (_, background) = color_param(params, "bg", "background color")?;
+    let ratio = ratio_param(params, default_ratio)?;
+
+    Ok(match suggest_foreground(&foreground, &background, ratio) {
+        Some(suggestion) => format!(
+            r#"{{"color":"{}","ratio":{},"required_ratio":{ratio}}}"#,
+            suggestion.color.to_hex(),
+            suggestion.ratio
+        ),
+        None => format!(r#"{{"color":null,"required_ratio":{ratio}}}"#),
+    })
+}
+
+/// `palette {colors, ratio?}`: every ordered pair of different colors and whether it passes.
+fn palette(params: &Value, default_ratio: f32) -> Result<String, RpcError> {
+    let inputs: Vec<&str> = params
+        .get("colors")
+        .and_then(Value::as_array)
+        .and_then(|colors| colors.iter().map(Value::as_str).collect())
+        .ok_or_else(|| {
+            (
+                INVALID_PARAMS,
+                "colors has to be an array of strings".to_string(),
+            )
+        })?;
+    let colors = inputs
+        .iter()
+        .map(|input| parse_color("color", input))
+        .collect::<Result<Vec<Color>, Error>>()
+        .map_err(|err| (INVALID_PARAMS, err.message))?;
+    let ratio = ratio_param(params, default_ratio)?;
+
+    let matrix = contrast_matrix(&colors);
+    let mut pairs = Vec::new();
+    for (row, foreground) in inputs.iter().enumerate() {
+        for (column, background) in inputs.iter().enumerate() {
+            if row != column {
+                pairs.push(format!(
+                    r#"{{"fg":"{}","bg":"{}","ratio":{},"passes":{}}}"#,
+                    escape_json(foreground),
+                    escape_json(background),
+                    matrix[row][column],
+                    matrix[row][column] >= ratio
+                ));
+            }
+        }
+    }
+
+    Ok(format!(
+        r#"{{"required_ratio":{ratio},"pairs":[{}]}}"#,
+        pairs.join(",")
+    ))
+}
+
+/// Answers one line, `None` for notifications, which don't get a response.
+fn respond(line: &str, default_ratio: f32) -> Option<String> {
+    let request: Value = match serde_json::from_str(line) {
+        Ok(request) => request,
+        Err(err) => return Some(error_response("null", (PARSE_ERROR, err.to_string()))),
+    };
+    let id = request.get("id").map(Value::to_string);
+    let Some(method) = request.get("method").and_then(Value::as_str) else {
+        // Without a method it can't be a notification, so it's answered even without an id
+        let id = id.as_deref().unwrap_or("null");
+        return Some(error_response(
+            id,
+            (INVALID_REQUEST, "missing the method".to_string()),
+        ));
+    };
+
+    let result = match (method, &request["params"]) {
+        (_, params) if !params.is_object() => Err((
+            INVALID_PARAMS,
+            "params has to be an object with named parameters".to_string(),
+        )),
+        ("check", params) => check(params, default_ratio),
+        ("suggest", params) => suggest(params, default_ratio),
+        ("palette", params) => palette(params, default_ratio),
+        (method, _) => Err((
+            METHOD_NOT_FOUND,
+            format!("unknown method {method}, use check, suggest or palette"),
+        )),
+    };
+
+    let id = id?;
+    Some(match result {
+        Ok(result) => format!(r#"{{"jsonrpc":"2.0","id":{id},"result":{result}}}"#),
+        Err(error) => error_response(&id, error),
+    })
+}
+
+fn error_response(id: &str, (code, message): RpcError) -> String {
+    format!(
+        r#"{{"jsonrpc":"2.0","id":{id},"error":{{"code":{code},"message":"{}"}}}}"#,
+        escape_json(&message)
+    )
+}
+
+pub fn run(args: &PluginHostArgs) -> Result<(), Error> {
+    let mut stdout = io::stdout().lock();
+
+    for line in io::stdin().lock().lines() {
+        let line = line.map_err(|err| Error::io(format!("could not read stdin: {err}")))?;
+        if line.trim().is_empty() {
+            continue;
+        }
+
+        if let Some(response) = respond(&line, args.ratio) {
+            // Flushed right away, the plugin waits for the response before sending more
+            writeln!(stdout, "{response}")
+                .and_then(|()| stdout.flush())
+                .map_err(|err| Error::io(format!("could not write the response: {err}")))?;
+        }
+    }
+
+    Ok(())
+}
@@ -0,0 +1,46 @@
+use std::env;
+use std::io::{self, IsTerminal};
+
+use clap::ValueEnum;
+use contrast_checker::Color;
+
+#[derive(Clone, Copy, ValueEnum)]
+pub enum Preview {
+    /// Only when stdout is a terminal with 24-bit color support
+    Auto,
+    Always,
+    Never,
+}
+
+impl Preview {
+    pub fn enabled(self) -> bool {
+        match self {
+            Preview::Always => true,
+            Preview::Never => false,
+            Preview::Auto => {
+                // https://no-color.org
+                let no_color = env::var_os("NO_COLOR").is_some_and(|value| !value.is_empty());
+                // There's no reliable way of asking the terminal, but every terminal with 24-bit
+                // color sets COLORTERM
+                let truecolor = env::var("COLORTERM")
+                    .is_ok_and(|value| value == "truecolor" || value == "24bit");
+
+                !no_color && truecolor && io::stdout().is_terminal()
+            }
+        }
+    }
+}
+
+/// Renders sample text with the foreground on the background using ANSI truecolor escapes.
+/// Translucent colors are composited first, so the swatch shows what users would see.
+pub fn swatch(foreground: &Color, background: &Color) -> String {
+    let background = background.composite_over(&Color::new(255, 255, 255));
+    let foreground = foreground.composite_over(&background);
+    let channels = |color: &Color| format!("{};{};{}", color.red, color.green, color.blue);
+
+    format!(
+        "\x1b[38;2;{}m\x1b[48;2;{}m  Sample text  \x1b[0m",
+        channels(&foreground),
+        channels(&background)
+    )
+}
@@ -0,0 +1,46 @@
+use clap::Args;
+use contrast_checker::contrast_range;
+
+use super::{parse_color, Error};
+
+#[derive(Args)]
+pub struct RangeArgs {
+    /// The color to check, in any of the supported notations
+    color: String,
+}
+
+fn format_headroom(headroom: Option<f32>, background: &str) -> String {
+    match headroom {
+        Some(headroom) => format!(
+            "{:.0}% lightness before failing AA on {background}",
+            headroom * 100.0
+        ),
+        None => format!("none, fails AA on {background}"),
+    }
+}
+
+pub fn run(args: &RangeArgs) -> Result<(), Error> {
+    let color = parse_color("color", &args.color)?;
+    let range = contrast_range(&color);
+
+    println!("contrast against white: {:.2}:1", range.against_white);
+    println!("contrast against black: {:.2}:1", range.against_black);
+    println!(
+        "lighten headroom: {}",
+        format_headroom(range.lighten_headroom, "white")
+    );
+    println!(
+        "darken headroom: {}",
+        format_headroom(range.darken_headroom, "black")
+    );
+    println!(
+        "can host AA text: {}",
+        if range.can_host_aa_text() {
+            "yes"
+        } else {
+            "no"
+        }
+    );
+
+    Ok(())
+}
@@ -0,0 +1,95 @@
+//! `--export-fixes` of the commands that audit pairs: the replacements of the failing foregrounds
+//! are written to a file, as a JSON object or as CSS custom properties, for other tools to apply.
+
+use std::fs;
+use std::path::Path;
+
+use contrast_checker::audit::Replacement;
+use contrast_checker::report::escape_json;
+use contrast_checker::Color;
+
+use super::Error;
+
+/// Whether an input is a literal color rather than a name, like a token, that can be overridden.
+fn is_literal(input: &str) -> bool {
+    Color::from_css(input).is_ok()
+}
+
+/// The custom property a token is overridden with, `text.muted` becomes `--text-muted`.
+fn custom_property(name: &str) -> String {
+    let name: String = name
+        .trim_start_matches("--")
+        .chars()
+        .map(|c| {
+            if c.is_ascii_alphanumeric() || c == '-' || c == '_' {
+                c
+            } else {
+                '-'
+            }
+        })
+        .collect();
+    format!("--{name}")
+}
+
+/// A JSON object of every input to its replacement, `null` when there's none.
+pub fn json(replacements: &[Replacement]) -> String {
+    let entries: Vec<String> = replacements
+        .iter()
+        .flat_map(|replacement| {
+            let value = match replacement.replacement {
+                Some(color) => format!("\"{}\"", color.to_hex()),
+                None => "null".to_string(),
+            };
+            replacement
+                .inputs
+                .iter()
+                .map(move |input| format!("  \"{}\": {value}", escape_json(input)))
+        })
+        .collect();
+
+    if entries.is_empty() {
+        "{}\n".to_string()
+    } else {
+        format!("{{\n{}\n}}\n", entries.join(",\n"))
+    }
+}
+
+/// A `:root` rule overriding every named input, literals and colors without a replacement are
+/// left as comments.
+fn css(replacements: &[Replacement], threshold: &str) -> String {
+    let mut css = format!(
+        "/* Replacements reaching {threshold} on every background they're used on */\n:root {{\n"
+    );
+
+    for replacement in replacements {
+        let original = replacement.original.to_hex();
+        for input in &replacement.inputs {
+            let line = match (replacement.replacement, is_literal(input)) {
+                (Some(color), false) => format!(
+                    "{}: {}; /* was {original} */",
+                    custom_property(input),
+                    color.to_hex()
+                ),
+                (Some(color), true) => format!("/* {input} -> {} */", color.to_hex()),
+                (None, _) => {
+                    format!("/* {input}: no lightness of {original} reaches {threshold} */")
+                }
+            };
+            css.push_str(&format!("  {line}\n"));
+        }
+    }
+
+    css.push_str("}\n");
+    css
+}
+
+/// Writes the replacements to `path`, as CSS when it ends in `.css` and as JSON otherwise.
+pub fn export(path: &Path, replacements: &[Replacement], threshold: &str) -> Result<(), Error> {
+    let contents = match path.extension() {
+        Some(extension) if extension.eq_ignore_ascii_case("css") => css(replacements, threshold),
+        _ => json(replacements),
+    };
+
+    fs::write(path, contents)
+        .map_err(|err| Error::io(format!("could not write {}: {err}", path.display())))
+}
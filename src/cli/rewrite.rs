@@ -0,0 +1,200 @@
+//! Rewrites of the colors of a source file, for `scan --fix` and `scan --diff`. Only the bytes of
+//! the values change, so the formatting around them is kept as it was.
+
+use std::ops::Range;
+use std::path::Path;
+
+use contrast_checker::{Color, ColorFormat};
+
+/// A value to replace in the source.
+pub struct Edit {
+    pub span: Range<usize>,
+    pub replacement: String,
+}
+
+/// Writes `color` in the notation `original` uses: the function keeps its name and separators, so
+/// `rgba(150, 150, 150)` stays `rgba()` with commas, and hex keeps its case and its length when
+/// the color can be written with it. Named colors and functions without a notation of their own
+/// become hex.
+pub fn restyle(original: &str, color: &Color) -> String {
+    let original = original.trim();
+    let Some((function, arguments)) = original.split_once('(') else {
+        return restyle_hex(original, color);
+    };
+    let lowercase = function.to_ascii_lowercase();
+    let format = if lowercase.starts_with("rgb") {
+        ColorFormat::Rgb
+    } else if lowercase.starts_with("hsl") {
+        ColorFormat::Hsl
+    } else if lowercase.starts_with("oklch") {
+        ColorFormat::Oklch
+    } else {
+        return color.to_hex();
+    };
+
+    // Written as `name(a b c)` or `name(a b c / alpha)`
+    let written = color.format_as(format);
+    let written = &written[written.find('(').map_or(0, |open| open + 1)..written.len() - 1];
+    let (channels, alpha) = match written.split_once(" / ") {
+        Some((channels, alpha)) => (channels, Some(alpha)),
+        None => (written, None),
+    };
+    let had_alpha = arguments.contains('/') || arguments.matches(',').count() >= 3;
+    let alpha = alpha.or(had_alpha.then_some("1"));
+
+    let separator = if arguments.contains(", ") {
+        ", "
+    } else if arguments.contains(',') {
+        ","
+    } else {
+        " "
+    };
+    let mut rewritten = format!("{function}({}", channels.replace(' ', separator));
+    if let Some(alpha) = alpha {
+        match separator {
+            " " => rewritten.push_str(" / "),
+            separator => rewritten.push_str(separator),
+        }
+        rewritten.push_str(alpha);
+    }
+    rewritten.push(')');
+    rewritten
+}
+
+/// Writes `color` as hex like `original`: uppercase if it is, with an alpha if it has one and in
+/// three or four digits if it has that many and the color can be.
+fn restyle_hex(original: &str, color: &Color) -> String {
+    let mut hex = color.to_hex();
+    let Some(digits) = original.strip_prefix('#') else {
+        return hex;
+    };
+    if matches!(digits.len(), 4 | 8) && hex.len() == 7 {
+        hex.push_str("ff");
+    }
+    if matches!(digits.len(), 3 | 4) {
+        let bytes = hex.as_bytes();
+        let pairs: Vec<&[u8]> = bytes[1..].chunks(2).collect();
+        if pairs.iter().all(|pair| pair[0] == pair[1]) {
+            hex = pairs.iter().fold("#".to_string(), |mut short, pair| {
+                short.push(char::from(pair[0]));
+                short
+            });
+        }
+    }
+
+    if digits.chars().any(|c| c.is_ascii_uppercase()) {
+        hex.to_ascii_uppercase()
+    } else {
+        hex
+    }
+}
+
+/// Applies edits that don't overlap, in any order.
+pub fn apply(source: &str, edits: &[Edit]) -> String {
+    let mut edits: Vec<&Edit> = edits.iter().collect();
+    edits.sort_by_key(|edit| edit.span.start);
+
+    let mut rewritten = String::with_capacity(source.len());
+    let mut position = 0;
+    for edit in edits {
+        rewritten.push_str(&source[position..edit.span.start]);
+        rewritten.push_str(&edit.replacement);
+        position = edit.span.end;
+    }
+    rewritten.push_str(&source[position..]);
+
+    rewritten
+}
+
+/// Lines of unchanged context around every change, like `diff -u` and git.
+const CONTEXT: usize = 3;
+
+fn push_line(diff: &mut String, prefix: char, line: &str) {
+    diff.push(prefix);
+    diff.push_str(line);
+    if !line.ends_with('\n') {
+        diff.push_str("\n\\ No newline at end of file\n");
+    }
+}
+
+/// A unified diff of two versions of a file that `git apply` and `patch -p1` accept. The edits
+/// never add or remove lines, so the versions are compared line by line.
+pub fn unified_diff(path: &Path, original: &str, rewritten: &str) -> String {
+    let old: Vec<&str> = original.split_inclusive('\n').collect();
+    let new: Vec<&str> = rewritten.split_inclusive('\n').collect();
+    let changed: Vec<usize> = (0..old.len().max(new.len()))
+        .filter(|&index| old.get(index) != new.get(index))
+        .collect();
+    if changed.is_empty() {
+        return String::new();
+    }
+
+    let path = path.display().to_string().replace('\\', "/");
+    let path = path.trim_start_matches("./");
+    let mut diff = format!("--- a/{path}\n+++ b/{path}\n");
+
+    let mut index = 0;
+    while index < changed.len() {
+        // Changes closer than twice the context share a hunk
+        let mut last = index;
+        while last + 1 < changed.len() && changed[last + 1] - changed[last] <= 2 * CONTEXT {
+            last += 1;
+        }
+
+        let start = changed[index].saturating_sub(CONTEXT);
+        let end = (changed[last] + CONTEXT + 1).min(old.len());
+        diff.push_str(&format!(
+            "@@ -{},{} +{},{} @@\n",
+            start + 1,
+            end - start,
+            start + 1,
+            end - start
+        ));
+        for line in start..end {
+            if changed[index..=last].contains(&line) {
+                push_line(&mut diff, '-', old[line]);
+                push_line(&mut diff, '+', new[line]);
+            } else {
+                push_line(&mut diff, ' ', old[line]);
+            }
+        }
+
+        index = last + 1;
+    }
+
+    diff
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn functions_keep_their_name_and_separators() {
+        let gray = Color::new(118, 118, 118);
+        assert_eq!(restyle("rgb(150, 150, 150)", &gray), "rgb(118, 118, 118)");
+        assert_eq!(restyle("rgb(150,150,150)", &gray), "rgb(118,118,118)");
+        assert_eq!(restyle("RGBA(150 150 150)", &gray), "RGBA(118 118 118)");
+        assert_eq!(
+            restyle("rgba(150, 150, 150, 1)", &gray),
+            "rgba(118, 118, 118, 1)"
+        );
+        assert_eq!(
+            restyle("rgb(150 150 150 / 50%)", &gray.with_opacity(0.5)),
+            "rgb(118 118 118 / 0.502)"
+        );
+        assert_eq!(restyle("hsl(0, 0%, 59%)", &gray), "hsl(0, 0%, 46.27%)");
+        assert_eq!(restyle("color(srgb 0.6 0.6 0.6)", &gray), "#767676");
+    }
+
+    #[test]
+    fn hex_keeps_its_case_and_length() {
+        let gray = Color::new(0x66, 0x66, 0x66);
+        assert_eq!(restyle("#999", &gray), "#666");
+        assert_eq!(restyle("#999", &Color::new(0x76, 0x76, 0x76)), "#767676");
+        assert_eq!(restyle("#999F", &gray), "#666F");
+        assert_eq!(restyle("#ABCDEF", &gray), "#666666");
+        assert_eq!(restyle("#999999ff", &gray), "#666666ff");
+        assert_eq!(restyle("Gray", &gray), "#666666");
+    }
+}
@@ -0,0 +1,122 @@
+//! `tokens --rules`, audit rules written in [Rhai](https://rhai.rs) for policies `$pairs` can't
+//! list one by one, like "every brand token on both surfaces":
+//!
+//! ```rhai
+//! for text in tokens("brand.*") {
+//!     for surface in tokens("surface.*") {
+//!         check(text, surface);
+//!     }
+//! }
+//! ```
+//!
+//! Scripts get `tokens()` or `tokens(pattern)`, the names of the tokens of the file in order,
+//! where `*` in a pattern matches anything, `color(name)`, the hex of a token, and
+//! `contrast(foreground, background)`, the WCAG 2 ratio of two tokens, to decide what to check.
+//! `check(foreground, background)` adds the pair to the audit, so it's reported and fails like
+//! the others. `print` writes to stderr, stdout has the report.
+
+use std::cell::RefCell;
+use std::fs;
+use std::path::Path;
+use std::rc::Rc;
+
+use contrast_checker::contrast_ratio;
+use contrast_checker::tokens::TokenFile;
+use rhai::{Array, Dynamic, Engine, EvalAltResult};
+
+use super::Error;
+
+/// How many operations a script can run before it's stopped, so rules stuck in a loop fail.
+const MAX_OPERATIONS: u64 = 10_000_000;
+
+/// Whether a token name matches a pattern where `*` matches any run of characters.
+fn matches(pattern: &str, name: &str) -> bool {
+    let mut parts = pattern.split('*');
+    let first = parts.next().unwrap_or_default();
+    let Some(mut rest) = name.strip_prefix(first) else {
+        return false;
+    };
+
+    let parts: Vec<&str> = parts.collect();
+    let Some((last, middle)) = parts.split_last() else {
+        return rest.is_empty();
+    };
+    for part in middle {
+        match rest.find(part) {
+            Some(index) => rest = &rest[index + part.len()..],
+            None => return false,
+        }
+    }
+
+    rest.len() >= last.len() && rest.ends_with(last)
+}
+
+/// Runs the rules of `path` over the tokens of a file and returns the foreground and background
+/// names of the pairs they check, in order.
+pub fn checked_pairs(path: &Path, file: &TokenFile) -> Result<Vec<(String, String)>, Error> {
+    let script = fs::read_to_string(path)
+        .map_err(|err| Error::io(format!("could not read {}: {err}", path.display())))?;
+
+    let file = Rc::new(file.clone());
+    let pairs = Rc::new(RefCell::new(Vec::new()));
+    let mut engine = Engine::new();
+    engine.set_max_operations(MAX_OPERATIONS);
+    // stdout has the report
+    engine.on_print(|text| eprintln!("{text}"));
+
+    let color = {
+        let file = file.clone();
+        move |name: &str| -> Result<_, Box<EvalAltResult>> {
+            file.get(name)
+                .map(|token| token.color)
+                .ok_or_else(|| format!("there's no color token called {name}").into())
+        }
+    };
+
+    let names = {
+        let file = file.clone();
+        move |pattern: &str| -> Array {
+            file.tokens
+                .iter()
+                .filter(|token| matches(pattern, &token.name))
+                .map(|token| Dynamic::from(token.name.clone()))
+                .collect()
+        }
+    };
+    engine.register_fn("tokens", {
+        let names = names.clone();
+        move || names("*")
+    });
+    engine.register_fn("tokens", names);
+    engine.register_fn("color", {
+        let color = color.clone();
+        move |name: &str| color(name).map(|color| color.to_hex())
+    });
+    engine.register_fn("contrast", {
+        let color = color.clone();
+        move |foreground: &str, background: &str| -> Result<f64, Box<EvalAltResult>> {
+            Ok(f64::from(contrast_ratio(
+                &color(foreground)?,
+                &color(background)?,
+            )))
+        }
+    });
+    engine.register_fn("check", {
+        let pairs = pairs.clone();
+        move |foreground: &str, background: &str| -> Result<(), Box<EvalAltResult>> {
+            color(foreground)?;
+            color(background)?;
+            pairs
+                .borrow_mut()
+                .push((foreground.to_string(), background.to_string()));
+            Ok(())
+        }
+    });
+
+    engine
+        .run(&script)
+        .map_err(|err| format!("{}: {err}", path.display()))?;
+
+    let pairs = pairs.borrow().clone();
+    Ok(pairs)
+}
@@ -0,0 +1,40 @@
+use clap::Args;
+use contrast_checker::{contrast_ratio, luminance_matches, relative_luminance};
+
+use super::{parse_color, Error};
+
+#[derive(Args)]
+pub struct SameLuminanceArgs {
+    /// The color to replace, in any of the supported notations
+    color: String,
+
+    /// How many alternatives to find, spread evenly around the color wheel
+    #[arg(long, default_value_t = 6)]
+    count: usize,
+}
+
+pub fn run(args: &SameLuminanceArgs) -> Result<(), Error> {
+    let color = parse_color("color", &args.color)?;
+
+    println!(
+        "luminance of {}: {:.4}",
+        args.color,
+        relative_luminance(&color)
+    );
+    let alternatives = luminance_matches(&color, args.count);
+    if alternatives.is_empty() && args.count > 0 {
+        println!("{} is a gray, there's no hue to change", args.color);
+    }
+
+    for alternative in alternatives {
+        let (hue, _, _) = alternative.to_hsl();
+        println!(
+            "{}  hue {hue:>3.0}  luminance {:.4}  contrast {:.2}:1",
+            alternative.to_hex(),
+            relative_luminance(&alternative),
+            contrast_ratio(&alternative, &color)
+        );
+    }
+
+    Ok(())
+}
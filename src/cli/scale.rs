@@ -0,0 +1,63 @@
+use clap::Args;
+use contrast_checker::tint_scale;
+
+use super::config::Config;
+use super::{parse_color, Error, Notation};
+
+#[derive(Args)]
+pub struct ScaleArgs {
+    /// Base color of the scale, in any of the supported notations
+    color: String,
+
+    /// How many tints and shades to generate
+    #[arg(long, default_value_t = 10, value_parser = clap::value_parser!(u16).range(1..=100))]
+    steps: u16,
+
+    /// Notation of the generated colors, hex unless the configuration file says otherwise
+    #[arg(long, value_enum)]
+    notation: Option<Notation>,
+}
+
+/// Names the steps like Tailwind does (50, 100, ..., 900 and 950) when there are 10 or 11 of
+/// them, otherwise they are numbered from 1.
+fn step_name(index: usize, steps: usize) -> String {
+    match (index, steps) {
+        (0, 10 | 11) => "50".to_string(),
+        (_, 10 | 11) if index == 10 => "950".to_string(),
+        (_, 10 | 11) => (index * 100).to_string(),
+        _ => (index + 1).to_string(),
+    }
+}
+
+pub fn run(args: &ScaleArgs, config: &Config) -> Result<(), Error> {
+    let base = parse_color("color", &args.color)?;
+    let steps = usize::from(args.steps);
+    let format = config.color_format(args.notation);
+
+    let scale = tint_scale(&base, steps);
+    let colors: Vec<String> = scale
+        .iter()
+        .map(|step| step.color.format_as(format))
+        .collect();
+    let width = colors.iter().map(String::len).max().unwrap_or(0).max(7);
+
+    println!("step  {:<width$}  on white  on black  AA text", "color");
+    for (index, (step, color)) in scale.iter().zip(&colors).enumerate() {
+        let usage = match (step.aa_on_white(), step.aa_on_black()) {
+            (true, true) => "on white and black",
+            (true, false) => "on white",
+            (false, true) => "on black",
+            (false, false) => "-",
+        };
+        let base_marker = if step.is_base { "  (base)" } else { "" };
+
+        println!(
+            "{:<4}  {color:<width$}  {:>7.2}   {:>7.2}   {usage}{base_marker}",
+            step_name(index, steps),
+            step.against_white,
+            step.against_black,
+        );
+    }
+
+    Ok(())
+}
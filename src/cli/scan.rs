@@ -0,0 +1,914 @@
+use std::fs;
+use std::io;
+use std::ops::Range;
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+
+use clap::{Args, ValueEnum};
+use contrast_checker::audit::{Audit, PairInput, ResolvedPair};
+use contrast_checker::report::{BatchReport, HtmlWriter, ReportWriter};
+use contrast_checker::scan::{
+    component_name, css_in_js_rules, css_rules, postcss_rules, sfc_rules, svg_elements, CssRule,
+    PostcssRule,
+};
+use contrast_checker::source_map::{source_map_url, SourceMap, SourceMapUrl};
+use contrast_checker::{compliance_gap, contrast_ratio, Color, RoundingPolicy};
+
+use super::config::Config;
+use super::rewrite::{apply, restyle, unified_diff, Edit};
+use super::watch::{watch, Outcome};
+use super::{parse_color, read_input, Error, Rounding};
+
+#[derive(Args)]
+pub struct ScanArgs {
+    /// CSS, SCSS, Less or SVG file to audit, the JSON of a PostCSS AST, or a JavaScript or
+    /// TypeScript file with styled-components or emotion styles, or a Vue or Svelte component.
+    /// A directory is audited as a project, every file of those kinds in it but PostCSS ASTs.
+    /// "-" reads from stdin
+    input: PathBuf,
+
+    /// Background assumed for rules that only set a text color, and SVG elements that only have
+    /// a fill or a stroke, over the one the configuration sets for the file. Without either those
+    /// are skipped
+    #[arg(long)]
+    background: Option<String>,
+
+    /// Contrast ratio every rule has to reach, over the one the configuration sets for the file.
+    /// 4.5 (AA for normal text) without either
+    #[arg(long)]
+    ratio: Option<f32>,
+
+    /// Which ratio is compared against the threshold
+    #[arg(long, value_enum, default_value_t = Rounding::Exact)]
+    rounding: Rounding,
+
+    /// Keep running and audit the file again every time it changes, printing the rules that
+    /// start or stop passing
+    #[arg(long)]
+    watch: bool,
+
+    /// Rewrite the failing text colors in the file with the closest lightness that passes, in
+    /// the notation they're written in. Colors that come from a variable are reported as manual
+    /// fixes with the value to give it, and fail the scan until they're done
+    #[arg(long, conflicts_with = "watch")]
+    fix: bool,
+
+    /// With --fix, print the changes without writing the file
+    #[arg(long, requires = "fix")]
+    dry_run: bool,
+
+    /// Print the changes --fix would make as a unified diff, for git apply or patch -p1
+    #[arg(long, conflicts_with_all = ["fix", "watch"])]
+    diff: bool,
+
+    /// Group the findings instead of listing them all together, big stylesheets have too many
+    #[arg(long, value_enum, conflicts_with_all = ["fix", "diff", "watch"])]
+    group_by: Option<GroupBy>,
+
+    /// Source map of a compiled stylesheet, so findings point at the SCSS or Less they come from.
+    /// The one of the sourceMappingURL comment of the file is used when it's missing
+    #[arg(long, value_name = "FILE")]
+    source_map: Option<PathBuf>,
+
+    /// How findings are printed
+    #[arg(long, value_enum, default_value_t = ScanFormat::Text, conflicts_with_all = ["fix", "diff", "watch"])]
+    format: ScanFormat,
+
+    /// Say which commit wrote the line of every failure, with its author and date, when the file
+    /// is in a git repository
+    #[cfg(feature = "blame")]
+    #[arg(long, conflicts_with_all = ["fix", "diff", "watch"])]
+    blame: bool,
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+pub enum GroupBy {
+    /// The rules of the same selector together
+    Selector,
+    /// The rules of the same component together: the block of a BEM class like card__title, the
+    /// prefix of a utility class like text-gray-500, or else the id or element
+    Component,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum ScanFormat {
+    /// The failing rules with a hint for each
+    Text,
+    /// A standalone HTML page with every rule, its groups collapse
+    Html,
+}
+
+/// A color of a pair found in the file, as written and with the variable it came from.
+struct Paint {
+    value: String,
+    variable: Option<String>,
+}
+
+impl Paint {
+    fn new(value: Option<String>, variable: Option<String>) -> Option<Paint> {
+        Some(Paint {
+            value: value?,
+            variable,
+        })
+    }
+
+    /// Names the color the way it's written in the file, like `$text (#777)`.
+    fn describe(&self) -> String {
+        match &self.variable {
+            Some(variable) => format!("{variable} ({})", self.value),
+            None => self.value.clone(),
+        }
+    }
+}
+
+/// A pair to check: where it is, what paints in front and what paints behind.
+struct Pair {
+    line: usize,
+    name: String,
+    foreground: Paint,
+    background: Option<Paint>,
+    /// Where the foreground is written in the file, for --fix.
+    foreground_span: Option<Range<usize>>,
+    /// Where the foreground comes from according to the source map, like `src/card.scss:12`.
+    origin: Option<String>,
+}
+
+/// An element with a fill and a stroke checks the stroke against the fill, otherwise its only
+/// paint is checked against `--background`.
+fn svg_pairs(source: &str) -> Vec<Pair> {
+    svg_elements(source)
+        .into_iter()
+        .filter_map(|element| {
+            let (foreground, background) = match (element.fill, element.stroke) {
+                (Some(fill), Some(stroke)) => (stroke, Some(fill)),
+                (Some(paint), None) | (None, Some(paint)) => (paint, None),
+                (None, None) => return None,
+            };
+
+            Some(Pair {
+                line: element.line,
+                name: element.name,
+                foreground: Paint::new(Some(foreground), None)?,
+                background: Paint::new(background, None),
+                foreground_span: None,
+                origin: None,
+            })
+        })
+        .collect()
+}
+
+/// What the file given to scan is.
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Input {
+    /// CSS, SCSS or Less.
+    Stylesheet,
+    Svg,
+    /// The JSON of a PostCSS AST, which no stylesheet starts like.
+    Postcss,
+    /// JavaScript or TypeScript with CSS-in-JS, known by its extension.
+    CssInJs,
+    /// A Vue or Svelte component, known by its extension too.
+    Component,
+}
+
+/// Extensions of JavaScript and TypeScript, whose styles are CSS-in-JS.
+pub const SCRIPTS: [&str; 8] = ["js", "jsx", "mjs", "cjs", "ts", "tsx", "mts", "cts"];
+
+/// Extensions of the files a directory is scanned for. PostCSS ASTs are left out, projects have
+/// plenty of JSON that isn't one.
+pub const PROJECT_FILES: [&str; 6] = ["css", "scss", "less", "svg", "vue", "svelte"];
+
+impl Input {
+    fn of(path: &Path, source: &str) -> Input {
+        let extension = |expected: &str| {
+            path.extension()
+                .is_some_and(|extension| extension.eq_ignore_ascii_case(expected))
+        };
+        let start = source.trim_start();
+        if extension("vue") || extension("svelte") {
+            Input::Component
+        } else if extension("svg") || start.starts_with('<') {
+            Input::Svg
+        } else if extension("json") || start.starts_with('{') {
+            Input::Postcss
+        } else if SCRIPTS.into_iter().any(extension) {
+            Input::CssInJs
+        } else {
+            Input::Stylesheet
+        }
+    }
+}
+
+/// The rules of a PostCSS AST, located in the files PostCSS read them from.
+fn postcss_pairs(source: &str) -> Result<Vec<Pair>, Error> {
+    let rules = postcss_rules(source).map_err(|err| format!("invalid PostCSS AST, {err}"))?;
+    Ok(rules
+        .into_iter()
+        .filter_map(|PostcssRule { rule, file }| {
+            Some(Pair {
+                origin: file.map(|file| format!("{file}:{}", rule.line)),
+                line: rule.line,
+                name: rule.selector,
+                foreground: Paint::new(rule.foreground, rule.foreground_variable)?,
+                background: Paint::new(rule.background, rule.background_variable),
+                foreground_span: None,
+            })
+        })
+        .collect())
+}
+
+/// A source map and the directory its sources are relative to, the one of the map.
+struct LinkedMap {
+    map: SourceMap,
+    directory: PathBuf,
+}
+
+impl LinkedMap {
+    /// The original location of a position of the stylesheet, like `src/card.scss:12`.
+    fn origin(&self, line: usize, column: usize) -> Option<String> {
+        let original = self.map.original(line, column)?;
+        // Bundlers write sources like webpack://app/card.scss, which can't be joined
+        let source = if original.source.contains("://") {
+            original.source.to_string()
+        } else {
+            self.directory.join(original.source).display().to_string()
+        };
+        Some(format!("{source}:{}", original.line))
+    }
+}
+
+/// The rules of a stylesheet, located in their original source when there's a source map.
+fn css_pairs(source: &str, rules: Vec<CssRule>, map: Option<&LinkedMap>) -> Vec<Pair> {
+    rules
+        .into_iter()
+        .filter_map(|rule| {
+            // Minified stylesheets are a single line, so the column of the declaration matters
+            let origin = map.and_then(|map| {
+                let (line, column) = match &rule.foreground_span {
+                    Some(span) => {
+                        let before = &source[..span.start];
+                        let line_start = before.rfind('\n').map_or(0, |index| index + 1);
+                        (before.matches('\n').count() + 1, span.start - line_start)
+                    }
+                    None => (rule.line, 0),
+                };
+                map.origin(line, column)
+            });
+
+            Some(Pair {
+                line: rule.line,
+                name: rule.selector,
+                foreground: Paint::new(rule.foreground, rule.foreground_variable)?,
+                background: Paint::new(rule.background, rule.background_variable),
+                foreground_span: rule.foreground_span,
+                origin,
+            })
+        })
+        .collect()
+}
+
+/// The source map given with --source-map, or else the one the stylesheet links to. A linked map
+/// that's missing is skipped, built stylesheets are often deployed without their maps.
+fn source_map(args: &ScanArgs, path: &Path, source: &str) -> Result<Option<LinkedMap>, Error> {
+    let (path, contents) = match (&args.source_map, source_map_url(source)) {
+        (Some(path), _) => (path.clone(), read_input(Some(path))?),
+        (None, Some(SourceMapUrl::Inline(json))) => (path.to_path_buf(), json),
+        (None, Some(SourceMapUrl::File(url))) => {
+            let directory = path.parent().unwrap_or(Path::new(""));
+            let path = directory.join(url);
+            match fs::read_to_string(&path) {
+                Ok(contents) => (path, contents),
+                Err(err) => {
+                    log::debug!("skipping the source map {}: {err}", path.display());
+                    return Ok(None);
+                }
+            }
+        }
+        (None, None) => return Ok(None),
+    };
+
+    let map = SourceMap::parse(&contents)
+        .map_err(|err| format!("{}: invalid source map, {err}", path.display()))?;
+    Ok(Some(LinkedMap {
+        map,
+        directory: path.parent().unwrap_or(Path::new("")).to_path_buf(),
+    }))
+}
+
+/// A pair whose colors could be resolved.
+struct Checked {
+    pair: Pair,
+    foreground: Color,
+    background: Color,
+    background_name: String,
+    ratio: f32,
+    /// The ratio the file of the pair has to reach.
+    threshold: f32,
+    passes: bool,
+}
+
+/// What a file is checked against: the flags, then the settings of its directory in the
+/// configuration.
+struct Settings {
+    ratio: f32,
+    background: Option<(String, Color)>,
+}
+
+impl Settings {
+    fn of(args: &ScanArgs, config: &Config, path: &Path) -> Result<Settings, Error> {
+        let config = if path.as_os_str() == "-" {
+            config.clone()
+        } else {
+            config.for_path(path)
+        };
+        let background = args
+            .background
+            .as_deref()
+            .or(config.background.as_deref())
+            .map(|input| {
+                parse_color("background color", input).map(|color| (input.to_string(), color))
+            })
+            .transpose()?;
+
+        Ok(Settings {
+            ratio: args.ratio.or(config.ratio).unwrap_or(4.5),
+            background,
+        })
+    }
+}
+
+/// The ratio findings have to reach, like `4.5:1`, or that it depends on their directory.
+fn required(checked: &[&Checked], default: f32) -> String {
+    let threshold = checked.first().map_or(default, |checked| checked.threshold);
+    if checked.iter().all(|checked| checked.threshold == threshold) {
+        format!("{threshold}:1")
+    } else {
+        "the ratio of their directory".to_string()
+    }
+}
+
+/// The checked pairs of a file, what they are ("rules", "styles" or "elements") and how many
+/// couldn't be resolved.
+fn audit(
+    args: &ScanArgs,
+    path: &Path,
+    source: &str,
+    settings: &Settings,
+    map: Option<&LinkedMap>,
+) -> Result<(Vec<Checked>, &'static str, usize), Error> {
+    let rounding: RoundingPolicy = args.rounding.into();
+    let (pairs, kind) = match Input::of(path, source) {
+        Input::Svg => (svg_pairs(source), "elements"),
+        Input::Postcss => (postcss_pairs(source)?, "rules"),
+        Input::Stylesheet => (css_pairs(source, css_rules(source), map), "rules"),
+        Input::CssInJs => (css_pairs(source, css_in_js_rules(source), map), "styles"),
+        Input::Component => (css_pairs(source, sfc_rules(source), map), "rules"),
+    };
+
+    let mut checked = Vec::new();
+    let mut skipped = 0;
+
+    for pair in pairs {
+        let background = match (&pair.background, &settings.background) {
+            (Some(paint), _) => contrast_checker::parse_color(&paint.value)
+                .ok()
+                .map(|color| (paint.describe(), color)),
+            (None, Some((input, color))) => Some((input.clone(), *color)),
+            (None, None) => None,
+        };
+
+        // Values like var(--text), currentColor or url(#gradient) can't be resolved without a
+        // browser
+        let (Ok(foreground), Some((background_name, background))) = (
+            contrast_checker::parse_color(&pair.foreground.value),
+            background,
+        ) else {
+            skipped += 1;
+            continue;
+        };
+
+        let ratio = contrast_ratio(&foreground, &background);
+        checked.push(Checked {
+            pair,
+            foreground,
+            background,
+            background_name,
+            ratio,
+            threshold: settings.ratio,
+            passes: rounding.apply(ratio) >= settings.ratio,
+        });
+    }
+
+    Ok((checked, kind, skipped))
+}
+
+impl Checked {
+    /// Where the pair is written, in the file or where its source map points to.
+    fn location(&self, path: &Path) -> String {
+        match &self.pair.origin {
+            Some(origin) => origin.clone(),
+            None => format!("{}:{}", path.display(), self.pair.line),
+        }
+    }
+
+    /// The finding, at the place the source map points to when there's one.
+    fn describe(&self, path: &Path) -> String {
+        let location = self.location(path);
+        format!(
+            "{location}: {}: {} on {} is {:.2}:1",
+            self.pair.name,
+            self.pair.foreground.describe(),
+            self.background_name,
+            self.ratio
+        )
+    }
+}
+
+/// What --fix and --diff do to a file.
+struct Fixes {
+    edits: Vec<Edit>,
+    /// A line per failing pair saying what changes, or why it can't.
+    changes: Vec<String>,
+    /// The failing pairs left as they are, including the manual ones.
+    unfixed: usize,
+    /// The failing pairs whose color comes from a variable, which has to be changed by hand where
+    /// it's declared since other rules may use it.
+    manual: usize,
+}
+
+/// The edits of --fix and --diff, and a line per failing pair saying what changes or why it
+/// can't. A color gets the same replacement in every failing rule, as in [`Audit::replacements`].
+fn fixes(args: &ScanArgs, settings: &Settings, source: &str, checked: &[Checked]) -> Fixes {
+    let failing: Vec<&Checked> = checked.iter().filter(|checked| !checked.passes).collect();
+
+    let audit = Audit::new()
+        .threshold(settings.ratio)
+        .rounding(args.rounding.into());
+    let pairs = failing
+        .iter()
+        .map(|checked| ResolvedPair {
+            input: PairInput {
+                foreground: checked.pair.foreground.value.clone(),
+                background: checked.background_name.clone(),
+                line: Some(checked.pair.line),
+                metadata: Vec::new(),
+            },
+            foreground: checked.foreground,
+            background: checked.background,
+        })
+        .collect();
+    let replacements = audit.replacements(&audit.check(pairs));
+
+    let mut edits = Vec::new();
+    let mut changes = Vec::new();
+    let mut manual = 0;
+    for checked in &failing {
+        let location = format!(
+            "{}:{}: {}",
+            args.input.display(),
+            checked.pair.line,
+            checked.pair.name
+        );
+        let replacement = replacements
+            .iter()
+            .find(|replacement| replacement.original == checked.foreground)
+            .and_then(|replacement| replacement.replacement);
+
+        if let Some(variable) = &checked.pair.foreground.variable {
+            manual += 1;
+            let suggestion = match replacement {
+                Some(replacement) => format!(
+                    "set {variable} to {} where it's declared ({:.2}:1 -> {:.2}:1 on {})",
+                    restyle(&checked.pair.foreground.value, &replacement),
+                    checked.ratio,
+                    contrast_ratio(&replacement, &checked.background),
+                    checked.background_name
+                ),
+                None => format!(
+                    "no lightness of {variable} ({}) reaches {}:1 on {}",
+                    checked.pair.foreground.value, settings.ratio, checked.background_name
+                ),
+            };
+            changes.push(format!("{location}: manual fix, {suggestion}"));
+            continue;
+        }
+        let Some(span) = &checked.pair.foreground_span else {
+            changes.push(format!(
+                "{location}: not fixed, only stylesheets are rewritten"
+            ));
+            continue;
+        };
+        let Some(replacement) = replacement else {
+            changes.push(format!(
+                "{location}: not fixed, no lightness of {} reaches {}:1 on {}",
+                checked.pair.foreground.value, settings.ratio, checked.background_name
+            ));
+            continue;
+        };
+
+        let written = restyle(&source[span.clone()], &replacement);
+        changes.push(format!(
+            "{location}: {} -> {written} on {} ({:.2}:1 -> {:.2}:1)",
+            checked.pair.foreground.value,
+            checked.background_name,
+            checked.ratio,
+            contrast_ratio(&replacement, &checked.background)
+        ));
+        edits.push(Edit {
+            span: span.clone(),
+            replacement: written,
+        });
+    }
+
+    let unfixed = failing.len() - edits.len();
+    Fixes {
+        edits,
+        changes,
+        unfixed,
+        manual,
+    }
+}
+
+/// The checked pairs in groups, in the order the groups first appear in the file.
+fn groups(checked: &[Checked], group_by: GroupBy) -> Vec<(String, Vec<&Checked>)> {
+    let mut groups: Vec<(String, Vec<&Checked>)> = Vec::new();
+    for checked in checked {
+        let name = match group_by {
+            GroupBy::Selector => checked.pair.name.clone(),
+            GroupBy::Component => component_name(&checked.pair.name),
+        };
+        match groups.iter_mut().find(|(group, _)| *group == name) {
+            Some((_, members)) => members.push(checked),
+            None => groups.push((name, vec![checked])),
+        }
+    }
+
+    groups
+}
+
+/// The report of checked pairs for the HTML writer, every foreground says which rule it's from.
+/// The verdicts are the ones of the pairs, their directories can ask for different ratios.
+fn report(args: &ScanArgs, checked: &[&Checked]) -> BatchReport {
+    let threshold = checked
+        .first()
+        .map_or(args.ratio.unwrap_or(4.5), |checked| checked.threshold);
+    let audit = Audit::new()
+        .threshold(threshold)
+        .rounding(args.rounding.into());
+    let pairs = checked
+        .iter()
+        .map(|checked| ResolvedPair {
+            input: PairInput {
+                foreground: format!(
+                    "{}: {}",
+                    checked.pair.name,
+                    checked.pair.foreground.describe()
+                ),
+                background: checked.background_name.clone(),
+                line: Some(checked.pair.line),
+                metadata: Vec::new(),
+            },
+            foreground: checked.foreground,
+            background: checked.background,
+        })
+        .collect();
+
+    let mut report = audit.check(pairs);
+    for (result, checked) in report.results.iter_mut().zip(checked) {
+        result.meets_threshold = checked.passes;
+    }
+
+    report
+}
+
+fn write_html(args: &ScanArgs, checked: &[Checked]) -> Result<(), Error> {
+    let mut writer = HtmlWriter::new(io::stdout().lock());
+    let written = match args.group_by {
+        Some(group_by) => {
+            let reports: Vec<(String, BatchReport)> = groups(checked, group_by)
+                .into_iter()
+                .map(|(name, members)| (name, report(args, &members)))
+                .collect();
+            let groups: Vec<(&str, &BatchReport)> = reports
+                .iter()
+                .map(|(name, report)| (name.as_str(), report))
+                .collect();
+            writer.write_groups(&groups)
+        }
+        None => writer.write_report(&report(args, &checked.iter().collect::<Vec<_>>())),
+    };
+
+    written.map_err(|err| Error::io(format!("could not write the report: {err}")))
+}
+
+/// Prints a failing pair and the hint of how far it is from passing, indented by `indent`.
+/// Says which commit wrote the line of a failure, with --blame.
+type Blamer = Box<dyn FnMut(&Checked) -> Option<String>>;
+
+#[cfg(feature = "blame")]
+fn blamer(args: &ScanArgs) -> Result<Blamer, Error> {
+    if !args.blame {
+        return Ok(Box::new(|_| None));
+    }
+    let mut blame = super::blame::Blame::open(&args.input)?;
+    let input = args.input.clone();
+    Ok(Box::new(move |checked| {
+        let location = checked.location(&input);
+        let (path, line) = location.rsplit_once(':')?;
+        blame.line(Path::new(path), line.parse().ok()?)
+    }))
+}
+
+#[cfg(not(feature = "blame"))]
+fn blamer(_: &ScanArgs) -> Result<Blamer, Error> {
+    Ok(Box::new(|_| None))
+}
+
+fn print_failure(args: &ScanArgs, checked: &Checked, indent: &str, blamer: &mut Blamer) {
+    println!(
+        "{indent}{}, needs {}:1",
+        checked.describe(&args.input),
+        checked.threshold
+    );
+    if let Some(gap) = compliance_gap(&checked.foreground, &checked.background, checked.threshold) {
+        println!("{indent}  {}", gap.hint());
+    }
+    if let Some(commit) = blamer(checked) {
+        println!("{indent}  {commit}");
+    }
+}
+
+/// Finds the files of a project that scan reads, skipping hidden directories and `node_modules`.
+fn project_files(directory: &Path, files: &mut Vec<PathBuf>) -> Result<(), Error> {
+    let entries = fs::read_dir(directory)
+        .map_err(|err| Error::io(format!("could not read {}: {err}", directory.display())))?;
+    for entry in entries {
+        let path = entry
+            .map_err(|err| Error::io(format!("could not read {}: {err}", directory.display())))?
+            .path();
+        let name = path.file_name().unwrap_or_default().to_string_lossy();
+        if name.starts_with('.') || name == "node_modules" {
+            continue;
+        }
+
+        if path.is_dir() {
+            project_files(&path, files)?;
+        } else if path.extension().is_some_and(|extension| {
+            let extension = extension.to_string_lossy().to_ascii_lowercase();
+            PROJECT_FILES.contains(&extension.as_str()) || SCRIPTS.contains(&extension.as_str())
+        }) {
+            files.push(path);
+        }
+    }
+
+    Ok(())
+}
+
+/// Audits every file of a directory, in the order of their paths. Findings are located in their
+/// file, or where its source map points to.
+fn project_audit(args: &ScanArgs, config: &Config) -> Result<(Vec<Checked>, usize), Error> {
+    let mut files = Vec::new();
+    project_files(&args.input, &mut files)?;
+    files.sort();
+
+    let mut checked = Vec::new();
+    let mut skipped = 0;
+    for path in files {
+        let source = read_input(Some(&path))?;
+        let map = source_map(args, &path, &source)?;
+        let settings = Settings::of(args, config, &path)?;
+        let (file_checked, _, file_skipped) = audit(args, &path, &source, &settings, map.as_ref())?;
+        checked.extend(file_checked.into_iter().map(|mut checked| {
+            checked
+                .pair
+                .origin
+                .get_or_insert_with(|| format!("{}:{}", path.display(), checked.pair.line));
+            checked
+        }));
+        skipped += file_skipped;
+    }
+
+    Ok((checked, skipped))
+}
+
+pub fn run(args: &ScanArgs, config: &Config) -> Result<ExitCode, Error> {
+    if args.input.is_dir() {
+        if args.watch || args.fix || args.diff || args.source_map.is_some() {
+            return Err(
+                "--watch, --fix, --diff and --source-map need a file, not a directory"
+                    .to_string()
+                    .into(),
+            );
+        }
+        let (checked, skipped) = project_audit(args, config)?;
+        return print_findings(args, &checked, "rules", skipped);
+    }
+
+    let settings = Settings::of(args, config, &args.input)?;
+    if args.watch {
+        return watch(&args.input, &format!("{}:1", settings.ratio), |source| {
+            let map = source_map(args, &args.input, source)?;
+            let (checked, _, _) = audit(args, &args.input, source, &settings, map.as_ref())?;
+            Ok(checked
+                .iter()
+                .map(|checked| Outcome {
+                    key: checked.pair.name.clone(),
+                    passes: checked.passes,
+                    message: checked.describe(&args.input),
+                })
+                .collect())
+        })
+        .map(|()| ExitCode::SUCCESS);
+    }
+
+    if (args.fix || args.diff) && args.input.as_os_str() == "-" {
+        return Err(
+            "--fix and --diff rewrite a file, they can't read from stdin"
+                .to_string()
+                .into(),
+        );
+    }
+
+    let source = read_input(Some(&args.input))?;
+    let map = source_map(args, &args.input, &source)?;
+    let (checked, kind, skipped) = audit(args, &args.input, &source, &settings, map.as_ref())?;
+
+    if args.fix || args.diff {
+        let failures = checked.iter().filter(|checked| !checked.passes).count();
+        if matches!(Input::of(&args.input, &source), Input::Svg | Input::Postcss) {
+            return Err(
+                "--fix and --diff only rewrite stylesheets, CSS-in-JS and components"
+                    .to_string()
+                    .into(),
+            );
+        }
+
+        let Fixes {
+            edits,
+            changes,
+            unfixed,
+            manual,
+        } = fixes(args, &settings, &source, &checked);
+        let rewritten = apply(&source, &edits);
+
+        if args.diff {
+            print!("{}", unified_diff(&args.input, &source, &rewritten));
+        } else {
+            for change in &changes {
+                println!("{change}");
+            }
+            if !changes.is_empty() {
+                println!();
+            }
+
+            if !args.dry_run && !edits.is_empty() {
+                fs::write(&args.input, &rewritten).map_err(|err| {
+                    Error::io(format!("could not write {}: {err}", args.input.display()))
+                })?;
+            }
+            let verb = if args.dry_run { "would fix" } else { "fixed" };
+            let manual = match manual {
+                0 => String::new(),
+                manual => format!(" ({manual} to fix by hand in a variable)"),
+            };
+            println!(
+                "{verb} {} of {failures} {kind} failing {}:1, {unfixed} left{manual}",
+                edits.len(),
+                settings.ratio
+            );
+        }
+
+        // Once the file is written only the pairs that couldn't be fixed fail, previews fail like a
+        // scan
+        let remaining = if args.fix && !args.dry_run {
+            unfixed
+        } else {
+            failures
+        };
+        return Ok(if remaining > 0 {
+            ExitCode::FAILURE
+        } else {
+            ExitCode::SUCCESS
+        });
+    }
+
+    print_findings(args, &checked, kind, skipped)
+}
+
+/// Prints the failing pairs, or the HTML report, and the exit code of the audit.
+fn print_findings(
+    args: &ScanArgs,
+    checked: &[Checked],
+    kind: &str,
+    skipped: usize,
+) -> Result<ExitCode, Error> {
+    let failures = checked.iter().filter(|checked| !checked.passes).count();
+    if args.format == ScanFormat::Html {
+        write_html(args, checked)?;
+        return Ok(if failures > 0 {
+            ExitCode::FAILURE
+        } else {
+            ExitCode::SUCCESS
+        });
+    }
+
+    let mut blamer = blamer(args)?;
+    match args.group_by {
+        // Only the groups with failures are listed, with how many of their rules fail
+        Some(group_by) => {
+            for (name, members) in groups(checked, group_by) {
+                let total = members.len();
+                let failing: Vec<&Checked> = members
+                    .into_iter()
+                    .filter(|checked| !checked.passes)
+                    .collect();
+                if failing.is_empty() {
+                    continue;
+                }
+                println!(
+                    "{name}: {} of {} {kind} fail {}",
+                    failing.len(),
+                    total,
+                    required(&failing, 4.5)
+                );
+                for checked in failing {
+                    print_failure(args, checked, "  ", &mut blamer);
+                }
+            }
+        }
+        None => {
+            for checked in checked.iter().filter(|checked| !checked.passes) {
+                print_failure(args, checked, "", &mut blamer);
+            }
+        }
+    }
+
+    if failures > 0 {
+        println!();
+    }
+    let all: Vec<&Checked> = checked.iter().collect();
+    println!(
+        "{failures} of {} {kind} fail {}",
+        checked.len(),
+        required(&all, args.ratio.unwrap_or(4.5))
+    );
+    if skipped > 0 {
+        println!("{skipped} skipped because their colors couldn't be resolved, like var()");
+    }
+
+    Ok(if failures > 0 {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use clap::Parser;
+
+    #[derive(Parser)]
+    struct Cli {
+        #[command(flatten)]
+        scan: ScanArgs,
+    }
+
+    fn fix(path: &str, source: &str) -> (String, Fixes) {
+        let args = Cli::parse_from(["scan", path, "--fix", "--background", "white"]).scan;
+        let settings = Settings {
+            ratio: 4.5,
+            background: Some(("white".to_string(), Color::new(255, 255, 255))),
+        };
+        let (checked, _, _) = audit(&args, Path::new(path), source, &settings, None).unwrap();
+        let fixes = fixes(&args, &settings, source, &checked);
+        (apply(source, &fixes.edits), fixes)
+    }
+
+    #[test]
+    fn fixes_keep_the_notation_of_the_file() {
+        let css = ".a { color: rgb(150, 150, 150); }\n.b { color: #AAA; }\n.c { color: #ccc; }\n";
+        let (rewritten, fixes) = fix("card.css", css);
+        assert_eq!(
+            rewritten,
+            ".a { color: rgb(118, 118, 118); }\n.b { color: #767676; }\n.c { color: #767676; }\n"
+        );
+        assert_eq!((fixes.unfixed, fixes.manual), (0, 0));
+    }
+
+    #[test]
+    fn variables_are_manual_fixes() {
+        let scss = "$text: #999;\n.card { color: $text; }\n.title { color: #999; }\n";
+        let (rewritten, fixes) = fix("card.scss", scss);
+        assert_eq!(
+            rewritten,
+            "$text: #999;\n.card { color: $text; }\n.title { color: #767676; }\n"
+        );
+        assert_eq!((fixes.edits.len(), fixes.unfixed, fixes.manual), (1, 1, 1));
+        assert_eq!(
+            fixes.changes[0],
+            "card.scss:2: .card: manual fix, set $text to #767676 where it's declared \
+             (2.85:1 -> 4.54:1 on white)"
+        );
+    }
+}
@@ -0,0 +1,393 @@
+//! Reads the screen of an X11 session: the colors under the pointer, a global hotkey to pick
+//! them with and a small window following the pointer to show results in. Wayland sessions
+//! running XWayland only show the X11 windows to it.
+
+use contrast_checker::Color;
+use x11rb::connection::Connection;
+use x11rb::protocol::xproto::{
+    ConfigureWindowAux, ConnectionExt, CreateGCAux, CreateWindowAux, Gcontext, ImageFormat,
+    ImageOrder, StackMode, Window, WindowClass,
+};
+#[cfg(feature = "tray")]
+use x11rb::protocol::xproto::{GrabMode, Keycode, ModMask};
+#[cfg(feature = "tray")]
+use x11rb::protocol::Event;
+use x11rb::rust_connection::RustConnection;
+use x11rb::{COPY_DEPTH_FROM_PARENT, COPY_FROM_PARENT};
+
+use super::Error;
+
+/// The size of the characters of the `fixed` font every X server has.
+const FONT_WIDTH: u16 = 6;
+const FONT_HEIGHT: u16 = 13;
+/// Space between the border of the overlay and its text.
+const PADDING: u16 = 4;
+/// Distance between the pointer and the overlay, which keeps it out of the region sampled around
+/// the pointer when it isn't bigger than twice this.
+pub const OFFSET: i32 = 24;
+
+/// The keysyms of the function keys start at F1 and are consecutive.
+#[cfg(feature = "tray")]
+const KEYSYM_F1: u32 = 0xffbe;
+
+/// A key with its modifiers, like `ctrl+alt+c`.
+#[cfg(feature = "tray")]
+#[derive(Clone, Copy)]
+pub struct Hotkey {
+    modifiers: u16,
+    keysym: u32,
+}
+
+#[cfg(feature = "tray")]
+impl Hotkey {
+    /// Parses `ctrl`, `shift`, `alt` and `super` joined with `+` to a letter, a digit or F1 to F12.
+    pub fn parse(input: &str) -> Result<Self, Error> {
+        let invalid = || {
+            format!("invalid hotkey {input}, use modifiers and a key like ctrl+alt+c or super+F9")
+        };
+        let mut parts: Vec<&str> = input.split('+').map(str::trim).collect();
+        let key = parts
+            .pop()
+            .filter(|key| !key.is_empty())
+            .ok_or_else(invalid)?;
+
+        let mut modifiers = 0;
+        for modifier in parts {
+            modifiers |= u16::from(match modifier.to_ascii_lowercase().as_str() {
+                "shift" => ModMask::SHIFT,
+                "ctrl" | "control" => ModMask::CONTROL,
+                "alt" => ModMask::M1,
+                "super" | "win" | "meta" => ModMask::M4,
+                _ => return Err(invalid().into()),
+            });
+        }
+
+        let keysym = match key.to_ascii_lowercase().as_bytes() {
+            [character] if character.is_ascii_alphanumeric() => u32::from(*character),
+            [b'f', number @ ..] => match std::str::from_utf8(number)
+                .ok()
+                .and_then(|n| n.parse().ok())
+            {
+                Some(number @ 1..=12) => KEYSYM_F1 + number - 1,
+                _ => return Err(invalid().into()),
+            },
+            _ => return Err(invalid().into()),
+        };
+
+        Ok(Self { modifiers, keysym })
+    }
+}
+
+/// How the root window stores a pixel, which depends on the server.
+struct PixelFormat {
+    bytes_per_pixel: usize,
+    bytes_per_row_padding: usize,
+    little_endian: bool,
+    masks: [u32; 3],
+}
+
+/// A connection to the X server and the root window of its default screen.
+pub struct Screen {
+    connection: RustConnection,
+    root: Window,
+    format: PixelFormat,
+    /// The size of the screen, in pixels.
+    pub width: u16,
+    pub height: u16,
+    black: u32,
+    white: u32,
+}
+
+/// A window without decorations that stays above the others, for text next to the pointer.
+pub struct Overlay<'a> {
+    screen: &'a Screen,
+    window: Window,
+    context: Gcontext,
+}
+
+fn x11_error(err: impl std::fmt::Display) -> Error {
+    Error::io(format!("X11 request failed: {err}"))
+}
+
+/// Scales a channel of however many bits the mask has to 8 bits.
+fn channel(pixel: u32, mask: u32) -> u8 {
+    let bits = mask.count_ones();
+    if bits == 0 {
+        return 0;
+    }
+    let value = (pixel & mask) >> mask.trailing_zeros();
+    (u64::from(value) * 255 / ((1u64 << bits) - 1)) as u8
+}
+
+impl Screen {
+    /// Connects to the display of `$DISPLAY`.
+    pub fn connect() -> Result<Self, Error> {
+        let (connection, screen) = x11rb::connect(None)
+            .map_err(|err| Error::io(format!("could not connect to the X11 display: {err}")))?;
+
+        let setup = connection.setup();
+        let screen = &setup.roots[screen];
+        let visual = screen
+            .allowed_depths
+            .iter()
+            .flat_map(|depth| &depth.visuals)
+            .find(|visual| visual.visual_id == screen.root_visual)
+            .ok_or("the X11 screen doesn't describe its visual")?;
+        let pixmap = setup
+            .pixmap_formats
+            .iter()
+            .find(|format| format.depth == screen.root_depth)
+            .ok_or("the X11 screen doesn't describe its pixel format")?;
+        if pixmap.bits_per_pixel < 8 {
+            return Err(format!(
+                "the X11 screen uses {} bits per pixel, only true color screens can be read",
+                pixmap.bits_per_pixel
+            )
+            .into());
+        }
+
+        let format = PixelFormat {
+            bytes_per_pixel: usize::from(pixmap.bits_per_pixel / 8),
+            bytes_per_row_padding: usize::from(pixmap.scanline_pad / 8),
+            little_endian: setup.image_byte_order == ImageOrder::LSB_FIRST,
+            masks: [visual.red_mask, visual.green_mask, visual.blue_mask],
+        };
+        let (root, width, height) = (screen.root, screen.width_in_pixels, screen.height_in_pixels);
+        let (black, white) = (screen.black_pixel, screen.white_pixel);
+
+        Ok(Self {
+            connection,
+            root,
+            format,
+            width,
+            height,
+            black,
+            white,
+        })
+    }
+
+    /// Where the pointer is, in screen coordinates.
+    pub fn pointer(&self) -> Result<(i16, i16), Error> {
+        let pointer = self
+            .connection
+            .query_pointer(self.root)
+            .map_err(x11_error)?
+            .reply()
+            .map_err(x11_error)?;
+        Ok((pointer.root_x, pointer.root_y))
+    }
+
+    /// The colors of a region of the screen, row by row.
+    pub fn capture(&self, x: i16, y: i16, width: u16, height: u16) -> Result<Vec<Color>, Error> {
+        let image = self
+            .connection
+            .get_image(ImageFormat::Z_PIXMAP, self.root, x, y, width, height, !0)
+            .map_err(x11_error)?
+            .reply()
+            .map_err(|err| Error::io(format!("could not read the screen at {x},{y}: {err}")))?;
+
+        let PixelFormat {
+            bytes_per_pixel,
+            bytes_per_row_padding,
+            little_endian,
+            masks: [red, green, blue],
+        } = self.format;
+        let row = usize::from(width) * bytes_per_pixel;
+        let stride = row.div_ceil(bytes_per_row_padding) * bytes_per_row_padding;
+
+        Ok(image
+            .data
+            .chunks(stride)
+            .take(usize::from(height))
+            .flat_map(|line| line[..row].chunks_exact(bytes_per_pixel))
+            .map(|bytes| {
+                let pixel = bytes
+                    .iter()
+                    .enumerate()
+                    .fold(0u32, |pixel, (index, &byte)| {
+                        let shift = if little_endian {
+                            index
+                        } else {
+                            bytes_per_pixel - 1 - index
+                        };
+                        pixel | u32::from(byte) << (8 * shift.min(3))
+                    });
+                Color::new(
+                    channel(pixel, red),
+                    channel(pixel, green),
+                    channel(pixel, blue),
+                )
+            })
+            .collect())
+    }
+
+    /// The color of the pixel under the pointer.
+    #[cfg(feature = "tray")]
+    pub fn color_under_pointer(&self) -> Result<Color, Error> {
+        let (x, y) = self.pointer()?;
+        self.capture(x, y, 1, 1)?
+            .first()
+            .copied()
+            .ok_or_else(|| format!("the screen has no pixel at {x},{y}").into())
+    }
+
+    /// Opens an overlay, it's shown by the first [`Overlay::show`].
+    pub fn overlay(&self) -> Result<Overlay<'_>, Error> {
+        let connection = &self.connection;
+        let window = connection.generate_id().map_err(x11_error)?;
+        let font = connection.generate_id().map_err(x11_error)?;
+        let context = connection.generate_id().map_err(x11_error)?;
+
+        connection
+            .create_window(
+                COPY_DEPTH_FROM_PARENT,
+                window,
+                self.root,
+                0,
+                0,
+                1,
+                1,
+                1,
+                WindowClass::INPUT_OUTPUT,
+                COPY_FROM_PARENT,
+                // Override redirect keeps window managers from decorating or moving it
+                &CreateWindowAux::new()
+                    .override_redirect(1)
+                    .background_pixel(self.white)
+                    .border_pixel(self.black),
+            )
+            .map_err(x11_error)?;
+        connection.open_font(font, b"fixed").map_err(x11_error)?;
+        connection
+            .create_gc(
+                context,
+                window,
+                &CreateGCAux::new()
+                    .foreground(self.black)
+                    .background(self.white)
+                    .font(font),
+            )
+            .map_err(x11_error)?;
+        connection.close_font(font).map_err(x11_error)?;
+
+        Ok(Overlay {
+            screen: self,
+            window,
+            context,
+        })
+    }
+
+    /// Grabs the hotkey on the whole screen, whatever window has the focus. The grab is repeated
+    /// with Caps Lock and Num Lock, which X11 counts as modifiers too.
+    #[cfg(feature = "tray")]
+    pub fn grab(&self, hotkey: Hotkey, input: &str) -> Result<(), Error> {
+        let keycode = self
+            .keycode(hotkey.keysym)?
+            .ok_or_else(|| format!("no key of the keyboard layout types the {input} hotkey"))?;
+
+        let num_lock = u16::from(ModMask::M2);
+        let caps_lock = u16::from(ModMask::LOCK);
+        for locks in [0, caps_lock, num_lock, caps_lock | num_lock] {
+            self.connection
+                .grab_key(
+                    true,
+                    self.root,
+                    ModMask::from(hotkey.modifiers | locks),
+                    keycode,
+                    GrabMode::ASYNC,
+                    GrabMode::ASYNC,
+                )
+                .map_err(x11_error)?
+                .check()
+                .map_err(|_| format!("{input} is already taken by another application"))?;
+        }
+
+        Ok(())
+    }
+
+    /// Blocks until the grabbed hotkey is pressed.
+    #[cfg(feature = "tray")]
+    pub fn wait_for_hotkey(&self) -> Result<(), Error> {
+        loop {
+            match self.connection.wait_for_event() {
+                Ok(Event::KeyPress(_)) => return Ok(()),
+                Ok(_) => {}
+                Err(err) => {
+                    return Err(format!("lost the connection to the X11 display: {err}").into())
+                }
+            }
+        }
+    }
+
+    /// The first key of the layout that types `keysym` without a modifier.
+    #[cfg(feature = "tray")]
+    fn keycode(&self, keysym: u32) -> Result<Option<Keycode>, Error> {
+        let setup = self.connection.setup();
+        let (first, last) = (setup.min_keycode, setup.max_keycode);
+        let mapping = self
+            .connection
+            .get_keyboard_mapping(first, last - first + 1)
+            .map_err(x11_error)?
+            .reply()
+            .map_err(x11_error)?;
+
+        let per_keycode = usize::from(mapping.keysyms_per_keycode).max(1);
+        Ok(mapping
+            .keysyms
+            .chunks(per_keycode)
+            .position(|keysyms| keysyms.first() == Some(&keysym))
+            .map(|index| first + index as Keycode))
+    }
+}
+
+impl Overlay<'_> {
+    /// Shows lines of text next to the pointer at `x`, `y`, on the other side of it near the
+    /// right and bottom edges of the screen, and above every other window.
+    pub fn show(&self, x: i16, y: i16, lines: &[String]) -> Result<(), Error> {
+        let connection = &self.screen.connection;
+        let columns = lines.iter().map(String::len).max().unwrap_or(0) as u16;
+        let width = columns * FONT_WIDTH + 2 * PADDING;
+        let height = lines.len() as u16 * FONT_HEIGHT + 2 * PADDING;
+        let place = |pointer: i16, size: u16, screen: u16| {
+            let after = i32::from(pointer) + OFFSET;
+            if after + i32::from(size) > i32::from(screen) {
+                i32::from(pointer) - OFFSET - i32::from(size)
+            } else {
+                after
+            }
+        };
+        let (x, y) = (
+            place(x, width, self.screen.width),
+            place(y, height, self.screen.height),
+        );
+
+        connection
+            .configure_window(
+                self.window,
+                &ConfigureWindowAux::new()
+                    .x(x)
+                    .y(y)
+                    .width(u32::from(width))
+                    .height(u32::from(height))
+                    .stack_mode(StackMode::ABOVE),
+            )
+            .map_err(x11_error)?;
+        connection.map_window(self.window).map_err(x11_error)?;
+        connection
+            .clear_area(false, self.window, 0, 0, 0, 0)
+            .map_err(x11_error)?;
+        for (index, line) in lines.iter().enumerate() {
+            let baseline = PADDING + (index as u16 + 1) * FONT_HEIGHT - 3;
+            connection
+                .image_text8(
+                    self.window,
+                    self.context,
+                    PADDING as i16,
+                    baseline as i16,
+                    line.as_bytes(),
+                )
+                .map_err(x11_error)?;
+        }
+        connection.flush().map_err(x11_error)
+    }
+}
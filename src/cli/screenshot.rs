@@ -0,0 +1,63 @@
+//! `screenshot`, an audit of apps without style information to read: the text regions of a
+//! screenshot are detected and the contrast of each is checked.
+
+use std::path::PathBuf;
+use std::process::ExitCode;
+
+use clap::Args;
+use contrast_checker::detect_text_regions;
+
+use super::image::region_pixels;
+use super::Error;
+
+#[derive(Args)]
+pub struct ScreenshotArgs {
+    /// PNG, JPEG or WebP screenshot
+    image: PathBuf,
+
+    /// Contrast ratio every region has to reach, 4.5 is AA for normal text
+    #[arg(long, default_value_t = 4.5)]
+    ratio: f32,
+}
+
+pub fn run(args: &ScreenshotArgs) -> Result<ExitCode, Error> {
+    let (region, pixels) = region_pixels(&args.image, None)?;
+    let regions = detect_text_regions(&pixels, region.width);
+
+    println!(
+        "found {} text regions in {}",
+        regions.len(),
+        args.image.display()
+    );
+    let mut failing = 0;
+    for region in &regions {
+        let colors = region.colors;
+        let verdict = if colors.ratio >= args.ratio {
+            "passes"
+        } else {
+            failing += 1;
+            "fails"
+        };
+        println!(
+            "{},{} {}x{}: {} on {}, {:.2}:1 {verdict}",
+            region.x,
+            region.y,
+            region.width,
+            region.height,
+            colors.text.to_hex(),
+            colors.background.to_hex(),
+            colors.ratio
+        );
+    }
+    println!(
+        "{failing} of {} regions fail {}:1",
+        regions.len(),
+        args.ratio
+    );
+
+    Ok(if failing == 0 {
+        ExitCode::SUCCESS
+    } else {
+        ExitCode::from(1)
+    })
+}
@@ -0,0 +1,360 @@
+use std::collections::HashMap;
+use std::net::{IpAddr, Ipv4Addr};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use clap::{Args, ValueEnum};
+use contrast_checker::check_compliance;
+use contrast_checker::report::{escape_json, percent_decode, Measurement, PairJson, Permalink};
+use tiny_http::{Header, Method, Request, Response, Server};
+
+use super::{parse_color, sessions, Algorithm, Error};
+
+#[derive(Args)]
+pub struct ServeArgs {
+    /// Address to listen on, use 0.0.0.0 to accept requests from other machines
+    #[arg(long, default_value = "127.0.0.1")]
+    host: String,
+
+    /// Port to listen on
+    #[arg(long, default_value_t = 8080)]
+    port: u16,
+
+    /// Also accept WebSocket clients on this port, at /session/NAME. Clients of a session send
+    /// the colors they change and all of them get every new result
+    #[arg(long)]
+    ws_port: Option<u16>,
+
+    /// Only answer requests with this key, in an "Authorization: Bearer KEY" or "X-Api-Key"
+    /// header or a key query parameter. The page at / stays public, it has no data
+    #[arg(long, env = "CONTRAST_CHECKER_API_KEY", hide_env_values = true)]
+    api_key: Option<String>,
+
+    /// Requests, and WebSocket messages, a client can send per minute before getting 429 Too
+    /// Many Requests
+    #[arg(long, value_name = "REQUESTS", default_value_t = 300)]
+    rate_limit: u32,
+}
+
+/// The longest URL answered, the colors of a check fit in much less.
+const MAX_URL: usize = 2048;
+
+/// The longest color accepted, longer inputs aren't colors.
+pub const MAX_COLOR: usize = 100;
+
+/// What the server and the WebSocket sessions check before answering, so they can be reached
+/// from a network: the API key and how often every client asks.
+pub struct Limits {
+    api_key: Option<String>,
+    rate_limit: u32,
+    /// The start of the minute of every client and how many requests it sent in it.
+    clients: Mutex<HashMap<IpAddr, (Instant, u32)>>,
+}
+
+impl Limits {
+    /// Whether `key` is the API key, or the server doesn't need one.
+    pub fn authorized(&self, key: Option<&str>) -> bool {
+        let Some(expected) = &self.api_key else {
+            return true;
+        };
+        // Compares every byte, so the time taken doesn't tell how much of a guess is right
+        let key = key.unwrap_or_default().as_bytes();
+        key.len() == expected.len()
+            && key
+                .iter()
+                .zip(expected.as_bytes())
+                .fold(0, |difference, (a, b)| difference | (a ^ b))
+                == 0
+    }
+
+    /// Counts a request of `client`, false once it sent more than the limit this minute.
+    pub fn allow(&self, client: IpAddr) -> bool {
+        let now = Instant::now();
+        let mut clients = self.clients.lock().unwrap();
+        // Forgets the clients that are done, so the map doesn't grow forever
+        if clients.len() > 10_000 {
+            clients.retain(|_, (start, _)| now.duration_since(*start) < Duration::from_secs(60));
+        }
+        let (start, count) = clients.entry(client).or_insert((now, 0));
+        if now.duration_since(*start) >= Duration::from_secs(60) {
+            *start = now;
+            *count = 0;
+        }
+        *count += 1;
+        *count <= self.rate_limit
+    }
+}
+
+/// The API key of a `key` query parameter, percent-decoded so a key with `+`, `&` or `=` can be
+/// sent in a URL.
+pub fn query_key(url: &str) -> Option<String> {
+    let (_, query) = url.split_once('?')?;
+    query
+        .split('&')
+        .find_map(|parameter| parameter.strip_prefix("key="))
+        .map(percent_decode)
+}
+
+/// The API key of an `Authorization: Bearer` or `X-Api-Key` header.
+pub fn header_key<'a>(mut headers: impl Iterator<Item = (&'a str, &'a str)>) -> Option<&'a str> {
+    headers.find_map(|(name, value)| {
+        if name.eq_ignore_ascii_case("authorization") {
+            value.strip_prefix("Bearer ")
+        } else if name.eq_ignore_ascii_case("x-api-key") {
+            Some(value)
+        } else {
+            None
+        }
+    })
+}
+
+fn error(status: u16, message: &str) -> (u16, &'static str, String) {
+    (
+        status,
+        JSON,
+        format!(r#"{{"error":"{}"}}"#, escape_json(message)),
+    )
+}
+
+/// Turns away the requests the server doesn't answer: too many from a client, with another
+/// method than GET, long URLs, bodies, or without the API key.
+fn reject(request: &Request, limits: &Limits) -> Option<(u16, &'static str, String)> {
+    let client = request
+        .remote_addr()
+        .map_or(IpAddr::V4(Ipv4Addr::UNSPECIFIED), |address| address.ip());
+    if !limits.allow(client) {
+        return Some(error(429, "too many requests, try again in a minute"));
+    }
+    if *request.method() != Method::Get {
+        return Some(error(405, "only GET requests are answered"));
+    }
+    if request.url().len() > MAX_URL {
+        return Some(error(
+            414,
+            &format!("URLs are at most {MAX_URL} characters"),
+        ));
+    }
+    if request.body_length().is_some_and(|length| length > 0) {
+        return Some(error(413, "requests can't have a body"));
+    }
+
+    let path = request.url().split('?').next().unwrap_or_default();
+    let headers = request
+        .headers()
+        .iter()
+        .map(|header| (header.field.as_str().as_str(), header.value.as_str()));
+    let query = query_key(request.url());
+    let key = header_key(headers).or(query.as_deref());
+    if path != "/" && !limits.authorized(key) {
+        return Some(error(
+            401,
+            "send the API key in an Authorization: Bearer header or a key parameter",
+        ));
+    }
+
+    None
+}
+
+/// The page served at `/`: it reads the [`Permalink`] in the fragment of its URL, checks it with
+/// `/contrast` and puts the new fragment in the URL whenever the colors change, so the link can
+/// be shared. A `key` in the fragment is sent along, for servers with an API key.
+const PAGE: &str = r##"<!DOCTYPE html>
+<html lang="en">
+<head>
+<meta charset="utf-8">
+<title>Contrast checker</title>
+<style>
+body { font-family: system-ui, sans-serif; margin: 2rem; color: #1a1a1a; background: #fff; }
+form { display: flex; gap: 0.5rem; flex-wrap: wrap; margin-bottom: 1rem; }
+#sample { padding: 1.5rem; font-size: 1.5rem; font-weight: 600; border: 1px solid #ddd; }
+</style>
+</head>
+<body>
+<h1>Contrast checker</h1>
+<form>
+<input name="fg" placeholder="foreground" required>
+<input name="bg" placeholder="background" required>
+<select name="algorithm">
+<option value="">wcag2</option><option>apca</option>
+<option>luminance-difference</option><option>weber</option>
+</select>
+<input name="ratio" placeholder="ratio" size="6">
+<button>Check</button>
+</form>
+<div id="sample">The quick brown fox</div>
+<p id="result"></p>
+<script>
+const form = document.querySelector("form");
+async function check() {
+  const query = location.hash.slice(1);
+  const params = new URLSearchParams(query);
+  for (const input of form.elements) {
+    if (input.name) input.value = params.get(input.name) || "";
+  }
+  if (!params.get("fg") || !params.get("bg")) return;
+  const response = await fetch("/contrast?" + query);
+  const body = await response.json();
+  const result = document.getElementById("result");
+  if (!response.ok) { result.textContent = body.error; return; }
+  const sample = document.getElementById("sample");
+  sample.style.color = body.foreground.hex;
+  sample.style.background = body.background.hex;
+  result.textContent = body.ratio.toFixed(2) + ":1, " + body.algorithm + " " +
+    body.contrast.toFixed(2) + (body.meets_threshold ? " reaches " : " misses ") + body.threshold;
+}
+form.addEventListener("submit", (event) => {
+  event.preventDefault();
+  const key = new URLSearchParams(location.hash.slice(1)).get("key");
+  const params = new URLSearchParams(key ? { key } : {});
+  for (const input of form.elements) {
+    if (input.name && input.value) params.set(input.name, input.value);
+  }
+  location.hash = params.toString();
+});
+window.addEventListener("hashchange", check);
+check();
+</script>
+</body>
+</html>
+"##;
+
+const JSON: &str = "application/json";
+
+/// Answers a request for `url`, returns the status code, the content type and the body.
+fn respond(url: &str) -> (u16, &'static str, String) {
+    let (path, query) = url.split_once('?').unwrap_or((url, ""));
+    match path {
+        "/" => return (200, "text/html; charset=utf-8", PAGE.to_string()),
+        "/contrast" => {}
+        _ => {
+            return (
+                404,
+                JSON,
+                r#"{"error":"not found, use GET /contrast?fg=...&bg=..."}"#.to_string(),
+            )
+        }
+    }
+
+    match contrast(query) {
+        Ok(body) => (200, JSON, body),
+        Err(err) => error(400, &err.message),
+    }
+}
+
+/// Checks the pair of a `/contrast` query, a [`Permalink`] with an optional algorithm and ratio.
+fn contrast(query: &str) -> Result<String, Error> {
+    let permalink = Permalink::parse(query).map_err(|err| err.to_string())?;
+    if permalink.foreground.len() > MAX_COLOR || permalink.background.len() > MAX_COLOR {
+        return Err(format!("colors are at most {MAX_COLOR} characters").into());
+    }
+    let foreground = parse_color("foreground color", &permalink.foreground)?;
+    let background = parse_color("background color", &permalink.background)?;
+    let algorithm = match &permalink.algorithm {
+        Some(name) => Algorithm::from_str(name, true)
+            .map_err(|_| format!("unknown algorithm \"{name}\""))?
+            .boxed(),
+        None => Algorithm::Wcag2.boxed(),
+    };
+    let threshold = permalink.threshold.unwrap_or(algorithm.default_threshold());
+    let contrast = algorithm.contrast(&foreground, &background);
+
+    let compliance = check_compliance(&foreground, &background);
+    let measurement = Measurement {
+        algorithm: algorithm.name(),
+        contrast,
+        threshold,
+        meets_threshold: algorithm.passes(contrast, threshold),
+    };
+    Ok(PairJson::new(
+        (&permalink.foreground, &foreground),
+        (&permalink.background, &background),
+        &compliance,
+        measurement,
+    )
+    .field("permalink", permalink.fragment())
+    .to_string())
+}
+
+pub fn run(args: &ServeArgs) -> Result<(), Error> {
+    let address = format!("{}:{}", args.host, args.port);
+    let server = Server::http(&address)
+        .map_err(|err| Error::io(format!("could not listen on {address}: {err}")))?;
+    eprintln!("listening on http://{address}/#fg=112233&bg=ffffff");
+    let loopback = args
+        .host
+        .parse::<IpAddr>()
+        .is_ok_and(|host| host.is_loopback())
+        || args.host == "localhost";
+    if !loopback && args.api_key.is_none() {
+        eprintln!("warning: anyone who can reach {address} can use it, set an --api-key");
+    }
+
+    let limits = Arc::new(Limits {
+        api_key: args.api_key.clone(),
+        rate_limit: args.rate_limit,
+        clients: Mutex::default(),
+    });
+    if let Some(port) = args.ws_port {
+        let address = format!("{}:{port}", args.host);
+        sessions::listen(&address, Arc::clone(&limits))?;
+        eprintln!("live sessions on ws://{address}/session/NAME");
+    }
+
+    // Lets browser based tools like Figma plugins call the API
+    // The headers are valid, so parsing them can't fail
+    let allow_origin = Header::from_bytes("Access-Control-Allow-Origin", "*").unwrap();
+    let allow = Header::from_bytes("Allow", "GET").unwrap();
+
+    for request in server.incoming_requests() {
+        let (status, content_type, body) =
+            reject(&request, &limits).unwrap_or_else(|| respond(request.url()));
+        log::debug!("{} {} -> {status}", request.method(), request.url());
+
+        // The content types are valid headers
+        let content_type = Header::from_bytes("Content-Type", content_type).unwrap();
+        let mut response = Response::from_string(body)
+            .with_status_code(status)
+            .with_header(content_type)
+            .with_header(allow_origin.clone());
+        if status == 405 {
+            response.add_header(allow.clone());
+        }
+        if let Err(err) = request.respond(response) {
+            log::warn!("could not send a response: {err}");
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn limits(api_key: &str) -> Limits {
+        Limits {
+            api_key: Some(api_key.to_string()),
+            rate_limit: 300,
+            clients: Mutex::new(HashMap::new()),
+        }
+    }
+
+    #[test]
+    fn query_keys_are_percent_decoded() {
+        let limits = limits("s3cr+t/k=y&1");
+        let url = "/contrast?fg=%23777&key=s3cr%2Bt%2Fk%3Dy%261&bg=white";
+
+        assert_eq!(query_key(url).as_deref(), Some("s3cr+t/k=y&1"));
+        assert!(limits.authorized(query_key(url).as_deref()));
+        assert!(!limits.authorized(query_key("/contrast?key=s3cr+t/k=y").as_deref()));
+        assert_eq!(query_key("/contrast?fg=%23777"), None);
+    }
+
+    #[test]
+    fn header_keys_are_taken_as_they_are() {
+        let headers = [("Authorization", "Bearer s3cr%2Bt")];
+        assert_eq!(header_key(headers.into_iter()), Some("s3cr%2Bt"));
+        let headers = [("Host", "localhost"), ("X-API-Key", "s3cr+t")];
+        assert_eq!(header_key(headers.into_iter()), Some("s3cr+t"));
+    }
+}
@@ -0,0 +1,264 @@
+//! Live sessions of `serve --ws-port`: WebSocket clients connect to `/session/NAME`, send the
+//! colors they change and every client of the session gets the new result, so a design tool
+//! plugin and a browser extension can follow the same pair.
+
+use std::collections::HashMap;
+use std::io::ErrorKind;
+use std::net::{IpAddr, TcpListener, TcpStream};
+use std::sync::mpsc::{self, Receiver, Sender};
+use std::sync::{Arc, Mutex};
+use std::thread;
+use std::time::Duration;
+
+use contrast_checker::report::{escape_json, Measurement, PairJson};
+use contrast_checker::{check_compliance, Color, TextSize, WcagLevel};
+use tungstenite::handshake::server::{ErrorResponse, Request, Response};
+use tungstenite::http::StatusCode;
+use tungstenite::protocol::WebSocketConfig;
+use tungstenite::{Message, WebSocket};
+
+use super::serve::{header_key, query_key, Limits, MAX_COLOR};
+use super::{parse_color, Error};
+
+/// How long a connection waits for a message before sending the updates of the other clients.
+const POLL_INTERVAL: Duration = Duration::from_millis(50);
+
+/// The largest message a client can send, updates of two colors are much smaller.
+const MAX_MESSAGE: usize = 4096;
+
+/// The longest session name, and how many sessions there can be at once.
+const MAX_NAME: usize = 64;
+const MAX_SESSIONS: usize = 1000;
+
+/// The pair of a session and the clients following it.
+#[derive(Default)]
+struct Session {
+    foreground: Option<(String, Color)>,
+    background: Option<(String, Color)>,
+    clients: Vec<Sender<String>>,
+    /// How many clients are connected, the senders of the ones that left are only forgotten
+    /// when a broadcast fails.
+    followers: usize,
+}
+
+impl Session {
+    /// The result of the pair, or what's missing for it.
+    fn result(&self, name: &str) -> String {
+        match (&self.foreground, &self.background) {
+            (Some((foreground_input, foreground)), Some((background_input, background))) => {
+                let compliance = check_compliance(foreground, background);
+                let threshold = WcagLevel::AA.minimum_ratio(TextSize::Normal);
+                PairJson::new(
+                    (foreground_input, foreground),
+                    (background_input, background),
+                    &compliance,
+                    Measurement::wcag2(&compliance, threshold),
+                )
+                .field("session", name)
+                .to_string()
+            }
+            (foreground, _) => {
+                let missing = if foreground.is_none() { "fg" } else { "bg" };
+                format!(
+                    r#"{{"waiting_for":"{missing}","session":"{}"}}"#,
+                    escape_json(name)
+                )
+            }
+        }
+    }
+
+    /// Sends `message` to every client, forgetting the ones that are gone.
+    fn broadcast(&mut self, message: &str) {
+        self.clients
+            .retain(|client| client.send(message.to_string()).is_ok());
+    }
+}
+
+type Sessions = Arc<Mutex<HashMap<String, Session>>>;
+
+/// Applies a message like `{"fg":"#777"}` or `{"fg":"#777","bg":"white"}` to a session.
+fn update(session: &mut Session, message: &str) -> Result<(), Error> {
+    let value: serde_json::Value = serde_json::from_str(message)
+        .map_err(|err| format!("messages are JSON objects like {{\"fg\":\"#777\"}}: {err}"))?;
+
+    let color = |name: &str, description: &str| -> Result<Option<(String, Color)>, Error> {
+        match value.get(name) {
+            None => Ok(None),
+            Some(serde_json::Value::String(input)) if input.len() > MAX_COLOR => {
+                Err(format!("colors are at most {MAX_COLOR} characters").into())
+            }
+            Some(serde_json::Value::String(input)) => {
+                Ok(Some((input.clone(), parse_color(description, input)?)))
+            }
+            Some(_) => Err(format!("the {name} field has to be a string").into()),
+        }
+    };
+    let foreground = color("fg", "foreground color")?;
+    let background = color("bg", "background color")?;
+    if foreground.is_none() && background.is_none() {
+        return Err("nothing to update, send fg, bg or both".to_string().into());
+    }
+
+    session.foreground = foreground.or(session.foreground.take());
+    session.background = background.or(session.background.take());
+    Ok(())
+}
+
+/// Accepts the handshake of `/session/NAME` requests with the API key, if the server has one,
+/// and returns the name of the session.
+// The callback returns the error response of tungstenite, which is big
+#[allow(clippy::result_large_err)]
+fn handshake(stream: TcpStream, limits: &Limits) -> Result<(WebSocket<TcpStream>, String), Error> {
+    let refuse = |status: StatusCode, message: &str| {
+        let mut error = ErrorResponse::new(Some(message.to_string()));
+        *error.status_mut() = status;
+        error
+    };
+    let config = WebSocketConfig {
+        max_message_size: Some(MAX_MESSAGE),
+        max_frame_size: Some(MAX_MESSAGE),
+        ..WebSocketConfig::default()
+    };
+
+    let mut name = None;
+    let websocket = tungstenite::accept_hdr_with_config(
+        stream,
+        |request: &Request, response: Response| {
+            let headers = request
+                .headers()
+                .iter()
+                .filter_map(|(name, value)| Some((name.as_str(), value.to_str().ok()?)));
+            let query = query_key(&request.uri().to_string());
+            if !limits.authorized(header_key(headers).or(query.as_deref())) {
+                return Err(refuse(StatusCode::UNAUTHORIZED, "send the API key"));
+            }
+            match request.uri().path().strip_prefix("/session/") {
+                Some(session) if !session.is_empty() && session.len() <= MAX_NAME => {
+                    name = Some(session.to_string());
+                    Ok(response)
+                }
+                _ => Err(refuse(
+                    StatusCode::NOT_FOUND,
+                    &format!("connect to /session/NAME, names are at most {MAX_NAME} characters"),
+                )),
+            }
+        },
+        Some(config),
+    )
+    .map_err(|err| format!("handshake failed: {err}"))?;
+
+    // The callback only accepts the connection once it has set the name
+    Ok((websocket, name.unwrap_or_default()))
+}
+
+/// Runs one connection until the client leaves: messages of the client update the session, and
+/// the results of every update of the session are sent back.
+fn serve_client(stream: TcpStream, sessions: &Sessions, limits: &Limits) -> Result<(), Error> {
+    let client = stream.peer_addr().map_err(|err| err.to_string())?.ip();
+    let (mut websocket, name) = handshake(stream, limits)?;
+    websocket
+        .get_ref()
+        .set_read_timeout(Some(POLL_INTERVAL))
+        .map_err(|err| err.to_string())?;
+    log::debug!("client joined session {name}");
+
+    let (sender, updates): (Sender<String>, Receiver<String>) = mpsc::channel();
+    {
+        let mut sessions = sessions.lock().unwrap();
+        if !sessions.contains_key(&name) && sessions.len() >= MAX_SESSIONS {
+            // Sessions nobody follows anymore make room for new ones
+            sessions.retain(|_, session| session.followers > 0);
+            if sessions.len() >= MAX_SESSIONS {
+                return Err(format!("{MAX_SESSIONS} sessions are open already").into());
+            }
+        }
+        let session = sessions.entry(name.clone()).or_default();
+        session.clients.push(sender);
+        session.followers += 1;
+    }
+
+    let followed = follow(&mut websocket, &name, sessions, limits, client, &updates);
+    if let Some(session) = sessions.lock().unwrap().get_mut(&name) {
+        session.followers -= 1;
+    }
+    log::debug!("client left session {name}");
+    followed
+}
+
+/// Sends the result of the session to a client, then applies its messages and sends it the
+/// results of every update until it leaves.
+fn follow(
+    websocket: &mut WebSocket<TcpStream>,
+    name: &str,
+    sessions: &Sessions,
+    limits: &Limits,
+    client: IpAddr,
+    updates: &Receiver<String>,
+) -> Result<(), Error> {
+    // A client joining a session right away gets where the others are
+    let result = sessions.lock().unwrap()[name].result(name);
+    websocket
+        .send(Message::Text(result))
+        .map_err(|err| err.to_string())?;
+
+    loop {
+        match websocket.read() {
+            Ok(Message::Text(_)) if !limits.allow(client) => websocket
+                .send(Message::Text(
+                    r#"{"error":"too many updates, try again in a minute"}"#.to_string(),
+                ))
+                .map_err(|err| err.to_string())?,
+            Ok(Message::Text(message)) => {
+                let mut sessions = sessions.lock().unwrap();
+                let session = sessions.entry(name.to_string()).or_default();
+                match update(session, &message) {
+                    Ok(()) => {
+                        let result = session.result(name);
+                        session.broadcast(&result);
+                    }
+                    Err(err) => websocket
+                        .send(Message::Text(format!(
+                            r#"{{"error":"{}"}}"#,
+                            escape_json(&err.message)
+                        )))
+                        .map_err(|err| err.to_string())?,
+                }
+            }
+            Ok(Message::Close(_)) => break,
+            Ok(_) => {}
+            Err(tungstenite::Error::Io(err))
+                if matches!(err.kind(), ErrorKind::WouldBlock | ErrorKind::TimedOut) => {}
+            Err(tungstenite::Error::ConnectionClosed) => break,
+            Err(err) => return Err(err.to_string().into()),
+        }
+
+        for update in updates.try_iter() {
+            websocket
+                .send(Message::Text(update))
+                .map_err(|err| err.to_string())?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Listens for WebSocket clients on `address` in the background, with a thread per client.
+pub fn listen(address: &str, limits: Arc<Limits>) -> Result<(), Error> {
+    let listener = TcpListener::bind(address)
+        .map_err(|err| Error::io(format!("could not listen on {address}: {err}")))?;
+    let sessions = Sessions::default();
+
+    thread::spawn(move || {
+        for stream in listener.incoming().flatten() {
+            let sessions = Arc::clone(&sessions);
+            let limits = Arc::clone(&limits);
+            thread::spawn(move || {
+                if let Err(err) = serve_client(stream, &sessions, &limits) {
+                    log::warn!("WebSocket client: {err}");
+                }
+            });
+        }
+    });
+
+    Ok(())
+}
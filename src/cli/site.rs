@@ -0,0 +1,401 @@
+//! `site`, the color parameters of a static site theme: Hugo and Jekyll configurations and
+//! `mkdocs.yml`, in TOML or YAML.
+
+use std::path::PathBuf;
+use std::process::ExitCode;
+
+use clap::Args;
+use contrast_checker::audit::{Audit, PairInput, ResolvedPair};
+use contrast_checker::{Color, ContrastAlgorithm};
+use toml::Value;
+use yaml_rust2::{Yaml, YamlLoader};
+
+use super::batch::{write_reports, BatchFormat};
+use super::config::Config;
+use super::{csv_dialect, parse_color, read_input, Algorithm, Error, Rounding, Style};
+
+#[derive(Args)]
+pub struct SiteArgs {
+    /// The configuration of the site, like hugo.toml, _config.yml or mkdocs.yml. "-" reads from
+    /// stdin
+    input: PathBuf,
+
+    /// Background for the text colors whose theme sets none
+    #[arg(long)]
+    background: Option<String>,
+
+    /// Contrast every pair has to reach, 4.5:1 (AA for normal text) or the default of the
+    /// algorithm
+    #[arg(long)]
+    ratio: Option<f32>,
+
+    /// How contrast is measured, the WCAG 2 verdicts are reported either way
+    #[arg(long, value_enum, default_value_t = Algorithm::Wcag2)]
+    algorithm: Algorithm,
+
+    /// Which ratio is compared against the thresholds
+    #[arg(long, value_enum, default_value_t = Rounding::Exact)]
+    rounding: Rounding,
+
+    /// How results are printed
+    #[arg(long, value_enum, default_value_t = BatchFormat::Table)]
+    format: BatchFormat,
+
+    /// How the table shows the verdicts
+    #[arg(long, value_enum, default_value_t = Style::Columns)]
+    style: Style,
+
+    /// Show the badges as [PASS] and [FAIL] instead of emoji
+    #[arg(long)]
+    ascii: bool,
+
+    /// With --format csv, separate the fields with ";" and write decimals with a comma, for
+    /// spreadsheets in locales like German or French
+    #[arg(long)]
+    decimal_comma: bool,
+}
+
+/// A string parameter of the configuration, by its path like `params.colors.text`.
+struct Param {
+    path: Vec<String>,
+    value: String,
+}
+
+impl Param {
+    fn name(&self) -> String {
+        self.path.join(".")
+    }
+}
+
+fn toml_params(value: &Value, path: &mut Vec<String>, params: &mut Vec<Param>) {
+    match value {
+        Value::Table(table) => {
+            for (key, value) in table {
+                path.push(key.clone());
+                toml_params(value, path, params);
+                path.pop();
+            }
+        }
+        Value::Array(array) => {
+            for (index, value) in array.iter().enumerate() {
+                path.push(index.to_string());
+                toml_params(value, path, params);
+                path.pop();
+            }
+        }
+        Value::String(value) => params.push(Param {
+            path: path.clone(),
+            value: value.clone(),
+        }),
+        _ => {}
+    }
+}
+
+fn yaml_params(value: &Yaml, path: &mut Vec<String>, params: &mut Vec<Param>) {
+    match value {
+        Yaml::Hash(hash) => {
+            for (key, value) in hash {
+                let key = match key {
+                    Yaml::String(key) | Yaml::Real(key) => key.clone(),
+                    Yaml::Integer(key) => key.to_string(),
+                    _ => continue,
+                };
+                path.push(key);
+                yaml_params(value, path, params);
+                path.pop();
+            }
+        }
+        Yaml::Array(array) => {
+            for (index, value) in array.iter().enumerate() {
+                path.push(index.to_string());
+                yaml_params(value, path, params);
+                path.pop();
+            }
+        }
+        Yaml::String(value) => params.push(Param {
+            path: path.clone(),
+            value: value.clone(),
+        }),
+        _ => {}
+    }
+}
+
+/// The string parameters of a configuration, TOML for .toml files and YAML for the others. Hex
+/// colors have to be quoted in YAML, `#` starts a comment.
+fn params(args: &SiteArgs, contents: &str) -> Result<Vec<Param>, Error> {
+    let mut params = Vec::new();
+    let toml = args
+        .input
+        .extension()
+        .is_some_and(|extension| extension.eq_ignore_ascii_case("toml"));
+    if toml {
+        let value: Value = contents
+            .parse()
+            .map_err(|err| format!("invalid TOML, {err}"))?;
+        toml_params(&value, &mut Vec::new(), &mut params);
+    } else {
+        let documents =
+            YamlLoader::load_from_str(contents).map_err(|err| format!("invalid YAML, {err}"))?;
+        for document in &documents {
+            yaml_params(document, &mut Vec::new(), &mut params);
+        }
+    }
+
+    Ok(params)
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Role {
+    Text,
+    Background,
+}
+
+/// Words of parameter names that mean background, the longest first so `bgcolor` isn't read as
+/// `bg` and a `color` stem.
+const BACKGROUNDS: [&str; 5] = ["backgroundcolor", "background", "bgcolor", "surface", "bg"];
+
+/// Words of parameter names that mean a color drawn on the background, like `text_color`,
+/// `linkColor` or a plain `link`.
+const TEXTS: [&str; 8] = [
+    "textcolor",
+    "foreground",
+    "fontcolor",
+    "fgcolor",
+    "color",
+    "text",
+    "link",
+    "heading",
+];
+
+/// Colors that aren't read on the background, like borders and the `theme_color` browsers paint
+/// their toolbar with.
+const IGNORED: [&str; 5] = ["border", "shadow", "outline", "theme", "selection"];
+
+/// The role of a parameter from its name, with what's left of the name once the role word is
+/// removed: `headerTextColor` is the text of `header`. Case and separators don't matter.
+fn role(key: &str) -> Option<(Role, String)> {
+    let key: String = key
+        .chars()
+        .filter(char::is_ascii_alphanumeric)
+        .map(|c| c.to_ascii_lowercase())
+        .collect();
+    if IGNORED.iter().any(|word| key.contains(word)) {
+        return None;
+    }
+
+    let stem = |word: &str| {
+        let start = key.find(word)?;
+        Some(format!("{}{}", &key[..start], &key[start + word.len()..]))
+    };
+    if let Some(stem) = BACKGROUNDS.iter().find_map(|word| stem(word)) {
+        return Some((Role::Background, stem));
+    }
+    TEXTS
+        .iter()
+        .find_map(|word| stem(word))
+        .map(|stem| (Role::Text, stem))
+}
+
+/// A color parameter with its role.
+struct ColorParam {
+    name: String,
+    /// The value as written.
+    value: String,
+    color: Color,
+    role: Role,
+    stem: String,
+    /// The path of the table the parameter is in.
+    parent: Vec<String>,
+    /// The parameter is part of a dark scheme, which has its own backgrounds.
+    dark: bool,
+}
+
+fn color_params(params: Vec<Param>) -> Vec<ColorParam> {
+    params
+        .into_iter()
+        .filter_map(|param| {
+            let (last, parent) = param.path.split_last()?;
+            let (role, stem) = role(last)?;
+            let color = contrast_checker::parse_color(param.value.trim()).ok()?;
+            let dark = param
+                .path
+                .iter()
+                .any(|segment| segment.to_ascii_lowercase().contains("dark"));
+            // The scheme is in the name of the table or of the parameter, a stem of `dark` is no
+            // element of the page
+            let stem = stem.replace("dark", "").replace("light", "");
+            Some(ColorParam {
+                name: param.name(),
+                value: param.value.trim().to_string(),
+                color,
+                role,
+                stem,
+                parent: parent.to_vec(),
+                dark,
+            })
+        })
+        .collect()
+}
+
+/// Material for MkDocs takes the names of its palette for `theme.palette.primary`, shades of
+/// Material Design, and draws the header with white or dark text on it.
+const MATERIAL_PRIMARIES: [(&str, &str, bool); 21] = [
+    ("red", "#ef5350", false),
+    ("pink", "#e91e63", false),
+    ("purple", "#ab47bc", false),
+    ("deep purple", "#7e57c2", false),
+    ("indigo", "#3f51b5", false),
+    ("blue", "#2196f3", false),
+    ("light blue", "#03a9f4", false),
+    ("cyan", "#00bcd4", false),
+    ("teal", "#009688", false),
+    ("green", "#4caf50", false),
+    ("light green", "#8bc34a", true),
+    ("lime", "#cddc39", true),
+    ("yellow", "#ffeb3b", true),
+    ("amber", "#ffc107", true),
+    ("orange", "#ff9800", true),
+    ("deep orange", "#ff7043", false),
+    ("brown", "#795548", false),
+    ("grey", "#757575", false),
+    ("blue grey", "#546e7a", false),
+    ("black", "#000000", false),
+    ("white", "#ffffff", true),
+];
+
+/// The header of every `theme.palette` scheme of Material for MkDocs that sets a primary color.
+fn material_pairs(params: &[Param]) -> Vec<ResolvedPair> {
+    params
+        .iter()
+        .filter(|param| {
+            param.path.first().is_some_and(|key| key == "theme")
+                && param.path.get(1).is_some_and(|key| key == "palette")
+                && param.path.last().is_some_and(|key| key == "primary")
+        })
+        .filter_map(|param| {
+            let name = param.value.trim().to_ascii_lowercase().replace('-', " ");
+            let (_, hex, light) = MATERIAL_PRIMARIES
+                .iter()
+                .find(|(known, ..)| *known == name)?;
+            // `--md-primary-bg-color`, the dark one is the default text color of the theme
+            let (text, text_name) = if *light {
+                ("#000000de", "header text (#000000de)")
+            } else {
+                ("#ffffff", "header text (#ffffff)")
+            };
+            Some(ResolvedPair {
+                input: PairInput {
+                    foreground: text_name.to_string(),
+                    background: format!("{} ({hex})", param.name()),
+                    line: None,
+                    metadata: Vec::new(),
+                },
+                foreground: contrast_checker::parse_color(text).ok()?,
+                background: contrast_checker::parse_color(hex).ok()?,
+            })
+        })
+        .collect()
+}
+
+/// The background a text color is read on: the one with the same stem in the same table, like
+/// `header_background` for `header_text`, else the plain background of the table, else the plain
+/// background of the same scheme anywhere in the configuration.
+fn background_of<'a>(text: &ColorParam, params: &'a [ColorParam]) -> Option<&'a ColorParam> {
+    let backgrounds: Vec<&ColorParam> = params
+        .iter()
+        .filter(|param| param.role == Role::Background && param.dark == text.dark)
+        .collect();
+    let sibling = |param: &&&ColorParam| param.parent == text.parent;
+
+    backgrounds
+        .iter()
+        .filter(sibling)
+        .find(|param| param.stem == text.stem)
+        .or_else(|| {
+            backgrounds
+                .iter()
+                .filter(sibling)
+                .find(|param| param.stem.is_empty())
+        })
+        .or_else(|| backgrounds.iter().find(|param| param.stem.is_empty()))
+        .copied()
+}
+
+/// The pairs of a theme: every text color of the configuration on its background, and the header
+/// of Material for MkDocs.
+fn pairs(
+    args: &SiteArgs,
+    params: Vec<Param>,
+    default_background: Option<&(String, Color)>,
+) -> Vec<ResolvedPair> {
+    let mut pairs = material_pairs(&params);
+    let colors = color_params(params);
+    let describe = |param: &ColorParam| format!("{} ({})", param.name, param.value);
+
+    for text in colors.iter().filter(|param| param.role == Role::Text) {
+        let background = match background_of(text, &colors) {
+            Some(background) => Some((describe(background), background.color)),
+            None => default_background.cloned(),
+        };
+        let Some((background_name, background)) = background else {
+            log::info!(
+                "skipping {}, there's no background for it, give one with --background",
+                text.name
+            );
+            continue;
+        };
+        pairs.push(ResolvedPair {
+            input: PairInput {
+                foreground: describe(text),
+                background: background_name,
+                line: None,
+                metadata: Vec::new(),
+            },
+            foreground: text.color,
+            background,
+        });
+    }
+    log::debug!("{} pairs in {}", pairs.len(), args.input.display());
+
+    pairs
+}
+
+pub fn run(args: &SiteArgs, config: &Config) -> Result<ExitCode, Error> {
+    let dialect = csv_dialect(args.decimal_comma, matches!(args.format, BatchFormat::Csv))?;
+    let default_background = args
+        .background
+        .as_deref()
+        .map(|input| parse_color("background color", input).map(|color| (input.to_string(), color)))
+        .transpose()?;
+    let contents = read_input(Some(&args.input))?;
+    let params = params(args, &contents)?;
+    let pairs = pairs(args, params, default_background.as_ref());
+    if pairs.is_empty() {
+        return Err(format!(
+            "{} has no text and background colors to check",
+            args.input.display()
+        )
+        .into());
+    }
+
+    let algorithm = args.algorithm.boxed();
+    let threshold = args.ratio.unwrap_or(algorithm.default_threshold());
+    let mut audit = Audit::new()
+        .algorithm(algorithm)
+        .threshold(threshold)
+        .rounding(args.rounding.into());
+    if let Some(severities) = &config.severities {
+        audit = audit.severities(severities.clone());
+    }
+    let report = audit.check(pairs);
+
+    let name = args.input.file_stem().map_or("site".to_string(), |stem| {
+        stem.to_string_lossy().into_owned()
+    });
+    write_reports(
+        &[(name, report)],
+        args.format,
+        args.style.table_style(args.ascii),
+        dialect,
+    )
+}
@@ -0,0 +1,86 @@
+use std::path::PathBuf;
+
+use clap::{Args, ValueEnum};
+use contrast_checker::{
+    contrast_order, contrast_ratio, luminance_order, relative_luminance, split_colors,
+};
+
+use super::config::Config;
+use super::{parse_color, read_input, Error};
+
+#[derive(Args)]
+pub struct SortArgs {
+    /// Colors to sort, in any of the supported notations
+    #[arg(required_unless_present_any = ["file", "palette"])]
+    colors: Vec<String>,
+
+    /// Read the colors from a file instead, separated by whitespace, commas or new lines. Use "-"
+    /// for stdin
+    #[arg(long, conflicts_with = "colors")]
+    file: Option<PathBuf>,
+
+    /// Use a palette of the configuration file, or a built-in one like tailwind, instead
+    #[arg(long, conflicts_with_all = ["colors", "file"])]
+    palette: Option<String>,
+
+    /// What the colors are sorted by
+    #[arg(long, value_enum, default_value_t = SortKey::Luminance)]
+    by: SortKey,
+
+    /// The color contrast is measured against when sorting by contrast
+    #[arg(long, default_value = "white")]
+    against: String,
+
+    /// Sort the other way
+    #[arg(long)]
+    reverse: bool,
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+enum SortKey {
+    /// Relative luminance, darkest first
+    Luminance,
+    /// Contrast ratio against --against, highest first
+    Contrast,
+}
+
+pub fn run(args: &SortArgs, config: &Config) -> Result<(), Error> {
+    let labels: Vec<String> = match (&args.file, &args.palette) {
+        (Some(path), _) => split_colors(&read_input(Some(path))?)
+            .into_iter()
+            .map(str::to_string)
+            .collect(),
+        (None, Some(name)) => config.palette(name)?,
+        (None, None) => args.colors.clone(),
+    };
+    let colors = labels
+        .iter()
+        .map(|input| parse_color("color", input))
+        .collect::<Result<Vec<_>, _>>()?;
+    let against = parse_color("color to sort against", &args.against)?;
+
+    let mut order = match args.by {
+        SortKey::Luminance => luminance_order(&colors),
+        SortKey::Contrast => contrast_order(&colors, &against),
+    };
+    if args.reverse {
+        order.reverse();
+    }
+
+    let width = labels
+        .iter()
+        .map(|label| label.chars().count())
+        .max()
+        .unwrap_or(0);
+    for index in order {
+        let (label, color) = (&labels[index], &colors[index]);
+        match args.by {
+            SortKey::Luminance => println!("{label:width$}  {:.3}", relative_luminance(color)),
+            SortKey::Contrast => {
+                println!("{label:width$}  {:>5.2}:1", contrast_ratio(color, &against))
+            }
+        }
+    }
+
+    Ok(())
+}
@@ -0,0 +1,133 @@
+use std::path::PathBuf;
+
+use clap::{Args, ValueEnum};
+use contrast_checker::audit::{Audit, Replacement};
+use contrast_checker::{contrast_ratio, suggest_foreground, ColorFormat};
+
+use super::config::Config;
+use super::{parse_color, read_input, replacements, Error, Notation};
+
+#[derive(Args)]
+pub struct SuggestArgs {
+    /// Foreground (text) color to adjust
+    #[arg(required_unless_present = "audit")]
+    foreground: Option<String>,
+
+    /// Background color the foreground has to contrast with
+    #[arg(required_unless_present = "audit")]
+    background: Option<String>,
+
+    /// Suggest a replacement for every failing foreground of a file of pairs instead, one pair per
+    /// line like batch reads, "-" for stdin. A color used in several pairs gets one replacement
+    /// that passes on all its backgrounds
+    #[arg(long, value_name = "FILE", conflicts_with_all = ["foreground", "background"])]
+    audit: Option<PathBuf>,
+
+    /// Contrast ratio to reach, 4.5 is AA for normal text
+    #[arg(long, default_value_t = 4.5)]
+    ratio: f32,
+
+    /// Notation of the suggested color, hex unless the configuration file says otherwise
+    #[arg(long, value_enum)]
+    notation: Option<Notation>,
+
+    /// How the replacements of --audit are printed
+    #[arg(long, value_enum, default_value_t = SuggestFormat::Text, requires = "audit")]
+    format: SuggestFormat,
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+enum SuggestFormat {
+    /// A line per original color with every way it's written and its replacement
+    Text,
+    /// A JSON object of every input to its replacement, like --export-fixes writes
+    Json,
+}
+
+fn print_replacements(replacements: &[Replacement], format: ColorFormat, ratio: f32) {
+    for replacement in replacements {
+        let backgrounds: Vec<String> = replacement
+            .backgrounds
+            .iter()
+            .map(|background| background.format_as(format))
+            .collect();
+        match replacement.replacement {
+            Some(color) => println!(
+                "{} -> {} (on {})",
+                replacement.inputs.join(", "),
+                color.format_as(format),
+                backgrounds.join(", ")
+            ),
+            None => println!(
+                "{}: no lightness reaches {ratio}:1 on {}",
+                replacement.inputs.join(", "),
+                backgrounds.join(", ")
+            ),
+        }
+    }
+
+    let replaced = replacements
+        .iter()
+        .filter(|replacement| replacement.replacement.is_some())
+        .count();
+    match replacements.len() {
+        0 => println!("every pair reaches {ratio}:1, there's nothing to replace"),
+        failing => println!("{replaced} of {failing} failing colors have a replacement"),
+    }
+}
+
+fn run_audit(args: &SuggestArgs, config: &Config) -> Result<(), Error> {
+    let contents = read_input(args.audit.as_ref())?;
+    let audit = Audit::new()
+        .threshold(args.ratio)
+        .pairs_from_lines(&contents)
+        .map_err(|err| err.to_string())?;
+    let report = audit.run().map_err(|err| err.to_string())?;
+
+    let replacements = audit.replacements(&report);
+    match args.format {
+        SuggestFormat::Text => print_replacements(
+            &replacements,
+            config.color_format(args.notation),
+            args.ratio,
+        ),
+        SuggestFormat::Json => print!("{}", replacements::json(&replacements)),
+    }
+
+    Ok(())
+}
+
+pub fn run(args: &SuggestArgs, config: &Config) -> Result<(), Error> {
+    if args.audit.is_some() {
+        return run_audit(args, config);
+    }
+    // Clap requires both colors without --audit
+    let (Some(foreground_input), Some(background_input)) = (&args.foreground, &args.background)
+    else {
+        return Err("missing colors to suggest for".into());
+    };
+    let foreground = parse_color("foreground color", foreground_input)?;
+    let background = parse_color("background color", background_input)?;
+    let current_ratio = contrast_ratio(&foreground, &background);
+
+    let Some(suggestion) = suggest_foreground(&foreground, &background, args.ratio) else {
+        return Err(format!(
+            "no lightness of {foreground_input} reaches {}:1 against {background_input}",
+            args.ratio
+        )
+        .into());
+    };
+
+    println!(
+        "suggested foreground: {}",
+        suggestion
+            .color
+            .format_as(config.color_format(args.notation))
+    );
+    println!(
+        "contrast ratio: {:.2}:1 (was {current_ratio:.2}:1)",
+        suggestion.ratio
+    );
+
+    Ok(())
+}
@@ -0,0 +1,133 @@
+#[cfg(feature = "image")]
+use std::path::PathBuf;
+use std::process::ExitCode;
+
+use clap::Args;
+#[cfg(feature = "image")]
+use contrast_checker::overlay_contrast;
+use contrast_checker::{Color, RoundingPolicy, SurfaceStack};
+
+use super::config::Config;
+#[cfg(feature = "image")]
+use super::image::{parse_region, region_pixels, Region};
+use super::{parse_color, Error, Rounding};
+
+#[derive(Args)]
+pub struct SurfaceArgs {
+    /// Text color drawn on top of the stack, in any of the supported notations
+    text: String,
+
+    /// Bottom layer of the surface. Defaults to the background of the configuration file
+    #[arg(long)]
+    base: Option<String>,
+
+    /// Use the average of a PNG, JPEG or WebP image as the bottom layer, like a photo behind a
+    /// translucent card
+    #[cfg(feature = "image")]
+    #[arg(long, conflicts_with = "base")]
+    base_image: Option<PathBuf>,
+
+    /// Only average the part of the image behind the surface, as x,y,width,height in pixels
+    #[cfg(feature = "image")]
+    #[arg(long, requires = "base_image", value_parser = parse_region)]
+    region: Option<Region>,
+
+    /// A layer drawn over the ones before it, bottom first. The opacity after @ is a number from
+    /// 0 to 1 or a percentage and multiplies the alpha of the color, so white@8% and #ffffff14 are
+    /// the same layer
+    #[arg(long = "overlay", value_name = "COLOR[@OPACITY]")]
+    overlays: Vec<String>,
+
+    /// Contrast ratio the text has to reach on the flattened surface, 4.5 is AA for normal text
+    #[arg(long, default_value_t = 4.5)]
+    ratio: f32,
+
+    /// Which ratio is compared against the threshold
+    #[arg(long, value_enum, default_value_t = Rounding::Exact)]
+    rounding: Rounding,
+}
+
+/// Parses an overlay like `white@8%`, `black@0.12` or `#6200ee1f`.
+fn parse_overlay(input: &str) -> Result<Color, Error> {
+    let Some((color, opacity)) = input.rsplit_once('@') else {
+        return parse_color("overlay color", input);
+    };
+
+    let color = parse_color("overlay color", color)?;
+    let opacity = match opacity.trim().strip_suffix('%') {
+        Some(percentage) => percentage.trim().parse::<f32>().map(|value| value / 100.0),
+        None => opacity.trim().parse::<f32>(),
+    };
+    match opacity {
+        Ok(opacity) if (0.0..=1.0).contains(&opacity) => {
+            Ok(color.with_opacity(color.opacity() * opacity))
+        }
+        _ => Err(format!(
+            "invalid overlay {input:?}: the opacity after @ must be between 0 and 1, or 0% and 100%"
+        )
+        .into()),
+    }
+}
+
+fn base(args: &SurfaceArgs, config: &Config) -> Result<(String, Color), Error> {
+    #[cfg(feature = "image")]
+    if let Some(path) = &args.base_image {
+        let (_, pixels) = region_pixels(path, args.region)?;
+        // The text color doesn't matter for the average
+        let average = overlay_contrast(&Color::new(0, 0, 0), pixels)
+            .ok_or_else(|| format!("{} has no pixels", path.display()))?
+            .average;
+        return Ok((format!("average of {}", path.display()), average));
+    }
+
+    let input = args
+        .base
+        .as_deref()
+        .or(config.background.as_deref())
+        .ok_or("missing the base, pass --base or set a background in the configuration file")?;
+    Ok((input.to_string(), parse_color("base color", input)?))
+}
+
+pub fn run(args: &SurfaceArgs, config: &Config) -> Result<ExitCode, Error> {
+    let text = parse_color("text color", &args.text)?;
+    let (base_name, base) = base(args, config)?;
+    let stack = args
+        .overlays
+        .iter()
+        .try_fold(SurfaceStack::new(base), |stack, input| {
+            Ok::<_, Error>(stack.overlay(parse_overlay(input)?))
+        })?;
+    let rounding: RoundingPolicy = args.rounding.into();
+
+    let layers = stack.layers();
+    println!("base       {base_name}  -> {}", layers[0].to_hex());
+    for (index, (overlay, surface)) in stack.overlays.iter().zip(&layers[1..]).enumerate() {
+        println!(
+            "overlay {:<2} {} at {:.0}%  -> {}",
+            index + 1,
+            Color {
+                alpha: u8::MAX,
+                ..*overlay
+            }
+            .to_hex(),
+            overlay.opacity() * 100.0,
+            surface.to_hex()
+        );
+    }
+
+    let report = stack.check(&text);
+    println!(
+        "\ntext {} on {}: {:.2}:1",
+        args.text,
+        report.background.to_hex(),
+        report.ratio
+    );
+
+    Ok(if rounding.apply(report.ratio) >= args.ratio {
+        println!("passes {}:1", args.ratio);
+        ExitCode::SUCCESS
+    } else {
+        println!("fails {}:1", args.ratio);
+        ExitCode::FAILURE
+    })
+}
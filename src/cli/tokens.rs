@@ -0,0 +1,272 @@
+use std::path::PathBuf;
+use std::process::ExitCode;
+
+use clap::Args;
+use contrast_checker::audit::{Audit, PairInput, ResolvedPair};
+use contrast_checker::presets::ThemePreset;
+use contrast_checker::report::{threshold_label, BatchReport};
+use contrast_checker::tokens::{parse_tokens, ColorToken, TokenFile};
+use contrast_checker::{ContrastAlgorithm, RoundingPolicy, TextSize, WcagLevel};
+
+use super::batch::{inputs, sheet_name, write_reports, BatchFormat};
+use super::config::Config;
+use super::watch::{watch, Outcome};
+use super::{csv_dialect, read_input, replacements, Algorithm, Error, Rounding, Style};
+
+#[derive(Args)]
+pub struct TokensArgs {
+    /// Design tokens JSON files, in the W3C format or a plain object of names to colors, like one
+    /// per theme. Reads from stdin when missing or "-". Spreadsheets get a sheet for each
+    inputs: Vec<PathBuf>,
+
+    /// Contrast every pair has to reach, 4.5:1 (AA for normal text) or the default of the
+    /// algorithm
+    #[arg(long)]
+    ratio: Option<f32>,
+
+    /// How contrast is measured, the WCAG 2 verdicts are reported either way
+    #[arg(long, value_enum, default_value_t = Algorithm::Wcag2)]
+    algorithm: Algorithm,
+
+    /// Which ratio is compared against the thresholds
+    #[arg(long, value_enum, default_value_t = Rounding::Exact)]
+    rounding: Rounding,
+
+    /// Check every combination of tokens even when the file declares its pairs in $pairs
+    #[arg(long)]
+    all: bool,
+
+    /// Check the role pairs of a theme system instead, each against its own WCAG AA requirement:
+    /// material3, bootstrap or vscode-theme (the colors of a VS Code theme file)
+    #[arg(long, value_parser = parse_preset, conflicts_with_all = ["all", "ratio", "algorithm"])]
+    preset: Option<ThemePreset>,
+
+    /// Also check the pairs a Rhai script of audit rules checks, instead of every combination
+    /// when the file declares no $pairs
+    #[cfg(feature = "scripting")]
+    #[arg(long, value_name = "SCRIPT", conflicts_with_all = ["all", "preset"])]
+    rules: Option<PathBuf>,
+
+    /// How results are printed
+    #[arg(long, value_enum, default_value_t = BatchFormat::Table)]
+    format: BatchFormat,
+
+    /// How the table shows the verdicts
+    #[arg(long, value_enum, default_value_t = Style::Columns)]
+    style: Style,
+
+    /// Show the badges as [PASS] and [FAIL] instead of emoji
+    #[arg(long)]
+    ascii: bool,
+
+    /// With --format csv, separate the fields with ";" and write decimals with a comma, for
+    /// spreadsheets in locales like German or French
+    #[arg(long)]
+    decimal_comma: bool,
+
+    /// Also write a replacement for every failing token to this file, as CSS custom properties
+    /// when it ends in .css and as a JSON object of token names to new colors otherwise
+    #[arg(long, value_name = "FILE", conflicts_with = "watch")]
+    export_fixes: Option<PathBuf>,
+
+    /// Keep running and check the file again every time it changes, printing the pairs that
+    /// start or stop passing
+    #[arg(long, requires = "inputs", conflicts_with = "format")]
+    watch: bool,
+}
+
+fn parse_preset(input: &str) -> Result<ThemePreset, String> {
+    ThemePreset::from_name(input).ok_or_else(|| {
+        let names: Vec<&str> = ThemePreset::ALL
+            .iter()
+            .map(|preset| preset.name())
+            .collect();
+        format!("unknown preset {input}, expected {}", names.join(", "))
+    })
+}
+
+fn resolved_pair(foreground: &ColorToken, background: &ColorToken) -> ResolvedPair {
+    ResolvedPair {
+        input: PairInput {
+            foreground: foreground.name.clone(),
+            background: background.name.clone(),
+            line: None,
+            metadata: Vec::new(),
+        },
+        foreground: foreground.color,
+        background: background.color,
+    }
+}
+
+/// The pairs the rules check, after the ones the file declares in `$pairs`. The rules say which
+/// pairs matter, so the combinations checked when there are no `$pairs` are left out.
+#[cfg(feature = "scripting")]
+fn rule_pairs(
+    rules: &std::path::Path,
+    file: &contrast_checker::tokens::TokenFile,
+    pairs: Vec<ResolvedPair>,
+) -> Result<Vec<ResolvedPair>, Error> {
+    let mut pairs = if file.pairs.is_empty() {
+        Vec::new()
+    } else {
+        pairs
+    };
+    for (foreground, background) in super::rules::checked_pairs(rules, file)? {
+        // The rules can only check tokens of the file
+        if let Some((foreground, background)) = file.get(&foreground).zip(file.get(&background)) {
+            pairs.push(resolved_pair(foreground, background));
+        }
+    }
+
+    Ok(pairs)
+}
+
+/// Checks the role pairs of a preset. The report has the threshold of text, pairs that need less,
+/// like UI components, say so after their foreground and get the verdict of their own requirement.
+fn preset_audit(
+    args: &TokensArgs,
+    config: &Config,
+    file: &TokenFile,
+    preset: ThemePreset,
+) -> Result<(Audit<Box<dyn ContrastAlgorithm>>, BatchReport), Error> {
+    let names: Vec<&str> = file
+        .tokens
+        .iter()
+        .map(|token| token.name.as_str())
+        .collect();
+    let found = preset.find_pairs(&names);
+    if found.is_empty() {
+        return Err(format!("none of the tokens have the roles of {}", preset.name()).into());
+    }
+
+    let threshold = WcagLevel::AA.minimum_ratio(TextSize::Normal);
+    let pairs = found
+        .iter()
+        .map(|pair| {
+            let mut resolved =
+                resolved_pair(&file.tokens[pair.foreground], &file.tokens[pair.background]);
+            let required = pair.role.minimum_ratio();
+            if required != threshold {
+                resolved
+                    .input
+                    .foreground
+                    .push_str(&format!(" (needs {required}:1)"));
+            }
+            resolved
+        })
+        .collect();
+
+    let rounding: RoundingPolicy = args.rounding.into();
+    let mut audit = Audit::new()
+        .algorithm(Algorithm::Wcag2.boxed())
+        .threshold(threshold)
+        .rounding(rounding);
+    if let Some(severities) = &config.severities {
+        audit = audit.severities(severities.clone());
+    }
+    let mut report = audit.check(pairs);
+    for (result, pair) in report.results.iter_mut().zip(&found) {
+        result.meets_threshold = rounding.apply(result.contrast) >= pair.role.minimum_ratio();
+    }
+
+    Ok((audit, report))
+}
+
+fn audit(
+    args: &TokensArgs,
+    config: &Config,
+    contents: &str,
+) -> Result<(Audit<Box<dyn ContrastAlgorithm>>, BatchReport), Error> {
+    let file = parse_tokens(contents).map_err(|err| err.to_string())?;
+    if let Some(preset) = args.preset {
+        return preset_audit(args, config, &file, preset);
+    }
+
+    let pairs = if args.all {
+        file.combinations()
+    } else {
+        file.checked_pairs()
+    };
+    let pairs = pairs
+        .into_iter()
+        .map(|(foreground, background)| resolved_pair(foreground, background))
+        .collect();
+    #[cfg(feature = "scripting")]
+    let pairs = match &args.rules {
+        Some(rules) => rule_pairs(rules, &file, pairs)?,
+        None => pairs,
+    };
+
+    let algorithm = args.algorithm.boxed();
+    let threshold = args.ratio.unwrap_or(algorithm.default_threshold());
+    let mut audit = Audit::new()
+        .algorithm(algorithm)
+        .threshold(threshold)
+        .rounding(args.rounding.into());
+    if let Some(severities) = &config.severities {
+        audit = audit.severities(severities.clone());
+    }
+    let report = audit.check(pairs);
+    Ok((audit, report))
+}
+
+pub fn run(args: &TokensArgs, config: &Config) -> Result<ExitCode, Error> {
+    let dialect = csv_dialect(args.decimal_comma, matches!(args.format, BatchFormat::Csv))?;
+    if args.watch {
+        let [path] = &args.inputs[..] else {
+            return Err("--watch follows a single file".to_string().into());
+        };
+        let algorithm = args.algorithm.boxed();
+        let threshold = args.ratio.unwrap_or(algorithm.default_threshold());
+        let threshold = threshold_label(algorithm.name(), threshold);
+        return watch(path, &threshold, |contents| {
+            let (_, report) = audit(args, config, contents)?;
+            Ok(report
+                .results
+                .iter()
+                .map(|result| Outcome {
+                    key: format!("{} on {}", result.foreground_input, result.background_input),
+                    passes: result.meets_threshold,
+                    message: format!(
+                        "{} on {} is {}",
+                        result.foreground_input,
+                        result.background_input,
+                        threshold_label(
+                            &report.algorithm,
+                            RoundingPolicy::TwoDecimals.apply(result.contrast),
+                        )
+                    ),
+                })
+                .collect())
+        })
+        .map(|()| ExitCode::SUCCESS);
+    }
+
+    let inputs = inputs(&args.inputs);
+
+    let mut reports = Vec::new();
+    let mut fixes = Vec::new();
+    for input in &inputs {
+        let (audit, report) =
+            audit(args, config, &read_input(*input)?).map_err(|err| match input {
+                Some(path) if inputs.len() > 1 => {
+                    Error::new(err.status, format!("{}: {err}", path.display()))
+                }
+                _ => err,
+            })?;
+        if args.export_fixes.is_some() {
+            fixes.extend(audit.replacements(&report));
+        }
+        reports.push((sheet_name(*input), report));
+    }
+
+    if let (Some(path), Some((_, report))) = (&args.export_fixes, reports.first()) {
+        replacements::export(path, &fixes, &report.threshold_label())?;
+    }
+    write_reports(
+        &reports,
+        args.format,
+        args.style.table_style(args.ascii),
+        dialect,
+    )
+}
@@ -0,0 +1,132 @@
+//! `tray`, the quick-check for auditors: a global hotkey picks the text color under the pointer,
+//! pressing it again picks the background, and the ratio and grade are shown in a notification.
+//! The tray icon keeps the last result in its tooltip.
+
+use std::process;
+
+use clap::Args;
+use contrast_checker::{check_compliance, suggest_foreground, Color, TextSize, WcagLevel};
+use ksni::blocking::TrayMethods;
+use ksni::menu::StandardItem;
+use ksni::{MenuItem, ToolTip};
+use notify_rust::Notification;
+
+use super::check::grade;
+use super::screen::{Hotkey, Screen};
+use super::Error;
+
+#[derive(Args)]
+pub struct TrayArgs {
+    /// The hotkey that picks the color under the pointer, first the text and then the background
+    #[arg(long, default_value = "ctrl+alt+c")]
+    hotkey: String,
+}
+
+/// The tray icon, with the last result as its tooltip.
+struct QuickCheck {
+    last: String,
+}
+
+impl ksni::Tray for QuickCheck {
+    fn id(&self) -> String {
+        env!("CARGO_PKG_NAME").to_string()
+    }
+
+    fn title(&self) -> String {
+        "Contrast checker".to_string()
+    }
+
+    fn icon_name(&self) -> String {
+        "color-select".to_string()
+    }
+
+    fn tool_tip(&self) -> ToolTip {
+        ToolTip {
+            title: self.title(),
+            description: self.last.clone(),
+            ..Default::default()
+        }
+    }
+
+    fn menu(&self) -> Vec<MenuItem<Self>> {
+        vec![StandardItem {
+            label: "Quit".to_string(),
+            activate: Box::new(|_| process::exit(0)),
+            ..Default::default()
+        }
+        .into()]
+    }
+}
+
+/// Shows a notification, or warns when there's no notification server to show it.
+fn notify(summary: &str, body: &str) {
+    let shown = Notification::new()
+        .appname(env!("CARGO_PKG_NAME"))
+        .summary(summary)
+        .body(body)
+        .show();
+    if let Err(err) = shown {
+        log::warn!("could not show the notification: {err}");
+    }
+}
+
+/// The notification of a picked pair: the ratio and grade, and the closest AA foreground when it
+/// falls short of AA for normal text.
+fn result(foreground: &Color, background: &Color) -> (String, String) {
+    let compliance = check_compliance(foreground, background);
+    let summary = format!("{:.2}:1, {}", compliance.ratio, grade(&compliance));
+    let mut body = format!("{} on {}", foreground.to_hex(), background.to_hex());
+
+    if !compliance.aa_normal_text {
+        let target = WcagLevel::AA.minimum_ratio(TextSize::Normal);
+        if let Some(suggestion) = suggest_foreground(foreground, background, target) {
+            body.push_str(&format!(
+                ", {} reaches {:.2}:1",
+                suggestion.color.to_hex(),
+                suggestion.ratio
+            ));
+        }
+    }
+
+    (summary, body)
+}
+
+pub fn run(args: &TrayArgs) -> Result<(), Error> {
+    let hotkey = Hotkey::parse(&args.hotkey)?;
+    let screen = Screen::connect()?;
+    screen.grab(hotkey, &args.hotkey)?;
+
+    let waiting = format!("press {} on the text color", args.hotkey);
+    // The hotkey works without the icon, some desktops don't have a tray
+    let tray = match (QuickCheck {
+        last: waiting.clone(),
+    })
+    .spawn()
+    {
+        Ok(tray) => Some(tray),
+        Err(err) => {
+            log::warn!("no tray icon: {err}");
+            None
+        }
+    };
+    println!("{waiting}, then on the background");
+
+    loop {
+        screen.wait_for_hotkey()?;
+        let foreground = screen.color_under_pointer()?;
+        notify(
+            &format!("Text color {}", foreground.to_hex()),
+            &format!("press {} on the background", args.hotkey),
+        );
+
+        screen.wait_for_hotkey()?;
+        let background = screen.color_under_pointer()?;
+        let (summary, body) = result(&foreground, &background);
+        notify(&summary, &body);
+        println!("{summary} - {body}");
+
+        if let Some(tray) = &tray {
+            tray.update(|tray| tray.last = format!("{summary}\n{body}"));
+        }
+    }
+}
@@ -0,0 +1,208 @@
+//! The history `batch --record` appends to, and `trend`, which tells from it whether the audits
+//! of a project get better or worse. The history is a TSV file with a run per line, so it can be
+//! committed next to the project and read by other tools.
+
+use std::fs::{self, OpenOptions};
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::process::{Command, ExitCode};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use clap::Args;
+use contrast_checker::report::{tsv_field, BatchReport, ScoreWeights};
+
+use super::batch::score;
+use super::Error;
+
+/// Where `--record` and `trend` keep the history when they're given no file.
+pub const DEFAULT_HISTORY: &str = ".contrast-history.tsv";
+
+const HEADER: &str = "recorded\tcommit\tinputs\tpairs\tfailures\terrors\tscore";
+
+#[derive(Args)]
+pub struct TrendArgs {
+    /// The history written by batch --record
+    #[arg(default_value = DEFAULT_HISTORY)]
+    history: PathBuf,
+
+    /// Only compare the last runs
+    #[arg(long, value_name = "RUNS")]
+    last: Option<usize>,
+
+    /// Score the latest run can lose against the one before it without failing
+    #[arg(long, default_value_t = 0.0)]
+    tolerance: f32,
+}
+
+/// An audit of the history.
+struct Run {
+    /// Seconds since the Unix epoch.
+    recorded: u64,
+    commit: String,
+    inputs: String,
+    pairs: usize,
+    failures: usize,
+    errors: usize,
+    score: f32,
+}
+
+/// The commit the working tree is at, when it's a git repository.
+fn current_commit() -> Option<String> {
+    let output = Command::new("git")
+        .args(["rev-parse", "--short", "HEAD"])
+        .output()
+        .ok()?;
+    let commit = String::from_utf8(output.stdout).ok()?;
+    (output.status.success() && !commit.trim().is_empty()).then(|| commit.trim().to_string())
+}
+
+/// Appends a run with the reports of a batch to the history, writing the header when the file
+/// is new.
+pub fn record(
+    path: &Path,
+    reports: &[(String, BatchReport)],
+    weights: &ScoreWeights,
+) -> Result<(), Error> {
+    let recorded = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |time| time.as_secs());
+    let inputs: Vec<&str> = reports.iter().map(|(name, _)| name.as_str()).collect();
+    let count = |count: fn(&BatchReport) -> usize| -> usize {
+        reports.iter().map(|(_, report)| count(report)).sum()
+    };
+
+    let new = !path.exists();
+    let mut file = OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(path)
+        .map_err(|err| Error::io(format!("could not open {}: {err}", path.display())))?;
+    let mut line = String::new();
+    if new {
+        line.push_str(HEADER);
+        line.push('\n');
+    }
+    line.push_str(&format!(
+        "{recorded}\t{}\t{}\t{}\t{}\t{}\t{:.1}\n",
+        current_commit().unwrap_or_default(),
+        tsv_field(&inputs.join(" ")),
+        count(|report| report.results.len()),
+        count(BatchReport::failures),
+        count(BatchReport::errors),
+        score(reports, weights)
+    ));
+    file.write_all(line.as_bytes())
+        .map_err(|err| Error::io(format!("could not write to {}: {err}", path.display())))
+}
+
+fn parse_history(path: &Path, contents: &str) -> Result<Vec<Run>, Error> {
+    let mut runs = Vec::new();
+    for (index, line) in contents.lines().enumerate() {
+        if line.trim().is_empty() || line == HEADER {
+            continue;
+        }
+        let invalid = || format!("{}:{}: invalid run", path.display(), index + 1);
+        let fields: Vec<&str> = line.split('\t').collect();
+        let [recorded, commit, inputs, pairs, failures, errors, score] = fields[..] else {
+            return Err(invalid().into());
+        };
+        runs.push(Run {
+            recorded: recorded.parse().map_err(|_| invalid())?,
+            commit: commit.to_string(),
+            inputs: inputs.to_string(),
+            pairs: pairs.parse().map_err(|_| invalid())?,
+            failures: failures.parse().map_err(|_| invalid())?,
+            errors: errors.parse().map_err(|_| invalid())?,
+            score: score.parse().map_err(|_| invalid())?,
+        });
+    }
+
+    Ok(runs)
+}
+
+/// The day of a time, like `2026-10-14`. Days are counted from the epoch with the algorithm of
+/// Howard Hinnant's `civil_from_days`, the history only needs dates.
+pub fn date(seconds: u64) -> String {
+    let days = (seconds / 86_400) as i64 + 719_468;
+    let era = days.div_euclid(146_097);
+    let day_of_era = days.rem_euclid(146_097);
+    let year_of_era =
+        (day_of_era - day_of_era / 1460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let shifted_month = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * shifted_month + 2) / 5 + 1;
+    let month = if shifted_month < 10 {
+        shifted_month + 3
+    } else {
+        shifted_month - 9
+    };
+    let year = year_of_era + era * 400 + i64::from(month <= 2);
+
+    format!("{year:04}-{month:02}-{day:02}")
+}
+
+pub fn run(args: &TrendArgs) -> Result<ExitCode, Error> {
+    let contents = fs::read_to_string(&args.history).map_err(|err| {
+        Error::io(format!(
+            "could not read {}, record runs with batch --record: {err}",
+            args.history.display()
+        ))
+    })?;
+    let mut runs = parse_history(&args.history, &contents)?;
+    if let Some(last) = args.last {
+        runs.drain(..runs.len().saturating_sub(last));
+    }
+    let (Some(first), Some(latest)) = (runs.first(), runs.last()) else {
+        return Err(format!("{} has no runs", args.history.display()).into());
+    };
+
+    let mut previous: Option<&Run> = None;
+    for run in &runs {
+        let change = previous.map_or(String::new(), |previous| {
+            format!("  {:+.1}", run.score - previous.score)
+        });
+        println!(
+            "{}  {:7}  {:>5.1}{change:7}  {} of {} pairs fail, {} error{}  {}",
+            date(run.recorded),
+            if run.commit.is_empty() {
+                "-"
+            } else {
+                &run.commit
+            },
+            run.score,
+            run.failures,
+            run.pairs,
+            run.errors,
+            if run.errors == 1 { "" } else { "s" },
+            run.inputs
+        );
+        previous = Some(run);
+    }
+
+    let change = latest.score - first.score;
+    let direction = if change > 0.0 {
+        "improving"
+    } else if change < 0.0 {
+        "regressing"
+    } else {
+        "unchanged"
+    };
+    println!(
+        "\n{direction}: the score went from {:.1} to {:.1} ({change:+.1}) over {} runs",
+        first.score,
+        latest.score,
+        runs.len()
+    );
+
+    // The latest run is what a pipeline just recorded, it fails when it's worse than the last one
+    let before = runs.len().checked_sub(2).map(|index| &runs[index]);
+    if let Some(before) = before.filter(|before| latest.score < before.score - args.tolerance) {
+        println!(
+            "the latest run lost {:.1} points against the one before it",
+            before.score - latest.score
+        );
+        return Ok(ExitCode::FAILURE);
+    }
+
+    Ok(ExitCode::SUCCESS)
+}
@@ -0,0 +1,36 @@
+use log::{Level, LevelFilter, Log, Metadata, Record};
+
+/// Prints the log messages of the library to stderr, so stdout stays clean for scripts.
+struct StderrLogger;
+
+impl Log for StderrLogger {
+    fn enabled(&self, _: &Metadata) -> bool {
+        true
+    }
+
+    fn log(&self, record: &Record) {
+        let level = match record.level() {
+            Level::Error => "error",
+            Level::Warn => "warning",
+            Level::Info => "info",
+            Level::Debug => "debug",
+            Level::Trace => "trace",
+        };
+        eprintln!("{level}: {}", record.args());
+    }
+
+    fn flush(&self) {}
+}
+
+/// Shows parsing steps with one `--verbose` and every luminance and ratio computed with two.
+pub fn init(verbosity: u8) {
+    let level = match verbosity {
+        0 => return,
+        1 => LevelFilter::Debug,
+        _ => LevelFilter::Trace,
+    };
+
+    // Only fails when a logger is already set, which never happens in the binary
+    let _ = log::set_logger(&StderrLogger);
+    log::set_max_level(level);
+}
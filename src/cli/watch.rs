@@ -0,0 +1,118 @@
+//! `--watch` of the commands that audit a file: the audit is run again whenever the file changes
+//! and only what changed is printed.
+
+use std::collections::HashMap;
+use std::fs;
+use std::path::Path;
+use std::thread;
+use std::time::{Duration, SystemTime};
+
+use super::Error;
+
+/// How often the modification time of the file is checked. Polling keeps this free of
+/// dependencies and works on network drives, where change notifications often don't.
+const POLL_INTERVAL: Duration = Duration::from_millis(300);
+
+/// A checked pair of an audit.
+pub struct Outcome {
+    /// Identifies the pair between runs, like the selector of a rule. Repeated keys are matched
+    /// in the order they appear.
+    pub key: String,
+    pub passes: bool,
+    /// Describes the pair and its ratio, printed when it starts or stops passing.
+    pub message: String,
+}
+
+fn modified(path: &Path) -> Option<SystemTime> {
+    fs::metadata(path)
+        .and_then(|metadata| metadata.modified())
+        .ok()
+}
+
+/// Outcomes by key and repetition of the key.
+fn by_key(outcomes: &[Outcome]) -> HashMap<(&str, usize), bool> {
+    let mut seen: HashMap<&str, usize> = HashMap::new();
+    outcomes
+        .iter()
+        .map(|outcome| {
+            let repetition = seen.entry(&outcome.key).or_default();
+            *repetition += 1;
+            ((outcome.key.as_str(), *repetition), outcome.passes)
+        })
+        .collect()
+}
+
+fn print_summary(path: &Path, outcomes: &[Outcome], threshold: &str) {
+    let failures = outcomes.iter().filter(|outcome| !outcome.passes).count();
+    println!(
+        "{}: {failures} of {} pairs fail {threshold}",
+        path.display(),
+        outcomes.len()
+    );
+}
+
+/// Prints the pairs that fail now but didn't before, new ones included, and the ones that pass
+/// now but didn't before.
+fn print_changes(previous: &[Outcome], current: &[Outcome]) {
+    let previous = by_key(previous);
+    let mut seen: HashMap<&str, usize> = HashMap::new();
+
+    for outcome in current {
+        let repetition = seen.entry(&outcome.key).or_default();
+        *repetition += 1;
+        match (
+            previous.get(&(outcome.key.as_str(), *repetition)),
+            outcome.passes,
+        ) {
+            (Some(true) | None, false) => println!("  newly fails: {}", outcome.message),
+            (Some(false), true) => println!("  now passes:  {}", outcome.message),
+            _ => {}
+        }
+    }
+}
+
+/// Runs `audit` on the contents of `path` now and every time the file changes, until the process
+/// is interrupted. `threshold` is written in the summaries, like `4.5:1`. The first run prints
+/// the failing pairs, later ones only the changes. Errors, like a file that doesn't parse halfway
+/// through an edit, are printed and the watch goes on.
+pub fn watch(
+    path: &Path,
+    threshold: &str,
+    mut audit: impl FnMut(&str) -> Result<Vec<Outcome>, Error>,
+) -> Result<(), Error> {
+    if path.as_os_str() == "-" {
+        return Err("--watch needs a file, it can't watch stdin"
+            .to_string()
+            .into());
+    }
+
+    let mut previous: Option<Vec<Outcome>> = None;
+    let mut last_modified = None;
+
+    loop {
+        let current_modified = modified(path);
+        if current_modified.is_some() && current_modified != last_modified {
+            last_modified = current_modified;
+
+            let outcomes = fs::read_to_string(path)
+                .map_err(|err| Error::io(format!("could not read {}: {err}", path.display())))
+                .and_then(|contents| audit(&contents));
+            match outcomes {
+                Ok(outcomes) => {
+                    match &previous {
+                        Some(previous) => print_changes(previous, &outcomes),
+                        None => outcomes
+                            .iter()
+                            .filter(|outcome| !outcome.passes)
+                            .for_each(|outcome| println!("  fails: {}", outcome.message)),
+                    }
+                    print_summary(path, &outcomes, threshold);
+                    previous = Some(outcomes);
+                }
+                Err(err) => eprintln!("error: {err}"),
+            }
+        }
+
+        thread::sleep(POLL_INTERVAL);
+    }
+}
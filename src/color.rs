@@ -0,0 +1,425 @@
+use alloc::format;
+use alloc::string::String;
+use core::fmt;
+
+#[cfg(not(any(feature = "std", test)))]
+use crate::math::Float;
+
+/// Errors returned when a 2 digit hex value can't be converted into a channel value.
+#[derive(Debug)]
+pub enum HexToDecError {
+    RightDigitInvalid,
+    LeftDigitInvalid,
+    LeftDigitOutOfRange,
+    RightDigitOutOfRange,
+    InputLengthOutOfRange,
+}
+
+impl fmt::Display for HexToDecError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            HexToDecError::LeftDigitInvalid | HexToDecError::LeftDigitOutOfRange => {
+                write!(f, "the first digit isn't a hex digit")
+            }
+            HexToDecError::RightDigitInvalid | HexToDecError::RightDigitOutOfRange => {
+                write!(f, "the second digit isn't a hex digit")
+            }
+            HexToDecError::InputLengthOutOfRange => write!(f, "expected 2 hex digits"),
+        }
+    }
+}
+
+impl core::error::Error for HexToDecError {}
+
+/// Errors returned by [`Color::from_hex`].
+#[derive(Debug)]
+pub enum ColorFromHexError {
+    InputIsEmpty,
+    InputIsNotAscii,
+    InvalidInputLength,
+    /// One of the channels isn't made of hex digits, like `zz` in `#zz0000`.
+    InvalidChannel(HexToDecError),
+}
+
+impl From<HexToDecError> for ColorFromHexError {
+    fn from(err: HexToDecError) -> Self {
+        ColorFromHexError::InvalidChannel(err)
+    }
+}
+
+impl fmt::Display for ColorFromHexError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ColorFromHexError::InvalidChannel(_) => {
+                write!(f, "the color contains characters that aren't hex digits")
+            }
+            ColorFromHexError::InputIsEmpty => write!(f, "the color is empty"),
+            ColorFromHexError::InputIsNotAscii => {
+                write!(f, "the color contains non ASCII characters")
+            }
+            ColorFromHexError::InvalidInputLength => {
+                write!(
+                    f,
+                    "expected 3, 4, 6 or 8 hex digits with or without a starting #"
+                )
+            }
+        }
+    }
+}
+
+impl core::error::Error for ColorFromHexError {
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+        match self {
+            ColorFromHexError::InvalidChannel(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+/// This function expects a trimmed, 2 digit hex value without #
+fn hex_to_dec(hex: &str) -> Result<u8, HexToDecError> {
+    // Bytes rather than chars, a non ASCII byte is never a hex digit anyway
+    let [left, right] = hex.as_bytes() else {
+        return Err(HexToDecError::InputLengthOutOfRange);
+    };
+
+    let digit = |byte: u8| {
+        char::from(byte)
+            .to_digit(16)
+            .and_then(|x| u8::try_from(x).ok())
+    };
+    let left_value = digit(*left).ok_or(HexToDecError::LeftDigitInvalid)?;
+    let right_value = digit(*right).ok_or(HexToDecError::RightDigitInvalid)?;
+
+    // Both digits are below 16, so the value fits in an u8
+    Ok(left_value * 16 + right_value)
+}
+
+/// Rounds a channel between 0 and 255 to the nearest integer.
+fn channel_to_u8(value: f32) -> u8 {
+    value.round().clamp(0.0, 255.0) as u8
+}
+
+/// An 8-bit sRGB color, the channels and the alpha are stored the same way hex writes them, so
+/// colors can be compared and hashed. The alpha goes from 0 (fully transparent) to 255 (fully
+/// opaque), [`Color::opacity`] returns it between 0 and 1. [`Color::normalized`] gives the channels
+/// as floats for the math.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Color {
+    pub red: u8,
+    pub green: u8,
+    pub blue: u8,
+    pub alpha: u8,
+}
+
+/// Colors are serialized as hex strings, see [`Color::to_hex`], and can be deserialized from any
+/// notation [`Color::from_css`] accepts.
+#[cfg(feature = "serde")]
+impl serde::Serialize for Color {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&self.to_hex())
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Color {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let input = String::deserialize(deserializer)?;
+        Color::from_css(&input).map_err(serde::de::Error::custom)
+    }
+}
+
+impl fmt::Display for Color {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "(r: {}, g: {}, b: {}", self.red, self.green, self.blue)?;
+
+        if !self.is_opaque() {
+            write!(f, ", a: {}", self.opacity())?;
+        }
+
+        write!(f, ")")
+    }
+}
+
+impl Color {
+    /// Creates an opaque color from its 8-bit red, green and blue channels.
+    pub const fn new(r: u8, g: u8, b: u8) -> Color {
+        Color {
+            red: r,
+            green: g,
+            blue: b,
+            alpha: u8::MAX,
+        }
+    }
+
+    /// Creates a color from its 8-bit red, green and blue channels and an alpha between 0 and 1.
+    pub fn new_with_alpha(r: u8, g: u8, b: u8, alpha: f32) -> Color {
+        Color::new(r, g, b).with_opacity(alpha)
+    }
+
+    /// Creates an opaque color from channels between 0 and 255 that came out of some math, they
+    /// are rounded to the nearest integer and clamped.
+    pub fn from_f32_channels(red: f32, green: f32, blue: f32) -> Color {
+        Color::new(
+            channel_to_u8(red),
+            channel_to_u8(green),
+            channel_to_u8(blue),
+        )
+    }
+
+    /// Returns a copy of the color with an alpha between 0 and 1, e.g. `0.5` for half transparent.
+    pub fn with_opacity(self, opacity: f32) -> Color {
+        Color {
+            alpha: channel_to_u8(opacity * 255.0),
+            ..self
+        }
+    }
+
+    /// Returns the alpha between 0 (fully transparent) and 1 (fully opaque).
+    pub fn opacity(&self) -> f32 {
+        f32::from(self.alpha) / 255.0
+    }
+
+    pub fn is_opaque(&self) -> bool {
+        self.alpha == u8::MAX
+    }
+
+    /// This function expects an RGB value of 6 hex digits with or without a starting #.
+    /// The 3 digit CSS shorthand is also accepted, so `#08f` is the same as `#0088ff`. An alpha
+    /// channel can be added with 8 digits, or 4 for the shorthand, like `#00000080`.
+    pub fn from_hex(hex: &str) -> Result<Color, ColorFromHexError> {
+        if hex.is_empty() {
+            return Err(ColorFromHexError::InputIsEmpty);
+        }
+
+        if !hex.is_ascii() {
+            return Err(ColorFromHexError::InputIsNotAscii);
+        }
+
+        let lowercase = hex.to_lowercase();
+        let trimmed_input = lowercase.trim();
+        let trimmed_input = trimmed_input.strip_prefix('#').unwrap_or(trimmed_input);
+
+        // Only allow input like RGB, RGBA, RRGGBB or RRGGBBAA, with or without #
+        let expanded_input: String;
+        let trimmed_input = match trimmed_input.len() {
+            3 | 4 => {
+                expanded_input = trimmed_input.chars().flat_map(|c| [c, c]).collect();
+                expanded_input.as_str()
+            }
+            6 | 8 => trimmed_input,
+            _ => return Err(ColorFromHexError::InvalidInputLength),
+        };
+
+        let red_hex = &trimmed_input[0..2];
+        let green_hex = &trimmed_input[2..4];
+        let blue_hex = &trimmed_input[4..6];
+        let alpha = match trimmed_input.get(6..) {
+            Some(alpha_hex) if !alpha_hex.is_empty() => hex_to_dec(alpha_hex)?,
+            _ => u8::MAX,
+        };
+
+        let color = Color {
+            red: hex_to_dec(red_hex)?,
+            green: hex_to_dec(green_hex)?,
+            blue: hex_to_dec(blue_hex)?,
+            alpha,
+        };
+        log::debug!(
+            "hex {hex:?} split into red {red_hex}, green {green_hex}, blue {blue_hex}: {color}"
+        );
+
+        Ok(color)
+    }
+
+    /// Creates a color from its HSL representation. The hue is an angle in degrees, saturation and
+    /// lightness go from 0 to 1. Formula obtained from https://www.w3.org/TR/css-color-4/#hsl-to-rgb
+    pub fn from_hsl(hue: f32, saturation: f32, lightness: f32) -> Color {
+        let hue = hue.rem_euclid(360.0);
+        let saturation = saturation.clamp(0.0, 1.0);
+        let lightness = lightness.clamp(0.0, 1.0);
+
+        let chroma = saturation * lightness.min(1.0 - lightness);
+        let channel = |offset: f32| {
+            let k = (offset + hue / 30.0) % 12.0;
+            let value = lightness - chroma * (k - 3.0).min(9.0 - k).clamp(-1.0, 1.0);
+            value * 255.0
+        };
+
+        Color::from_f32_channels(channel(0.0), channel(8.0), channel(4.0))
+    }
+
+    /// Returns the HSL representation of the color as `(hue, saturation, lightness)`. The hue is an
+    /// angle in degrees between 0 and 360, saturation and lightness go from 0 to 1.
+    pub fn to_hsl(&self) -> (f32, f32, f32) {
+        let [red, green, blue] = self.normalized();
+        let max = red.max(green).max(blue);
+        let min = red.min(green).min(blue);
+        let lightness = (max + min) / 2.0;
+        let chroma = max - min;
+
+        // Grays don't have a hue or saturation
+        if chroma == 0.0 {
+            return (0.0, 0.0, lightness);
+        }
+
+        let saturation = chroma / (1.0 - (2.0 * lightness - 1.0).abs());
+        let hue = if max == red {
+            (green - blue) / chroma
+        } else if max == green {
+            (blue - red) / chroma + 2.0
+        } else {
+            (red - green) / chroma + 4.0
+        };
+
+        ((hue * 60.0).rem_euclid(360.0), saturation, lightness)
+    }
+
+    /// Formats the color as `#rrggbb`, or `#rrggbbaa` when it isn't opaque. This is the canonical
+    /// form of a color: two colors are equal exactly when their hex is the same.
+    pub fn to_hex(&self) -> String {
+        let hex = format!("#{:02x}{:02x}{:02x}", self.red, self.green, self.blue);
+
+        if self.is_opaque() {
+            hex
+        } else {
+            format!("{hex}{:02x}", self.alpha)
+        }
+    }
+
+    /// Returns the red, green and blue channels divided by 255, so they go from 0 to 1.
+    pub fn normalized(&self) -> [f32; 3] {
+        [self.red, self.green, self.blue].map(|channel| f32::from(channel) / 255f32)
+    }
+
+    /// Composites the color over `background` with the "source over" operator, which is what
+    /// browsers do when a translucent element is drawn on top of another one. The result is
+    /// opaque when the background is opaque.
+    /// Formula obtained from https://www.w3.org/TR/compositing-1/#simplealphacompositing
+    pub fn composite_over(&self, background: &Color) -> Color {
+        let (foreground_alpha, background_alpha) = (self.opacity(), background.opacity());
+        let alpha = foreground_alpha + background_alpha * (1.0 - foreground_alpha);
+
+        // Both colors are fully transparent, there's nothing to show
+        if alpha == 0.0 {
+            return Color::new_with_alpha(0, 0, 0, 0.0);
+        }
+
+        let channel = |foreground: u8, background_channel: u8| {
+            (f32::from(foreground) * foreground_alpha
+                + f32::from(background_channel) * background_alpha * (1.0 - foreground_alpha))
+                / alpha
+        };
+
+        Color::from_f32_channels(
+            channel(self.red, background.red),
+            channel(self.green, background.green),
+            channel(self.blue, background.blue),
+        )
+        .with_opacity(alpha)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn from_hex_parses_valid_input() {
+        let color = Color::from_hex("#F26CA7").unwrap();
+        assert_eq!((color.red, color.green, color.blue), (242, 108, 167));
+
+        let shorthand = Color::from_hex("08f").unwrap();
+        assert_eq!(
+            (shorthand.red, shorthand.green, shorthand.blue),
+            (0, 136, 255)
+        );
+    }
+
+    #[test]
+    fn from_hex_rejects_invalid_digits() {
+        for input in [
+            "#zzzzzz",
+            "#gg0000",
+            "#00gg00",
+            "#0000gg",
+            "#ff00ffzz",
+            "#12g",
+        ] {
+            assert!(
+                matches!(
+                    Color::from_hex(input),
+                    Err(ColorFromHexError::InvalidChannel(_))
+                ),
+                "{input} should be rejected"
+            );
+        }
+    }
+
+    #[test]
+    fn from_hex_reports_which_digit_is_invalid() {
+        assert!(matches!(
+            Color::from_hex("#g00000"),
+            Err(ColorFromHexError::InvalidChannel(
+                HexToDecError::LeftDigitInvalid
+            ))
+        ));
+        assert!(matches!(
+            Color::from_hex("#0g0000"),
+            Err(ColorFromHexError::InvalidChannel(
+                HexToDecError::RightDigitInvalid
+            ))
+        ));
+    }
+
+    #[test]
+    fn from_hex_rejects_invalid_lengths() {
+        for input in ["#", "#1", "#12", "#12345", "#1234567", "#123456789", "   "] {
+            assert!(
+                matches!(
+                    Color::from_hex(input),
+                    Err(ColorFromHexError::InvalidInputLength)
+                ),
+                "{input:?} should be rejected"
+            );
+        }
+    }
+
+    #[test]
+    fn from_hex_rejects_empty_and_non_ascii_input() {
+        assert!(matches!(
+            Color::from_hex(""),
+            Err(ColorFromHexError::InputIsEmpty)
+        ));
+        assert!(matches!(
+            Color::from_hex("#ffé"),
+            Err(ColorFromHexError::InputIsNotAscii)
+        ));
+    }
+
+    #[test]
+    fn errors_expose_their_source() {
+        use alloc::string::ToString;
+        use core::error::Error;
+
+        let err = Color::from_hex("#zz0000").unwrap_err();
+        assert!(err.source().is_some());
+        assert_eq!(
+            err.to_string(),
+            "the color contains characters that aren't hex digits"
+        );
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde_round_trips_through_hex() {
+        let color = Color::new_with_alpha(242, 108, 167, 0.8);
+        let json = serde_json::to_string(&color).unwrap();
+        assert_eq!(json, "\"#f26ca7cc\"");
+
+        let parsed: Color = serde_json::from_str(&json).unwrap();
+        assert_eq!(parsed, color);
+        assert!(serde_json::from_str::<Color>("\"rgb(0 0 0)\"").is_ok());
+        assert!(serde_json::from_str::<Color>("\"#zzz\"").is_err());
+    }
+}
@@ -0,0 +1,166 @@
+use alloc::vec::Vec;
+
+use crate::{contrast_ratio, Color, ContrastContext, RoundingPolicy, SuccessCriterion, WcagLevel};
+
+/// What a color of a [`Component`] is used for, which decides the success criterion its contrast
+/// against the background is graded with.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ComponentRole {
+    /// The label, graded as text (SC 1.4.3 and 1.4.6).
+    Text,
+    /// The boundary that shows where the component is, graded as non-text contrast (SC 1.4.11).
+    Border,
+    /// An icon needed to understand the component, graded as non-text contrast (SC 1.4.11).
+    Icon,
+}
+
+impl ComponentRole {
+    pub fn name(self) -> &'static str {
+        match self {
+            ComponentRole::Text => "text",
+            ComponentRole::Border => "border",
+            ComponentRole::Icon => "icon",
+        }
+    }
+}
+
+/// The colors of a UI component, like a button or an input, checked together against its
+/// background with [`Component::check`]. Only the roles that are set are checked.
+///
+/// ```
+/// use contrast_checker::{Color, Component, ComponentRole, WcagLevel};
+///
+/// let button = Component::new(Color::new(255, 255, 255))
+///     .text(Color::new(0x59, 0x59, 0x59))
+///     .border(Color::new(0xcc, 0xcc, 0xcc));
+///
+/// let report = button.check(WcagLevel::AA);
+/// assert!(!report.passes());
+/// // The text reaches 4.5:1 but the border is below the 3:1 of non-text contrast
+/// let failing: Vec<_> = report.failures().map(|check| check.role).collect();
+/// assert_eq!(failing, [ComponentRole::Border]);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Component {
+    pub background: Color,
+    pub text: Option<Color>,
+    pub border: Option<Color>,
+    pub icon: Option<Color>,
+    /// Whether the text is at least 18pt, or 14pt and bold, which lowers its thresholds.
+    pub large_text: bool,
+    pub rounding: RoundingPolicy,
+}
+
+/// The contrast of one role of a component against its background.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RoleCheck {
+    pub role: ComponentRole,
+    pub color: Color,
+    pub ratio: f32,
+    pub required_ratio: f32,
+    /// The success criterion `required_ratio` comes from.
+    pub criterion: SuccessCriterion,
+    pub passes: bool,
+}
+
+/// The verdict of a whole component at a level, it passes when every role does.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ComponentReport {
+    pub level: WcagLevel,
+    pub checks: Vec<RoleCheck>,
+}
+
+impl ComponentReport {
+    pub fn passes(&self) -> bool {
+        self.checks.iter().all(|check| check.passes)
+    }
+
+    /// The roles that don't reach their threshold.
+    pub fn failures(&self) -> impl Iterator<Item = &RoleCheck> {
+        self.checks.iter().filter(|check| !check.passes)
+    }
+}
+
+impl Component {
+    /// A component without colors on `background`, comparing unrounded ratios.
+    pub fn new(background: Color) -> Component {
+        Component {
+            background,
+            text: None,
+            border: None,
+            icon: None,
+            large_text: false,
+            rounding: RoundingPolicy::Exact,
+        }
+    }
+
+    pub fn text(mut self, color: Color) -> Component {
+        self.text = Some(color);
+        self
+    }
+
+    pub fn border(mut self, color: Color) -> Component {
+        self.border = Some(color);
+        self
+    }
+
+    pub fn icon(mut self, color: Color) -> Component {
+        self.icon = Some(color);
+        self
+    }
+
+    pub fn large_text(mut self, large_text: bool) -> Component {
+        self.large_text = large_text;
+        self
+    }
+
+    pub fn rounding(mut self, rounding: RoundingPolicy) -> Component {
+        self.rounding = rounding;
+        self
+    }
+
+    /// Checks every role that's set against the background, with the criterion that applies to
+    /// it at `level`. Non-text contrast only exists at AA, so borders and icons need its 3:1 at
+    /// AAA too, AAA conformance includes AA.
+    pub fn check(&self, level: WcagLevel) -> ComponentReport {
+        let text_context = if self.large_text {
+            ContrastContext::LargeText
+        } else {
+            ContrastContext::Text
+        };
+        let roles = [
+            (ComponentRole::Text, self.text, text_context),
+            (ComponentRole::Border, self.border, ContrastContext::Ui),
+            (ComponentRole::Icon, self.icon, ContrastContext::Ui),
+        ];
+
+        let checks = roles
+            .into_iter()
+            .filter_map(|(role, color, context)| {
+                let color = color?;
+                let (required_ratio, criterion) =
+                    match (context.minimum_ratio(level), context.criterion(level)) {
+                        (Some(ratio), Some(criterion)) => (ratio, criterion),
+                        _ => (
+                            context.minimum_ratio(WcagLevel::AA)?,
+                            context.criterion(WcagLevel::AA)?,
+                        ),
+                    };
+                let ratio = contrast_ratio(&color, &self.background);
+
+                Some(RoleCheck {
+                    role,
+                    color,
+                    ratio,
+                    required_ratio,
+                    criterion,
+                    passes: self.rounding.apply(ratio) >= required_ratio,
+                })
+            })
+            .collect();
+
+        ComponentReport { level, checks }
+    }
+}
@@ -0,0 +1,486 @@
+use alloc::vec::Vec;
+
+#[cfg(feature = "rayon")]
+use rayon::prelude::*;
+
+use crate::const_contrast::{luminance, luminance_ratio, luminance_ratio_f64, LINEAR_CHANNELS};
+#[cfg(not(any(feature = "std", test)))]
+use crate::math::Float;
+use crate::{Color, ComplianceReport};
+
+/// Converts a normalized (divided by 255) sRGB component into linear light, which is the value
+/// used by the CIE XYZ color space. CIE XYZ is a device independent color space.
+/// Magical values come from the official sRGB spec. https://en.wikipedia.org/wiki/SRGB
+///
+/// Inputs outside of `0.0..=1.0` are clamped, so the result is always between 0 and 1.
+/// [`linear_to_srgb`] is the inverse of this function: for every 8-bit channel value `c`,
+/// `linear_to_srgb(srgb_to_linear(c / 255))` is within `1e-6` of `c / 255`, which is far below
+/// the `1 / 255` step between channel values.
+pub fn srgb_to_linear(normalized_component: f32) -> f32 {
+    let normalized_component = normalized_component.clamp(0.0, 1.0);
+
+    if normalized_component <= 0.04045 {
+        normalized_component / 12.92
+    } else {
+        let tmp: f32 = (normalized_component + 0.055) / 1.055;
+        tmp.powf(2.4f32)
+    }
+}
+
+/// Converts a linear light component back into a normalized sRGB component, multiply the result
+/// by 255 to get a channel value. Magical values come from the official sRGB spec.
+/// https://en.wikipedia.org/wiki/SRGB
+///
+/// Inputs outside of `0.0..=1.0` are clamped, so the result is always between 0 and 1.
+pub fn linear_to_srgb(linear_component: f32) -> f32 {
+    let linear_component = linear_component.clamp(0.0, 1.0);
+
+    if linear_component <= 0.0031308 {
+        linear_component * 12.92
+    } else {
+        1.055 * linear_component.powf(1.0 / 2.4) - 0.055
+    }
+}
+
+/// Formula to calculate relative luminance obtained from https://www.w3.org/TR/WCAG21/relative-luminance.html
+/// Magical values come from the official sRGB spec. https://en.wikipedia.org/wiki/SRGB
+///
+/// The result goes from 0 for the darkest black to 1 for the lightest white. Pure black and pure
+/// white always return exactly 0 and 1. The alpha channel is ignored, use [`contrast_ratio`] to
+/// take transparency into account.
+///
+/// Channels are 8-bit, so [`srgb_to_linear`] is read from a table of its 256 values instead of
+/// calling `powf` every time, which matters when comparing thousands of colors.
+///
+/// The math is done in `f64` and only the result is rounded to `f32`, ratios are computed from
+/// the `f64` luminance too so they don't pile up rounding errors.
+pub fn relative_luminance(color: &Color) -> f32 {
+    luminance([color.red, color.green, color.blue]) as f32
+}
+
+/// Colors converted at once by [`relative_luminance_batch`], small enough for the channels to stay
+/// in the L1 cache.
+const BATCH_SIZE: usize = 256;
+
+/// Relative luminance of every color of a palette, exactly the values [`relative_luminance`]
+/// returns one by one, for audits of tens of thousands of colors.
+///
+/// The colors are converted in batches stored as a structure of arrays: the linear values of
+/// every red, green and blue channel are looked up into three arrays first, so the weighted sum
+/// runs over contiguous `f64`s and the compiler turns it into SIMD instructions.
+///
+/// ```
+/// use contrast_checker::{relative_luminance, relative_luminance_batch, Color};
+///
+/// let colors = [Color::new(0, 0, 0), Color::new(0x77, 0x77, 0x77), Color::new(255, 255, 255)];
+/// let luminances = relative_luminance_batch(&colors);
+/// assert_eq!(luminances[1], relative_luminance(&colors[1]));
+/// assert_eq!(luminances[2], 1.0);
+/// ```
+pub fn relative_luminance_batch(colors: &[Color]) -> Vec<f32> {
+    let mut luminances = Vec::with_capacity(colors.len());
+    let mut red = [0.0f64; BATCH_SIZE];
+    let mut green = [0.0f64; BATCH_SIZE];
+    let mut blue = [0.0f64; BATCH_SIZE];
+
+    for batch in colors.chunks(BATCH_SIZE) {
+        for (index, color) in batch.iter().enumerate() {
+            red[index] = LINEAR_CHANNELS[usize::from(color.red)];
+            green[index] = LINEAR_CHANNELS[usize::from(color.green)];
+            blue[index] = LINEAR_CHANNELS[usize::from(color.blue)];
+        }
+
+        let count = batch.len();
+        luminances.extend(
+            red[..count]
+                .iter()
+                .zip(&green[..count])
+                .zip(&blue[..count])
+                // The same operations in the same order as luminance(), so the results match
+                .map(|((red, green), blue)| (0.2126 * red + 0.7152 * green + 0.0722 * blue) as f32),
+        );
+    }
+
+    luminances
+}
+
+/// Returns the colors that are actually seen on screen: the background composited over a white
+/// page, and the foreground composited over that background.
+pub(crate) fn visible_colors(foreground: &Color, background: &Color) -> (Color, Color) {
+    let is_translucent = !foreground.is_opaque() || !background.is_opaque();
+    let background = background.composite_over(&Color::new(255, 255, 255));
+    let foreground = foreground.composite_over(&background);
+    if is_translucent {
+        log::trace!("translucent colors composited into {foreground} on {background}");
+    }
+
+    (foreground, background)
+}
+
+/// Relative luminance of a color computed once, to compare it against many other colors without
+/// computing it again every time. Like [`relative_luminance`] it ignores the alpha channel, so it
+/// only gives the right ratio for opaque colors.
+///
+/// ```
+/// use contrast_checker::{contrast_ratio, Color, Luminance};
+///
+/// let (text, white) = (Color::new(0x77, 0x77, 0x77), Color::new(255, 255, 255));
+/// let white_luminance = Luminance::of(&white);
+///
+/// let ratio = Luminance::of(&text).contrast_ratio(white_luminance);
+/// assert_eq!(ratio, contrast_ratio(&text, &white));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+pub struct Luminance(f64);
+
+impl Luminance {
+    pub fn of(color: &Color) -> Luminance {
+        Luminance(luminance([color.red, color.green, color.blue]))
+    }
+
+    /// The relative luminance, from 0 for black to 1 for white.
+    pub fn value(self) -> f32 {
+        self.0 as f32
+    }
+
+    /// Same formula as [`contrast_ratio`], the order doesn't matter.
+    pub fn contrast_ratio(self, other: Luminance) -> f32 {
+        luminance_ratio(self.0, other.0)
+    }
+}
+
+/// Which of the two colors of a pair is lighter.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Polarity {
+    /// The foreground is darker than the background, or as light as it.
+    DarkOnLight,
+    LightOnDark,
+}
+
+/// Everything [`check`] computes on the way to the ratio of a pair, so tools that show the details
+/// don't have to compute them again.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ContrastReport {
+    /// The foreground as seen on screen, composited over the background when it's translucent.
+    pub foreground: Color,
+    /// The background as seen on screen, composited over white when it's translucent.
+    pub background: Color,
+    pub foreground_luminance: f32,
+    pub background_luminance: f32,
+    pub ratio: f32,
+    pub polarity: Polarity,
+}
+
+impl ContrastReport {
+    /// Grades the ratio of the pair, see [`ComplianceReport::from_ratio`].
+    pub fn compliance(&self) -> ComplianceReport {
+        ComplianceReport::from_ratio(self.ratio)
+    }
+}
+
+/// Computes the contrast of a pair along with the values it comes from, see [`contrast_ratio`]
+/// for how translucent colors are handled.
+///
+/// ```
+/// use contrast_checker::{check, Color, Polarity};
+///
+/// let report = check(&Color::new(0xf2, 0x6c, 0xa7), &Color::new(0, 0, 0));
+/// assert!((report.foreground_luminance - 0.3239).abs() < 1e-4);
+/// assert_eq!(report.background_luminance, 0.0);
+/// assert_eq!(report.polarity, Polarity::LightOnDark);
+/// assert!(report.compliance().aaa_normal_text);
+/// ```
+pub fn check(foreground: &Color, background: &Color) -> ContrastReport {
+    let (foreground, background) = visible_colors(foreground, background);
+    let foreground_luminance = Luminance::of(&foreground).0;
+    let background_luminance = Luminance::of(&background).0;
+
+    let ratio = luminance_ratio(foreground_luminance, background_luminance);
+    log::trace!(
+        "luminance {foreground_luminance} of {foreground} against {background_luminance} of \
+         {background}: {ratio}"
+    );
+
+    ContrastReport {
+        foreground,
+        background,
+        foreground_luminance: foreground_luminance as f32,
+        background_luminance: background_luminance as f32,
+        ratio,
+        polarity: if foreground_luminance <= background_luminance {
+            Polarity::DarkOnLight
+        } else {
+            Polarity::LightOnDark
+        },
+    }
+}
+
+/// Formula for contrast ratio obtained from https://www.w3.org/TR/WCAG21/#dfn-contrast-ratio
+///
+/// The order of the arguments doesn't matter for opaque colors, the result always goes from 1
+/// (no contrast) to 21. A translucent foreground is composited over the background first, so the
+/// ratio matches what users see. A translucent background is assumed to be drawn on a white page.
+/// [`check`] returns the luminances too.
+///
+/// The ratio is computed in `f64` and rounded down to an `f32`, so it's never above the real
+/// ratio and a pair a hair below 3, 4.5 or 7 doesn't get the verdict of the threshold. Use
+/// [`contrast_ratio_f64`] for the unrounded value.
+pub fn contrast_ratio(foreground: &Color, background: &Color) -> f32 {
+    check(foreground, background).ratio
+}
+
+/// [`contrast_ratio`] before it's rounded to an `f32`, for comparisons finer than 7 digits.
+///
+/// ```
+/// use contrast_checker::{contrast_ratio, contrast_ratio_f64, Color};
+///
+/// let (text, white) = (Color::new(0x76, 0x76, 0x76), Color::new(255, 255, 255));
+/// let ratio = contrast_ratio_f64(&text, &white);
+/// assert!(ratio > 4.54 && ratio < 4.55);
+/// assert!(f64::from(contrast_ratio(&text, &white)) <= ratio);
+/// ```
+pub fn contrast_ratio_f64(foreground: &Color, background: &Color) -> f64 {
+    let (foreground, background) = visible_colors(foreground, background);
+    luminance_ratio_f64(Luminance::of(&foreground).0, Luminance::of(&background).0)
+}
+
+/// The lowest contrast found by [`worst_case_contrast`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct WorstCase {
+    /// Position of the background that caused the lowest contrast.
+    pub index: usize,
+    pub ratio: f32,
+}
+
+/// Checks one foreground against several backgrounds, e.g. a text color used on different
+/// surfaces, and returns the background with the lowest contrast ratio.
+///
+/// Returns `None` when `backgrounds` is empty. If several backgrounds tie, the first one wins.
+pub fn worst_case_contrast(foreground: &Color, backgrounds: &[Color]) -> Option<WorstCase> {
+    backgrounds
+        .iter()
+        .enumerate()
+        .map(|(index, background)| WorstCase {
+            index,
+            ratio: contrast_ratio(foreground, background),
+        })
+        .fold(None, |worst: Option<WorstCase>, current| match worst {
+            Some(worst) if worst.ratio <= current.ratio => Some(worst),
+            _ => Some(current),
+        })
+}
+
+/// The candidate picked by [`best_text_color`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct BestTextColor {
+    /// Position of the candidate.
+    pub index: usize,
+    pub color: Color,
+    pub ratio: f32,
+}
+
+/// Picks the candidate with the highest contrast as text on `background`, like choosing between
+/// black and white text for a badge.
+///
+/// Returns `None` when `candidates` is empty. If several candidates tie, the first one wins.
+///
+/// ```
+/// use contrast_checker::{best_text_color, Color};
+///
+/// let badge = Color::new(0xf2, 0x6c, 0xa7);
+/// let best = best_text_color(&badge, &[Color::new(255, 255, 255), Color::new(0, 0, 0)]).unwrap();
+///
+/// assert_eq!(best.color, Color::new(0, 0, 0));
+/// assert_eq!(format!("{:.2}", best.ratio), "7.48");
+/// ```
+pub fn best_text_color(background: &Color, candidates: &[Color]) -> Option<BestTextColor> {
+    candidates
+        .iter()
+        .enumerate()
+        .map(|(index, candidate)| BestTextColor {
+            index,
+            color: *candidate,
+            ratio: contrast_ratio(candidate, background),
+        })
+        .fold(None, |best: Option<BestTextColor>, current| match best {
+            Some(best) if best.ratio >= current.ratio => Some(best),
+            _ => Some(current),
+        })
+}
+
+/// Picks a label color for each segment of a chart (bars, pie slices, ...), choosing the
+/// candidate with the highest contrast against the segment color. If several candidates tie, the
+/// first one wins.
+///
+/// ```
+/// use contrast_checker::{label_colors, Color};
+///
+/// let segments = [Color::new(33, 33, 122), Color::new(250, 220, 90)];
+/// let candidates = [Color::new(0, 0, 0), Color::new(255, 255, 255)];
+///
+/// let labels = label_colors(&segments, &candidates);
+/// assert!(std::ptr::eq(labels[0], &candidates[1]));
+/// assert!(std::ptr::eq(labels[1], &candidates[0]));
+/// ```
+///
+/// # Panics
+///
+/// Panics if `candidates` is empty.
+pub fn label_colors<'a>(segments: &[Color], candidates: &'a [Color]) -> Vec<&'a Color> {
+    assert!(!candidates.is_empty(), "at least one label color is needed");
+
+    segments
+        .iter()
+        .map(|segment| {
+            // We've checked there's at least one candidate
+            let best = best_text_color(segment, candidates).unwrap();
+            &candidates[best.index]
+        })
+        .collect()
+}
+
+/// The contrast of one of the foregrounds of [`contrast_ratios`] against the shared background.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ContrastResult {
+    /// Luminance of the foreground as seen on the background.
+    pub foreground_luminance: f32,
+    pub ratio: f32,
+    pub polarity: Polarity,
+}
+
+impl ContrastResult {
+    /// Grades the ratio, see [`ComplianceReport::from_ratio`].
+    pub fn compliance(&self) -> ComplianceReport {
+        ComplianceReport::from_ratio(self.ratio)
+    }
+}
+
+/// Checks many foregrounds against one background, like every text color of a theme on its
+/// surface. The background is composited and linearized once, so this is the fast path for
+/// tools with long lists of colors, and the results are the same as [`check`] gives for each
+/// pair, in the order of `foregrounds`.
+///
+/// ```
+/// use contrast_checker::{contrast_ratio, contrast_ratios, Color, Polarity};
+///
+/// let white = Color::new(255, 255, 255);
+/// let foregrounds = [
+///     Color::new(0x77, 0x77, 0x77),
+///     Color::new(0, 0, 0),
+///     Color::new_with_alpha(0, 0, 0, 0.5),
+/// ];
+///
+/// let results = contrast_ratios(&foregrounds, &white);
+/// assert_eq!(results[0].ratio, contrast_ratio(&foregrounds[0], &white));
+/// assert_eq!(results[1].polarity, Polarity::DarkOnLight);
+/// // Translucent foregrounds are seen over the background, like with contrast_ratio
+/// assert_eq!(results[2].ratio, contrast_ratio(&foregrounds[2], &white));
+/// ```
+pub fn contrast_ratios(foregrounds: &[Color], background: &Color) -> Vec<ContrastResult> {
+    let background = background.composite_over(&Color::new(255, 255, 255));
+    let background_luminance = Luminance::of(&background);
+
+    let mut results = Vec::with_capacity(foregrounds.len());
+    results.extend(foregrounds.iter().map(|foreground| {
+        let luminance = Luminance::of(&foreground.composite_over(&background));
+        ContrastResult {
+            foreground_luminance: luminance.value(),
+            ratio: luminance.contrast_ratio(background_luminance),
+            polarity: if luminance <= background_luminance {
+                Polarity::DarkOnLight
+            } else {
+                Polarity::LightOnDark
+            },
+        }
+    }));
+
+    results
+}
+
+/// Computes the contrast of every foreground against every background, `matrix[i][j]` being
+/// `foregrounds[i]` on `backgrounds[j]`, with the luminance of every color computed once. Unlike
+/// [`contrast_matrix`] the two lists can differ, like the text colors and the surfaces of a theme.
+/// With the `rayon` feature the rows are computed in parallel.
+///
+/// ```
+/// use contrast_checker::{contrast_ratio, pairwise_contrast, Color};
+///
+/// let texts = [Color::new(0x77, 0x77, 0x77), Color::new(0xf2, 0x6c, 0xa7)];
+/// let surfaces = [
+///     Color::new(255, 255, 255),
+///     Color::new(0x22, 0x22, 0x22),
+///     Color::new(0xee, 0xee, 0xee),
+/// ];
+///
+/// let matrix = pairwise_contrast(&texts, &surfaces);
+/// assert_eq!((matrix.len(), matrix[0].len()), (2, 3));
+/// assert_eq!(matrix[1][2], contrast_ratio(&texts[1], &surfaces[2]));
+/// ```
+pub fn pairwise_contrast(foregrounds: &[Color], backgrounds: &[Color]) -> Vec<Vec<f32>> {
+    let backgrounds: Vec<(Color, Luminance)> = backgrounds
+        .iter()
+        .map(|background| {
+            let background = background.composite_over(&Color::new(255, 255, 255));
+            (background, Luminance::of(&background))
+        })
+        .collect();
+
+    let row = |foreground: &Color| -> Vec<f32> {
+        // Only translucent foregrounds look different on every background
+        let opaque = foreground.is_opaque().then(|| Luminance::of(foreground));
+        backgrounds
+            .iter()
+            .map(|(background, background_luminance)| {
+                opaque
+                    .unwrap_or_else(|| Luminance::of(&foreground.composite_over(background)))
+                    .contrast_ratio(*background_luminance)
+            })
+            .collect()
+    };
+
+    #[cfg(feature = "rayon")]
+    return foregrounds.par_iter().map(row).collect();
+
+    #[cfg(not(feature = "rayon"))]
+    foregrounds.iter().map(row).collect()
+}
+
+/// Computes the contrast of every combination of colors in a palette. `matrix[i][j]` is the
+/// contrast of `colors[i]` used as foreground on `colors[j]` as background, so for opaque
+/// colors the matrix is symmetric and its diagonal is 1.
+///
+/// Opaque palettes compute the luminance of every color once, see [`Luminance`], so palettes of
+/// thousands of colors stay fast. With the `rayon` feature the rows are computed in parallel.
+pub fn contrast_matrix(colors: &[Color]) -> Vec<Vec<f32>> {
+    let opaque = colors.iter().all(Color::is_opaque);
+    let luminances: Vec<Luminance> = if opaque {
+        colors.iter().map(Luminance::of).collect()
+    } else {
+        Vec::new()
+    };
+
+    let row = |index: usize| -> Vec<f32> {
+        if opaque {
+            luminances
+                .iter()
+                .map(|background| luminances[index].contrast_ratio(*background))
+                .collect()
+        } else {
+            colors
+                .iter()
+                .map(|background| contrast_ratio(&colors[index], background))
+                .collect()
+        }
+    };
+
+    #[cfg(feature = "rayon")]
+    return (0..colors.len()).into_par_iter().map(row).collect();
+
+    #[cfg(not(feature = "rayon"))]
+    (0..colors.len()).map(row).collect()
+}
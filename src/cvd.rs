@@ -0,0 +1,140 @@
+use crate::contrast::visible_colors;
+use crate::{contrast_ratio, linear_to_srgb, srgb_to_linear, Color};
+
+/// Types of color vision deficiency (color blindness) that can be simulated. Each one is the
+/// complete loss of one kind of cone, which is the worst case of the more common anomalous
+/// trichromacies.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ColorVisionDeficiency {
+    /// No long wavelength (red) cones.
+    Protanopia,
+    /// No medium wavelength (green) cones.
+    Deuteranopia,
+    /// No short wavelength (blue) cones.
+    Tritanopia,
+}
+
+impl ColorVisionDeficiency {
+    pub const ALL: [ColorVisionDeficiency; 3] = [
+        ColorVisionDeficiency::Protanopia,
+        ColorVisionDeficiency::Deuteranopia,
+        ColorVisionDeficiency::Tritanopia,
+    ];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            ColorVisionDeficiency::Protanopia => "protanopia",
+            ColorVisionDeficiency::Deuteranopia => "deuteranopia",
+            ColorVisionDeficiency::Tritanopia => "tritanopia",
+        }
+    }
+
+    /// Matrices for linear RGB with severity 1, obtained from
+    /// https://www.inf.ufrgs.br/~oliveira/pubs_files/CVD_Simulation/CVD_Simulation.html
+    fn matrix(self) -> [[f32; 3]; 3] {
+        match self {
+            ColorVisionDeficiency::Protanopia => [
+                [0.152286, 1.052583, -0.204868],
+                [0.114503, 0.786281, 0.099216],
+                [-0.003882, -0.048116, 1.051998],
+            ],
+            ColorVisionDeficiency::Deuteranopia => [
+                [0.367322, 0.860646, -0.227968],
+                [0.280085, 0.672501, 0.047413],
+                [-0.011820, 0.042940, 0.968881],
+            ],
+            ColorVisionDeficiency::Tritanopia => [
+                [1.255528, -0.076749, -0.178779],
+                [-0.078411, 0.930809, 0.147602],
+                [0.004733, 0.691367, 0.303900],
+            ],
+        }
+    }
+}
+
+/// Returns how the color looks to someone with the given deficiency, using the model by Machado,
+/// Oliveira and Fernandes (2009). Alpha is kept as is.
+pub fn simulate_cvd(color: &Color, deficiency: ColorVisionDeficiency) -> Color {
+    let linear = color.normalized().map(srgb_to_linear);
+    let channel = |row: [f32; 3]| {
+        let value = row[0] * linear[0] + row[1] * linear[1] + row[2] * linear[2];
+        linear_to_srgb(value.clamp(0.0, 1.0)) * 255.0
+    };
+    let [red, green, blue] = deficiency.matrix();
+
+    Color {
+        alpha: color.alpha,
+        ..Color::from_f32_channels(channel(red), channel(green), channel(blue))
+    }
+}
+
+/// The contrast of a pair as seen with a color vision deficiency.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CvdContrast {
+    pub deficiency: ColorVisionDeficiency,
+    pub foreground: Color,
+    pub background: Color,
+    pub ratio: f32,
+}
+
+/// Simulates every [`ColorVisionDeficiency`] on a pair and recomputes its contrast. Translucent
+/// colors are composited before the simulation, since the deficiency applies to what's on screen.
+pub fn cvd_contrast(foreground: &Color, background: &Color) -> [CvdContrast; 3] {
+    let (foreground, background) = visible_colors(foreground, background);
+
+    ColorVisionDeficiency::ALL.map(|deficiency| {
+        let foreground = simulate_cvd(&foreground, deficiency);
+        let background = simulate_cvd(&background, deficiency);
+
+        CvdContrast {
+            deficiency,
+            ratio: contrast_ratio(&foreground, &background),
+            foreground,
+            background,
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn simulated(hex: &str, deficiency: ColorVisionDeficiency) -> String {
+        simulate_cvd(&Color::from_hex(hex).unwrap(), deficiency).to_hex()
+    }
+
+    /// Expected colors computed with the matrices of the paper, in linear sRGB with the channels
+    /// clamped before going back to sRGB.
+    #[test]
+    fn matches_the_machado_model() {
+        use ColorVisionDeficiency::*;
+
+        assert_eq!(simulated("#ff0000", Protanopia), "#6d5f00");
+        assert_eq!(simulated("#00ff00", Protanopia), "#ffe500");
+        assert_eq!(simulated("#f26ca7", Protanopia), "#7f8ba9");
+        assert_eq!(simulated("#ff0000", Deuteranopia), "#a39000");
+        assert_eq!(simulated("#00ff00", Deuteranopia), "#efd63a");
+        assert_eq!(simulated("#f26ca7", Deuteranopia), "#a3a3a4");
+        assert_eq!(simulated("#ff0000", Tritanopia), "#ff000f");
+        assert_eq!(simulated("#00ff00", Tritanopia), "#00f7d9");
+        assert_eq!(simulated("#f26ca7", Tritanopia), "#ff6483");
+    }
+
+    #[test]
+    fn grays_stay_the_same() {
+        for deficiency in ColorVisionDeficiency::ALL {
+            for row in deficiency.matrix() {
+                assert!((row.iter().sum::<f32>() - 1.0).abs() < 1e-5);
+            }
+            for hex in ["#000000", "#777777", "#ffffff"] {
+                assert_eq!(simulated(hex, deficiency), hex);
+            }
+        }
+
+        let translucent = Color::new_with_alpha(255, 0, 0, 0.5);
+        let simulated = simulate_cvd(&translucent, ColorVisionDeficiency::Protanopia);
+        assert_eq!(simulated.alpha, translucent.alpha);
+    }
+}
@@ -0,0 +1,233 @@
+use alloc::vec;
+use alloc::vec::Vec;
+
+use crate::const_contrast::LINEAR_CHANNELS;
+#[cfg(not(any(feature = "std", test)))]
+use crate::math::Float;
+use crate::Color;
+
+/// D65 white point, which is the one sRGB uses.
+const WHITE: [f64; 3] = [0.950_47, 1.0, 1.088_83];
+
+impl Color {
+    /// Returns the CIE L*a*b* representation of the color as `(lightness, a, b)` with a D65 white
+    /// point. Lightness goes from 0 to 100. Alpha is ignored, like [`relative_luminance`] does.
+    /// Formulas obtained from https://en.wikipedia.org/wiki/CIELAB_color_space
+    ///
+    /// [`relative_luminance`]: crate::relative_luminance
+    pub fn to_lab(&self) -> (f32, f32, f32) {
+        let (lightness, a, b) = lab(self);
+        (lightness as f32, a as f32, b as f32)
+    }
+}
+
+/// Same as [`Color::to_lab`] but keeping the precision, CIEDE2000 is sensitive to it.
+fn lab(color: &Color) -> (f64, f64, f64) {
+    let [red, green, blue] =
+        [color.red, color.green, color.blue].map(|channel| LINEAR_CHANNELS[usize::from(channel)]);
+
+    let xyz = [
+        0.412_456_4 * red + 0.357_576_1 * green + 0.180_437_5 * blue,
+        0.212_672_9 * red + 0.715_152_2 * green + 0.072_175 * blue,
+        0.019_333_9 * red + 0.119_192 * green + 0.950_304_1 * blue,
+    ];
+
+    let delta: f64 = 6.0 / 29.0;
+    let [x, y, z] = [0, 1, 2].map(|index| {
+        let t = xyz[index] / WHITE[index];
+        if t > delta.powi(3) {
+            t.cbrt()
+        } else {
+            t / (3.0 * delta.powi(2)) + 4.0 / 29.0
+        }
+    });
+
+    (116.0 * y - 16.0, 500.0 * (x - y), 200.0 * (y - z))
+}
+
+/// How different two colors look, based on their CIEDE2000 difference.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum DeltaEBand {
+    /// Below 1, the colors look the same.
+    Imperceptible,
+    /// From 1 to 2, the difference is only seen when looking closely.
+    Noticeable,
+    /// 2 or more, the difference is seen at a glance.
+    Distinct,
+}
+
+impl DeltaEBand {
+    /// Bands obtained from http://zschuessler.github.io/DeltaE/learn/
+    pub fn from_delta_e(delta_e: f32) -> DeltaEBand {
+        if delta_e < 1.0 {
+            DeltaEBand::Imperceptible
+        } else if delta_e < 2.0 {
+            DeltaEBand::Noticeable
+        } else {
+            DeltaEBand::Distinct
+        }
+    }
+
+    pub fn name(self) -> &'static str {
+        match self {
+            DeltaEBand::Imperceptible => "imperceptible",
+            DeltaEBand::Noticeable => "noticeable",
+            DeltaEBand::Distinct => "distinct",
+        }
+    }
+}
+
+/// Computes the CIEDE2000 color difference of two colors, 0 means identical and 1 is about the
+/// smallest difference people can see. Unlike the contrast ratio it also takes hue and chroma into
+/// account, so red and green with the same luminance are far apart. Alpha is ignored.
+///
+/// Formula obtained from https://hajim.rochester.edu/ece/sites/gsharma/ciede2000/ciede2000noteCRNA.pdf
+pub fn delta_e_2000(first: &Color, second: &Color) -> f32 {
+    ciede2000(lab(first), lab(second)) as f32
+}
+
+/// Computes the CIEDE2000 difference of every two colors of a palette, to feed its structure to
+/// clustering or multidimensional scaling. `matrix[i][j]` is the difference between `colors[i]`
+/// and `colors[j]`, so the matrix is symmetric and its diagonal is 0. Every color is converted to
+/// L*a*b* once.
+///
+/// ```
+/// use contrast_checker::{delta_e_2000, delta_e_matrix, Color};
+///
+/// let palette = [Color::new(0x76, 0x76, 0x76), Color::new(0xf2, 0x6c, 0xa7)];
+/// let matrix = delta_e_matrix(&palette);
+///
+/// assert_eq!(matrix[0][0], 0.0);
+/// assert_eq!(matrix[0][1], matrix[1][0]);
+/// assert_eq!(matrix[0][1], delta_e_2000(&palette[0], &palette[1]));
+/// ```
+pub fn delta_e_matrix(colors: &[Color]) -> Vec<Vec<f32>> {
+    let labs: Vec<(f64, f64, f64)> = colors.iter().map(lab).collect();
+    let mut matrix = vec![vec![0.0; colors.len()]; colors.len()];
+    for first in 0..labs.len() {
+        for second in first + 1..labs.len() {
+            let delta_e = ciede2000(labs[first], labs[second]) as f32;
+            matrix[first][second] = delta_e;
+            matrix[second][first] = delta_e;
+        }
+    }
+
+    matrix
+}
+
+fn ciede2000((l1, a1, b1): (f64, f64, f64), (l2, a2, b2): (f64, f64, f64)) -> f64 {
+    let c1 = a1.hypot(b1);
+    let c2 = a2.hypot(b2);
+    let c_mean = (c1 + c2) / 2.0;
+    let g = 0.5 * (1.0 - (c_mean.powi(7) / (c_mean.powi(7) + 25f64.powi(7))).sqrt());
+
+    let a1 = (1.0 + g) * a1;
+    let a2 = (1.0 + g) * a2;
+    let c1 = a1.hypot(b1);
+    let c2 = a2.hypot(b2);
+    let hue = |a: f64, b: f64| {
+        if a == 0.0 && b == 0.0 {
+            0.0
+        } else {
+            b.atan2(a).to_degrees().rem_euclid(360.0)
+        }
+    };
+    let h1 = hue(a1, b1);
+    let h2 = hue(a2, b2);
+
+    let delta_l = l2 - l1;
+    let delta_c = c2 - c1;
+    let delta_h = if c1 * c2 == 0.0 {
+        0.0
+    } else if (h2 - h1).abs() <= 180.0 {
+        h2 - h1
+    } else if h2 - h1 > 180.0 {
+        h2 - h1 - 360.0
+    } else {
+        h2 - h1 + 360.0
+    };
+    let delta_h = 2.0 * (c1 * c2).sqrt() * (delta_h / 2.0).to_radians().sin();
+
+    let l_mean = (l1 + l2) / 2.0;
+    let c_mean = (c1 + c2) / 2.0;
+    let h_mean = if c1 * c2 == 0.0 {
+        h1 + h2
+    } else if (h1 - h2).abs() <= 180.0 {
+        (h1 + h2) / 2.0
+    } else if h1 + h2 < 360.0 {
+        (h1 + h2 + 360.0) / 2.0
+    } else {
+        (h1 + h2 - 360.0) / 2.0
+    };
+
+    let t = 1.0 - 0.17 * (h_mean - 30.0).to_radians().cos()
+        + 0.24 * (2.0 * h_mean).to_radians().cos()
+        + 0.32 * (3.0 * h_mean + 6.0).to_radians().cos()
+        - 0.20 * (4.0 * h_mean - 63.0).to_radians().cos();
+    let delta_theta = 30.0 * (-((h_mean - 275.0) / 25.0).powi(2)).exp();
+    let r_c = 2.0 * (c_mean.powi(7) / (c_mean.powi(7) + 25f64.powi(7))).sqrt();
+    let s_l = 1.0 + 0.015 * (l_mean - 50.0).powi(2) / (20.0 + (l_mean - 50.0).powi(2)).sqrt();
+    let s_c = 1.0 + 0.045 * c_mean;
+    let s_h = 1.0 + 0.015 * c_mean * t;
+    let r_t = -(2.0 * delta_theta).to_radians().sin() * r_c;
+
+    let l_term = delta_l / s_l;
+    let c_term = delta_c / s_c;
+    let h_term = delta_h / s_h;
+
+    (l_term.powi(2) + c_term.powi(2) + h_term.powi(2) + r_t * c_term * h_term).sqrt()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    type Lab = (f64, f64, f64);
+
+    /// Pairs of the test data of Sharma, Wu and Dalal, obtained from
+    /// https://hajim.rochester.edu/ece/sites/gsharma/ciede2000/ciede2000noteCRNA.pdf
+    #[rustfmt::skip]
+    const SHARMA: [(Lab, Lab, f64); 20] = [
+        ((50.0, 2.6772, -79.7751), (50.0, 0.0, -82.7485), 2.0425),
+        ((50.0, 3.1571, -77.2803), (50.0, 0.0, -82.7485), 2.8615),
+        ((50.0, 2.8361, -74.0200), (50.0, 0.0, -82.7485), 3.4412),
+        ((50.0, -1.3802, -84.2814), (50.0, 0.0, -82.7485), 1.0000),
+        ((50.0, 0.0, 0.0), (50.0, -1.0, 2.0), 2.3669),
+        ((50.0, 2.4900, -0.0010), (50.0, -2.4900, 0.0009), 7.1792),
+        ((50.0, 2.4900, -0.0010), (50.0, -2.4900, 0.0010), 7.1792),
+        ((50.0, 2.4900, -0.0010), (50.0, -2.4900, 0.0011), 7.2195),
+        ((50.0, -0.0010, 2.4900), (50.0, 0.0009, -2.4900), 4.8045),
+        ((50.0, -0.0010, 2.4900), (50.0, 0.0011, -2.4900), 4.7461),
+        ((50.0, 2.5000, 0.0), (50.0, 0.0, -2.5000), 4.3065),
+        ((50.0, 2.5000, 0.0), (73.0, 25.0, -18.0), 27.1492),
+        ((50.0, 2.5000, 0.0), (61.0, -5.0, 29.0), 22.8977),
+        ((50.0, 2.5000, 0.0), (56.0, -27.0, -3.0), 31.9030),
+        ((50.0, 2.5000, 0.0), (58.0, 24.0, 15.0), 19.4535),
+        ((50.0, 2.5000, 0.0), (50.0, 3.1736, 0.5854), 1.0000),
+        ((60.2574, -34.0099, 36.2677), (60.4626, -34.1751, 39.4387), 1.2644),
+        ((63.0109, -31.0961, -5.8663), (62.8187, -29.7946, -4.0864), 1.2630),
+        ((35.0831, -44.1164, 3.7933), (35.0232, -40.0716, 1.5901), 1.8645),
+        ((22.7233, 20.0904, -46.6940), (23.0331, 14.9730, -42.5619), 2.0373),
+    ];
+
+    #[test]
+    fn matches_the_sharma_test_data() {
+        for (first, second, expected) in SHARMA {
+            // The expected differences are rounded to 4 decimals
+            let delta_e = ciede2000(first, second);
+            assert!(
+                (delta_e - expected).abs() < 1e-4,
+                "{first:?} and {second:?}: expected {expected}, got {delta_e}"
+            );
+            assert!((ciede2000(second, first) - delta_e).abs() < 1e-12);
+        }
+    }
+
+    #[test]
+    fn identical_colors_have_no_difference() {
+        let color = Color::new(0xf2, 0x6c, 0xa7);
+        assert_eq!(delta_e_2000(&color, &color), 0.0);
+        assert!(delta_e_2000(&Color::new(0, 0, 0), &Color::new(255, 255, 255)) > 99.0);
+    }
+}
@@ -0,0 +1,79 @@
+#[cfg(not(any(feature = "std", test)))]
+use crate::math::Float;
+use crate::oklab::{linear_to_color, linear_to_oklab};
+use crate::{srgb_to_linear, Color};
+
+/// How colors that sRGB can't show are brought into it. WCAG ratios are defined for sRGB, so
+/// wide gamut colors like vivid Display P3 reds have to be mapped before being checked.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum GamutMapping {
+    /// Reduce the OKLCH chroma until the color fits, keeping its lightness and hue. This is what
+    /// CSS does, and what keeps the luminance closest to the original.
+    #[default]
+    ReduceChroma,
+    /// Clamp every channel to the sRGB range, which is simpler but can shift lightness and hue.
+    Clip,
+}
+
+/// Converts linear Display P3 into linear sRGB, the result is outside of `0.0..=1.0` for colors
+/// sRGB can't show. Matrix obtained from https://www.w3.org/TR/css-color-4/#color-conversion-code
+/// by combining the P3 to XYZ and XYZ to sRGB matrices.
+fn p3_to_linear_srgb([red, green, blue]: [f32; 3]) -> [f32; 3] {
+    [
+        1.224_940_2 * red - 0.224_940_18 * green,
+        -0.042_056_955 * red + 1.042_056_9 * green,
+        -0.019_637_555 * red - 0.078_636_05 * green + 1.098_273_6 * blue,
+    ]
+}
+
+fn in_gamut(linear: [f32; 3]) -> bool {
+    linear
+        .iter()
+        .all(|channel| (-1e-5..=1.0 + 1e-5).contains(channel))
+}
+
+impl Color {
+    /// Creates an opaque color from Display P3 channels between 0 and 1, like the ones of CSS
+    /// `color(display-p3 1 0 0)`. Colors outside of sRGB have their chroma reduced, see
+    /// [`Color::from_display_p3_with`] to clip them instead.
+    ///
+    /// ```
+    /// use contrast_checker::Color;
+    ///
+    /// // The same color in both gamuts
+    /// assert_eq!(Color::from_display_p3(1.0, 1.0, 1.0), Color::new(255, 255, 255));
+    /// // Pure P3 red is more vivid than anything sRGB can show
+    /// assert!(!Color::display_p3_in_srgb(1.0, 0.0, 0.0));
+    /// ```
+    pub fn from_display_p3(red: f32, green: f32, blue: f32) -> Color {
+        Color::from_display_p3_with(red, green, blue, GamutMapping::default())
+    }
+
+    /// Same as [`Color::from_display_p3`] with a choice of how colors outside of sRGB are mapped.
+    pub fn from_display_p3_with(red: f32, green: f32, blue: f32, mapping: GamutMapping) -> Color {
+        // Display P3 uses the sRGB transfer function
+        let linear = p3_to_linear_srgb([red, green, blue].map(srgb_to_linear));
+        if in_gamut(linear) {
+            return linear_to_color(linear);
+        }
+
+        log::debug!("display-p3 ({red}, {green}, {blue}) is outside of sRGB, using {mapping:?}");
+        match mapping {
+            GamutMapping::Clip => linear_to_color(linear),
+            GamutMapping::ReduceChroma => {
+                let (lightness, a, b) = linear_to_oklab(linear);
+                Color::from_oklch(
+                    lightness,
+                    a.hypot(b),
+                    b.atan2(a).to_degrees().rem_euclid(360.0),
+                )
+            }
+        }
+    }
+
+    /// Whether sRGB can show the Display P3 color, so no mapping is needed to check it.
+    pub fn display_p3_in_srgb(red: f32, green: f32, blue: f32) -> bool {
+        in_gamut(p3_to_linear_srgb([red, green, blue].map(srgb_to_linear)))
+    }
+}
@@ -0,0 +1,113 @@
+use crate::contrast::visible_colors;
+#[cfg(not(any(feature = "std", test)))]
+use crate::math::Float;
+use crate::{contrast_ratio, linear_to_srgb, srgb_to_linear, Color};
+
+/// Channel multipliers of a full strength night light, about what a 2700K white point does to
+/// sRGB. Obtained from the blackbody tables of https://github.com/jonls/redshift
+const WARM_TINT: [f32; 3] = [1.0, 0.73, 0.42];
+
+/// How a display renders colors, to estimate the contrast of a pair in conditions other than the
+/// full brightness sRGB screen WCAG assumes, like a phone dimmed at night or a kiosk in the sun.
+/// The changes are applied in linear light, and the WCAG ratio of the result still has the 0.05
+/// flare of ambient light, so dimming lowers contrast.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DisplayProfile {
+    /// Share of the full brightness the display runs at, from 0 to 1.
+    pub brightness: f32,
+    /// Exponent applied to the linear light of every channel, above 1 for displays that crush
+    /// shadows at low brightness, like many OLED phones.
+    pub gamma: f32,
+    /// Strength of the night light warm tint, from 0 (off) to 1.
+    pub warmth: f32,
+    /// Share of the light coming off the screen that is reflected sunlight or room light, from 0
+    /// to 1. Glare adds the same light to every color and washes out contrast far more than the
+    /// 0.05 flare of WCAG. The model is a rough one, meant to check outdoor and automotive screens
+    /// with a safety margin rather than to predict what a given panel does.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub glare: f32,
+}
+
+impl Default for DisplayProfile {
+    fn default() -> Self {
+        DisplayProfile::STANDARD
+    }
+}
+
+impl DisplayProfile {
+    /// The display WCAG assumes, colors are left as they are.
+    pub const STANDARD: DisplayProfile = DisplayProfile {
+        brightness: 1.0,
+        gamma: 1.0,
+        warmth: 0.0,
+        glare: 0.0,
+    };
+    /// A laptop or phone at half brightness.
+    pub const DIMMED: DisplayProfile = DisplayProfile {
+        brightness: 0.5,
+        gamma: 1.0,
+        warmth: 0.0,
+        glare: 0.0,
+    };
+    /// A phone in bed: low brightness, night light on and the shadows of an OLED panel.
+    pub const NIGHT: DisplayProfile = DisplayProfile {
+        brightness: 0.2,
+        gamma: 1.1,
+        warmth: 0.6,
+        glare: 0.0,
+    };
+    /// A glossy screen at full brightness in daylight, where a fifth of the light is glare.
+    pub const SUNLIGHT: DisplayProfile = DisplayProfile {
+        brightness: 1.0,
+        gamma: 1.0,
+        warmth: 0.0,
+        glare: 0.2,
+    };
+
+    /// Returns the color as the display shows it. Alpha is kept as is, translucent colors are
+    /// better checked with [`DisplayProfile::contrast_ratio`], which composites them first.
+    ///
+    /// ```
+    /// use contrast_checker::{Color, DisplayProfile};
+    ///
+    /// let white = Color::new(255, 255, 255);
+    /// assert_eq!(DisplayProfile::STANDARD.apply(&white), white);
+    /// // A night light takes more blue than red out
+    /// let night_white = DisplayProfile::NIGHT.apply(&white);
+    /// assert!(night_white.blue < night_white.red);
+    /// ```
+    pub fn apply(&self, color: &Color) -> Color {
+        let linear = color.normalized().map(srgb_to_linear);
+        let channel = |index: usize| {
+            let tint = 1.0 - self.warmth.clamp(0.0, 1.0) * (1.0 - WARM_TINT[index]);
+            let value = linear[index].powf(self.gamma.max(0.0)) * self.brightness * tint;
+            let glare = self.glare.clamp(0.0, 1.0);
+            let value = value.clamp(0.0, 1.0) * (1.0 - glare) + glare;
+            linear_to_srgb(value) * 255.0
+        };
+
+        Color {
+            alpha: color.alpha,
+            ..Color::from_f32_channels(channel(0), channel(1), channel(2))
+        }
+    }
+
+    /// The contrast ratio of the pair as seen on the display. Translucent colors are composited
+    /// like [`contrast_ratio`] does before the display transforms them.
+    ///
+    /// ```
+    /// use contrast_checker::{contrast_ratio, Color, DisplayProfile};
+    ///
+    /// let (gray, white) = (Color::new(0x76, 0x76, 0x76), Color::new(255, 255, 255));
+    /// assert!(contrast_ratio(&gray, &white) >= 4.5);
+    /// assert!(DisplayProfile::NIGHT.contrast_ratio(&gray, &white) < 4.5);
+    /// // Even black on white falls below AA in the sun
+    /// let black = Color::new(0, 0, 0);
+    /// assert!(DisplayProfile::SUNLIGHT.contrast_ratio(&black, &white) < 4.5);
+    /// ```
+    pub fn contrast_ratio(&self, foreground: &Color, background: &Color) -> f32 {
+        let (foreground, background) = visible_colors(foreground, background);
+        contrast_ratio(&self.apply(&foreground), &self.apply(&background))
+    }
+}
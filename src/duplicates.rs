@@ -0,0 +1,172 @@
+use alloc::vec;
+use alloc::vec::Vec;
+
+use crate::{delta_e_matrix, Color};
+
+/// The CIEDE2000 difference below which [`near_duplicates`] groups colors by default. Below 2 the
+/// difference is only seen when looking closely, see [`crate::DeltaEBand`].
+pub const DUPLICATE_DELTA_E: f32 = 2.0;
+
+/// Colors of a palette that look almost the same, found by [`near_duplicates`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct DuplicateGroup {
+    /// Indexes of the colors in the palette, in order.
+    pub members: Vec<usize>,
+    /// Index of the color the others can be merged into, the one closest to all of them.
+    pub canonical: usize,
+    /// The largest CIEDE2000 difference between two colors of the group.
+    pub max_delta_e: f32,
+}
+
+/// Groups the colors of a palette that are less than `threshold` apart in CIEDE2000, like five
+/// slightly different grays, and suggests the one each group can be merged into. Every two colors
+/// of a group are closer than the threshold, so a ramp of similar steps isn't merged into one
+/// group. Groups are in the order of their first color, colors without a near duplicate are left
+/// out.
+///
+/// ```
+/// use contrast_checker::{near_duplicates, Color, DUPLICATE_DELTA_E};
+///
+/// let palette = [
+///     Color::new(0x76, 0x76, 0x76),
+///     Color::new(0xf2, 0x6c, 0xa7),
+///     Color::new(0x77, 0x77, 0x77),
+///     Color::new(0x78, 0x78, 0x78),
+/// ];
+/// let groups = near_duplicates(&palette, DUPLICATE_DELTA_E);
+///
+/// assert_eq!(groups.len(), 1);
+/// assert_eq!(groups[0].members, [0, 2, 3]);
+/// assert_eq!(groups[0].canonical, 2);
+/// ```
+pub fn near_duplicates(colors: &[Color], threshold: f32) -> Vec<DuplicateGroup> {
+    let count = colors.len();
+    let distances = delta_e_matrix(colors);
+
+    // Complete linkage: the distance of two clusters is the largest between their colors, so
+    // merging the closest ones until none is below the threshold never chains far colors together
+    let mut clusters: Vec<Option<Vec<usize>>> = (0..count).map(|index| Some(vec![index])).collect();
+    let mut linkage = distances.clone();
+    loop {
+        let mut closest: Option<(usize, usize, f32)> = None;
+        for first in 0..count {
+            if clusters[first].is_none() {
+                continue;
+            }
+            for second in first + 1..count {
+                let distance = linkage[first][second];
+                if clusters[second].is_some()
+                    && distance < threshold
+                    && closest.is_none_or(|(_, _, closest)| distance < closest)
+                {
+                    closest = Some((first, second, distance));
+                }
+            }
+        }
+        let Some((first, second, _)) = closest else {
+            break;
+        };
+
+        let merged = clusters[second].take().unwrap_or_default();
+        if let Some(cluster) = &mut clusters[first] {
+            cluster.extend(merged);
+        }
+        let merged_row: Vec<f32> = linkage[first]
+            .iter()
+            .zip(&linkage[second])
+            .map(|(first, second)| first.max(*second))
+            .collect();
+        for (row, distance) in linkage.iter_mut().zip(&merged_row) {
+            row[first] = *distance;
+        }
+        linkage[first] = merged_row;
+    }
+
+    let mut groups: Vec<DuplicateGroup> = clusters
+        .into_iter()
+        .flatten()
+        .filter(|members| members.len() > 1)
+        .map(|mut members| {
+            members.sort_unstable();
+            let spread = |member: usize| -> f32 {
+                members.iter().map(|other| distances[member][*other]).sum()
+            };
+            let canonical = members
+                .iter()
+                .copied()
+                .reduce(|best, member| {
+                    if spread(member) < spread(best) {
+                        member
+                    } else {
+                        best
+                    }
+                })
+                .unwrap_or_default();
+            let max_delta_e = members
+                .iter()
+                .flat_map(|first| members.iter().map(|second| distances[*first][*second]))
+                .fold(0.0, f32::max);
+
+            DuplicateGroup {
+                members,
+                canonical,
+                max_delta_e,
+            }
+        })
+        .collect();
+    groups.sort_by_key(|group| group.members[0]);
+
+    groups
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn gray(value: u8) -> Color {
+        Color::new(value, value, value)
+    }
+
+    #[test]
+    fn merges_grays_into_the_middle_one() {
+        let palette = [0x75, 0x76, 0x77, 0x78, 0x79].map(gray);
+        let groups = near_duplicates(&palette, DUPLICATE_DELTA_E);
+
+        assert_eq!(groups.len(), 1);
+        assert_eq!(groups[0].members, [0, 1, 2, 3, 4]);
+        assert_eq!(groups[0].canonical, 2);
+        assert!(groups[0].max_delta_e < DUPLICATE_DELTA_E);
+    }
+
+    #[test]
+    fn never_chains_colors_that_are_far_apart() {
+        // Every step is below the threshold but the ends are far apart
+        let ramp: Vec<Color> = (0..12).map(|step| gray(0x60 + step * 2)).collect();
+        let groups = near_duplicates(&ramp, DUPLICATE_DELTA_E);
+
+        assert!(groups.len() > 1);
+        for group in &groups {
+            assert!(group.max_delta_e < DUPLICATE_DELTA_E, "{group:?}");
+        }
+        let grouped: Vec<usize> = groups
+            .iter()
+            .flat_map(|group| group.members.clone())
+            .collect();
+        let mut sorted = grouped.clone();
+        sorted.sort_unstable();
+        sorted.dedup();
+        assert_eq!(grouped.len(), sorted.len());
+    }
+
+    #[test]
+    fn distinct_and_identical_colors() {
+        assert!(
+            near_duplicates(&[gray(0), gray(0xff), Color::new(0xf2, 0x6c, 0xa7)], 2.0).is_empty()
+        );
+        assert!(near_duplicates(&[], 2.0).is_empty());
+
+        let groups = near_duplicates(&[gray(0x33), gray(0x33)], 2.0);
+        assert_eq!(groups[0].members, [0, 1]);
+        assert_eq!((groups[0].canonical, groups[0].max_delta_e), (0, 0.0));
+    }
+}
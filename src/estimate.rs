@@ -0,0 +1,243 @@
+use alloc::vec;
+use alloc::vec::Vec;
+
+use crate::{contrast_ratio, relative_luminance, Color};
+
+/// Share of the pixels that aren't background a color needs to be taken as the text color, so
+/// the anti-aliased edges of the glyphs aren't.
+const MINIMUM_TEXT_SHARE: f32 = 0.05;
+
+/// The text and background colors estimated from the pixels of a piece of rendered text.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TextColors {
+    pub text: Color,
+    pub background: Color,
+    /// Share of the pixels, from 0 to 1, that aren't the background, roughly how much of the
+    /// sample the glyphs cover.
+    pub coverage: f32,
+    pub ratio: f32,
+}
+
+/// Colors within the same bucket only differ in the low 4 bits of each channel, which is how
+/// much JPEG noise and subpixel rendering move them.
+fn bucket(color: &Color) -> usize {
+    usize::from(color.red >> 4) << 8
+        | usize::from(color.green >> 4) << 4
+        | usize::from(color.blue >> 4)
+}
+
+/// The most common exact color of the pixels in a bucket.
+fn most_common_in(pixels: &[Color], bucket_index: usize) -> Color {
+    let mut colors: Vec<(Color, usize)> = Vec::new();
+    for pixel in pixels.iter().filter(|pixel| bucket(pixel) == bucket_index) {
+        match colors.iter_mut().find(|(color, _)| color == pixel) {
+            Some((_, count)) => *count += 1,
+            None => colors.push((*pixel, 1)),
+        }
+    }
+
+    colors
+        .into_iter()
+        .max_by_key(|&(_, count)| count)
+        .map_or(Color::new(0, 0, 0), |(color, _)| color)
+}
+
+/// Estimates the colors of text from the pixels of a screenshot or a photo of it, like the region
+/// under the pointer. The background is the most common color, and the text is the color with
+/// the most contrast against it among the ones covering at least 5% of the rest, so the blended
+/// edges of the glyphs are skipped. Translucent pixels are taken as they are. Returns `None` when
+/// there are no pixels or they are all the background.
+///
+/// ```
+/// use contrast_checker::{estimate_text_colors, Color};
+///
+/// let white = Color::new(255, 255, 255);
+/// let gray = Color::new(0x77, 0x77, 0x77);
+/// let edge = Color::new(0xbb, 0xbb, 0xbb);
+///
+/// let mut pixels = vec![white; 80];
+/// pixels.extend([gray; 15]);
+/// pixels.extend([edge; 5]);
+///
+/// let colors = estimate_text_colors(pixels).unwrap();
+/// assert_eq!(colors.background, white);
+/// assert_eq!(colors.text, gray);
+/// assert!((colors.coverage - 0.2).abs() < 0.001);
+/// ```
+pub fn estimate_text_colors(pixels: impl IntoIterator<Item = Color>) -> Option<TextColors> {
+    let pixels: Vec<Color> = pixels.into_iter().collect();
+    let mut counts = vec![0usize; 1 << 12];
+    for pixel in &pixels {
+        counts[bucket(pixel)] += 1;
+    }
+
+    let (background_bucket, &background_count) =
+        counts.iter().enumerate().max_by_key(|&(_, count)| count)?;
+    let rest = pixels.len() - background_count;
+    if rest == 0 {
+        return None;
+    }
+    let background = most_common_in(&pixels, background_bucket);
+
+    let minimum = ((rest as f32 * MINIMUM_TEXT_SHARE) as usize).max(1);
+    let text = counts
+        .iter()
+        .enumerate()
+        .filter(|&(index, &count)| index != background_bucket && count >= minimum)
+        .map(|(index, _)| most_common_in(&pixels, index))
+        .max_by(|a, b| contrast_ratio(a, &background).total_cmp(&contrast_ratio(b, &background)))?;
+
+    Some(TextColors {
+        text,
+        background,
+        coverage: rest as f32 / pixels.len() as f32,
+        ratio: contrast_ratio(&text, &background),
+    })
+}
+
+/// Side of the square cells an image is split into to find text, in pixels.
+const CELL: usize = 8;
+/// Contrast between neighboring pixels that makes an edge, low enough for faint text.
+const EDGE_RATIO: f32 = 1.25;
+/// Shares of edge pixels of a cell of text. Flat areas have fewer and noisy photos have more.
+const MIN_EDGE_SHARE: f32 = 0.08;
+const MAX_EDGE_SHARE: f32 = 0.8;
+/// Share of the edges a cell of text needs in each direction, since strokes of glyphs go both
+/// ways while the borders of boxes are straight lines.
+const MIN_DIRECTION_SHARE: f32 = 0.04;
+
+/// A part of an image that looks like text, with its colors.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TextRegion {
+    pub x: u32,
+    pub y: u32,
+    pub width: u32,
+    pub height: u32,
+    pub colors: TextColors,
+}
+
+/// Finds the parts of an image that look like text, like the labels of a screenshot, and
+/// estimates their colors with [`estimate_text_colors`]. `pixels` are the rows of an image
+/// `width` pixels wide.
+///
+/// The image is split into cells of 8x8 pixels, and cells where glyph strokes make between 8% and
+/// 80% of the pixels edges are taken as text, as long as there are edges between rows and between
+/// columns, which straight borders and lines don't have. Neighboring text cells are joined into
+/// regions, across gaps of one cell so the words of a line end up in one region. Regions of a
+/// single cell are skipped as noise. They are returned from the top left to the bottom right.
+///
+/// ```
+/// use contrast_checker::{detect_text_regions, Color};
+///
+/// // A white image 64 pixels wide with a pattern of dots standing in for gray text
+/// let (white, gray) = (Color::new(255, 255, 255), Color::new(0x77, 0x77, 0x77));
+/// let pixels: Vec<Color> = (0..64 * 32)
+///     .map(|index| {
+///         let (x, y) = (index % 64, index / 64);
+///         let stroke = (8..40).contains(&x) && (8..24).contains(&y) && x % 4 < 2 && y % 4 < 2;
+///         if stroke { gray } else { white }
+///     })
+///     .collect();
+///
+/// let regions = detect_text_regions(&pixels, 64);
+/// assert_eq!(regions.len(), 1);
+/// assert_eq!((regions[0].x, regions[0].y, regions[0].width, regions[0].height), (8, 8, 32, 16));
+/// assert_eq!(regions[0].colors.text, gray);
+/// assert_eq!(regions[0].colors.background, white);
+/// ```
+pub fn detect_text_regions(pixels: &[Color], width: u32) -> Vec<TextRegion> {
+    let width = width as usize;
+    if width == 0 {
+        return Vec::new();
+    }
+    let height = pixels.len() / width;
+    let luminances: Vec<f32> = pixels.iter().map(relative_luminance).collect();
+    let is_edge = |a: f32, b: f32| (a.max(b) + 0.05) / (a.min(b) + 0.05) >= EDGE_RATIO;
+
+    let (columns, rows) = (width.div_ceil(CELL), height.div_ceil(CELL));
+    // The pixels of every cell that are edges, and the ones with an edge to their right or below
+    let mut edges = vec![[0usize; 3]; columns * rows];
+    for y in 0..height {
+        for x in 0..width {
+            let luminance = luminances[y * width + x];
+            let right = x + 1 < width && is_edge(luminance, luminances[y * width + x + 1]);
+            let below = y + 1 < height && is_edge(luminance, luminances[(y + 1) * width + x]);
+            let cell = &mut edges[(y / CELL) * columns + x / CELL];
+            cell[0] += usize::from(right || below);
+            cell[1] += usize::from(right);
+            cell[2] += usize::from(below);
+        }
+    }
+
+    let cell_size = |column: usize, row: usize| {
+        let cell_width = CELL.min(width - column * CELL);
+        let cell_height = CELL.min(height - row * CELL);
+        cell_width * cell_height
+    };
+    let mut text: Vec<bool> = (0..columns * rows)
+        .map(|index| {
+            let size = cell_size(index % columns, index / columns) as f32;
+            let [all, right, below] = edges[index].map(|count| count as f32 / size);
+            (MIN_EDGE_SHARE..=MAX_EDGE_SHARE).contains(&all)
+                && right.min(below) >= MIN_DIRECTION_SHARE
+        })
+        .collect();
+
+    let mut regions = Vec::new();
+    for start in 0..text.len() {
+        if !text[start] {
+            continue;
+        }
+
+        // Flood fill of the text cells, reaching over a gap of one cell
+        let (mut left, mut top, mut right, mut bottom) = (columns, rows, 0, 0);
+        let mut cells = 0;
+        let mut pending = vec![start];
+        text[start] = false;
+        while let Some(index) = pending.pop() {
+            let (column, row) = (index % columns, index / columns);
+            (left, top) = (left.min(column), top.min(row));
+            (right, bottom) = (right.max(column), bottom.max(row));
+            cells += 1;
+
+            for (dx, dy) in [(-2, 0), (-1, 0), (1, 0), (2, 0), (0, -1), (0, 1)] {
+                let (Some(column), Some(row)) = (
+                    column
+                        .checked_add_signed(dx)
+                        .filter(|&column| column < columns),
+                    row.checked_add_signed(dy).filter(|&row| row < rows),
+                ) else {
+                    continue;
+                };
+                let neighbor = row * columns + column;
+                if text[neighbor] {
+                    text[neighbor] = false;
+                    pending.push(neighbor);
+                }
+            }
+        }
+        if cells < 2 {
+            continue;
+        }
+
+        let (x, y) = (left * CELL, top * CELL);
+        let region_width = ((right + 1) * CELL).min(width) - x;
+        let region_height = ((bottom + 1) * CELL).min(height) - y;
+        let region_pixels = (y..y + region_height)
+            .flat_map(|row| &pixels[row * width + x..row * width + x + region_width])
+            .copied();
+        if let Some(colors) = estimate_text_colors(region_pixels) {
+            regions.push(TextRegion {
+                x: x as u32,
+                y: y as u32,
+                width: region_width as u32,
+                height: region_height as u32,
+                colors,
+            });
+        }
+    }
+
+    regions
+}
@@ -0,0 +1,51 @@
+/// The statuses the command line tool exits with, so scripts and CI can tell a failed audit from
+/// input they got wrong or a file that couldn't be read. Clap exits with [`ExitStatus::Parse`] on
+/// invalid arguments too.
+///
+/// ```
+/// use contrast_checker::ExitStatus;
+///
+/// assert_eq!(ExitStatus::Threshold.code(), 1);
+/// assert_eq!(ExitStatus::from_code(4), Some(ExitStatus::Config));
+/// assert_eq!(ExitStatus::from_code(9), None);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[repr(u8)]
+pub enum ExitStatus {
+    /// Everything that was checked passes.
+    Pass = 0,
+    /// Some pair misses its threshold, or another check of the command fails.
+    Threshold = 1,
+    /// A color, a file or an argument is invalid.
+    Parse = 2,
+    /// A file or stream couldn't be read or written.
+    Io = 3,
+    /// The configuration file is invalid.
+    Config = 4,
+}
+
+impl ExitStatus {
+    pub fn code(self) -> u8 {
+        self as u8
+    }
+
+    /// The status of a code the tool exited with, `None` for codes it doesn't use.
+    pub fn from_code(code: u8) -> Option<ExitStatus> {
+        [
+            ExitStatus::Pass,
+            ExitStatus::Threshold,
+            ExitStatus::Parse,
+            ExitStatus::Io,
+            ExitStatus::Config,
+        ]
+        .into_iter()
+        .find(|status| status.code() == code)
+    }
+}
+
+#[cfg(feature = "std")]
+impl From<ExitStatus> for std::process::ExitCode {
+    fn from(status: ExitStatus) -> Self {
+        std::process::ExitCode::from(status.code())
+    }
+}
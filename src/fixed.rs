@@ -0,0 +1,220 @@
+//! Luminance and contrast in integer math, for microcontrollers without an FPU where the float
+//! paths go through slow software floats. The sRGB transfer function is read from the same table
+//! as the `const fn` versions, rounded to integers at compile time, so nothing here touches a float
+//! at run time.
+//!
+//! Luminances are fixed-point numbers where [`LUMINANCE_ONE`] is 1, and ratios ones where
+//! [`RATIO_ONE`] is 1:1. Both are within a documented bound of the float results:
+//!
+//! ```
+//! use contrast_checker::fixed::{self, RATIO_ONE};
+//! use contrast_checker::{contrast_ratio, Color, TextSize, WcagLevel};
+//!
+//! let (gray, white) = ([0x77, 0x77, 0x77], [0xff, 0xff, 0xff]);
+//! let ratio = fixed::contrast_ratio(gray, white);
+//! let exact = contrast_ratio(&Color::new(0x77, 0x77, 0x77), &Color::new(0xff, 0xff, 0xff));
+//! assert!((ratio as f32 / RATIO_ONE as f32 - exact).abs() <= fixed::RATIO_ERROR);
+//!
+//! // 4.48:1 misses AA
+//! assert!(!fixed::passes(gray, white, WcagLevel::AA, TextSize::Normal));
+//! ```
+
+use crate::const_contrast::LINEAR_CHANNELS;
+use crate::{TextSize, WcagLevel};
+
+/// The fixed-point luminance of white, luminances have 24 fractional bits.
+pub const LUMINANCE_ONE: u32 = 1 << 24;
+
+/// The fixed-point ratio of 1:1, ratios have 16 fractional bits so 21:1 is `21 * RATIO_ONE`.
+pub const RATIO_ONE: u32 = 1 << 16;
+
+/// How far a luminance of [`relative_luminance`], divided by [`LUMINANCE_ONE`], can be from the
+/// exact one: half a unit from the table and half from the weighted sum, 2^-24.
+pub const LUMINANCE_ERROR: f32 = 1.0 / (1 << 24) as f32;
+
+/// How far a ratio of [`contrast_ratio`], divided by [`RATIO_ONE`], can be from the exact one,
+/// 2^-14 or about 0.00006. The error of the luminances is amplified up to 21 times 20 against
+/// black, that's at most 2.6e-5, and the division rounds to half a unit, 7.6e-6. Verdicts only
+/// differ from the float paths for ratios closer than that to a threshold.
+pub const RATIO_ERROR: f32 = 1.0 / (1 << 14) as f32;
+
+/// `srgb_to_linear(c / 255)` for every 8-bit channel value `c`, with 24 fractional bits.
+const LINEAR: [u32; 256] = {
+    let mut table = [0; 256];
+    let mut channel = 0;
+    while channel < 256 {
+        table[channel] = (LINEAR_CHANNELS[channel] * LUMINANCE_ONE as f64 + 0.5) as u32;
+        channel += 1;
+    }
+    table
+};
+
+/// The linear value of an 8-bit sRGB channel, where [`LUMINANCE_ONE`] is 1.
+pub const fn srgb_to_linear(channel: u8) -> u32 {
+    LINEAR[channel as usize]
+}
+
+/// Relative luminance of an opaque 8-bit color, where [`LUMINANCE_ONE`] is white. The
+/// coefficients are the exact 0.2126, 0.7152 and 0.0722 of WCAG, as ten-thousandths.
+pub const fn relative_luminance(rgb: [u8; 3]) -> u32 {
+    let [red, green, blue] = rgb;
+    let sum = 2126 * srgb_to_linear(red) as u64
+        + 7152 * srgb_to_linear(green) as u64
+        + 722 * srgb_to_linear(blue) as u64;
+
+    ((sum + 5000) / 10000) as u32
+}
+
+/// The ratio of two luminances of [`relative_luminance`], `(20 * L1 + 1) / (20 * L2 + 1)` like
+/// the float paths, rounded to the nearest unit of [`RATIO_ONE`].
+pub const fn luminance_ratio(first: u32, second: u32) -> u32 {
+    let (numerator, denominator) = ratio_terms(first, second);
+
+    ((numerator * RATIO_ONE as u64 + denominator / 2) / denominator) as u32
+}
+
+/// `20 * L1 + 1` and `20 * L2 + 1` of the lighter and darker of two luminances, in units of
+/// [`LUMINANCE_ONE`].
+const fn ratio_terms(first: u32, second: u32) -> (u64, u64) {
+    let (lighter, darker) = if first > second {
+        (first as u64, second as u64)
+    } else {
+        (second as u64, first as u64)
+    };
+
+    (
+        20 * lighter + LUMINANCE_ONE as u64,
+        20 * darker + LUMINANCE_ONE as u64,
+    )
+}
+
+/// Whether the unrounded ratio of two luminances reaches `minimum`, a ratio where [`RATIO_ONE`]
+/// is 1:1. Cross-multiplying keeps a ratio that only rounds up to the threshold from passing.
+const fn luminances_reach(first: u32, second: u32, minimum: u32) -> bool {
+    let (numerator, denominator) = ratio_terms(first, second);
+
+    numerator * RATIO_ONE as u64 >= minimum as u64 * denominator
+}
+
+/// Contrast ratio of two opaque 8-bit colors, where [`RATIO_ONE`] is 1:1, within
+/// [`RATIO_ERROR`] of [`crate::contrast_ratio`].
+pub const fn contrast_ratio(foreground: [u8; 3], background: [u8; 3]) -> u32 {
+    luminance_ratio(
+        relative_luminance(foreground),
+        relative_luminance(background),
+    )
+}
+
+/// The ratio a WCAG level requires, where [`RATIO_ONE`] is 1:1. The thresholds are exact.
+pub const fn minimum_ratio(level: WcagLevel, size: TextSize) -> u32 {
+    match (level, size) {
+        (WcagLevel::AA, TextSize::Normal) | (WcagLevel::AAA, TextSize::Large) => RATIO_ONE * 9 / 2,
+        (WcagLevel::AA, TextSize::Large) => RATIO_ONE * 3,
+        (WcagLevel::AAA, TextSize::Normal) => RATIO_ONE * 7,
+    }
+}
+
+/// Whether a pair of opaque 8-bit colors reaches a WCAG level, comparing the unrounded ratio
+/// like [`crate::const_passes`], so a pair whose [`contrast_ratio`] only rounds up to the
+/// threshold still fails.
+pub const fn passes(
+    foreground: [u8; 3],
+    background: [u8; 3],
+    level: WcagLevel,
+    size: TextSize,
+) -> bool {
+    luminances_reach(
+        relative_luminance(foreground),
+        relative_luminance(background),
+        minimum_ratio(level, size),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::const_contrast::luminance;
+
+    /// The ratio in `f64`, without the rounding to `f32` of the float paths.
+    fn exact_ratio(foreground: [u8; 3], background: [u8; 3]) -> f64 {
+        let (first, second) = (luminance(foreground), luminance(background));
+        (20.0 * first.max(second) + 1.0) / (20.0 * first.min(second) + 1.0)
+    }
+
+    #[test]
+    fn luminance_is_within_its_bound_for_every_color() {
+        for rgb in 0..1u32 << 24 {
+            let [_, red, green, blue] = rgb.to_be_bytes();
+            let fixed =
+                f64::from(relative_luminance([red, green, blue])) / f64::from(LUMINANCE_ONE);
+            let error = (fixed - luminance([red, green, blue])).abs();
+            assert!(error <= f64::from(LUMINANCE_ERROR), "{rgb:06x}: {error}");
+        }
+
+        assert_eq!(relative_luminance([0, 0, 0]), 0);
+        assert_eq!(relative_luminance([255, 255, 255]), LUMINANCE_ONE);
+    }
+
+    #[test]
+    fn ratios_are_within_their_bound() {
+        assert_eq!(contrast_ratio([0, 0, 0], [255, 255, 255]), 21 * RATIO_ONE);
+        assert_eq!(
+            contrast_ratio([0x12, 0x34, 0x56], [0x12, 0x34, 0x56]),
+            RATIO_ONE
+        );
+
+        let grays = (0..=255).map(|value| [value, value, value]);
+        let colors = (0..1u32 << 24).step_by(4093).map(|rgb| {
+            let [_, red, green, blue] = rgb.to_be_bytes();
+            [red, green, blue]
+        });
+        let samples: Vec<[u8; 3]> = grays.chain(colors).collect();
+        for foreground in &samples {
+            for background in samples.iter().step_by(7) {
+                let fixed =
+                    f64::from(contrast_ratio(*foreground, *background)) / f64::from(RATIO_ONE);
+                let error = (fixed - exact_ratio(*foreground, *background)).abs();
+                assert!(
+                    error <= f64::from(RATIO_ERROR),
+                    "{foreground:?} on {background:?}: {error}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn ratios_that_round_up_to_a_threshold_fail() {
+        // (20 * 0.175 + 1) / 1 is exactly 4.5:1, one unit of luminance less is a hair below it
+        let at = (3 * LUMINANCE_ONE as u64 * 7 / 120) as u32 + 1;
+        let minimum = minimum_ratio(WcagLevel::AA, TextSize::Normal);
+
+        assert!(luminances_reach(at, 0, minimum));
+        assert_eq!(luminance_ratio(at - 1, 0), minimum);
+        assert!(!luminances_reach(at - 1, 0, minimum));
+        assert!(!luminances_reach(0, at - 1, minimum));
+    }
+
+    #[test]
+    fn verdicts_match_the_float_paths_away_from_the_thresholds() {
+        for foreground in 0..=255 {
+            for background in 0..=255 {
+                let foreground = [foreground, foreground, foreground];
+                let background = [background, background, background];
+                let exact = exact_ratio(foreground, background);
+                for (level, size) in [
+                    (WcagLevel::AA, TextSize::Normal),
+                    (WcagLevel::AA, TextSize::Large),
+                    (WcagLevel::AAA, TextSize::Normal),
+                ] {
+                    let threshold = f64::from(level.minimum_ratio(size));
+                    if (exact - threshold).abs() > f64::from(RATIO_ERROR) {
+                        assert_eq!(
+                            passes(foreground, background, level, size),
+                            exact >= threshold
+                        );
+                    }
+                }
+            }
+        }
+    }
+}
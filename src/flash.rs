@@ -0,0 +1,216 @@
+use alloc::vec::Vec;
+
+use crate::{relative_luminance, Color};
+
+/// The change in relative luminance that makes a flash, 10% of white.
+/// Obtained from https://www.w3.org/TR/WCAG22/#dfn-general-flash-and-red-flash-thresholds
+pub const FLASH_LUMINANCE_CHANGE: f32 = 0.1;
+
+/// Changes only make a flash when the darker color is below this relative luminance, flickering
+/// between two light colors is harmless.
+pub const FLASH_DARKER_LUMINANCE: f32 = 0.8;
+
+/// The most flashes any one second may have to meet success criterion 2.3.1.
+pub const MAX_FLASHES_PER_SECOND: usize = 3;
+
+/// The change of relative luminance between two consecutive keyframes.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct LuminanceJump {
+    /// Index of the keyframe the change starts from.
+    pub from: usize,
+    /// Index of the keyframe it goes to, the first one for the jump that loops back.
+    pub to: usize,
+    /// The luminance of `to` minus the one of `from`, negative when the color gets darker.
+    pub delta: f32,
+}
+
+/// The luminance changes of an animation, found by [`check_flashes`].
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct FlashCheck {
+    /// The relative luminance of every keyframe, in order.
+    pub luminances: Vec<f32>,
+    /// The largest jump between consecutive keyframes, none with fewer than two keyframes.
+    pub largest_jump: Option<LuminanceJump>,
+    /// The most flashes in any one second of the animation.
+    pub flashes: usize,
+}
+
+impl FlashCheck {
+    /// Whether the animation stays under the general flash threshold of WCAG, at most
+    /// [`MAX_FLASHES_PER_SECOND`] flashes in any one second.
+    pub fn passes(&self) -> bool {
+        self.flashes <= MAX_FLASHES_PER_SECOND
+    }
+}
+
+/// Screens an animation through `keyframes` for flashing, the keyframes evenly spaced over
+/// `duration` seconds. With `repeat` the animation loops, going from the last keyframe back to the
+/// first one.
+///
+/// A flash is a pair of opposing changes of at least [`FLASH_LUMINANCE_CHANGE`] where the darker
+/// color is below [`FLASH_DARKER_LUMINANCE`]. Keyframes that keep going the same way count as a
+/// single change, so a slow fade isn't a flash. Colors are taken as opaque, composite translucent
+/// ones over what's behind them first. This is a screening of the colors only: the red flash
+/// threshold and the area that flashes, which WCAG also looks at, aren't checked.
+///
+/// ```
+/// use contrast_checker::{check_flashes, Color};
+///
+/// let (black, white) = (Color::new(0, 0, 0), Color::new(255, 255, 255));
+/// // Black to white and back, twice a second
+/// let blink = check_flashes(&[black, white], 0.5, true);
+/// assert_eq!(blink.flashes, 2);
+/// assert!(blink.passes());
+///
+/// // Eight times a second is a seizure risk
+/// assert!(!check_flashes(&[black, white], 0.125, true).passes());
+/// ```
+pub fn check_flashes(keyframes: &[Color], duration: f32, repeat: bool) -> FlashCheck {
+    let luminances: Vec<f32> = keyframes.iter().map(relative_luminance).collect();
+    let count = luminances.len();
+    if count < 2 {
+        return FlashCheck {
+            luminances,
+            largest_jump: None,
+            flashes: 0,
+        };
+    }
+
+    let jumps = (0..count - 1)
+        .map(|from| (from, from + 1))
+        .chain(repeat.then_some((count - 1, 0)))
+        .map(|(from, to)| LuminanceJump {
+            from,
+            to,
+            delta: luminances[to] - luminances[from],
+        });
+    let largest_jump = jumps.reduce(|largest, jump| {
+        if jump.delta.abs() > largest.delta.abs() {
+            jump
+        } else {
+            largest
+        }
+    });
+
+    // A looping animation is unrolled for long enough that every one second window fits in it
+    let duration = duration.max(0.001);
+    let (iterations, intervals) = if repeat {
+        ((1.0 / duration) as usize + 2, count)
+    } else {
+        (1, count - 1)
+    };
+    let frames: Vec<(f32, f32)> = (0..=iterations * intervals)
+        .map(|frame| {
+            let time = frame as f32 * duration / intervals as f32;
+            (time, luminances[frame % count])
+        })
+        .collect();
+
+    let changes = flash_changes(&frames);
+    let flashes = changes
+        .iter()
+        .enumerate()
+        .map(|(index, start)| {
+            let within = changes[index..]
+                .iter()
+                .take_while(|time| **time - *start < 1.0)
+                .count();
+            within / 2
+        })
+        .max()
+        .unwrap_or(0);
+
+    FlashCheck {
+        luminances,
+        largest_jump,
+        flashes,
+    }
+}
+
+/// The times at which the changes that can make a flash end, every one going the opposite way of
+/// the one before. `frames` are the times and luminances of the animation.
+fn flash_changes(frames: &[(f32, f32)]) -> Vec<f32> {
+    let mut changes = Vec::new();
+    let mut last_rising: Option<bool> = None;
+    let mut start = 0;
+
+    for end in 1..frames.len() {
+        let rising = frames[end].1 > frames[start].1;
+        let keeps_going = frames
+            .get(end + 1)
+            .is_some_and(|(_, next)| *next == frames[end].1 || (*next > frames[end].1) == rising);
+        let flat = frames[end].1 == frames[start].1;
+        if keeps_going || flat {
+            if flat {
+                start = end;
+            }
+            continue;
+        }
+
+        let (from, to) = (frames[start].1, frames[end].1);
+        if (to - from).abs() >= FLASH_LUMINANCE_CHANGE
+            && from.min(to) < FLASH_DARKER_LUMINANCE
+            && last_rising != Some(rising)
+        {
+            changes.push(frames[end].0);
+            last_rising = Some(rising);
+        }
+        start = end;
+    }
+
+    changes
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn gray(value: u8) -> Color {
+        Color::new(value, value, value)
+    }
+
+    #[test]
+    fn counts_blinks_per_second() {
+        let blink = [gray(0), gray(255)];
+        assert_eq!(check_flashes(&blink, 1.0, true).flashes, 1);
+        assert_eq!(check_flashes(&blink, 0.25, true).flashes, 4);
+        // Played once, black to white is a single change
+        assert_eq!(check_flashes(&blink, 0.1, false).flashes, 0);
+
+        let strobe = [0, 255, 0, 255, 0, 255, 0, 255, 0].map(gray);
+        let check = check_flashes(&strobe, 1.0, false);
+        assert_eq!(check.flashes, 4);
+        assert!(!check.passes());
+        // The same keyframes over two seconds are two flashes a second
+        assert_eq!(check_flashes(&strobe, 2.0, false).flashes, 2);
+    }
+
+    #[test]
+    fn fades_and_light_flicker_are_not_flashes() {
+        // Every step goes the same way, so the fade in and out is one flash
+        let fade: Vec<Color> = (0..=10).map(|step| gray(step * 25)).collect();
+        assert_eq!(check_flashes(&fade, 1.0, true).flashes, 1);
+
+        // Both colors are above 0.8
+        let light = [gray(0xf0), gray(0xff)];
+        assert_eq!(check_flashes(&light, 0.1, true).flashes, 0);
+        // The changes are below 10%
+        let dark = [gray(0x10), gray(0x30)];
+        assert_eq!(check_flashes(&dark, 0.1, true).flashes, 0);
+    }
+
+    #[test]
+    fn finds_the_largest_jump() {
+        let keyframes = [gray(0x80), gray(0x90), gray(0)];
+        let check = check_flashes(&keyframes, 1.0, true);
+        let jump = check.largest_jump.unwrap();
+        assert_eq!((jump.from, jump.to), (1, 2));
+        assert!(jump.delta < 0.0);
+        assert_eq!(check.luminances.len(), 3);
+
+        assert_eq!(check_flashes(&[gray(0)], 1.0, true).largest_jump, None);
+        assert!(check_flashes(&[], 1.0, true).passes());
+    }
+}
@@ -0,0 +1,144 @@
+use alloc::vec::Vec;
+
+use crate::wcag::{FOCUS_APPEARANCE, NON_TEXT_CONTRAST};
+use crate::{contrast_ratio, Color, RoundingPolicy, SuccessCriterion, WcagLevel};
+
+/// What a focus indicator is drawn next to, see [`FocusIndicator`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum FocusAdjacency {
+    /// The fill of the focused component, which an inset ring or an outline without offset
+    /// touches.
+    Component,
+    /// What's around the component, which an outline or an offset ring is drawn on.
+    Background,
+}
+
+impl FocusAdjacency {
+    pub fn name(self) -> &'static str {
+        match self {
+            FocusAdjacency::Component => "component",
+            FocusAdjacency::Background => "background",
+        }
+    }
+}
+
+/// A focus indicator, like a focus ring, checked with [`FocusIndicator::check`] against the
+/// colors next to it. Only the adjacent colors that are set are checked.
+///
+/// Each adjacency needs 3:1. At AA that's the non-text contrast of SC 1.4.11, which covers the
+/// visual state of components. At AAA it's SC 2.4.13 of WCAG 2.2, which asks for 3:1 between the
+/// focused and unfocused pixels, and those pixels are the component or the background before the
+/// indicator is drawn on them.
+///
+/// ```
+/// use contrast_checker::{Color, FocusAdjacency, FocusIndicator, WcagLevel};
+///
+/// // A blue ring on a blue button, on a white page
+/// let ring = FocusIndicator::new(Color::new(0x1a, 0x73, 0xe8))
+///     .component(Color::new(0x0b, 0x57, 0xd0))
+///     .background(Color::new(255, 255, 255));
+///
+/// let report = ring.check(WcagLevel::AA);
+/// let failing: Vec<_> = report.failures().map(|check| check.adjacency).collect();
+/// assert_eq!(failing, [FocusAdjacency::Component]);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct FocusIndicator {
+    pub indicator: Color,
+    pub component: Option<Color>,
+    pub background: Option<Color>,
+    pub rounding: RoundingPolicy,
+}
+
+/// The contrast of a focus indicator against one adjacent color.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct AdjacencyCheck {
+    pub adjacency: FocusAdjacency,
+    /// The adjacent color.
+    pub color: Color,
+    pub ratio: f32,
+    pub required_ratio: f32,
+    /// The success criterion `required_ratio` comes from.
+    pub criterion: SuccessCriterion,
+    pub passes: bool,
+}
+
+/// The verdict of a focus indicator at a level, it passes when every adjacency does.
+#[derive(Debug, Clone, PartialEq)]
+pub struct FocusReport {
+    pub level: WcagLevel,
+    pub checks: Vec<AdjacencyCheck>,
+}
+
+impl FocusReport {
+    pub fn passes(&self) -> bool {
+        self.checks.iter().all(|check| check.passes)
+    }
+
+    /// The adjacencies that don't reach 3:1.
+    pub fn failures(&self) -> impl Iterator<Item = &AdjacencyCheck> {
+        self.checks.iter().filter(|check| !check.passes)
+    }
+}
+
+impl FocusIndicator {
+    /// An indicator without adjacent colors, comparing unrounded ratios.
+    pub fn new(indicator: Color) -> FocusIndicator {
+        FocusIndicator {
+            indicator,
+            component: None,
+            background: None,
+            rounding: RoundingPolicy::Exact,
+        }
+    }
+
+    pub fn component(mut self, color: Color) -> FocusIndicator {
+        self.component = Some(color);
+        self
+    }
+
+    pub fn background(mut self, color: Color) -> FocusIndicator {
+        self.background = Some(color);
+        self
+    }
+
+    pub fn rounding(mut self, rounding: RoundingPolicy) -> FocusIndicator {
+        self.rounding = rounding;
+        self
+    }
+
+    /// Checks the indicator against every adjacent color that's set, the component first.
+    pub fn check(&self, level: WcagLevel) -> FocusReport {
+        let criterion = match level {
+            WcagLevel::AA => NON_TEXT_CONTRAST,
+            WcagLevel::AAA => FOCUS_APPEARANCE,
+        };
+        // Both criteria ask for 3:1
+        let required_ratio = 3.0;
+        let adjacencies = [
+            (FocusAdjacency::Component, self.component),
+            (FocusAdjacency::Background, self.background),
+        ];
+
+        let checks = adjacencies
+            .into_iter()
+            .filter_map(|(adjacency, color)| {
+                let color = color?;
+                let ratio = contrast_ratio(&self.indicator, &color);
+
+                Some(AdjacencyCheck {
+                    adjacency,
+                    color,
+                    ratio,
+                    required_ratio,
+                    criterion,
+                    passes: self.rounding.apply(ratio) >= required_ratio,
+                })
+            })
+            .collect();
+
+        FocusReport { level, checks }
+    }
+}
@@ -0,0 +1,123 @@
+use alloc::format;
+use alloc::string::{String, ToString};
+
+#[cfg(not(any(feature = "std", test)))]
+use crate::math::Float;
+use crate::Color;
+
+/// The notations [`Color::format_as`] can write, all of them are read back by
+/// [`Color::from_css`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ColorFormat {
+    /// `#f26ca7`, or `#f26ca780` when translucent.
+    #[default]
+    Hex,
+    /// `rgb(242 108 167)`, or `rgb(242 108 167 / 0.5)` when translucent.
+    Rgb,
+    /// `hsl(333.58 83.75% 68.63%)`
+    Hsl,
+    /// `oklch(0.70967 0.17512 354.927)`
+    Oklch,
+}
+
+/// Writes a number with at most `decimals` decimals, without trailing zeros.
+fn number(value: f32, decimals: i32) -> String {
+    let scale = 10f32.powi(decimals);
+    let rounded = (value * scale).round() / scale;
+    // Avoids printing -0 for tiny negative values
+    let rounded = if rounded == 0.0 { 0.0 } else { rounded };
+
+    let text = format!("{rounded:.*}", decimals as usize);
+    match text.contains('.') {
+        true => text.trim_end_matches('0').trim_end_matches('.').to_string(),
+        false => text,
+    }
+}
+
+impl Color {
+    /// Writes the color in the given notation. There are enough decimals for the result to parse
+    /// back into the same color, and the alpha is only written when the color is translucent.
+    ///
+    /// ```
+    /// use contrast_checker::{Color, ColorFormat};
+    ///
+    /// let pink = Color::new(242, 108, 167);
+    /// assert_eq!(pink.format_as(ColorFormat::Rgb), "rgb(242 108 167)");
+    /// assert_eq!(pink.with_opacity(0.5).format_as(ColorFormat::Hex), "#f26ca780");
+    ///
+    /// let oklch = pink.format_as(ColorFormat::Oklch);
+    /// assert_eq!(Color::from_css(&oklch).unwrap(), pink);
+    /// ```
+    pub fn format_as(&self, format: ColorFormat) -> String {
+        let channels = match format {
+            ColorFormat::Hex => return self.to_hex(),
+            ColorFormat::Rgb => format!("rgb({} {} {}", self.red, self.green, self.blue),
+            ColorFormat::Hsl => {
+                let (hue, saturation, lightness) = self.to_hsl();
+                format!(
+                    "hsl({} {}% {}%",
+                    number(hue, 2),
+                    number(saturation * 100.0, 2),
+                    number(lightness * 100.0, 2)
+                )
+            }
+            ColorFormat::Oklch => {
+                let (lightness, chroma, hue) = self.to_oklch();
+                // Rounding the chroma up can push colors at the edge of sRGB out of it, and
+                // parsing would then bring them back with less chroma than they had
+                let chroma = (chroma * 1e5).floor() / 1e5;
+                format!(
+                    "oklch({} {} {}",
+                    number(lightness, 5),
+                    number(chroma, 5),
+                    number(hue, 3)
+                )
+            }
+        };
+
+        if self.is_opaque() {
+            format!("{channels})")
+        } else {
+            format!("{channels} / {})", number(self.opacity(), 3))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn every_format_parses_back_into_the_same_color() {
+        for red in (0..=255).step_by(15) {
+            for green in (0..=255).step_by(17) {
+                for blue in (0..=255).step_by(51) {
+                    for alpha in [255, 128, 1] {
+                        let color = Color {
+                            alpha,
+                            ..Color::new(red, green, blue)
+                        };
+                        for format in [
+                            ColorFormat::Hex,
+                            ColorFormat::Rgb,
+                            ColorFormat::Hsl,
+                            ColorFormat::Oklch,
+                        ] {
+                            let text = color.format_as(format);
+                            assert_eq!(Color::from_css(&text), Ok(color), "{text}");
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn numbers_have_no_trailing_zeros() {
+        assert_eq!(number(340.0, 2), "340");
+        assert_eq!(number(0.5, 3), "0.5");
+        assert_eq!(number(-0.00001, 4), "0");
+        assert_eq!(number(59.216, 2), "59.22");
+    }
+}
@@ -0,0 +1,94 @@
+use alloc::vec::Vec;
+use core::ops::RangeInclusive;
+
+#[cfg(not(any(feature = "std", test)))]
+use crate::math::Float;
+use crate::{contrast_ratio, delta_e_2000, Color};
+
+// Grays are left out of generated palettes, they can't be told apart by hue in a chart
+const MIN_CHROMA: f32 = 0.04;
+const MAX_CHROMA: f32 = 0.25;
+/// Passing candidates drawn for every color, the one farthest from the others is kept.
+const CANDIDATES: usize = 20;
+/// Draws allowed to find a single passing candidate before giving up.
+const MAX_DRAWS: usize = 2000;
+
+/// Picks a hue in `range`, in degrees. Ranges whose start is above their end go through 0, so
+/// `330.0..=30.0` are the reds.
+fn hue_in(range: &RangeInclusive<f32>, random: f32) -> f32 {
+    let (start, end) = (*range.start(), *range.end());
+    let width = if start <= end {
+        end - start
+    } else {
+        end + 360.0 - start
+    };
+
+    (start + width * random).rem_euclid(360.0)
+}
+
+/// Generates up to `count` random colors that all reach `ratio` against `background`, for chart
+/// series or avatars that have to stay readable. Hues are limited to `hue_range` (OKLCH degrees)
+/// when given, and grays are never generated.
+///
+/// `rng` returns random numbers in `0.0..1.0`, so any generator can be used and a seeded one
+/// makes the result repeatable. Every new color is the candidate farthest (CIEDE2000) from the
+/// ones already picked, so the colors are spread out instead of clustering. Fewer than `count`
+/// colors are returned when the ratio leaves almost no colors to pick from, like 21:1.
+///
+/// ```
+/// use contrast_checker::{contrast_ratio, generate_accessible, Color};
+///
+/// // A tiny linear congruential generator, use a real one like rand in practice
+/// let mut state = 42u32;
+/// let rng = move || {
+///     state = state.wrapping_mul(1_664_525).wrapping_add(1_013_904_223);
+///     (state >> 8) as f32 / (1 << 24) as f32
+/// };
+///
+/// let white = Color::new(255, 255, 255);
+/// let colors = generate_accessible(&white, 4.5, 5, Some(200.0..=260.0), rng);
+/// assert_eq!(colors.len(), 5);
+/// assert!(colors.iter().all(|color| contrast_ratio(color, &white) >= 4.5));
+/// ```
+pub fn generate_accessible(
+    background: &Color,
+    ratio: f32,
+    count: usize,
+    hue_range: Option<RangeInclusive<f32>>,
+    mut rng: impl FnMut() -> f32,
+) -> Vec<Color> {
+    let hue_range = hue_range.unwrap_or(0.0..=360.0);
+    let mut draw = || {
+        (0..MAX_DRAWS).find_map(|_| {
+            let lightness = rng();
+            let chroma = MIN_CHROMA + (MAX_CHROMA - MIN_CHROMA) * rng();
+            let hue = hue_in(&hue_range, rng());
+            let color = Color::from_oklch(lightness, chroma, hue);
+
+            (contrast_ratio(&color, background) >= ratio).then_some(color)
+        })
+    };
+
+    let mut colors: Vec<Color> = Vec::with_capacity(count);
+    while colors.len() < count {
+        let distance_to_picked = |candidate: &Color| {
+            colors
+                .iter()
+                .map(|color| delta_e_2000(color, candidate))
+                .fold(f32::INFINITY, f32::min)
+        };
+
+        let best = (0..CANDIDATES)
+            .map_while(|_| draw())
+            .filter(|candidate| !colors.contains(candidate))
+            .map(|candidate| (distance_to_picked(&candidate), candidate))
+            .max_by(|(first, _), (second, _)| first.total_cmp(second));
+
+        match best {
+            Some((_, color)) => colors.push(color),
+            None => break,
+        }
+    }
+
+    colors
+}
@@ -0,0 +1,106 @@
+use alloc::vec::Vec;
+
+use crate::{contrast_ratio, Color, RoundingPolicy};
+
+/// One point of a gradient sampled by [`contrast_along_gradient`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct GradientSample {
+    /// Where the sample is, from 0 at the first stop to 1 at the second one.
+    pub position: f32,
+    pub color: Color,
+    pub ratio: f32,
+}
+
+/// The contrast of a text color at evenly spaced points of a gradient, from the first stop to
+/// the second one.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct GradientContrast {
+    pub samples: Vec<GradientSample>,
+}
+
+impl GradientContrast {
+    /// The sample with the lowest contrast, the first one if several tie. `None` when there are
+    /// no samples, which only happens to a `GradientContrast` built by hand.
+    pub fn minimum(&self) -> Option<&GradientSample> {
+        self.samples.iter().reduce(|lowest, sample| {
+            if sample.ratio < lowest.ratio {
+                sample
+            } else {
+                lowest
+            }
+        })
+    }
+
+    /// The first sample, going from the first stop, that is below `threshold`.
+    pub fn first_failure(
+        &self,
+        threshold: f32,
+        rounding: RoundingPolicy,
+    ) -> Option<&GradientSample> {
+        self.samples
+            .iter()
+            .find(|sample| rounding.apply(sample.ratio) < threshold)
+    }
+}
+
+/// Mixes two colors like CSS gradients do, interpolating the channels premultiplied by alpha so
+/// a transparent stop doesn't darken the other one.
+/// Formula obtained from https://www.w3.org/TR/css-color-4/#interpolation-alpha
+fn interpolate(start: &Color, end: &Color, position: f32) -> Color {
+    let (start_alpha, end_alpha) = (start.opacity(), end.opacity());
+    let alpha = start_alpha + (end_alpha - start_alpha) * position;
+    if alpha == 0.0 {
+        return Color::new_with_alpha(0, 0, 0, 0.0);
+    }
+
+    let channel = |start_channel: u8, end_channel: u8| {
+        let start_channel = f32::from(start_channel) * start_alpha;
+        let end_channel = f32::from(end_channel) * end_alpha;
+        (start_channel + (end_channel - start_channel) * position) / alpha
+    };
+
+    Color::from_f32_channels(
+        channel(start.red, end.red),
+        channel(start.green, end.green),
+        channel(start.blue, end.blue),
+    )
+    .with_opacity(alpha)
+}
+
+/// Samples a two stop gradient at `steps` evenly spaced points, both stops included, and
+/// computes the contrast of `text` at each of them. Fewer than 2 steps still sample both stops.
+///
+/// ```
+/// use contrast_checker::{contrast_along_gradient, Color, RoundingPolicy};
+///
+/// let white = Color::new(255, 255, 255);
+/// let (navy, tomato) = (Color::new(0, 0, 128), Color::new(255, 99, 71));
+/// let gradient = contrast_along_gradient(&white, &navy, &tomato, 11);
+///
+/// assert_eq!(gradient.samples.len(), 11);
+/// assert_eq!(gradient.minimum().unwrap().position, 1.0);
+/// assert_eq!(gradient.first_failure(4.5, RoundingPolicy::Exact).unwrap().position, 0.8);
+/// ```
+pub fn contrast_along_gradient(
+    text: &Color,
+    start: &Color,
+    end: &Color,
+    steps: usize,
+) -> GradientContrast {
+    let steps = steps.max(2);
+    let samples = (0..steps)
+        .map(|index| {
+            let position = index as f32 / (steps - 1) as f32;
+            let color = interpolate(start, end, position);
+            GradientSample {
+                position,
+                color,
+                ratio: contrast_ratio(text, &color),
+            }
+        })
+        .collect();
+
+    GradientContrast { samples }
+}
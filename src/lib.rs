@@ -0,0 +1,122 @@
+//! Contrast ratio calculations for sRGB colors based on the WCAG guidelines.
+//!
+//! ```
+//! use contrast_checker::{contrast_ratio, Color};
+//!
+//! let white = Color::new(255, 255, 255);
+//! let black = Color::new(0, 0, 0);
+//!
+//! assert!((contrast_ratio(&white, &black) - 21.0).abs() < 0.001);
+//! ```
+//!
+//! The default `std` feature can be turned off to use the crate in `no_std` environments, like
+//! the firmware of embedded displays. The color math, parsing and WCAG grading only need an
+//! allocator then, the audit and report modules need std.
+//!
+//! The parsers, [`parse_color`], `tokens::parse_tokens` and the extractors of [`scan`], read
+//! untrusted input in batch and serve mode, so they never panic: anything they can't read is an
+//! error or skipped. Colors longer than [`MAX_COLOR_LENGTH`] and alias chains deeper than
+//! `tokens::MAX_ALIAS_DEPTH` are rejected before any work is done on them, and stylesheets can't
+//! grow their work past [`scan::MAX_NESTING_DEPTH`] and [`scan::MAX_SELECTOR_LENGTH`] by nesting
+//! rules. The fuzz targets in `fuzz/` check it, run them with `cargo +nightly fuzz run
+//! parse_color`, `parse_tokens` or `scan`. What they find goes in `fuzz/artifacts`, every input
+//! there is replayed by `cargo test`.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
+
+mod advisory;
+mod algorithm;
+mod ansi;
+mod apca;
+#[cfg(feature = "std")]
+pub mod audit;
+mod color;
+mod component;
+mod const_contrast;
+mod contrast;
+mod cvd;
+mod delta_e;
+mod display_p3;
+mod display_profile;
+mod duplicates;
+mod estimate;
+mod exit;
+#[cfg(feature = "fixed-point")]
+pub mod fixed;
+mod flash;
+mod focus;
+mod format;
+mod generate;
+mod gradient;
+mod link;
+#[cfg(not(any(feature = "std", test)))]
+mod math;
+mod named;
+mod oklab;
+mod overlay;
+pub mod palettes;
+mod parse;
+pub mod presets;
+#[cfg(feature = "pyo3")]
+mod python;
+mod range;
+#[cfg(feature = "std")]
+pub mod report;
+mod scale;
+pub mod scan;
+mod sort;
+#[cfg(feature = "source-maps")]
+pub mod source_map;
+mod suggest;
+mod surface;
+#[cfg(feature = "tokens")]
+pub mod tokens;
+mod wcag;
+
+pub use advisory::{advisories, Advisory, AdvisoryKind};
+pub use algorithm::{Apca, ContrastAlgorithm, LuminanceDifference, Wcag2, Weber};
+pub use apca::apca_contrast;
+pub use color::{Color, ColorFromHexError, HexToDecError};
+pub use component::{Component, ComponentReport, ComponentRole, RoleCheck};
+pub use const_contrast::{const_contrast_ratio, const_passes, const_relative_luminance};
+pub use contrast::{
+    best_text_color, check, contrast_matrix, contrast_ratio, contrast_ratio_f64, contrast_ratios,
+    label_colors, linear_to_srgb, pairwise_contrast, relative_luminance, relative_luminance_batch,
+    srgb_to_linear, worst_case_contrast, BestTextColor, ContrastReport, ContrastResult, Luminance,
+    Polarity, WorstCase,
+};
+pub use cvd::{cvd_contrast, simulate_cvd, ColorVisionDeficiency, CvdContrast};
+pub use delta_e::{delta_e_2000, delta_e_matrix, DeltaEBand};
+pub use display_p3::GamutMapping;
+pub use display_profile::DisplayProfile;
+pub use duplicates::{near_duplicates, DuplicateGroup, DUPLICATE_DELTA_E};
+pub use estimate::{detect_text_regions, estimate_text_colors, TextColors, TextRegion};
+pub use exit::ExitStatus;
+pub use flash::{
+    check_flashes, FlashCheck, LuminanceJump, FLASH_DARKER_LUMINANCE, FLASH_LUMINANCE_CHANGE,
+    MAX_FLASHES_PER_SECOND,
+};
+pub use focus::{AdjacencyCheck, FocusAdjacency, FocusIndicator, FocusReport};
+pub use format::ColorFormat;
+pub use generate::generate_accessible;
+pub use gradient::{contrast_along_gradient, GradientContrast, GradientSample};
+pub use link::{check_link, LinkCheck, LinkPair, LinkReport, LINK_TEXT_RATIO};
+pub use overlay::{overlay_contrast, OverlayContrast};
+pub use parse::{
+    parse_color, split_colors, split_colors_iter, ParseColorError, ParseColorErrorKind,
+    SplitColors, MAX_COLOR_LENGTH,
+};
+pub use range::{contrast_range, ContrastRange};
+pub use scale::{tint_scale, ScaleStep};
+pub use sort::{contrast_order, luminance_order, sort_by_contrast, sort_by_luminance};
+pub use suggest::{
+    compliance_gap, luminance_matches, required_lightness_change, suggest_foreground,
+    ComplianceGap, LightnessDelta, LightnessFix, Suggestion,
+};
+pub use surface::SurfaceStack;
+pub use wcag::{
+    check_compliance, ComplianceReport, ContrastContext, RoundingPolicy, SuccessCriterion,
+    TextSize, WcagLevel, INDISTINGUISHABLE_RATIO,
+};
@@ -0,0 +1,112 @@
+use crate::contrast::visible_colors;
+use crate::{contrast_ratio, Color, RoundingPolicy, TextSize, WcagLevel};
+
+/// Contrast needed between a link and the text around it when color is the only thing that tells
+/// them apart, from https://www.w3.org/WAI/WCAG21/Techniques/general/G183
+pub const LINK_TEXT_RATIO: f32 = 3.0;
+
+/// The pairs of colors [`check_link`] checks.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum LinkPair {
+    /// The link against the body text around it, so it can be told apart without an underline.
+    LinkText,
+    /// The link against the background, as text.
+    LinkBackground,
+    /// The body text against the background, as text.
+    TextBackground,
+}
+
+impl LinkPair {
+    pub fn name(self) -> &'static str {
+        match self {
+            LinkPair::LinkText => "link on text",
+            LinkPair::LinkBackground => "link on background",
+            LinkPair::TextBackground => "text on background",
+        }
+    }
+}
+
+/// The contrast of one pair of a link check.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct LinkCheck {
+    pub pair: LinkPair,
+    pub ratio: f32,
+    pub required_ratio: f32,
+    pub passes: bool,
+}
+
+/// The verdict of a link color at a level, it passes when the three pairs do.
+#[derive(Debug, Clone, PartialEq)]
+pub struct LinkReport {
+    pub level: WcagLevel,
+    /// The link against the text, then both against the background.
+    pub checks: [LinkCheck; 3],
+}
+
+impl LinkReport {
+    pub fn passes(&self) -> bool {
+        self.checks.iter().all(|check| check.passes)
+    }
+
+    /// The pairs that don't reach their threshold.
+    pub fn failures(&self) -> impl Iterator<Item = &LinkCheck> {
+        self.checks.iter().filter(|check| !check.passes)
+    }
+}
+
+/// Checks a link color that is only told apart from the body text by its color, as in technique
+/// G183 for SC 1.4.1 (Use of Color): the link needs 3:1 against the text, and both need the
+/// normal text ratio of the level against the background. Links that are underlined, or stand
+/// out some other way, only need the last two.
+///
+/// ```
+/// use contrast_checker::{check_link, Color, LinkPair, RoundingPolicy, WcagLevel};
+///
+/// let (link, text, white) = (
+///     Color::new(0x00, 0x66, 0xcc),
+///     Color::new(0x33, 0x33, 0x33),
+///     Color::new(255, 255, 255),
+/// );
+///
+/// let report = check_link(&link, &text, &white, WcagLevel::AA, RoundingPolicy::Exact);
+/// // Both read well on white, but they are too close to each other
+/// let failing: Vec<_> = report.failures().map(|check| check.pair).collect();
+/// assert_eq!(failing, [LinkPair::LinkText]);
+/// ```
+pub fn check_link(
+    link: &Color,
+    text: &Color,
+    background: &Color,
+    level: WcagLevel,
+    rounding: RoundingPolicy,
+) -> LinkReport {
+    let text_ratio = level.minimum_ratio(TextSize::Normal);
+    let pair = |pair: LinkPair, first: &Color, second: &Color, required_ratio: f32| {
+        let ratio = contrast_ratio(first, second);
+        LinkCheck {
+            pair,
+            ratio,
+            required_ratio,
+            passes: rounding.apply(ratio) >= required_ratio,
+        }
+    };
+
+    // Both are drawn on the background, so translucent ones are compared as they're seen on it
+    let (visible_link, _) = visible_colors(link, background);
+    let (visible_text, _) = visible_colors(text, background);
+
+    LinkReport {
+        level,
+        checks: [
+            pair(
+                LinkPair::LinkText,
+                &visible_link,
+                &visible_text,
+                LINK_TEXT_RATIO,
+            ),
+            pair(LinkPair::LinkBackground, link, background, text_ratio),
+            pair(LinkPair::TextBackground, text, background, text_ratio),
+        ],
+    }
+}
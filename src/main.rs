@@ -1,178 +1,242 @@
-use core::fmt;
-
-#[derive(Debug)]
-enum HexToDecError {
-    RightDigitInvalid,
-    LeftDigitInvalid,
-    LeftDigitOutOfRange,
-    RightDigitOutOfRange,
-    InputLengthOutOfRange,
-}
-
-#[derive(Debug)]
-enum ColorFromHexError {
-    InputIsEmpty,
-    InputIsNotAscii,
-    InvalidInputLength,
-}
-
-/// This function expects a trimmed, 2 digit hex value without #
-fn hex_to_dec(hex: &str) -> Result<u8, HexToDecError> {
-    if hex.len() != 2 {
-        return Err(HexToDecError::InputLengthOutOfRange);
-    }
-
-    let mut chars = hex.chars();
-
-    // We'll always have 2 chars if we've reached this point, so it's okay to consume the value
-    // with unwrap.
-    let left = chars.next().unwrap();
-    let right = chars.next().unwrap();
-
-    let left_value = match left.to_digit(16) {
-        Some(x) => x,
-        None => return Err(HexToDecError::LeftDigitInvalid),
-    };
-
-    let right_value = match right.to_digit(16) {
-        Some(x) => x,
-        None => return Err(HexToDecError::RightDigitInvalid),
-    };
-
-    // If the input contained characters 'larger' than F
-    if left_value > 15 {
-        return Err(HexToDecError::LeftDigitOutOfRange);
-    }
-
-    if right_value > 15 {
-        return Err(HexToDecError::RightDigitOutOfRange);
-    }
-
-    // We now that each value is less than 16 and will fit in an u8
-    Ok(u8::try_from(left_value).ok().unwrap() * 16u8 + u8::try_from(right_value).ok().unwrap())
+mod cli;
+
+use std::path::PathBuf;
+use std::process::ExitCode;
+
+use clap::{CommandFactory, Parser, Subcommand};
+
+use cli::axe::AxeArgs;
+use cli::batch::BatchArgs;
+use cli::check::CheckArgs;
+use cli::completions::CompletionsArgs;
+use cli::component::ComponentArgs;
+use cli::config::Config;
+use cli::convert::ConvertArgs;
+use cli::cross::CrossArgs;
+use cli::diff::DiffArgs;
+use cli::duplicates::DuplicatesArgs;
+use cli::filter::FilterArgs;
+use cli::fix::FixArgs;
+use cli::flash::FlashArgs;
+use cli::focus::FocusArgs;
+use cli::generate::GenerateArgs;
+use cli::gradient::GradientArgs;
+#[cfg(feature = "image")]
+use cli::image::ImageArgs;
+use cli::interactive::InteractiveArgs;
+use cli::link::LinkArgs;
+#[cfg(feature = "screen")]
+use cli::live::LiveArgs;
+use cli::matrix::MatrixArgs;
+#[cfg(feature = "cdp")]
+use cli::page::PageArgs;
+use cli::pick::PickArgs;
+use cli::plugin_host::PluginHostArgs;
+use cli::range::RangeArgs;
+use cli::same_luminance::SameLuminanceArgs;
+use cli::scale::ScaleArgs;
+use cli::scan::ScanArgs;
+#[cfg(feature = "image")]
+use cli::screenshot::ScreenshotArgs;
+#[cfg(feature = "server")]
+use cli::serve::ServeArgs;
+use cli::site::SiteArgs;
+use cli::sort::SortArgs;
+use cli::suggest::SuggestArgs;
+use cli::surface::SurfaceArgs;
+use cli::tokens::TokensArgs;
+#[cfg(feature = "tray")]
+use cli::tray::TrayArgs;
+use cli::trend::TrendArgs;
+use cli::Error;
+
+/// Calculates the contrast ratio of two sRGB colors based on the WCAG guidelines.
+#[derive(Parser)]
+#[command(version, about, args_conflicts_with_subcommands = true)]
+struct Cli {
+    #[command(subcommand)]
+    command: Option<Command>,
+
+    #[command(flatten)]
+    check: Option<CheckArgs>,
+
+    /// Print the commands, algorithms, input and output formats and features of this build as JSON
+    #[arg(long, exclusive = true)]
+    capabilities: bool,
+
+    /// Read defaults from this file instead of ~/.config/contrast-checker/config.toml
+    #[arg(long, global = true)]
+    config: Option<PathBuf>,
+
+    /// Print how colors are parsed to stderr, twice to also print every luminance and ratio
+    #[arg(short, long, action = clap::ArgAction::Count, global = true)]
+    verbose: u8,
 }
 
-struct Color {
-    red: f32,
-    green: f32,
-    blue: f32,
+#[derive(Subcommand)]
+enum Command {
+    /// Reports the contrast of a single color against pure white and pure black
+    Range(RangeArgs),
+    /// Suggests the closest foreground, by lightness, that reaches the ratio against the background
+    Suggest(SuggestArgs),
+    /// Fixes a failing foreground by changing only its OKLCH lightness, keeping hue and chroma
+    Fix(FixArgs),
+    /// Prints the contrast of every combination of colors in a palette
+    Matrix(MatrixArgs),
+    /// Groups the colors of a palette that look almost the same, by their CIEDE2000 difference,
+    /// and suggests which one to merge each group into. Exits with 1 if there's any
+    Duplicates(DuplicatesArgs),
+    /// Sorts colors by relative luminance, darkest first, or by contrast against a color, highest
+    /// first
+    Sort(SortArgs),
+    /// Checks every foreground of a list on every background of another, printing pairs as they're
+    /// checked and the failure rate, and exits with 1 if any fails
+    Cross(CrossArgs),
+    /// Finds colors with a different hue but the same luminance, so swapping them keeps contrast
+    SameLuminance(SameLuminanceArgs),
+    /// Checks many foreground and background pairs, one per line, and exits with 1 if any fails
+    Batch(BatchArgs),
+    /// Opens a terminal UI to adjust both colors with the arrow keys and see the ratio update live
+    Interactive(InteractiveArgs),
+    /// Checks the text, border and icon colors of a UI component against its background, each with
+    /// the threshold of its success criterion
+    Component(ComponentArgs),
+    /// Flattens a surface made of a base and translucent overlays, like elevation overlays, and
+    /// checks a text color on top of it
+    Surface(SurfaceArgs),
+    /// Checks a focus indicator against the component it's on and the background around it, each
+    /// adjacency needs 3:1
+    Focus(FocusArgs),
+    /// Checks that links can be told apart from the body text by color alone: 3:1 against the
+    /// text, and both readable on the background
+    Link(LinkArgs),
+    /// Audits the color and background-color declarations of a CSS file and reports failing rules
+    Scan(ScanArgs),
+    /// Generates tints and shades of a color and flags which ones work for AA text
+    Scale(ScaleArgs),
+    /// Keeps the candidate foregrounds that reach the ratio against a background, best first
+    Filter(FilterArgs),
+    /// Picks the text color with the highest contrast on a background, black or white by default
+    Pick(PickArgs),
+    /// Audits the color pairs of a design tokens JSON file, or every combination of its colors
+    Tokens(TokensArgs),
+    /// Reads the history of batch --record and tells whether the score improves, exits with 1 if
+    /// the latest run is worse than the one before it
+    Trend(TrendArgs),
+    /// Checks the color-contrast results of axe-core again, with WCAG 2 and APCA, and exits with 1
+    /// if any node disagrees
+    Axe(AxeArgs),
+    /// Audits the color parameters of a Hugo, Jekyll or MkDocs theme configuration, every text
+    /// color on its background
+    Site(SiteArgs),
+    /// Checks a text color along a two stop gradient and reports where it drops below the ratio
+    Gradient(GradientArgs),
+    /// Screens the keyframes of an animation for flashing: the luminance of each, the largest jump
+    /// and the flashes in the worst second. Exits with 1 if there are more than three
+    Flash(FlashArgs),
+    /// Converts colors between hex, rgb(), hsl() and oklch()
+    Convert(ConvertArgs),
+    /// Compares the contrast of the pairs of two versions of a tokens file and exits with 1 if any
+    /// pair newly fails the ratio
+    Diff(DiffArgs),
+    /// Generates random colors that all reach the ratio against a background, for chart palettes
+    Generate(GenerateArgs),
+    /// Prints shell completions for bash, zsh, fish, elvish or PowerShell, or a man page with --man
+    Completions(CompletionsArgs),
+    /// Reports the range of contrast of a text color over an image, or a region of it
+    #[cfg(feature = "image")]
+    Image(ImageArgs),
+    /// Finds the text in a screenshot and checks the contrast of each region of it, exits with 1
+    /// if any fails
+    #[cfg(feature = "image")]
+    Screenshot(ScreenshotArgs),
+    /// Runs a small HTTP API answering GET /contrast?fg=...&bg=... with JSON
+    #[cfg(feature = "server")]
+    Serve(ServeArgs),
+    /// Answers JSON-RPC requests on stdin, one per line, for plugins that spawn the tool: check,
+    /// suggest and palette
+    PluginHost(PluginHostArgs),
+    /// Waits for a global hotkey that picks the text and then the background color under the
+    /// pointer, and shows the ratio and grade in a notification
+    #[cfg(feature = "tray")]
+    Tray(TrayArgs),
+    /// Shows the contrast of the text under the pointer in an overlay that follows it, estimating
+    /// the text and background colors from the pixels around it
+    #[cfg(feature = "screen")]
+    Live(LiveArgs),
+    /// Audits the rendered text of a tab of Chrome through the DevTools protocol, with the colors
+    /// and backgrounds the browser computed, and exits with 1 if any fails
+    #[cfg(feature = "cdp")]
+    Page(PageArgs),
 }
 
-impl fmt::Display for Color {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "(r: {}, g: {}, b: {})", self.red, self.green, self.blue)
-    }
-}
-
-impl Color {
-    fn new(r: u8, g: u8, b: u8) -> Color {
-        Color {
-            red: f32::from(r),
-            green: f32::from(g),
-            blue: f32::from(b),
-        }
-    }
-
-    /// This function expects an RGB value of 6 hex digits with or without a starting #
-    fn from_hex(hex: &str) -> Result<Color, ColorFromHexError> {
-        if hex.is_empty() {
-            return Err(ColorFromHexError::InputIsEmpty);
-        }
-
-        if !hex.is_ascii() {
-            return Err(ColorFromHexError::InputIsNotAscii);
-        }
-
-        let lowercase = hex.to_lowercase();
-        let mut trimmed_input = lowercase.trim();
-
-        // Only allow input like RRGGBB or #RRGGBB
-        if trimmed_input.len() < 6 || trimmed_input.len() > 7 {
-            return Err(ColorFromHexError::InvalidInputLength);
-        }
-
-        if trimmed_input.starts_with("#") {
-            trimmed_input = &trimmed_input[1..];
-        }
-
-        let red_hex = &trimmed_input[0..2];
-        println!("red hex: {red_hex}");
-        let green_hex = &trimmed_input[2..4];
-        println!("green hex: {green_hex}");
-        let blue_hex = &trimmed_input[4..];
-        println!("blue hex: {blue_hex}");
-
-        Ok(Color {
-            red: f32::from(hex_to_dec(red_hex).unwrap()),
-            green: f32::from(hex_to_dec(green_hex).unwrap()),
-            blue: f32::from(hex_to_dec(blue_hex).unwrap()),
-        })
+fn run(cli: &Cli) -> Result<ExitCode, Error> {
+    let success = |()| ExitCode::SUCCESS;
+    if cli.capabilities {
+        return cli::capabilities::run(&Cli::command()).map(success);
     }
-
-    fn normalize(&self) -> Color {
-        Color {
-            red: self.red / 255f32,
-            green: self.green / 255f32,
-            blue: self.blue / 255f32,
+    let config = Config::load(cli.config.as_deref())?;
+    cli::set_palettes(config.registry().map_err(Error::config)?);
+
+    match (&cli.command, &cli.check) {
+        (Some(Command::Range(args)), _) => cli::range::run(args).map(success),
+        (Some(Command::Suggest(args)), _) => cli::suggest::run(args, &config).map(success),
+        (Some(Command::Fix(args)), _) => cli::fix::run(args, &config).map(success),
+        (Some(Command::Matrix(args)), _) => cli::matrix::run(args, &config).map(success),
+        (Some(Command::Duplicates(args)), _) => cli::duplicates::run(args, &config),
+        (Some(Command::Sort(args)), _) => cli::sort::run(args, &config).map(success),
+        (Some(Command::Cross(args)), _) => cli::cross::run(args, &config),
+        (Some(Command::SameLuminance(args)), _) => cli::same_luminance::run(args).map(success),
+        (Some(Command::Batch(args)), _) => cli::batch::run(args, &config),
+        (Some(Command::Interactive(args)), _) => cli::interactive::run(args).map(success),
+        (Some(Command::Component(args)), _) => cli::component::run(args, &config),
+        (Some(Command::Surface(args)), _) => cli::surface::run(args, &config),
+        (Some(Command::Focus(args)), _) => cli::focus::run(args, &config),
+        (Some(Command::Link(args)), _) => cli::link::run(args, &config),
+        (Some(Command::Scan(args)), _) => cli::scan::run(args, &config),
+        (Some(Command::Scale(args)), _) => cli::scale::run(args, &config).map(success),
+        (Some(Command::Filter(args)), _) => cli::filter::run(args).map(success),
+        (Some(Command::Pick(args)), _) => cli::pick::run(args, &config).map(success),
+        (Some(Command::Tokens(args)), _) => cli::tokens::run(args, &config),
+        (Some(Command::Trend(args)), _) => cli::trend::run(args),
+        (Some(Command::Site(args)), _) => cli::site::run(args, &config),
+        (Some(Command::Axe(args)), _) => cli::axe::run(args),
+        (Some(Command::Gradient(args)), _) => cli::gradient::run(args),
+        (Some(Command::Flash(args)), _) => cli::flash::run(args),
+        (Some(Command::Convert(args)), _) => cli::convert::run(args, &config).map(success),
+        (Some(Command::Diff(args)), _) => cli::diff::run(args),
+        (Some(Command::Generate(args)), _) => cli::generate::run(args, &config).map(success),
+        (Some(Command::Completions(args)), _) => {
+            cli::completions::run(args, Cli::command()).map(success)
         }
+        #[cfg(feature = "image")]
+        (Some(Command::Image(args)), _) => cli::image::run(args).map(success),
+        #[cfg(feature = "image")]
+        (Some(Command::Screenshot(args)), _) => cli::screenshot::run(args),
+        #[cfg(feature = "server")]
+        (Some(Command::Serve(args)), _) => cli::serve::run(args).map(success),
+        (Some(Command::PluginHost(args)), _) => cli::plugin_host::run(args).map(success),
+        #[cfg(feature = "tray")]
+        (Some(Command::Tray(args)), _) => cli::tray::run(args).map(success),
+        #[cfg(feature = "screen")]
+        (Some(Command::Live(args)), _) => cli::live::run(args).map(success),
+        #[cfg(feature = "cdp")]
+        (Some(Command::Page(args)), _) => cli::page::run(args, &config),
+        (None, Some(args)) => cli::check::run(args, &config),
+        // clap requires either a subcommand or the check arguments
+        (None, None) => unreachable!(),
     }
 }
 
-/// This expects the input component to be normalized by dividing it by 255.
-/// CIE XYZ is a device independent color space
-/// Magical values come from the official sRGB spec. https://en.wikipedia.org/wiki/SRGB
-fn srgb_component_to_cie_xyz(normalized_component: f32) -> f32 {
-    if normalized_component <= 0.04045 {
-        normalized_component / 12.92
-    } else {
-        let tmp: f32 = (normalized_component + 0.055) / 1.055;
-        tmp.powf(2.4f32)
-    }
-}
-
-/// Formula to calculate relative luminance obtained from https://www.w3.org/TR/WCAG21/relative-luminance.html
-/// Magical values come from the official sRGB spec. https://en.wikipedia.org/wiki/SRGB
-fn relative_luminance(color: &Color) -> f32 {
-    let normalized_color = color.normalize();
-
-    let red_component_luminance: f32 = srgb_component_to_cie_xyz(normalized_color.red);
-    let green_component_luminance: f32 = srgb_component_to_cie_xyz(normalized_color.green);
-    let blue_component_luminance: f32 = srgb_component_to_cie_xyz(normalized_color.blue);
-
-    0.2126 * red_component_luminance
-        + 0.7152 * green_component_luminance
-        + 0.0722 * blue_component_luminance
-}
-
-/// Formula for contrast ratio obtained from https://www.w3.org/TR/WCAG21/#dfn-contrast-ratio
-fn contrast_ratio(foreground: &Color, background: &Color) -> f32 {
-    let foreground_luminance = relative_luminance(foreground);
-    let background_luminance = relative_luminance(background);
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+    cli::verbose::init(cli.verbose);
 
-    if foreground_luminance > background_luminance {
-        (foreground_luminance + 0.05) / (background_luminance + 0.05)
-    } else {
-        (background_luminance + 0.05) / (foreground_luminance + 0.05)
+    match run(&cli) {
+        Ok(code) => code,
+        Err(err) => {
+            eprintln!("error: {err}");
+            err.status.into()
+        }
     }
 }
-
-fn main() {
-    let white = Color::from_hex("#FFFFFF").unwrap();
-    println!("white from hex: {white}");
-    let white_luminance = relative_luminance(&white);
-
-    let target = Color::new(242, 108, 167);
-    println!("target from rgb: {target}");
-    let target_luminance = relative_luminance(&target);
-
-    println!("luminance of white is {}", white_luminance);
-    println!("luminance of target is {}", target_luminance);
-    println!(
-        "contrast target, white is {}",
-        contrast_ratio(&target, &white)
-    );
-    println!(
-        "contrast white, target is {}",
-        contrast_ratio(&white, &target)
-    );
-}
@@ -0,0 +1,82 @@
+//! Float functions that come from std, implemented with `libm` for `no_std` builds. Modules
+//! import [`Float`] only without std, so the calls look the same in both builds.
+
+pub(crate) trait Float: Sized {
+    fn atan2(self, other: Self) -> Self;
+    fn cbrt(self) -> Self;
+    fn cos(self) -> Self;
+    fn exp(self) -> Self;
+    fn floor(self) -> Self;
+    fn hypot(self, other: Self) -> Self;
+    fn powf(self, n: Self) -> Self;
+    fn powi(self, n: i32) -> Self;
+    fn rem_euclid(self, rhs: Self) -> Self;
+    fn round(self) -> Self;
+    fn sin(self) -> Self;
+    fn sqrt(self) -> Self;
+}
+
+macro_rules! impl_float {
+    ($float:ty, $abs:ident, $atan2:ident, $cbrt:ident, $cos:ident, $exp:ident, $floor:ident,
+     $hypot:ident, $pow:ident, $fmod:ident, $round:ident, $sin:ident, $sqrt:ident) => {
+        impl Float for $float {
+            fn atan2(self, other: Self) -> Self {
+                libm::$atan2(self, other)
+            }
+
+            fn cbrt(self) -> Self {
+                libm::$cbrt(self)
+            }
+
+            fn cos(self) -> Self {
+                libm::$cos(self)
+            }
+
+            fn exp(self) -> Self {
+                libm::$exp(self)
+            }
+
+            fn floor(self) -> Self {
+                libm::$floor(self)
+            }
+
+            fn hypot(self, other: Self) -> Self {
+                libm::$hypot(self, other)
+            }
+
+            fn powf(self, n: Self) -> Self {
+                libm::$pow(self, n)
+            }
+
+            fn powi(self, n: i32) -> Self {
+                libm::$pow(self, n as $float)
+            }
+
+            fn rem_euclid(self, rhs: Self) -> Self {
+                let remainder = libm::$fmod(self, rhs);
+                if remainder < 0.0 {
+                    remainder + libm::$abs(rhs)
+                } else {
+                    remainder
+                }
+            }
+
+            fn round(self) -> Self {
+                libm::$round(self)
+            }
+
+            fn sin(self) -> Self {
+                libm::$sin(self)
+            }
+
+            fn sqrt(self) -> Self {
+                libm::$sqrt(self)
+            }
+        }
+    };
+}
+
+impl_float!(
+    f32, fabsf, atan2f, cbrtf, cosf, expf, floorf, hypotf, powf, fmodf, roundf, sinf, sqrtf
+);
+impl_float!(f64, fabs, atan2, cbrt, cos, exp, floor, hypot, pow, fmod, round, sin, sqrt);
@@ -0,0 +1,170 @@
+#[cfg(not(any(feature = "std", test)))]
+use crate::math::Float;
+use crate::{linear_to_srgb, srgb_to_linear, Color};
+
+/// Converts OKLab into linear sRGB, the result can be outside of `0.0..=1.0` for colors that
+/// sRGB can't show. Matrices obtained from https://bottosson.github.io/posts/oklab/
+fn oklab_to_linear(lightness: f32, a: f32, b: f32) -> [f32; 3] {
+    // The matrices are rounded, so grays would come out with slightly different channels
+    if a == 0.0 && b == 0.0 {
+        return [lightness.powi(3); 3];
+    }
+
+    let l = (lightness + 0.396_337_78 * a + 0.215_803_76 * b).powi(3);
+    let m = (lightness - 0.105_561_346 * a - 0.063_854_17 * b).powi(3);
+    let s = (lightness - 0.089_484_18 * a - 1.291_485_5 * b).powi(3);
+
+    [
+        4.076_741_7 * l - 3.307_711_6 * m + 0.230_969_94 * s,
+        -1.268_438 * l + 2.609_757_4 * m - 0.341_319_4 * s,
+        -0.004_196_086_3 * l - 0.703_418_6 * m + 1.707_614_7 * s,
+    ]
+}
+
+/// Converts linear sRGB into OKLab, channels outside of `0.0..=1.0` work too so colors of wider
+/// gamuts can be converted before being mapped into sRGB.
+pub(crate) fn linear_to_oklab([red, green, blue]: [f32; 3]) -> (f32, f32, f32) {
+    let l = (0.412_221_46 * red + 0.536_332_55 * green + 0.051_445_995 * blue).cbrt();
+    let m = (0.211_903_5 * red + 0.680_699_5 * green + 0.107_396_96 * blue).cbrt();
+    let s = (0.088_302_46 * red + 0.281_718_85 * green + 0.629_978_7 * blue).cbrt();
+
+    (
+        0.210_454_26 * l + 0.793_617_8 * m - 0.004_072_047 * s,
+        1.977_998_5 * l - 2.428_592_2 * m + 0.450_593_7 * s,
+        0.025_904_037 * l + 0.782_771_77 * m - 0.808_675_77 * s,
+    )
+}
+
+pub(crate) fn linear_to_color(linear: [f32; 3]) -> Color {
+    let [red, green, blue] = linear.map(|channel| linear_to_srgb(channel) * 255.0);
+
+    Color::from_f32_channels(red, green, blue)
+}
+
+impl Color {
+    /// Returns the OKLab representation of the color as `(lightness, a, b)`. Lightness goes from
+    /// 0 to 1, `a` and `b` are roughly between -0.4 and 0.4. Formula obtained from
+    /// https://bottosson.github.io/posts/oklab/
+    pub fn to_oklab(&self) -> (f32, f32, f32) {
+        linear_to_oklab(self.normalized().map(srgb_to_linear))
+    }
+
+    /// Creates an opaque color from its OKLab representation. Colors outside of sRGB have their
+    /// channels clamped.
+    pub fn from_oklab(lightness: f32, a: f32, b: f32) -> Color {
+        linear_to_color(oklab_to_linear(lightness.clamp(0.0, 1.0), a, b))
+    }
+
+    /// Returns the OKLCH representation of the color as `(lightness, chroma, hue)`. Lightness
+    /// goes from 0 to 1, chroma is 0 for grays and rarely above 0.4, the hue is an angle in
+    /// degrees between 0 and 360.
+    pub fn to_oklch(&self) -> (f32, f32, f32) {
+        let (lightness, a, b) = self.to_oklab();
+        let chroma = a.hypot(b);
+
+        // The chroma and hue of grays are only rounding noise
+        if chroma < 1e-4 {
+            return (lightness, 0.0, 0.0);
+        }
+
+        (lightness, chroma, b.atan2(a).to_degrees().rem_euclid(360.0))
+    }
+
+    /// Creates an opaque color from its OKLCH representation. The hue is an angle in degrees.
+    ///
+    /// Colors outside of sRGB are brought into it by reducing the chroma, keeping the lightness
+    /// and hue, like CSS does. Clamping the channels instead would change lightness and hue,
+    /// which are the reason to use OKLCH in the first place.
+    pub fn from_oklch(lightness: f32, chroma: f32, hue: f32) -> Color {
+        let lightness = lightness.clamp(0.0, 1.0);
+        let hue = hue.to_radians();
+        let linear_at =
+            |chroma: f32| oklab_to_linear(lightness, chroma * hue.cos(), chroma * hue.sin());
+        let in_gamut = |linear: [f32; 3]| {
+            linear
+                .iter()
+                .all(|channel| (-1e-5..=1.0 + 1e-5).contains(channel))
+        };
+
+        let chroma = chroma.max(0.0);
+        if in_gamut(linear_at(chroma)) {
+            return linear_to_color(linear_at(chroma));
+        }
+
+        let mut inside = 0.0;
+        let mut outside = chroma;
+        for _ in 0..24 {
+            let middle = (inside + outside) / 2.0;
+            if in_gamut(linear_at(middle)) {
+                inside = middle;
+            } else {
+                outside = middle;
+            }
+        }
+
+        linear_to_color(linear_at(inside))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_oklab(actual: (f32, f32, f32), expected: (f32, f32, f32), tolerance: f32) {
+        let (l, a, b) = actual;
+        let (expected_l, expected_a, expected_b) = expected;
+        assert!(
+            (l - expected_l).abs() <= tolerance
+                && (a - expected_a).abs() <= tolerance
+                && (b - expected_b).abs() <= tolerance,
+            "expected {expected:?}, got {actual:?}"
+        );
+    }
+
+    /// The table of https://bottosson.github.io/posts/oklab/ is in XYZ, which is converted into
+    /// linear sRGB with the matrix obtained from
+    /// http://www.brucelindbloom.com/index.html?Eqn_RGB_XYZ_Matrix.html
+    #[test]
+    fn matches_the_reference_values() {
+        let xyz_to_linear = |[x, y, z]: [f32; 3]| {
+            [
+                3.240_454_2 * x - 1.537_138_5 * y - 0.498_531_4 * z,
+                -0.969_266 * x + 1.876_010_8 * y + 0.041_556 * z,
+                0.055_643_4 * x - 0.204_025_9 * y + 1.057_225_2 * z,
+            ]
+        };
+        let table = [
+            ([0.95, 1.0, 1.089], (1.0, 0.0, 0.0)),
+            ([1.0, 0.0, 0.0], (0.45, 1.236, -0.019)),
+            ([0.0, 1.0, 0.0], (0.922, -0.671, 0.263)),
+            ([0.0, 0.0, 1.0], (0.153, -1.415, -0.449)),
+        ];
+        for (xyz, expected) in table {
+            // The table is rounded to 3 decimals
+            assert_oklab(linear_to_oklab(xyz_to_linear(xyz)), expected, 1e-3);
+        }
+    }
+
+    /// Values of the sample colors of https://www.w3.org/TR/css-color-4/#specifying-oklab-oklch
+    #[test]
+    fn converts_the_srgb_primaries() {
+        let oklab = |hex: &str| Color::from_hex(hex).unwrap().to_oklab();
+        assert_oklab(oklab("#ff0000"), (0.627_955, 0.224_863, 0.125_846), 1e-4);
+        assert_oklab(oklab("#00ff00"), (0.866_440, -0.233_888, 0.179_498), 1e-4);
+        assert_oklab(oklab("#0000ff"), (0.452_014, -0.032_457, -0.311_528), 1e-4);
+        assert_oklab(oklab("#ffffff"), (1.0, 0.0, 0.0), 1e-4);
+    }
+
+    #[test]
+    fn colors_survive_a_round_trip() {
+        for hex in [
+            "#000000", "#767676", "#f26ca7", "#00ff00", "#0000ff", "#ffffff",
+        ] {
+            let (l, a, b) = Color::from_hex(hex).unwrap().to_oklab();
+            assert_eq!(Color::from_oklab(l, a, b).to_hex(), hex);
+
+            let (l, c, h) = Color::from_hex(hex).unwrap().to_oklch();
+            assert_eq!(Color::from_oklch(l, c, h).to_hex(), hex);
+        }
+    }
+}
@@ -0,0 +1,73 @@
+use crate::contrast::visible_colors;
+use crate::{contrast_ratio, linear_to_srgb, relative_luminance, srgb_to_linear, Color};
+
+/// How readable text is over a background that isn't a flat color, like a photo.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct OverlayContrast {
+    /// Number of background pixels that were sampled.
+    pub samples: usize,
+    pub min_luminance: f32,
+    pub average_luminance: f32,
+    pub max_luminance: f32,
+    /// The average of the background, which is what a multi-layer surface built on it starts
+    /// from, see [`SurfaceStack`](crate::SurfaceStack).
+    pub average: Color,
+    /// Contrast against the average of the background, roughly what the text looks like on a
+    /// blurred or distant version of it.
+    pub average_ratio: f32,
+    /// Contrast against the pixel that is closest to the text, the parts of the text over it
+    /// are the hardest to read.
+    pub worst_ratio: f32,
+    pub best_ratio: f32,
+}
+
+/// Computes the range of contrast of a text color over every pixel of a background. Pixels are
+/// averaged in linear light, like the eye does when they are too small to tell apart. Returns
+/// `None` when there are no pixels.
+pub fn overlay_contrast(
+    text: &Color,
+    background: impl IntoIterator<Item = Color>,
+) -> Option<OverlayContrast> {
+    let mut samples = 0;
+    let mut min_luminance = f32::INFINITY;
+    let mut max_luminance = f32::NEG_INFINITY;
+    let mut worst_ratio = f32::INFINITY;
+    let mut best_ratio = f32::NEG_INFINITY;
+    let mut linear_sum = [0.0f64; 3];
+
+    for pixel in background {
+        let (_, pixel) = visible_colors(text, &pixel);
+        let luminance = relative_luminance(&pixel);
+        let ratio = contrast_ratio(text, &pixel);
+
+        samples += 1;
+        min_luminance = min_luminance.min(luminance);
+        max_luminance = max_luminance.max(luminance);
+        worst_ratio = worst_ratio.min(ratio);
+        best_ratio = best_ratio.max(ratio);
+        // f64 so that big images don't lose precision
+        for (sum, channel) in linear_sum.iter_mut().zip(pixel.normalized()) {
+            *sum += f64::from(srgb_to_linear(channel));
+        }
+    }
+
+    if samples == 0 {
+        return None;
+    }
+
+    let [red, green, blue] =
+        linear_sum.map(|sum| linear_to_srgb((sum / samples as f64) as f32) * 255.0);
+    let average = Color::from_f32_channels(red, green, blue);
+
+    Some(OverlayContrast {
+        samples,
+        min_luminance,
+        average_luminance: relative_luminance(&average),
+        max_luminance,
+        average_ratio: contrast_ratio(text, &average),
+        average,
+        worst_ratio,
+        best_ratio,
+    })
+}
@@ -0,0 +1,238 @@
+//! Palettes whose colors can be referred to by name, like `tailwind:sky-500`. The Tailwind,
+//! Material Design, Open Color and XKCD palettes are built in with the `palettes` feature, and
+//! custom ones can be registered, e.g. from a configuration or tokens file.
+
+use alloc::borrow::Cow;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+
+#[cfg(feature = "palettes")]
+mod builtin;
+#[cfg(feature = "wasm-plugins")]
+mod wasm;
+
+use crate::Color;
+
+#[cfg(feature = "wasm-plugins")]
+pub use wasm::{PluginError, WasmPaletteSource};
+
+/// The colors of a palette, the built-in ones are borrowed from their static tables.
+#[derive(Debug, Clone)]
+enum Colors {
+    #[cfg(feature = "palettes")]
+    Builtin(&'static builtin::BuiltinColors),
+    Custom(Vec<(String, Color)>),
+}
+
+/// A palette: its name and its colors by name.
+#[derive(Debug, Clone)]
+pub struct Palette {
+    name: Cow<'static, str>,
+    colors: Colors,
+}
+
+impl Palette {
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Looks up a color by name, ignoring case.
+    pub fn get(&self, name: &str) -> Option<Color> {
+        match &self.colors {
+            #[cfg(feature = "palettes")]
+            Colors::Builtin(colors) => {
+                let name = name.as_bytes();
+                if name.len() > builtin::LONGEST_NAME {
+                    return None;
+                }
+
+                // Lowercased on the stack like the names of the tables
+                let mut lowercase = [0; builtin::LONGEST_NAME];
+                let lowercase = &mut lowercase[..name.len()];
+                lowercase.copy_from_slice(name);
+                lowercase.make_ascii_lowercase();
+                colors.get(core::str::from_utf8(lowercase).ok()?).copied()
+            }
+            Colors::Custom(colors) => colors
+                .iter()
+                .find(|(color, _)| color.eq_ignore_ascii_case(name))
+                .map(|(_, color)| *color),
+        }
+    }
+
+    /// The colors by name, in the order of the palette.
+    pub fn colors(&self) -> impl Iterator<Item = (&str, Color)> + '_ {
+        let (builtin, custom): (Option<phf::ordered_map::Entries<&str, Color>>, _) =
+            match &self.colors {
+                #[cfg(feature = "palettes")]
+                Colors::Builtin(colors) => (Some(colors.entries()), None),
+                Colors::Custom(colors) => (None, Some(colors.iter())),
+            };
+        let builtin = builtin
+            .into_iter()
+            .flatten()
+            .map(|(name, color)| (*name, *color));
+        let custom = custom
+            .into_iter()
+            .flatten()
+            .map(|(name, color)| (name.as_str(), *color));
+        builtin.chain(custom)
+    }
+}
+
+impl PartialEq for Palette {
+    fn eq(&self, other: &Palette) -> bool {
+        self.name == other.name && self.colors().eq(other.colors())
+    }
+}
+
+/// Palettes that colors can be looked up in by `palette:color` references.
+///
+/// ```
+/// use contrast_checker::palettes::PaletteRegistry;
+/// use contrast_checker::Color;
+///
+/// let mut registry = PaletteRegistry::new();
+/// registry.register("brand", vec![("pink".to_string(), Color::new(0xf2, 0x6c, 0xa7))]);
+///
+/// assert_eq!(registry.get("brand:pink"), Some(Color::new(0xf2, 0x6c, 0xa7)));
+/// assert_eq!(registry.get("brand:blue"), None);
+/// ```
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct PaletteRegistry {
+    palettes: Vec<Palette>,
+}
+
+impl PaletteRegistry {
+    /// A registry without any palette.
+    pub fn new() -> PaletteRegistry {
+        PaletteRegistry::default()
+    }
+
+    /// A registry with the built-in palettes, `tailwind` (v3, shades 50 to 950), `material` (the
+    /// 2014 palette, 50 to 900), `open-color` (0 to 9) and `xkcd` (the 949 colors of its survey).
+    /// Colors are named by hue and shade, like `tailwind:sky-500`, `material:blue-grey-700` or
+    /// `open-color:grape-6`, and by their survey name in XKCD, like `xkcd:dusty rose`. Their
+    /// colors aren't copied, the registry borrows the static tables.
+    ///
+    /// ```
+    /// use contrast_checker::palettes::PaletteRegistry;
+    ///
+    /// let registry = PaletteRegistry::with_builtins();
+    /// assert_eq!(registry.get("tailwind:sky-500").unwrap().to_hex(), "#0ea5e9");
+    /// assert_eq!(registry.get("material:red-500").unwrap().to_hex(), "#f44336");
+    /// assert_eq!(registry.get("xkcd:Dusty Rose").unwrap().to_hex(), "#c0737a");
+    /// ```
+    #[cfg(feature = "palettes")]
+    pub fn with_builtins() -> PaletteRegistry {
+        let palettes = builtin::ALL
+            .iter()
+            .map(|(name, colors)| Palette {
+                name: Cow::Borrowed(name),
+                colors: Colors::Builtin(colors),
+            })
+            .collect();
+
+        PaletteRegistry { palettes }
+    }
+
+    /// Adds a palette, replacing the one with the same name, ignoring case like lookups do, if
+    /// there's one.
+    pub fn register(&mut self, name: &str, colors: Vec<(String, Color)>) {
+        self.palettes
+            .retain(|palette| !palette.name.eq_ignore_ascii_case(name));
+        self.palettes.push(Palette {
+            name: Cow::Owned(name.to_string()),
+            colors: Colors::Custom(colors),
+        });
+    }
+
+    pub fn palette(&self, name: &str) -> Option<&Palette> {
+        self.palettes
+            .iter()
+            .find(|palette| palette.name.eq_ignore_ascii_case(name))
+    }
+
+    pub fn palettes(&self) -> &[Palette] {
+        &self.palettes
+    }
+
+    /// Looks up a reference like `tailwind:sky-500`, ignoring case.
+    pub fn get(&self, reference: &str) -> Option<Color> {
+        let (palette, color) = reference.trim().split_once(':')?;
+        self.palette(palette.trim())?.get(color.trim())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[cfg(feature = "palettes")]
+    #[test]
+    fn builtin_palettes_have_every_color() {
+        let sizes: Vec<(&str, usize)> = builtin::ALL
+            .iter()
+            .map(|(name, colors)| (*name, colors.len()))
+            .collect();
+        assert_eq!(
+            sizes,
+            [
+                ("tailwind", 22 * 11),
+                ("material", 19 * 10),
+                ("open-color", 13 * 10),
+                ("xkcd", 949)
+            ]
+        );
+
+        // Lookups lowercase into a buffer of the longest name
+        for (_, colors) in builtin::ALL {
+            for name in colors.keys() {
+                assert!(name.len() <= builtin::LONGEST_NAME);
+                assert_eq!(*name, name.to_lowercase());
+            }
+        }
+    }
+
+    #[cfg(feature = "palettes")]
+    #[test]
+    fn builtin_colors_are_in_the_order_of_the_palette() {
+        let registry = PaletteRegistry::with_builtins();
+        let tailwind = registry.palette("tailwind").unwrap();
+        let names: Vec<&str> = tailwind.colors().take(3).map(|(name, _)| name).collect();
+        assert_eq!(names, ["slate-50", "slate-100", "slate-200"]);
+        assert_eq!(
+            registry.get("xkcd:blue with a hint of purple"),
+            Some(Color::new(0x53, 0x3c, 0xc6))
+        );
+        assert_eq!(registry.get("xkcd:blue with a hint of purple!"), None);
+    }
+
+    #[cfg(feature = "palettes")]
+    #[test]
+    fn references_ignore_case_and_replaced_palettes_are_gone() {
+        let mut registry = PaletteRegistry::with_builtins();
+        assert_eq!(
+            registry.get("Open-Color:Blue-5"),
+            Some(Color::new(0x33, 0x9a, 0xf0))
+        );
+
+        registry.register("tailwind", Vec::new());
+        assert_eq!(registry.get("tailwind:sky-500"), None);
+        assert_eq!(registry.palettes().len(), 4);
+    }
+
+    #[test]
+    fn registering_replaces_a_palette_whatever_its_case() {
+        let mut registry = PaletteRegistry::new();
+        registry.register(
+            "Brand",
+            vec![("pink".to_string(), Color::new(0xf2, 0x6c, 0xa7))],
+        );
+        registry.register("brand", vec![("blue".to_string(), Color::new(0, 0, 0xff))]);
+
+        assert_eq!(registry.palettes().len(), 1);
+        assert_eq!(registry.get("BRAND:pink"), None);
+        assert_eq!(registry.get("brand:blue"), Some(Color::new(0, 0, 0xff)));
+    }
+}
@@ -0,0 +1,1548 @@
+//! Colors of well-known palettes by name, in perfect hash maps built at compile time so looking a
+//! color up is a single hash and comparison. Their order is the one of the published palette.
+
+use phf::phf_ordered_map;
+
+use crate::Color;
+
+/// A built-in palette: its name and its colors, whose names are lowercase.
+pub(crate) type BuiltinColors = phf::OrderedMap<&'static str, Color>;
+
+/// Tailwind CSS v3, shades 50 to 950, obtained from https://tailwindcss.com/docs/customizing-colors
+pub(crate) static TAILWIND: BuiltinColors = phf_ordered_map! {
+    "slate-50" => Color::new(0xf8, 0xfa, 0xfc),
+    "slate-100" => Color::new(0xf1, 0xf5, 0xf9),
+    "slate-200" => Color::new(0xe2, 0xe8, 0xf0),
+    "slate-300" => Color::new(0xcb, 0xd5, 0xe1),
+    "slate-400" => Color::new(0x94, 0xa3, 0xb8),
+    "slate-500" => Color::new(0x64, 0x74, 0x8b),
+    "slate-600" => Color::new(0x47, 0x55, 0x69),
+    "slate-700" => Color::new(0x33, 0x41, 0x55),
+    "slate-800" => Color::new(0x1e, 0x29, 0x3b),
+    "slate-900" => Color::new(0x0f, 0x17, 0x2a),
+    "slate-950" => Color::new(0x02, 0x06, 0x17),
+    "gray-50" => Color::new(0xf9, 0xfa, 0xfb),
+    "gray-100" => Color::new(0xf3, 0xf4, 0xf6),
+    "gray-200" => Color::new(0xe5, 0xe7, 0xeb),
+    "gray-300" => Color::new(0xd1, 0xd5, 0xdb),
+    "gray-400" => Color::new(0x9c, 0xa3, 0xaf),
+    "gray-500" => Color::new(0x6b, 0x72, 0x80),
+    "gray-600" => Color::new(0x4b, 0x55, 0x63),
+    "gray-700" => Color::new(0x37, 0x41, 0x51),
+    "gray-800" => Color::new(0x1f, 0x29, 0x37),
+    "gray-900" => Color::new(0x11, 0x18, 0x27),
+    "gray-950" => Color::new(0x03, 0x07, 0x12),
+    "zinc-50" => Color::new(0xfa, 0xfa, 0xfa),
+    "zinc-100" => Color::new(0xf4, 0xf4, 0xf5),
+    "zinc-200" => Color::new(0xe4, 0xe4, 0xe7),
+    "zinc-300" => Color::new(0xd4, 0xd4, 0xd8),
+    "zinc-400" => Color::new(0xa1, 0xa1, 0xaa),
+    "zinc-500" => Color::new(0x71, 0x71, 0x7a),
+    "zinc-600" => Color::new(0x52, 0x52, 0x5b),
+    "zinc-700" => Color::new(0x3f, 0x3f, 0x46),
+    "zinc-800" => Color::new(0x27, 0x27, 0x2a),
+    "zinc-900" => Color::new(0x18, 0x18, 0x1b),
+    "zinc-950" => Color::new(0x09, 0x09, 0x0b),
+    "neutral-50" => Color::new(0xfa, 0xfa, 0xfa),
+    "neutral-100" => Color::new(0xf5, 0xf5, 0xf5),
+    "neutral-200" => Color::new(0xe5, 0xe5, 0xe5),
+    "neutral-300" => Color::new(0xd4, 0xd4, 0xd4),
+    "neutral-400" => Color::new(0xa3, 0xa3, 0xa3),
+    "neutral-500" => Color::new(0x73, 0x73, 0x73),
+    "neutral-600" => Color::new(0x52, 0x52, 0x52),
+    "neutral-700" => Color::new(0x40, 0x40, 0x40),
+    "neutral-800" => Color::new(0x26, 0x26, 0x26),
+    "neutral-900" => Color::new(0x17, 0x17, 0x17),
+    "neutral-950" => Color::new(0x0a, 0x0a, 0x0a),
+    "stone-50" => Color::new(0xfa, 0xfa, 0xf9),
+    "stone-100" => Color::new(0xf5, 0xf5, 0xf4),
+    "stone-200" => Color::new(0xe7, 0xe5, 0xe4),
+    "stone-300" => Color::new(0xd6, 0xd3, 0xd1),
+    "stone-400" => Color::new(0xa8, 0xa2, 0x9e),
+    "stone-500" => Color::new(0x78, 0x71, 0x6c),
+    "stone-600" => Color::new(0x57, 0x53, 0x4e),
+    "stone-700" => Color::new(0x44, 0x40, 0x3c),
+    "stone-800" => Color::new(0x29, 0x25, 0x24),
+    "stone-900" => Color::new(0x1c, 0x19, 0x17),
+    "stone-950" => Color::new(0x0c, 0x0a, 0x09),
+    "red-50" => Color::new(0xfe, 0xf2, 0xf2),
+    "red-100" => Color::new(0xfe, 0xe2, 0xe2),
+    "red-200" => Color::new(0xfe, 0xca, 0xca),
+    "red-300" => Color::new(0xfc, 0xa5, 0xa5),
+    "red-400" => Color::new(0xf8, 0x71, 0x71),
+    "red-500" => Color::new(0xef, 0x44, 0x44),
+    "red-600" => Color::new(0xdc, 0x26, 0x26),
+    "red-700" => Color::new(0xb9, 0x1c, 0x1c),
+    "red-800" => Color::new(0x99, 0x1b, 0x1b),
+    "red-900" => Color::new(0x7f, 0x1d, 0x1d),
+    "red-950" => Color::new(0x45, 0x0a, 0x0a),
+    "orange-50" => Color::new(0xff, 0xf7, 0xed),
+    "orange-100" => Color::new(0xff, 0xed, 0xd5),
+    "orange-200" => Color::new(0xfe, 0xd7, 0xaa),
+    "orange-300" => Color::new(0xfd, 0xba, 0x74),
+    "orange-400" => Color::new(0xfb, 0x92, 0x3c),
+    "orange-500" => Color::new(0xf9, 0x73, 0x16),
+    "orange-600" => Color::new(0xea, 0x58, 0x0c),
+    "orange-700" => Color::new(0xc2, 0x41, 0x0c),
+    "orange-800" => Color::new(0x9a, 0x34, 0x12),
+    "orange-900" => Color::new(0x7c, 0x2d, 0x12),
+    "orange-950" => Color::new(0x43, 0x14, 0x07),
+    "amber-50" => Color::new(0xff, 0xfb, 0xeb),
+    "amber-100" => Color::new(0xfe, 0xf3, 0xc7),
+    "amber-200" => Color::new(0xfd, 0xe6, 0x8a),
+    "amber-300" => Color::new(0xfc, 0xd3, 0x4d),
+    "amber-400" => Color::new(0xfb, 0xbf, 0x24),
+    "amber-500" => Color::new(0xf5, 0x9e, 0x0b),
+    "amber-600" => Color::new(0xd9, 0x77, 0x06),
+    "amber-700" => Color::new(0xb4, 0x53, 0x09),
+    "amber-800" => Color::new(0x92, 0x40, 0x0e),
+    "amber-900" => Color::new(0x78, 0x35, 0x0f),
+    "amber-950" => Color::new(0x45, 0x1a, 0x03),
+    "yellow-50" => Color::new(0xfe, 0xfc, 0xe8),
+    "yellow-100" => Color::new(0xfe, 0xf9, 0xc3),
+    "yellow-200" => Color::new(0xfe, 0xf0, 0x8a),
+    "yellow-300" => Color::new(0xfd, 0xe0, 0x47),
+    "yellow-400" => Color::new(0xfa, 0xcc, 0x15),
+    "yellow-500" => Color::new(0xea, 0xb3, 0x08),
+    "yellow-600" => Color::new(0xca, 0x8a, 0x04),
+    "yellow-700" => Color::new(0xa1, 0x62, 0x07),
+    "yellow-800" => Color::new(0x85, 0x4d, 0x0e),
+    "yellow-900" => Color::new(0x71, 0x3f, 0x12),
+    "yellow-950" => Color::new(0x42, 0x20, 0x06),
+    "lime-50" => Color::new(0xf7, 0xfe, 0xe7),
+    "lime-100" => Color::new(0xec, 0xfc, 0xcb),
+    "lime-200" => Color::new(0xd9, 0xf9, 0x9d),
+    "lime-300" => Color::new(0xbe, 0xf2, 0x64),
+    "lime-400" => Color::new(0xa3, 0xe6, 0x35),
+    "lime-500" => Color::new(0x84, 0xcc, 0x16),
+    "lime-600" => Color::new(0x65, 0xa3, 0x0d),
+    "lime-700" => Color::new(0x4d, 0x7c, 0x0f),
+    "lime-800" => Color::new(0x3f, 0x62, 0x12),
+    "lime-900" => Color::new(0x36, 0x53, 0x14),
+    "lime-950" => Color::new(0x1a, 0x2e, 0x05),
+    "green-50" => Color::new(0xf0, 0xfd, 0xf4),
+    "green-100" => Color::new(0xdc, 0xfc, 0xe7),
+    "green-200" => Color::new(0xbb, 0xf7, 0xd0),
+    "green-300" => Color::new(0x86, 0xef, 0xac),
+    "green-400" => Color::new(0x4a, 0xde, 0x80),
+    "green-500" => Color::new(0x22, 0xc5, 0x5e),
+    "green-600" => Color::new(0x16, 0xa3, 0x4a),
+    "green-700" => Color::new(0x15, 0x80, 0x3d),
+    "green-800" => Color::new(0x16, 0x65, 0x34),
+    "green-900" => Color::new(0x14, 0x53, 0x2d),
+    "green-950" => Color::new(0x05, 0x2e, 0x16),
+    "emerald-50" => Color::new(0xec, 0xfd, 0xf5),
+    "emerald-100" => Color::new(0xd1, 0xfa, 0xe5),
+    "emerald-200" => Color::new(0xa7, 0xf3, 0xd0),
+    "emerald-300" => Color::new(0x6e, 0xe7, 0xb7),
+    "emerald-400" => Color::new(0x34, 0xd3, 0x99),
+    "emerald-500" => Color::new(0x10, 0xb9, 0x81),
+    "emerald-600" => Color::new(0x05, 0x96, 0x69),
+    "emerald-700" => Color::new(0x04, 0x78, 0x57),
+    "emerald-800" => Color::new(0x06, 0x5f, 0x46),
+    "emerald-900" => Color::new(0x06, 0x4e, 0x3b),
+    "emerald-950" => Color::new(0x02, 0x2c, 0x22),
+    "teal-50" => Color::new(0xf0, 0xfd, 0xfa),
+    "teal-100" => Color::new(0xcc, 0xfb, 0xf1),
+    "teal-200" => Color::new(0x99, 0xf6, 0xe4),
+    "teal-300" => Color::new(0x5e, 0xea, 0xd4),
+    "teal-400" => Color::new(0x2d, 0xd4, 0xbf),
+    "teal-500" => Color::new(0x14, 0xb8, 0xa6),
+    "teal-600" => Color::new(0x0d, 0x94, 0x88),
+    "teal-700" => Color::new(0x0f, 0x76, 0x6e),
+    "teal-800" => Color::new(0x11, 0x5e, 0x59),
+    "teal-900" => Color::new(0x13, 0x4e, 0x4a),
+    "teal-950" => Color::new(0x04, 0x2f, 0x2e),
+    "cyan-50" => Color::new(0xec, 0xfe, 0xff),
+    "cyan-100" => Color::new(0xcf, 0xfa, 0xfe),
+    "cyan-200" => Color::new(0xa5, 0xf3, 0xfc),
+    "cyan-300" => Color::new(0x67, 0xe8, 0xf9),
+    "cyan-400" => Color::new(0x22, 0xd3, 0xee),
+    "cyan-500" => Color::new(0x06, 0xb6, 0xd4),
+    "cyan-600" => Color::new(0x08, 0x91, 0xb2),
+    "cyan-700" => Color::new(0x0e, 0x74, 0x90),
+    "cyan-800" => Color::new(0x15, 0x5e, 0x75),
+    "cyan-900" => Color::new(0x16, 0x4e, 0x63),
+    "cyan-950" => Color::new(0x08, 0x33, 0x44),
+    "sky-50" => Color::new(0xf0, 0xf9, 0xff),
+    "sky-100" => Color::new(0xe0, 0xf2, 0xfe),
+    "sky-200" => Color::new(0xba, 0xe6, 0xfd),
+    "sky-300" => Color::new(0x7d, 0xd3, 0xfc),
+    "sky-400" => Color::new(0x38, 0xbd, 0xf8),
+    "sky-500" => Color::new(0x0e, 0xa5, 0xe9),
+    "sky-600" => Color::new(0x02, 0x84, 0xc7),
+    "sky-700" => Color::new(0x03, 0x69, 0xa1),
+    "sky-800" => Color::new(0x07, 0x59, 0x85),
+    "sky-900" => Color::new(0x0c, 0x4a, 0x6e),
+    "sky-950" => Color::new(0x08, 0x2f, 0x49),
+    "blue-50" => Color::new(0xef, 0xf6, 0xff),
+    "blue-100" => Color::new(0xdb, 0xea, 0xfe),
+    "blue-200" => Color::new(0xbf, 0xdb, 0xfe),
+    "blue-300" => Color::new(0x93, 0xc5, 0xfd),
+    "blue-400" => Color::new(0x60, 0xa5, 0xfa),
+    "blue-500" => Color::new(0x3b, 0x82, 0xf6),
+    "blue-600" => Color::new(0x25, 0x63, 0xeb),
+    "blue-700" => Color::new(0x1d, 0x4e, 0xd8),
+    "blue-800" => Color::new(0x1e, 0x40, 0xaf),
+    "blue-900" => Color::new(0x1e, 0x3a, 0x8a),
+    "blue-950" => Color::new(0x17, 0x25, 0x54),
+    "indigo-50" => Color::new(0xee, 0xf2, 0xff),
+    "indigo-100" => Color::new(0xe0, 0xe7, 0xff),
+    "indigo-200" => Color::new(0xc7, 0xd2, 0xfe),
+    "indigo-300" => Color::new(0xa5, 0xb4, 0xfc),
+    "indigo-400" => Color::new(0x81, 0x8c, 0xf8),
+    "indigo-500" => Color::new(0x63, 0x66, 0xf1),
+    "indigo-600" => Color::new(0x4f, 0x46, 0xe5),
+    "indigo-700" => Color::new(0x43, 0x38, 0xca),
+    "indigo-800" => Color::new(0x37, 0x30, 0xa3),
+    "indigo-900" => Color::new(0x31, 0x2e, 0x81),
+    "indigo-950" => Color::new(0x1e, 0x1b, 0x4b),
+    "violet-50" => Color::new(0xf5, 0xf3, 0xff),
+    "violet-100" => Color::new(0xed, 0xe9, 0xfe),
+    "violet-200" => Color::new(0xdd, 0xd6, 0xfe),
+    "violet-300" => Color::new(0xc4, 0xb5, 0xfd),
+    "violet-400" => Color::new(0xa7, 0x8b, 0xfa),
+    "violet-500" => Color::new(0x8b, 0x5c, 0xf6),
+    "violet-600" => Color::new(0x7c, 0x3a, 0xed),
+    "violet-700" => Color::new(0x6d, 0x28, 0xd9),
+    "violet-800" => Color::new(0x5b, 0x21, 0xb6),
+    "violet-900" => Color::new(0x4c, 0x1d, 0x95),
+    "violet-950" => Color::new(0x2e, 0x10, 0x65),
+    "purple-50" => Color::new(0xfa, 0xf5, 0xff),
+    "purple-100" => Color::new(0xf3, 0xe8, 0xff),
+    "purple-200" => Color::new(0xe9, 0xd5, 0xff),
+    "purple-300" => Color::new(0xd8, 0xb4, 0xfe),
+    "purple-400" => Color::new(0xc0, 0x84, 0xfc),
+    "purple-500" => Color::new(0xa8, 0x55, 0xf7),
+    "purple-600" => Color::new(0x93, 0x33, 0xea),
+    "purple-700" => Color::new(0x7e, 0x22, 0xce),
+    "purple-800" => Color::new(0x6b, 0x21, 0xa8),
+    "purple-900" => Color::new(0x58, 0x1c, 0x87),
+    "purple-950" => Color::new(0x3b, 0x07, 0x64),
+    "fuchsia-50" => Color::new(0xfd, 0xf4, 0xff),
+    "fuchsia-100" => Color::new(0xfa, 0xe8, 0xff),
+    "fuchsia-200" => Color::new(0xf5, 0xd0, 0xfe),
+    "fuchsia-300" => Color::new(0xf0, 0xab, 0xfc),
+    "fuchsia-400" => Color::new(0xe8, 0x79, 0xf9),
+    "fuchsia-500" => Color::new(0xd9, 0x46, 0xef),
+    "fuchsia-600" => Color::new(0xc0, 0x26, 0xd3),
+    "fuchsia-700" => Color::new(0xa2, 0x1c, 0xaf),
+    "fuchsia-800" => Color::new(0x86, 0x19, 0x8f),
+    "fuchsia-900" => Color::new(0x70, 0x1a, 0x75),
+    "fuchsia-950" => Color::new(0x4a, 0x04, 0x4e),
+    "pink-50" => Color::new(0xfd, 0xf2, 0xf8),
+    "pink-100" => Color::new(0xfc, 0xe7, 0xf3),
+    "pink-200" => Color::new(0xfb, 0xcf, 0xe8),
+    "pink-300" => Color::new(0xf9, 0xa8, 0xd4),
+    "pink-400" => Color::new(0xf4, 0x72, 0xb6),
+    "pink-500" => Color::new(0xec, 0x48, 0x99),
+    "pink-600" => Color::new(0xdb, 0x27, 0x77),
+    "pink-700" => Color::new(0xbe, 0x18, 0x5d),
+    "pink-800" => Color::new(0x9d, 0x17, 0x4d),
+    "pink-900" => Color::new(0x83, 0x18, 0x43),
+    "pink-950" => Color::new(0x50, 0x07, 0x24),
+    "rose-50" => Color::new(0xff, 0xf1, 0xf2),
+    "rose-100" => Color::new(0xff, 0xe4, 0xe6),
+    "rose-200" => Color::new(0xfe, 0xcd, 0xd3),
+    "rose-300" => Color::new(0xfd, 0xa4, 0xaf),
+    "rose-400" => Color::new(0xfb, 0x71, 0x85),
+    "rose-500" => Color::new(0xf4, 0x3f, 0x5e),
+    "rose-600" => Color::new(0xe1, 0x1d, 0x48),
+    "rose-700" => Color::new(0xbe, 0x12, 0x3c),
+    "rose-800" => Color::new(0x9f, 0x12, 0x39),
+    "rose-900" => Color::new(0x88, 0x13, 0x37),
+    "rose-950" => Color::new(0x4c, 0x05, 0x19),
+};
+
+/// The 2014 Material Design palette without the accent shades, 50 to 900, obtained from
+/// https://m2.material.io/design/color/the-color-system.html
+pub(crate) static MATERIAL: BuiltinColors = phf_ordered_map! {
+    "red-50" => Color::new(0xff, 0xeb, 0xee),
+    "red-100" => Color::new(0xff, 0xcd, 0xd2),
+    "red-200" => Color::new(0xef, 0x9a, 0x9a),
+    "red-300" => Color::new(0xe5, 0x73, 0x73),
+    "red-400" => Color::new(0xef, 0x53, 0x50),
+    "red-500" => Color::new(0xf4, 0x43, 0x36),
+    "red-600" => Color::new(0xe5, 0x39, 0x35),
+    "red-700" => Color::new(0xd3, 0x2f, 0x2f),
+    "red-800" => Color::new(0xc6, 0x28, 0x28),
+    "red-900" => Color::new(0xb7, 0x1c, 0x1c),
+    "pink-50" => Color::new(0xfc, 0xe4, 0xec),
+    "pink-100" => Color::new(0xf8, 0xbb, 0xd0),
+    "pink-200" => Color::new(0xf4, 0x8f, 0xb1),
+    "pink-300" => Color::new(0xf0, 0x62, 0x92),
+    "pink-400" => Color::new(0xec, 0x40, 0x7a),
+    "pink-500" => Color::new(0xe9, 0x1e, 0x63),
+    "pink-600" => Color::new(0xd8, 0x1b, 0x60),
+    "pink-700" => Color::new(0xc2, 0x18, 0x5b),
+    "pink-800" => Color::new(0xad, 0x14, 0x57),
+    "pink-900" => Color::new(0x88, 0x0e, 0x4f),
+    "purple-50" => Color::new(0xf3, 0xe5, 0xf5),
+    "purple-100" => Color::new(0xe1, 0xbe, 0xe7),
+    "purple-200" => Color::new(0xce, 0x93, 0xd8),
+    "purple-300" => Color::new(0xba, 0x68, 0xc8),
+    "purple-400" => Color::new(0xab, 0x47, 0xbc),
+    "purple-500" => Color::new(0x9c, 0x27, 0xb0),
+    "purple-600" => Color::new(0x8e, 0x24, 0xaa),
+    "purple-700" => Color::new(0x7b, 0x1f, 0xa2),
+    "purple-800" => Color::new(0x6a, 0x1b, 0x9a),
+    "purple-900" => Color::new(0x4a, 0x14, 0x8c),
+    "deep-purple-50" => Color::new(0xed, 0xe7, 0xf6),
+    "deep-purple-100" => Color::new(0xd1, 0xc4, 0xe9),
+    "deep-purple-200" => Color::new(0xb3, 0x9d, 0xdb),
+    "deep-purple-300" => Color::new(0x95, 0x75, 0xcd),
+    "deep-purple-400" => Color::new(0x7e, 0x57, 0xc2),
+    "deep-purple-500" => Color::new(0x67, 0x3a, 0xb7),
+    "deep-purple-600" => Color::new(0x5e, 0x35, 0xb1),
+    "deep-purple-700" => Color::new(0x51, 0x2d, 0xa8),
+    "deep-purple-800" => Color::new(0x45, 0x27, 0xa0),
+    "deep-purple-900" => Color::new(0x31, 0x1b, 0x92),
+    "indigo-50" => Color::new(0xe8, 0xea, 0xf6),
+    "indigo-100" => Color::new(0xc5, 0xca, 0xe9),
+    "indigo-200" => Color::new(0x9f, 0xa8, 0xda),
+    "indigo-300" => Color::new(0x79, 0x86, 0xcb),
+    "indigo-400" => Color::new(0x5c, 0x6b, 0xc0),
+    "indigo-500" => Color::new(0x3f, 0x51, 0xb5),
+    "indigo-600" => Color::new(0x39, 0x49, 0xab),
+    "indigo-700" => Color::new(0x30, 0x3f, 0x9f),
+    "indigo-800" => Color::new(0x28, 0x35, 0x93),
+    "indigo-900" => Color::new(0x1a, 0x23, 0x7e),
+    "blue-50" => Color::new(0xe3, 0xf2, 0xfd),
+    "blue-100" => Color::new(0xbb, 0xde, 0xfb),
+    "blue-200" => Color::new(0x90, 0xca, 0xf9),
+    "blue-300" => Color::new(0x64, 0xb5, 0xf6),
+    "blue-400" => Color::new(0x42, 0xa5, 0xf5),
+    "blue-500" => Color::new(0x21, 0x96, 0xf3),
+    "blue-600" => Color::new(0x1e, 0x88, 0xe5),
+    "blue-700" => Color::new(0x19, 0x76, 0xd2),
+    "blue-800" => Color::new(0x15, 0x65, 0xc0),
+    "blue-900" => Color::new(0x0d, 0x47, 0xa1),
+    "light-blue-50" => Color::new(0xe1, 0xf5, 0xfe),
+    "light-blue-100" => Color::new(0xb3, 0xe5, 0xfc),
+    "light-blue-200" => Color::new(0x81, 0xd4, 0xfa),
+    "light-blue-300" => Color::new(0x4f, 0xc3, 0xf7),
+    "light-blue-400" => Color::new(0x29, 0xb6, 0xf6),
+    "light-blue-500" => Color::new(0x03, 0xa9, 0xf4),
+    "light-blue-600" => Color::new(0x03, 0x9b, 0xe5),
+    "light-blue-700" => Color::new(0x02, 0x88, 0xd1),
+    "light-blue-800" => Color::new(0x02, 0x77, 0xbd),
+    "light-blue-900" => Color::new(0x01, 0x57, 0x9b),
+    "cyan-50" => Color::new(0xe0, 0xf7, 0xfa),
+    "cyan-100" => Color::new(0xb2, 0xeb, 0xf2),
+    "cyan-200" => Color::new(0x80, 0xde, 0xea),
+    "cyan-300" => Color::new(0x4d, 0xd0, 0xe1),
+    "cyan-400" => Color::new(0x26, 0xc6, 0xda),
+    "cyan-500" => Color::new(0x00, 0xbc, 0xd4),
+    "cyan-600" => Color::new(0x00, 0xac, 0xc1),
+    "cyan-700" => Color::new(0x00, 0x97, 0xa7),
+    "cyan-800" => Color::new(0x00, 0x83, 0x8f),
+    "cyan-900" => Color::new(0x00, 0x60, 0x64),
+    "teal-50" => Color::new(0xe0, 0xf2, 0xf1),
+    "teal-100" => Color::new(0xb2, 0xdf, 0xdb),
+    "teal-200" => Color::new(0x80, 0xcb, 0xc4),
+    "teal-300" => Color::new(0x4d, 0xb6, 0xac),
+    "teal-400" => Color::new(0x26, 0xa6, 0x9a),
+    "teal-500" => Color::new(0x00, 0x96, 0x88),
+    "teal-600" => Color::new(0x00, 0x89, 0x7b),
+    "teal-700" => Color::new(0x00, 0x79, 0x6b),
+    "teal-800" => Color::new(0x00, 0x69, 0x5c),
+    "teal-900" => Color::new(0x00, 0x4d, 0x40),
+    "green-50" => Color::new(0xe8, 0xf5, 0xe9),
+    "green-100" => Color::new(0xc8, 0xe6, 0xc9),
+    "green-200" => Color::new(0xa5, 0xd6, 0xa7),
+    "green-300" => Color::new(0x81, 0xc7, 0x84),
+    "green-400" => Color::new(0x66, 0xbb, 0x6a),
+    "green-500" => Color::new(0x4c, 0xaf, 0x50),
+    "green-600" => Color::new(0x43, 0xa0, 0x47),
+    "green-700" => Color::new(0x38, 0x8e, 0x3c),
+    "green-800" => Color::new(0x2e, 0x7d, 0x32),
+    "green-900" => Color::new(0x1b, 0x5e, 0x20),
+    "light-green-50" => Color::new(0xf1, 0xf8, 0xe9),
+    "light-green-100" => Color::new(0xdc, 0xed, 0xc8),
+    "light-green-200" => Color::new(0xc5, 0xe1, 0xa5),
+    "light-green-300" => Color::new(0xae, 0xd5, 0x81),
+    "light-green-400" => Color::new(0x9c, 0xcc, 0x65),
+    "light-green-500" => Color::new(0x8b, 0xc3, 0x4a),
+    "light-green-600" => Color::new(0x7c, 0xb3, 0x42),
+    "light-green-700" => Color::new(0x68, 0x9f, 0x38),
+    "light-green-800" => Color::new(0x55, 0x8b, 0x2f),
+    "light-green-900" => Color::new(0x33, 0x69, 0x1e),
+    "lime-50" => Color::new(0xf9, 0xfb, 0xe7),
+    "lime-100" => Color::new(0xf0, 0xf4, 0xc3),
+    "lime-200" => Color::new(0xe6, 0xee, 0x9c),
+    "lime-300" => Color::new(0xdc, 0xe7, 0x75),
+    "lime-400" => Color::new(0xd4, 0xe1, 0x57),
+    "lime-500" => Color::new(0xcd, 0xdc, 0x39),
+    "lime-600" => Color::new(0xc0, 0xca, 0x33),
+    "lime-700" => Color::new(0xaf, 0xb4, 0x2b),
+    "lime-800" => Color::new(0x9e, 0x9d, 0x24),
+    "lime-900" => Color::new(0x82, 0x77, 0x17),
+    "yellow-50" => Color::new(0xff, 0xfd, 0xe7),
+    "yellow-100" => Color::new(0xff, 0xf9, 0xc4),
+    "yellow-200" => Color::new(0xff, 0xf5, 0x9d),
+    "yellow-300" => Color::new(0xff, 0xf1, 0x76),
+    "yellow-400" => Color::new(0xff, 0xee, 0x58),
+    "yellow-500" => Color::new(0xff, 0xeb, 0x3b),
+    "yellow-600" => Color::new(0xfd, 0xd8, 0x35),
+    "yellow-700" => Color::new(0xfb, 0xc0, 0x2d),
+    "yellow-800" => Color::new(0xf9, 0xa8, 0x25),
+    "yellow-900" => Color::new(0xf5, 0x7f, 0x17),
+    "amber-50" => Color::new(0xff, 0xf8, 0xe1),
+    "amber-100" => Color::new(0xff, 0xec, 0xb3),
+    "amber-200" => Color::new(0xff, 0xe0, 0x82),
+    "amber-300" => Color::new(0xff, 0xd5, 0x4f),
+    "amber-400" => Color::new(0xff, 0xca, 0x28),
+    "amber-500" => Color::new(0xff, 0xc1, 0x07),
+    "amber-600" => Color::new(0xff, 0xb3, 0x00),
+    "amber-700" => Color::new(0xff, 0xa0, 0x00),
+    "amber-800" => Color::new(0xff, 0x8f, 0x00),
+    "amber-900" => Color::new(0xff, 0x6f, 0x00),
+    "orange-50" => Color::new(0xff, 0xf3, 0xe0),
+    "orange-100" => Color::new(0xff, 0xe0, 0xb2),
+    "orange-200" => Color::new(0xff, 0xcc, 0x80),
+    "orange-300" => Color::new(0xff, 0xb7, 0x4d),
+    "orange-400" => Color::new(0xff, 0xa7, 0x26),
+    "orange-500" => Color::new(0xff, 0x98, 0x00),
+    "orange-600" => Color::new(0xfb, 0x8c, 0x00),
+    "orange-700" => Color::new(0xf5, 0x7c, 0x00),
+    "orange-800" => Color::new(0xef, 0x6c, 0x00),
+    "orange-900" => Color::new(0xe6, 0x51, 0x00),
+    "deep-orange-50" => Color::new(0xfb, 0xe9, 0xe7),
+    "deep-orange-100" => Color::new(0xff, 0xcc, 0xbc),
+    "deep-orange-200" => Color::new(0xff, 0xab, 0x91),
+    "deep-orange-300" => Color::new(0xff, 0x8a, 0x65),
+    "deep-orange-400" => Color::new(0xff, 0x70, 0x43),
+    "deep-orange-500" => Color::new(0xff, 0x57, 0x22),
+    "deep-orange-600" => Color::new(0xf4, 0x51, 0x1e),
+    "deep-orange-700" => Color::new(0xe6, 0x4a, 0x19),
+    "deep-orange-800" => Color::new(0xd8, 0x43, 0x15),
+    "deep-orange-900" => Color::new(0xbf, 0x36, 0x0c),
+    "brown-50" => Color::new(0xef, 0xeb, 0xe9),
+    "brown-100" => Color::new(0xd7, 0xcc, 0xc8),
+    "brown-200" => Color::new(0xbc, 0xaa, 0xa4),
+    "brown-300" => Color::new(0xa1, 0x88, 0x7f),
+    "brown-400" => Color::new(0x8d, 0x6e, 0x63),
+    "brown-500" => Color::new(0x79, 0x55, 0x48),
+    "brown-600" => Color::new(0x6d, 0x4c, 0x41),
+    "brown-700" => Color::new(0x5d, 0x40, 0x37),
+    "brown-800" => Color::new(0x4e, 0x34, 0x2e),
+    "brown-900" => Color::new(0x3e, 0x27, 0x23),
+    "grey-50" => Color::new(0xfa, 0xfa, 0xfa),
+    "grey-100" => Color::new(0xf5, 0xf5, 0xf5),
+    "grey-200" => Color::new(0xee, 0xee, 0xee),
+    "grey-300" => Color::new(0xe0, 0xe0, 0xe0),
+    "grey-400" => Color::new(0xbd, 0xbd, 0xbd),
+    "grey-500" => Color::new(0x9e, 0x9e, 0x9e),
+    "grey-600" => Color::new(0x75, 0x75, 0x75),
+    "grey-700" => Color::new(0x61, 0x61, 0x61),
+    "grey-800" => Color::new(0x42, 0x42, 0x42),
+    "grey-900" => Color::new(0x21, 0x21, 0x21),
+    "blue-grey-50" => Color::new(0xec, 0xef, 0xf1),
+    "blue-grey-100" => Color::new(0xcf, 0xd8, 0xdc),
+    "blue-grey-200" => Color::new(0xb0, 0xbe, 0xc5),
+    "blue-grey-300" => Color::new(0x90, 0xa4, 0xae),
+    "blue-grey-400" => Color::new(0x78, 0x90, 0x9c),
+    "blue-grey-500" => Color::new(0x60, 0x7d, 0x8b),
+    "blue-grey-600" => Color::new(0x54, 0x6e, 0x7a),
+    "blue-grey-700" => Color::new(0x45, 0x5a, 0x64),
+    "blue-grey-800" => Color::new(0x37, 0x47, 0x4f),
+    "blue-grey-900" => Color::new(0x26, 0x32, 0x38),
+};
+
+/// Open Color 1.9, shades 0 to 9, obtained from https://yeun.github.io/open-color/
+pub(crate) static OPEN_COLOR: BuiltinColors = phf_ordered_map! {
+    "gray-0" => Color::new(0xf8, 0xf9, 0xfa),
+    "gray-1" => Color::new(0xf1, 0xf3, 0xf5),
+    "gray-2" => Color::new(0xe9, 0xec, 0xef),
+    "gray-3" => Color::new(0xde, 0xe2, 0xe6),
+    "gray-4" => Color::new(0xce, 0xd4, 0xda),
+    "gray-5" => Color::new(0xad, 0xb5, 0xbd),
+    "gray-6" => Color::new(0x86, 0x8e, 0x96),
+    "gray-7" => Color::new(0x49, 0x50, 0x57),
+    "gray-8" => Color::new(0x34, 0x3a, 0x40),
+    "gray-9" => Color::new(0x21, 0x25, 0x29),
+    "red-0" => Color::new(0xff, 0xf5, 0xf5),
+    "red-1" => Color::new(0xff, 0xe3, 0xe3),
+    "red-2" => Color::new(0xff, 0xc9, 0xc9),
+    "red-3" => Color::new(0xff, 0xa8, 0xa8),
+    "red-4" => Color::new(0xff, 0x87, 0x87),
+    "red-5" => Color::new(0xff, 0x6b, 0x6b),
+    "red-6" => Color::new(0xfa, 0x52, 0x52),
+    "red-7" => Color::new(0xf0, 0x3e, 0x3e),
+    "red-8" => Color::new(0xe0, 0x31, 0x31),
+    "red-9" => Color::new(0xc9, 0x2a, 0x2a),
+    "pink-0" => Color::new(0xff, 0xf0, 0xf6),
+    "pink-1" => Color::new(0xff, 0xde, 0xeb),
+    "pink-2" => Color::new(0xfc, 0xc2, 0xd7),
+    "pink-3" => Color::new(0xfa, 0xa2, 0xc1),
+    "pink-4" => Color::new(0xf7, 0x83, 0xac),
+    "pink-5" => Color::new(0xf0, 0x65, 0x95),
+    "pink-6" => Color::new(0xe6, 0x49, 0x80),
+    "pink-7" => Color::new(0xd6, 0x33, 0x6c),
+    "pink-8" => Color::new(0xc2, 0x25, 0x5c),
+    "pink-9" => Color::new(0xa6, 0x1e, 0x4d),
+    "grape-0" => Color::new(0xf8, 0xf0, 0xfc),
+    "grape-1" => Color::new(0xf3, 0xd9, 0xfa),
+    "grape-2" => Color::new(0xee, 0xbe, 0xfa),
+    "grape-3" => Color::new(0xe5, 0x99, 0xf7),
+    "grape-4" => Color::new(0xda, 0x77, 0xf2),
+    "grape-5" => Color::new(0xcc, 0x5d, 0xe8),
+    "grape-6" => Color::new(0xbe, 0x4b, 0xdb),
+    "grape-7" => Color::new(0xae, 0x3e, 0xc9),
+    "grape-8" => Color::new(0x9c, 0x36, 0xb5),
+    "grape-9" => Color::new(0x86, 0x2e, 0x9c),
+    "violet-0" => Color::new(0xf3, 0xf0, 0xff),
+    "violet-1" => Color::new(0xe5, 0xdb, 0xff),
+    "violet-2" => Color::new(0xd0, 0xbf, 0xff),
+    "violet-3" => Color::new(0xb1, 0x97, 0xfc),
+    "violet-4" => Color::new(0x97, 0x75, 0xfa),
+    "violet-5" => Color::new(0x84, 0x5e, 0xf7),
+    "violet-6" => Color::new(0x79, 0x50, 0xf2),
+    "violet-7" => Color::new(0x70, 0x48, 0xe8),
+    "violet-8" => Color::new(0x67, 0x41, 0xd9),
+    "violet-9" => Color::new(0x5f, 0x3d, 0xc4),
+    "indigo-0" => Color::new(0xed, 0xf2, 0xff),
+    "indigo-1" => Color::new(0xdb, 0xe4, 0xff),
+    "indigo-2" => Color::new(0xba, 0xc8, 0xff),
+    "indigo-3" => Color::new(0x91, 0xa7, 0xff),
+    "indigo-4" => Color::new(0x74, 0x8f, 0xfc),
+    "indigo-5" => Color::new(0x5c, 0x7c, 0xfa),
+    "indigo-6" => Color::new(0x4c, 0x6e, 0xf5),
+    "indigo-7" => Color::new(0x42, 0x63, 0xeb),
+    "indigo-8" => Color::new(0x3b, 0x5b, 0xdb),
+    "indigo-9" => Color::new(0x36, 0x4f, 0xc7),
+    "blue-0" => Color::new(0xe7, 0xf5, 0xff),
+    "blue-1" => Color::new(0xd0, 0xeb, 0xff),
+    "blue-2" => Color::new(0xa5, 0xd8, 0xff),
+    "blue-3" => Color::new(0x74, 0xc0, 0xfc),
+    "blue-4" => Color::new(0x4d, 0xab, 0xf7),
+    "blue-5" => Color::new(0x33, 0x9a, 0xf0),
+    "blue-6" => Color::new(0x22, 0x8b, 0xe6),
+    "blue-7" => Color::new(0x1c, 0x7e, 0xd6),
+    "blue-8" => Color::new(0x19, 0x71, 0xc2),
+    "blue-9" => Color::new(0x18, 0x64, 0xab),
+    "cyan-0" => Color::new(0xe3, 0xfa, 0xfc),
+    "cyan-1" => Color::new(0xc5, 0xf6, 0xfa),
+    "cyan-2" => Color::new(0x99, 0xe9, 0xf2),
+    "cyan-3" => Color::new(0x66, 0xd9, 0xe8),
+    "cyan-4" => Color::new(0x3b, 0xc9, 0xdb),
+    "cyan-5" => Color::new(0x22, 0xb8, 0xcf),
+    "cyan-6" => Color::new(0x15, 0xaa, 0xbf),
+    "cyan-7" => Color::new(0x10, 0x98, 0xad),
+    "cyan-8" => Color::new(0x0c, 0x85, 0x99),
+    "cyan-9" => Color::new(0x0b, 0x72, 0x85),
+    "teal-0" => Color::new(0xe6, 0xfc, 0xf5),
+    "teal-1" => Color::new(0xc3, 0xfa, 0xe8),
+    "teal-2" => Color::new(0x96, 0xf2, 0xd7),
+    "teal-3" => Color::new(0x63, 0xe6, 0xbe),
+    "teal-4" => Color::new(0x38, 0xd9, 0xa9),
+    "teal-5" => Color::new(0x20, 0xc9, 0x97),
+    "teal-6" => Color::new(0x12, 0xb8, 0x86),
+    "teal-7" => Color::new(0x0c, 0xa6, 0x78),
+    "teal-8" => Color::new(0x09, 0x92, 0x68),
+    "teal-9" => Color::new(0x08, 0x7f, 0x5b),
+    "green-0" => Color::new(0xeb, 0xfb, 0xee),
+    "green-1" => Color::new(0xd3, 0xf9, 0xd8),
+    "green-2" => Color::new(0xb2, 0xf2, 0xbb),
+    "green-3" => Color::new(0x8c, 0xe9, 0x9a),
+    "green-4" => Color::new(0x69, 0xdb, 0x7c),
+    "green-5" => Color::new(0x51, 0xcf, 0x66),
+    "green-6" => Color::new(0x40, 0xc0, 0x57),
+    "green-7" => Color::new(0x37, 0xb2, 0x4d),
+    "green-8" => Color::new(0x2f, 0x9e, 0x44),
+    "green-9" => Color::new(0x2b, 0x8a, 0x3e),
+    "lime-0" => Color::new(0xf4, 0xfc, 0xe3),
+    "lime-1" => Color::new(0xe9, 0xfa, 0xc8),
+    "lime-2" => Color::new(0xd8, 0xf5, 0xa2),
+    "lime-3" => Color::new(0xc0, 0xeb, 0x75),
+    "lime-4" => Color::new(0xa9, 0xe3, 0x4b),
+    "lime-5" => Color::new(0x94, 0xd8, 0x2d),
+    "lime-6" => Color::new(0x82, 0xc9, 0x1e),
+    "lime-7" => Color::new(0x74, 0xb8, 0x16),
+    "lime-8" => Color::new(0x66, 0xa8, 0x0f),
+    "lime-9" => Color::new(0x5c, 0x94, 0x0d),
+    "yellow-0" => Color::new(0xff, 0xf9, 0xdb),
+    "yellow-1" => Color::new(0xff, 0xf3, 0xbf),
+    "yellow-2" => Color::new(0xff, 0xec, 0x99),
+    "yellow-3" => Color::new(0xff, 0xe0, 0x66),
+    "yellow-4" => Color::new(0xff, 0xd4, 0x3b),
+    "yellow-5" => Color::new(0xfc, 0xc4, 0x19),
+    "yellow-6" => Color::new(0xfa, 0xb0, 0x05),
+    "yellow-7" => Color::new(0xf5, 0x9f, 0x00),
+    "yellow-8" => Color::new(0xf0, 0x8c, 0x00),
+    "yellow-9" => Color::new(0xe6, 0x77, 0x00),
+    "orange-0" => Color::new(0xff, 0xf4, 0xe6),
+    "orange-1" => Color::new(0xff, 0xe8, 0xcc),
+    "orange-2" => Color::new(0xff, 0xd8, 0xa8),
+    "orange-3" => Color::new(0xff, 0xc0, 0x78),
+    "orange-4" => Color::new(0xff, 0xa9, 0x4d),
+    "orange-5" => Color::new(0xff, 0x92, 0x2b),
+    "orange-6" => Color::new(0xfd, 0x7e, 0x14),
+    "orange-7" => Color::new(0xf7, 0x67, 0x07),
+    "orange-8" => Color::new(0xe8, 0x59, 0x0c),
+    "orange-9" => Color::new(0xd9, 0x48, 0x0f),
+};
+
+/// The 949 colors named most often in the XKCD color survey, obtained from
+/// https://xkcd.com/color/rgb.txt and in the order of that file.
+pub(crate) static XKCD: BuiltinColors = phf_ordered_map! {
+    "cloudy blue" => Color::new(0xac, 0xc2, 0xd9),
+    "dark pastel green" => Color::new(0x56, 0xae, 0x57),
+    "dust" => Color::new(0xb2, 0x99, 0x6e),
+    "electric lime" => Color::new(0xa8, 0xff, 0x04),
+    "fresh green" => Color::new(0x69, 0xd8, 0x4f),
+    "light eggplant" => Color::new(0x89, 0x45, 0x85),
+    "nasty green" => Color::new(0x70, 0xb2, 0x3f),
+    "really light blue" => Color::new(0xd4, 0xff, 0xff),
+    "tea" => Color::new(0x65, 0xab, 0x7c),
+    "warm purple" => Color::new(0x95, 0x2e, 0x8f),
+    "yellowish tan" => Color::new(0xfc, 0xfc, 0x81),
+    "cement" => Color::new(0xa5, 0xa3, 0x91),
+    "dark grass green" => Color::new(0x38, 0x80, 0x04),
+    "dusty teal" => Color::new(0x4c, 0x90, 0x85),
+    "grey teal" => Color::new(0x5e, 0x9b, 0x8a),
+    "macaroni and cheese" => Color::new(0xef, 0xb4, 0x35),
+    "pinkish tan" => Color::new(0xd9, 0x9b, 0x82),
+    "spruce" => Color::new(0x0a, 0x5f, 0x38),
+    "strong blue" => Color::new(0x0c, 0x06, 0xf7),
+    "toxic green" => Color::new(0x61, 0xde, 0x2a),
+    "windows blue" => Color::new(0x37, 0x78, 0xbf),
+    "blue blue" => Color::new(0x22, 0x42, 0xc7),
+    "blue with a hint of purple" => Color::new(0x53, 0x3c, 0xc6),
+    "booger" => Color::new(0x9b, 0xb5, 0x3c),
+    "bright sea green" => Color::new(0x05, 0xff, 0xa6),
+    "dark green blue" => Color::new(0x1f, 0x63, 0x57),
+    "deep turquoise" => Color::new(0x01, 0x73, 0x74),
+    "green teal" => Color::new(0x0c, 0xb5, 0x77),
+    "strong pink" => Color::new(0xff, 0x07, 0x89),
+    "bland" => Color::new(0xaf, 0xa8, 0x8b),
+    "deep aqua" => Color::new(0x08, 0x78, 0x7f),
+    "lavender pink" => Color::new(0xdd, 0x85, 0xd7),
+    "light moss green" => Color::new(0xa6, 0xc8, 0x75),
+    "light seafoam green" => Color::new(0xa7, 0xff, 0xb5),
+    "olive yellow" => Color::new(0xc2, 0xb7, 0x09),
+    "pig pink" => Color::new(0xe7, 0x8e, 0xa5),
+    "deep lilac" => Color::new(0x96, 0x6e, 0xbd),
+    "desert" => Color::new(0xcc, 0xad, 0x60),
+    "dusty lavender" => Color::new(0xac, 0x86, 0xa8),
+    "purpley grey" => Color::new(0x94, 0x7e, 0x94),
+    "purply" => Color::new(0x98, 0x3f, 0xb2),
+    "candy pink" => Color::new(0xff, 0x63, 0xe9),
+    "light pastel green" => Color::new(0xb2, 0xfb, 0xa5),
+    "boring green" => Color::new(0x63, 0xb3, 0x65),
+    "kiwi green" => Color::new(0x8e, 0xe5, 0x3f),
+    "light grey green" => Color::new(0xb7, 0xe1, 0xa1),
+    "orange pink" => Color::new(0xff, 0x6f, 0x52),
+    "tea green" => Color::new(0xbd, 0xf8, 0xa3),
+    "very light brown" => Color::new(0xd3, 0xb6, 0x83),
+    "egg shell" => Color::new(0xff, 0xfc, 0xc4),
+    "eggplant purple" => Color::new(0x43, 0x05, 0x41),
+    "powder pink" => Color::new(0xff, 0xb2, 0xd0),
+    "reddish grey" => Color::new(0x99, 0x75, 0x70),
+    "baby shit brown" => Color::new(0xad, 0x90, 0x0d),
+    "liliac" => Color::new(0xc4, 0x8e, 0xfd),
+    "stormy blue" => Color::new(0x50, 0x7b, 0x9c),
+    "ugly brown" => Color::new(0x7d, 0x71, 0x03),
+    "custard" => Color::new(0xff, 0xfd, 0x78),
+    "darkish pink" => Color::new(0xda, 0x46, 0x7d),
+    "deep brown" => Color::new(0x41, 0x02, 0x00),
+    "greenish beige" => Color::new(0xc9, 0xd1, 0x79),
+    "manilla" => Color::new(0xff, 0xfa, 0x86),
+    "off blue" => Color::new(0x56, 0x84, 0xae),
+    "battleship grey" => Color::new(0x6b, 0x7c, 0x85),
+    "browny green" => Color::new(0x6f, 0x6c, 0x0a),
+    "bruise" => Color::new(0x7e, 0x40, 0x71),
+    "kelley green" => Color::new(0x00, 0x93, 0x37),
+    "sickly yellow" => Color::new(0xd0, 0xe4, 0x29),
+    "sunny yellow" => Color::new(0xff, 0xf9, 0x17),
+    "azul" => Color::new(0x1d, 0x5d, 0xec),
+    "darkgreen" => Color::new(0x05, 0x49, 0x07),
+    "green/yellow" => Color::new(0xb5, 0xce, 0x08),
+    "lichen" => Color::new(0x8f, 0xb6, 0x7b),
+    "light light green" => Color::new(0xc8, 0xff, 0xb0),
+    "pale gold" => Color::new(0xfd, 0xde, 0x6c),
+    "sun yellow" => Color::new(0xff, 0xdf, 0x22),
+    "tan green" => Color::new(0xa9, 0xbe, 0x70),
+    "burple" => Color::new(0x68, 0x32, 0xe3),
+    "butterscotch" => Color::new(0xfd, 0xb1, 0x47),
+    "toupe" => Color::new(0xc7, 0xac, 0x7d),
+    "dark cream" => Color::new(0xff, 0xf3, 0x9a),
+    "indian red" => Color::new(0x85, 0x0e, 0x04),
+    "light lavendar" => Color::new(0xef, 0xc0, 0xfe),
+    "poison green" => Color::new(0x40, 0xfd, 0x14),
+    "baby puke green" => Color::new(0xb6, 0xc4, 0x06),
+    "bright yellow green" => Color::new(0x9d, 0xff, 0x00),
+    "charcoal grey" => Color::new(0x3c, 0x41, 0x42),
+    "squash" => Color::new(0xf2, 0xab, 0x15),
+    "cinnamon" => Color::new(0xac, 0x4f, 0x06),
+    "light pea green" => Color::new(0xc4, 0xfe, 0x82),
+    "radioactive green" => Color::new(0x2c, 0xfa, 0x1f),
+    "raw sienna" => Color::new(0x9a, 0x62, 0x00),
+    "baby purple" => Color::new(0xca, 0x9b, 0xf7),
+    "cocoa" => Color::new(0x87, 0x5f, 0x42),
+    "light royal blue" => Color::new(0x3a, 0x2e, 0xfe),
+    "orangeish" => Color::new(0xfd, 0x8d, 0x49),
+    "rust brown" => Color::new(0x8b, 0x31, 0x03),
+    "sand brown" => Color::new(0xcb, 0xa5, 0x60),
+    "swamp" => Color::new(0x69, 0x83, 0x39),
+    "tealish green" => Color::new(0x0c, 0xdc, 0x73),
+    "burnt siena" => Color::new(0xb7, 0x52, 0x03),
+    "camo" => Color::new(0x7f, 0x8f, 0x4e),
+    "dusk blue" => Color::new(0x26, 0x53, 0x8d),
+    "fern" => Color::new(0x63, 0xa9, 0x50),
+    "old rose" => Color::new(0xc8, 0x7f, 0x89),
+    "pale light green" => Color::new(0xb1, 0xfc, 0x99),
+    "peachy pink" => Color::new(0xff, 0x9a, 0x8a),
+    "rosy pink" => Color::new(0xf6, 0x68, 0x8e),
+    "light bluish green" => Color::new(0x76, 0xfd, 0xa8),
+    "light bright green" => Color::new(0x53, 0xfe, 0x5c),
+    "light neon green" => Color::new(0x4e, 0xfd, 0x54),
+    "light seafoam" => Color::new(0xa0, 0xfe, 0xbf),
+    "tiffany blue" => Color::new(0x7b, 0xf2, 0xda),
+    "washed out green" => Color::new(0xbc, 0xf5, 0xa6),
+    "browny orange" => Color::new(0xca, 0x6b, 0x02),
+    "nice blue" => Color::new(0x10, 0x7a, 0xb0),
+    "sapphire" => Color::new(0x21, 0x38, 0xab),
+    "greyish teal" => Color::new(0x71, 0x9f, 0x91),
+    "orangey yellow" => Color::new(0xfd, 0xb9, 0x15),
+    "parchment" => Color::new(0xfe, 0xfc, 0xaf),
+    "straw" => Color::new(0xfc, 0xf6, 0x79),
+    "very dark brown" => Color::new(0x1d, 0x02, 0x00),
+    "terracota" => Color::new(0xcb, 0x68, 0x43),
+    "ugly blue" => Color::new(0x31, 0x66, 0x8a),
+    "clear blue" => Color::new(0x24, 0x7a, 0xfd),
+    "creme" => Color::new(0xff, 0xff, 0xb6),
+    "foam green" => Color::new(0x90, 0xfd, 0xa9),
+    "grey/green" => Color::new(0x86, 0xa1, 0x7d),
+    "light gold" => Color::new(0xfd, 0xdc, 0x5c),
+    "seafoam blue" => Color::new(0x78, 0xd1, 0xb6),
+    "topaz" => Color::new(0x13, 0xbb, 0xaf),
+    "violet pink" => Color::new(0xfb, 0x5f, 0xfc),
+    "wintergreen" => Color::new(0x20, 0xf9, 0x86),
+    "yellow tan" => Color::new(0xff, 0xe3, 0x6e),
+    "dark fuchsia" => Color::new(0x9d, 0x07, 0x59),
+    "indigo blue" => Color::new(0x3a, 0x18, 0xb1),
+    "light yellowish green" => Color::new(0xc2, 0xff, 0x89),
+    "pale magenta" => Color::new(0xd7, 0x67, 0xad),
+    "rich purple" => Color::new(0x72, 0x00, 0x58),
+    "sunflower yellow" => Color::new(0xff, 0xda, 0x03),
+    "green/blue" => Color::new(0x01, 0xc0, 0x8d),
+    "leather" => Color::new(0xac, 0x74, 0x34),
+    "racing green" => Color::new(0x01, 0x46, 0x00),
+    "vivid purple" => Color::new(0x99, 0x00, 0xfa),
+    "dark royal blue" => Color::new(0x02, 0x06, 0x6f),
+    "hazel" => Color::new(0x8e, 0x76, 0x18),
+    "muted pink" => Color::new(0xd1, 0x76, 0x8f),
+    "booger green" => Color::new(0x96, 0xb4, 0x03),
+    "canary" => Color::new(0xfd, 0xff, 0x63),
+    "cool grey" => Color::new(0x95, 0xa3, 0xa6),
+    "dark taupe" => Color::new(0x7f, 0x68, 0x4e),
+    "darkish purple" => Color::new(0x75, 0x19, 0x73),
+    "true green" => Color::new(0x08, 0x94, 0x04),
+    "coral pink" => Color::new(0xff, 0x61, 0x63),
+    "dark sage" => Color::new(0x59, 0x85, 0x56),
+    "dark slate blue" => Color::new(0x21, 0x47, 0x61),
+    "flat blue" => Color::new(0x3c, 0x73, 0xa8),
+    "mushroom" => Color::new(0xba, 0x9e, 0x88),
+    "rich blue" => Color::new(0x02, 0x1b, 0xf9),
+    "dirty purple" => Color::new(0x73, 0x4a, 0x65),
+    "greenblue" => Color::new(0x23, 0xc4, 0x8b),
+    "icky green" => Color::new(0x8f, 0xae, 0x22),
+    "light khaki" => Color::new(0xe6, 0xf2, 0xa2),
+    "warm blue" => Color::new(0x4b, 0x57, 0xdb),
+    "dark hot pink" => Color::new(0xd9, 0x01, 0x66),
+    "deep sea blue" => Color::new(0x01, 0x54, 0x82),
+    "carmine" => Color::new(0x9d, 0x02, 0x16),
+    "dark yellow green" => Color::new(0x72, 0x8f, 0x02),
+    "pale peach" => Color::new(0xff, 0xe5, 0xad),
+    "plum purple" => Color::new(0x4e, 0x05, 0x50),
+    "golden rod" => Color::new(0xf9, 0xbc, 0x08),
+    "neon red" => Color::new(0xff, 0x07, 0x3a),
+    "old pink" => Color::new(0xc7, 0x79, 0x86),
+    "very pale blue" => Color::new(0xd6, 0xff, 0xfe),
+    "blood orange" => Color::new(0xfe, 0x4b, 0x03),
+    "grapefruit" => Color::new(0xfd, 0x59, 0x56),
+    "sand yellow" => Color::new(0xfc, 0xe1, 0x66),
+    "clay brown" => Color::new(0xb2, 0x71, 0x3d),
+    "dark blue grey" => Color::new(0x1f, 0x3b, 0x4d),
+    "flat green" => Color::new(0x69, 0x9d, 0x4c),
+    "light green blue" => Color::new(0x56, 0xfc, 0xa2),
+    "warm pink" => Color::new(0xfb, 0x55, 0x81),
+    "dodger blue" => Color::new(0x3e, 0x82, 0xfc),
+    "gross green" => Color::new(0xa0, 0xbf, 0x16),
+    "ice" => Color::new(0xd6, 0xff, 0xfa),
+    "metallic blue" => Color::new(0x4f, 0x73, 0x8e),
+    "pale salmon" => Color::new(0xff, 0xb1, 0x9a),
+    "sap green" => Color::new(0x5c, 0x8b, 0x15),
+    "algae" => Color::new(0x54, 0xac, 0x68),
+    "bluey grey" => Color::new(0x89, 0xa0, 0xb0),
+    "greeny grey" => Color::new(0x7e, 0xa0, 0x7a),
+    "highlighter green" => Color::new(0x1b, 0xfc, 0x06),
+    "light light blue" => Color::new(0xca, 0xff, 0xfb),
+    "light mint" => Color::new(0xb6, 0xff, 0xbb),
+    "raw umber" => Color::new(0xa7, 0x5e, 0x09),
+    "vivid blue" => Color::new(0x15, 0x2e, 0xff),
+    "deep lavender" => Color::new(0x8d, 0x5e, 0xb7),
+    "dull teal" => Color::new(0x5f, 0x9e, 0x8f),
+    "light greenish blue" => Color::new(0x63, 0xf7, 0xb4),
+    "mud green" => Color::new(0x60, 0x66, 0x02),
+    "pinky" => Color::new(0xfc, 0x86, 0xaa),
+    "red wine" => Color::new(0x8c, 0x00, 0x34),
+    "shit green" => Color::new(0x75, 0x80, 0x00),
+    "tan brown" => Color::new(0xab, 0x7e, 0x4c),
+    "darkblue" => Color::new(0x03, 0x07, 0x64),
+    "rosa" => Color::new(0xfe, 0x86, 0xa4),
+    "lipstick" => Color::new(0xd5, 0x17, 0x4e),
+    "pale mauve" => Color::new(0xfe, 0xd0, 0xfc),
+    "claret" => Color::new(0x68, 0x00, 0x18),
+    "dandelion" => Color::new(0xfe, 0xdf, 0x08),
+    "orangered" => Color::new(0xfe, 0x42, 0x0f),
+    "poop green" => Color::new(0x6f, 0x7c, 0x00),
+    "ruby" => Color::new(0xca, 0x01, 0x47),
+    "dark" => Color::new(0x1b, 0x24, 0x31),
+    "greenish turquoise" => Color::new(0x00, 0xfb, 0xb0),
+    "pastel red" => Color::new(0xdb, 0x58, 0x56),
+    "piss yellow" => Color::new(0xdd, 0xd6, 0x18),
+    "bright cyan" => Color::new(0x41, 0xfd, 0xfe),
+    "dark coral" => Color::new(0xcf, 0x52, 0x4e),
+    "algae green" => Color::new(0x21, 0xc3, 0x6f),
+    "darkish red" => Color::new(0xa9, 0x03, 0x08),
+    "reddy brown" => Color::new(0x6e, 0x10, 0x05),
+    "blush pink" => Color::new(0xfe, 0x82, 0x8c),
+    "camouflage green" => Color::new(0x4b, 0x61, 0x13),
+    "lawn green" => Color::new(0x4d, 0xa4, 0x09),
+    "putty" => Color::new(0xbe, 0xae, 0x8a),
+    "vibrant blue" => Color::new(0x03, 0x39, 0xf8),
+    "dark sand" => Color::new(0xa8, 0x8f, 0x59),
+    "purple/blue" => Color::new(0x5d, 0x21, 0xd0),
+    "saffron" => Color::new(0xfe, 0xb2, 0x09),
+    "twilight" => Color::new(0x4e, 0x51, 0x8b),
+    "warm brown" => Color::new(0x96, 0x4e, 0x02),
+    "bluegrey" => Color::new(0x85, 0xa3, 0xb2),
+    "bubble gum pink" => Color::new(0xff, 0x69, 0xaf),
+    "duck egg blue" => Color::new(0xc3, 0xfb, 0xf4),
+    "greenish cyan" => Color::new(0x2a, 0xfe, 0xb7),
+    "petrol" => Color::new(0x00, 0x5f, 0x6a),
+    "royal" => Color::new(0x0c, 0x17, 0x93),
+    "butter" => Color::new(0xff, 0xff, 0x81),
+    "dusty orange" => Color::new(0xf0, 0x83, 0x3a),
+    "off yellow" => Color::new(0xf1, 0xf3, 0x3f),
+    "pale olive green" => Color::new(0xb1, 0xd2, 0x7b),
+    "orangish" => Color::new(0xfc, 0x82, 0x4a),
+    "leaf" => Color::new(0x71, 0xaa, 0x34),
+    "light blue grey" => Color::new(0xb7, 0xc9, 0xe2),
+    "dried blood" => Color::new(0x4b, 0x01, 0x01),
+    "lightish purple" => Color::new(0xa5, 0x52, 0xe6),
+    "rusty red" => Color::new(0xaf, 0x2f, 0x0d),
+    "lavender blue" => Color::new(0x8b, 0x88, 0xf8),
+    "light grass green" => Color::new(0x9a, 0xf7, 0x64),
+    "light mint green" => Color::new(0xa6, 0xfb, 0xb2),
+    "sunflower" => Color::new(0xff, 0xc5, 0x12),
+    "velvet" => Color::new(0x75, 0x08, 0x51),
+    "brick orange" => Color::new(0xc1, 0x4a, 0x09),
+    "lightish red" => Color::new(0xfe, 0x2f, 0x4a),
+    "pure blue" => Color::new(0x02, 0x03, 0xe2),
+    "twilight blue" => Color::new(0x0a, 0x43, 0x7a),
+    "violet red" => Color::new(0xa5, 0x00, 0x55),
+    "yellowy brown" => Color::new(0xae, 0x8b, 0x0c),
+    "carnation" => Color::new(0xfd, 0x79, 0x8f),
+    "muddy yellow" => Color::new(0xbf, 0xac, 0x05),
+    "dark seafoam green" => Color::new(0x3e, 0xaf, 0x76),
+    "deep rose" => Color::new(0xc7, 0x47, 0x67),
+    "dusty red" => Color::new(0xb9, 0x48, 0x4e),
+    "grey/blue" => Color::new(0x64, 0x7d, 0x8e),
+    "lemon lime" => Color::new(0xbf, 0xfe, 0x28),
+    "purple/pink" => Color::new(0xd7, 0x25, 0xde),
+    "brown yellow" => Color::new(0xb2, 0x97, 0x05),
+    "purple brown" => Color::new(0x67, 0x3a, 0x3f),
+    "wisteria" => Color::new(0xa8, 0x7d, 0xc2),
+    "banana yellow" => Color::new(0xfa, 0xfe, 0x4b),
+    "lipstick red" => Color::new(0xc0, 0x02, 0x2f),
+    "water blue" => Color::new(0x0e, 0x87, 0xcc),
+    "brown grey" => Color::new(0x8d, 0x84, 0x68),
+    "vibrant purple" => Color::new(0xad, 0x03, 0xde),
+    "baby green" => Color::new(0x8c, 0xff, 0x9e),
+    "barf green" => Color::new(0x94, 0xac, 0x02),
+    "eggshell blue" => Color::new(0xc4, 0xff, 0xf7),
+    "sandy yellow" => Color::new(0xfd, 0xee, 0x73),
+    "cool green" => Color::new(0x33, 0xb8, 0x64),
+    "pale" => Color::new(0xff, 0xf9, 0xd0),
+    "blue/grey" => Color::new(0x75, 0x8d, 0xa3),
+    "hot magenta" => Color::new(0xf5, 0x04, 0xc9),
+    "greyblue" => Color::new(0x77, 0xa1, 0xb5),
+    "purpley" => Color::new(0x87, 0x56, 0xe4),
+    "baby shit green" => Color::new(0x88, 0x97, 0x17),
+    "brownish pink" => Color::new(0xc2, 0x7e, 0x79),
+    "dark aquamarine" => Color::new(0x01, 0x73, 0x71),
+    "diarrhea" => Color::new(0x9f, 0x83, 0x03),
+    "light mustard" => Color::new(0xf7, 0xd5, 0x60),
+    "pale sky blue" => Color::new(0xbd, 0xf6, 0xfe),
+    "turtle green" => Color::new(0x75, 0xb8, 0x4f),
+    "bright olive" => Color::new(0x9c, 0xbb, 0x04),
+    "dark grey blue" => Color::new(0x29, 0x46, 0x5b),
+    "greeny brown" => Color::new(0x69, 0x60, 0x06),
+    "lemon green" => Color::new(0xad, 0xf8, 0x02),
+    "light periwinkle" => Color::new(0xc1, 0xc6, 0xfc),
+    "seaweed green" => Color::new(0x35, 0xad, 0x6b),
+    "sunshine yellow" => Color::new(0xff, 0xfd, 0x37),
+    "ugly purple" => Color::new(0xa4, 0x42, 0xa0),
+    "medium pink" => Color::new(0xf3, 0x61, 0x96),
+    "puke brown" => Color::new(0x94, 0x77, 0x06),
+    "very light pink" => Color::new(0xff, 0xf4, 0xf2),
+    "viridian" => Color::new(0x1e, 0x91, 0x67),
+    "bile" => Color::new(0xb5, 0xc3, 0x06),
+    "faded yellow" => Color::new(0xfe, 0xff, 0x7f),
+    "very pale green" => Color::new(0xcf, 0xfd, 0xbc),
+    "vibrant green" => Color::new(0x0a, 0xdd, 0x08),
+    "bright lime" => Color::new(0x87, 0xfd, 0x05),
+    "spearmint" => Color::new(0x1e, 0xf8, 0x76),
+    "light aquamarine" => Color::new(0x7b, 0xfd, 0xc7),
+    "light sage" => Color::new(0xbc, 0xec, 0xac),
+    "yellowgreen" => Color::new(0xbb, 0xf9, 0x0f),
+    "baby poo" => Color::new(0xab, 0x90, 0x04),
+    "dark seafoam" => Color::new(0x1f, 0xb5, 0x7a),
+    "deep teal" => Color::new(0x00, 0x55, 0x5a),
+    "heather" => Color::new(0xa4, 0x84, 0xac),
+    "rust orange" => Color::new(0xc4, 0x55, 0x08),
+    "dirty blue" => Color::new(0x3f, 0x82, 0x9d),
+    "fern green" => Color::new(0x54, 0x8d, 0x44),
+    "bright lilac" => Color::new(0xc9, 0x5e, 0xfb),
+    "weird green" => Color::new(0x3a, 0xe5, 0x7f),
+    "peacock blue" => Color::new(0x01, 0x67, 0x95),
+    "avocado green" => Color::new(0x87, 0xa9, 0x22),
+    "faded orange" => Color::new(0xf0, 0x94, 0x4d),
+    "grape purple" => Color::new(0x5d, 0x14, 0x51),
+    "hot green" => Color::new(0x25, 0xff, 0x29),
+    "lime yellow" => Color::new(0xd0, 0xfe, 0x1d),
+    "mango" => Color::new(0xff, 0xa6, 0x2b),
+    "shamrock" => Color::new(0x01, 0xb4, 0x4c),
+    "bubblegum" => Color::new(0xff, 0x6c, 0xb5),
+    "purplish brown" => Color::new(0x6b, 0x42, 0x47),
+    "vomit yellow" => Color::new(0xc7, 0xc1, 0x0c),
+    "pale cyan" => Color::new(0xb7, 0xff, 0xfa),
+    "key lime" => Color::new(0xae, 0xff, 0x6e),
+    "tomato red" => Color::new(0xec, 0x2d, 0x01),
+    "lightgreen" => Color::new(0x76, 0xff, 0x7b),
+    "merlot" => Color::new(0x73, 0x00, 0x39),
+    "night blue" => Color::new(0x04, 0x03, 0x48),
+    "purpleish pink" => Color::new(0xdf, 0x4e, 0xc8),
+    "apple" => Color::new(0x6e, 0xcb, 0x3c),
+    "baby poop green" => Color::new(0x8f, 0x98, 0x05),
+    "green apple" => Color::new(0x5e, 0xdc, 0x1f),
+    "heliotrope" => Color::new(0xd9, 0x4f, 0xf5),
+    "yellow/green" => Color::new(0xc8, 0xfd, 0x3d),
+    "almost black" => Color::new(0x07, 0x0d, 0x0d),
+    "cool blue" => Color::new(0x49, 0x84, 0xb8),
+    "leafy green" => Color::new(0x51, 0xb7, 0x3b),
+    "mustard brown" => Color::new(0xac, 0x7e, 0x04),
+    "dusk" => Color::new(0x4e, 0x54, 0x81),
+    "dull brown" => Color::new(0x87, 0x6e, 0x4b),
+    "frog green" => Color::new(0x58, 0xbc, 0x08),
+    "vivid green" => Color::new(0x2f, 0xef, 0x10),
+    "bright light green" => Color::new(0x2d, 0xfe, 0x54),
+    "fluro green" => Color::new(0x0a, 0xff, 0x02),
+    "kiwi" => Color::new(0x9c, 0xef, 0x43),
+    "seaweed" => Color::new(0x18, 0xd1, 0x7b),
+    "navy green" => Color::new(0x35, 0x53, 0x0a),
+    "ultramarine blue" => Color::new(0x18, 0x05, 0xdb),
+    "iris" => Color::new(0x62, 0x58, 0xc4),
+    "pastel orange" => Color::new(0xff, 0x96, 0x4f),
+    "yellowish orange" => Color::new(0xff, 0xab, 0x0f),
+    "perrywinkle" => Color::new(0x8f, 0x8c, 0xe7),
+    "tealish" => Color::new(0x24, 0xbc, 0xa8),
+    "dark plum" => Color::new(0x3f, 0x01, 0x2c),
+    "pear" => Color::new(0xcb, 0xf8, 0x5f),
+    "pinkish orange" => Color::new(0xff, 0x72, 0x4c),
+    "midnight purple" => Color::new(0x28, 0x01, 0x37),
+    "light urple" => Color::new(0xb3, 0x6f, 0xf6),
+    "dark mint" => Color::new(0x48, 0xc0, 0x72),
+    "greenish tan" => Color::new(0xbc, 0xcb, 0x7a),
+    "light burgundy" => Color::new(0xa8, 0x41, 0x5b),
+    "turquoise blue" => Color::new(0x06, 0xb1, 0xc4),
+    "ugly pink" => Color::new(0xcd, 0x75, 0x84),
+    "sandy" => Color::new(0xf1, 0xda, 0x7a),
+    "electric pink" => Color::new(0xff, 0x04, 0x90),
+    "muted purple" => Color::new(0x80, 0x5b, 0x87),
+    "mid green" => Color::new(0x50, 0xa7, 0x47),
+    "greyish" => Color::new(0xa8, 0xa4, 0x95),
+    "neon yellow" => Color::new(0xcf, 0xff, 0x04),
+    "banana" => Color::new(0xff, 0xff, 0x7e),
+    "carnation pink" => Color::new(0xff, 0x7f, 0xa7),
+    "tomato" => Color::new(0xef, 0x40, 0x26),
+    "sea" => Color::new(0x3c, 0x99, 0x92),
+    "muddy brown" => Color::new(0x88, 0x68, 0x06),
+    "turquoise green" => Color::new(0x04, 0xf4, 0x89),
+    "buff" => Color::new(0xfe, 0xf6, 0x9e),
+    "fawn" => Color::new(0xcf, 0xaf, 0x7b),
+    "muted blue" => Color::new(0x3b, 0x71, 0x9f),
+    "pale rose" => Color::new(0xfd, 0xc1, 0xc5),
+    "dark mint green" => Color::new(0x20, 0xc0, 0x73),
+    "amethyst" => Color::new(0x9b, 0x5f, 0xc0),
+    "blue/green" => Color::new(0x0f, 0x9b, 0x8e),
+    "chestnut" => Color::new(0x74, 0x28, 0x02),
+    "sick green" => Color::new(0x9d, 0xb9, 0x2c),
+    "pea" => Color::new(0xa4, 0xbf, 0x20),
+    "rusty orange" => Color::new(0xcd, 0x59, 0x09),
+    "stone" => Color::new(0xad, 0xa5, 0x87),
+    "rose red" => Color::new(0xbe, 0x01, 0x3c),
+    "pale aqua" => Color::new(0xb8, 0xff, 0xeb),
+    "deep orange" => Color::new(0xdc, 0x4d, 0x01),
+    "earth" => Color::new(0xa2, 0x65, 0x3e),
+    "mossy green" => Color::new(0x63, 0x8b, 0x27),
+    "grassy green" => Color::new(0x41, 0x9c, 0x03),
+    "pale lime green" => Color::new(0xb1, 0xff, 0x65),
+    "light grey blue" => Color::new(0x9d, 0xbc, 0xd4),
+    "pale grey" => Color::new(0xfd, 0xfd, 0xfe),
+    "asparagus" => Color::new(0x77, 0xab, 0x56),
+    "blueberry" => Color::new(0x46, 0x41, 0x96),
+    "purple red" => Color::new(0x99, 0x01, 0x47),
+    "pale lime" => Color::new(0xbe, 0xfd, 0x73),
+    "greenish teal" => Color::new(0x32, 0xbf, 0x84),
+    "caramel" => Color::new(0xaf, 0x6f, 0x09),
+    "deep magenta" => Color::new(0xa0, 0x02, 0x5c),
+    "light peach" => Color::new(0xff, 0xd8, 0xb1),
+    "milk chocolate" => Color::new(0x7f, 0x4e, 0x1e),
+    "ocher" => Color::new(0xbf, 0x9b, 0x0c),
+    "off green" => Color::new(0x6b, 0xa3, 0x53),
+    "purply pink" => Color::new(0xf0, 0x75, 0xe6),
+    "lightblue" => Color::new(0x7b, 0xc8, 0xf6),
+    "dusky blue" => Color::new(0x47, 0x5f, 0x94),
+    "golden" => Color::new(0xf5, 0xbf, 0x03),
+    "light beige" => Color::new(0xff, 0xfe, 0xb6),
+    "butter yellow" => Color::new(0xff, 0xfd, 0x74),
+    "dusky purple" => Color::new(0x89, 0x5b, 0x7b),
+    "french blue" => Color::new(0x43, 0x6b, 0xad),
+    "ugly yellow" => Color::new(0xd0, 0xc1, 0x01),
+    "greeny yellow" => Color::new(0xc6, 0xf8, 0x08),
+    "orangish red" => Color::new(0xf4, 0x36, 0x05),
+    "shamrock green" => Color::new(0x02, 0xc1, 0x4d),
+    "orangish brown" => Color::new(0xb2, 0x5f, 0x03),
+    "tree green" => Color::new(0x2a, 0x7e, 0x19),
+    "deep violet" => Color::new(0x49, 0x06, 0x48),
+    "gunmetal" => Color::new(0x53, 0x62, 0x67),
+    "blue/purple" => Color::new(0x5a, 0x06, 0xef),
+    "cherry" => Color::new(0xcf, 0x02, 0x34),
+    "sandy brown" => Color::new(0xc4, 0xa6, 0x61),
+    "warm grey" => Color::new(0x97, 0x8a, 0x84),
+    "dark indigo" => Color::new(0x1f, 0x09, 0x54),
+    "midnight" => Color::new(0x03, 0x01, 0x2d),
+    "bluey green" => Color::new(0x2b, 0xb1, 0x79),
+    "grey pink" => Color::new(0xc3, 0x90, 0x9b),
+    "soft purple" => Color::new(0xa6, 0x6f, 0xb5),
+    "blood" => Color::new(0x77, 0x00, 0x01),
+    "brown red" => Color::new(0x92, 0x2b, 0x05),
+    "medium grey" => Color::new(0x7d, 0x7f, 0x7c),
+    "berry" => Color::new(0x99, 0x0f, 0x4b),
+    "poo" => Color::new(0x8f, 0x73, 0x03),
+    "purpley pink" => Color::new(0xc8, 0x3c, 0xb9),
+    "light salmon" => Color::new(0xfe, 0xa9, 0x93),
+    "snot" => Color::new(0xac, 0xbb, 0x0d),
+    "easter purple" => Color::new(0xc0, 0x71, 0xfe),
+    "light yellow green" => Color::new(0xcc, 0xfd, 0x7f),
+    "dark navy blue" => Color::new(0x00, 0x02, 0x2e),
+    "drab" => Color::new(0x82, 0x83, 0x44),
+    "light rose" => Color::new(0xff, 0xc5, 0xcb),
+    "rouge" => Color::new(0xab, 0x12, 0x39),
+    "purplish red" => Color::new(0xb0, 0x05, 0x4b),
+    "slime green" => Color::new(0x99, 0xcc, 0x04),
+    "baby poop" => Color::new(0x93, 0x7c, 0x00),
+    "irish green" => Color::new(0x01, 0x95, 0x29),
+    "pink/purple" => Color::new(0xef, 0x1d, 0xe7),
+    "dark navy" => Color::new(0x00, 0x04, 0x35),
+    "greeny blue" => Color::new(0x42, 0xb3, 0x95),
+    "light plum" => Color::new(0x9d, 0x57, 0x83),
+    "pinkish grey" => Color::new(0xc8, 0xac, 0xa9),
+    "dirty orange" => Color::new(0xc8, 0x76, 0x06),
+    "rust red" => Color::new(0xaa, 0x27, 0x04),
+    "pale lilac" => Color::new(0xe4, 0xcb, 0xff),
+    "orangey red" => Color::new(0xfa, 0x42, 0x24),
+    "primary blue" => Color::new(0x08, 0x04, 0xf9),
+    "kermit green" => Color::new(0x5c, 0xb2, 0x00),
+    "brownish purple" => Color::new(0x76, 0x42, 0x4e),
+    "murky green" => Color::new(0x6c, 0x7a, 0x0e),
+    "wheat" => Color::new(0xfb, 0xdd, 0x7e),
+    "very dark purple" => Color::new(0x2a, 0x01, 0x34),
+    "bottle green" => Color::new(0x04, 0x4a, 0x05),
+    "watermelon" => Color::new(0xfd, 0x46, 0x59),
+    "deep sky blue" => Color::new(0x0d, 0x75, 0xf8),
+    "fire engine red" => Color::new(0xfe, 0x00, 0x02),
+    "yellow ochre" => Color::new(0xcb, 0x9d, 0x06),
+    "pumpkin orange" => Color::new(0xfb, 0x7d, 0x07),
+    "pale olive" => Color::new(0xb9, 0xcc, 0x81),
+    "light lilac" => Color::new(0xed, 0xc8, 0xff),
+    "lightish green" => Color::new(0x61, 0xe1, 0x60),
+    "carolina blue" => Color::new(0x8a, 0xb8, 0xfe),
+    "mulberry" => Color::new(0x92, 0x0a, 0x4e),
+    "shocking pink" => Color::new(0xfe, 0x02, 0xa2),
+    "auburn" => Color::new(0x9a, 0x30, 0x01),
+    "bright lime green" => Color::new(0x65, 0xfe, 0x08),
+    "celadon" => Color::new(0xbe, 0xfd, 0xb7),
+    "pinkish brown" => Color::new(0xb1, 0x72, 0x61),
+    "poo brown" => Color::new(0x88, 0x5f, 0x01),
+    "bright sky blue" => Color::new(0x02, 0xcc, 0xfe),
+    "celery" => Color::new(0xc1, 0xfd, 0x95),
+    "dirt brown" => Color::new(0x83, 0x65, 0x39),
+    "strawberry" => Color::new(0xfb, 0x29, 0x43),
+    "dark lime" => Color::new(0x84, 0xb7, 0x01),
+    "copper" => Color::new(0xb6, 0x63, 0x25),
+    "medium brown" => Color::new(0x7f, 0x51, 0x12),
+    "muted green" => Color::new(0x5f, 0xa0, 0x52),
+    "robin's egg" => Color::new(0x6d, 0xed, 0xfd),
+    "bright aqua" => Color::new(0x0b, 0xf9, 0xea),
+    "bright lavender" => Color::new(0xc7, 0x60, 0xff),
+    "ivory" => Color::new(0xff, 0xff, 0xcb),
+    "very light purple" => Color::new(0xf6, 0xce, 0xfc),
+    "light navy" => Color::new(0x15, 0x50, 0x84),
+    "pink red" => Color::new(0xf5, 0x05, 0x4f),
+    "olive brown" => Color::new(0x64, 0x54, 0x03),
+    "poop brown" => Color::new(0x7a, 0x59, 0x01),
+    "mustard green" => Color::new(0xa8, 0xb5, 0x04),
+    "ocean green" => Color::new(0x3d, 0x99, 0x73),
+    "very dark blue" => Color::new(0x00, 0x01, 0x33),
+    "dusty green" => Color::new(0x76, 0xa9, 0x73),
+    "light navy blue" => Color::new(0x2e, 0x5a, 0x88),
+    "minty green" => Color::new(0x0b, 0xf7, 0x7d),
+    "adobe" => Color::new(0xbd, 0x6c, 0x48),
+    "barney" => Color::new(0xac, 0x1d, 0xb8),
+    "jade green" => Color::new(0x2b, 0xaf, 0x6a),
+    "bright light blue" => Color::new(0x26, 0xf7, 0xfd),
+    "light lime" => Color::new(0xae, 0xfd, 0x6c),
+    "dark khaki" => Color::new(0x9b, 0x8f, 0x55),
+    "orange yellow" => Color::new(0xff, 0xad, 0x01),
+    "ocre" => Color::new(0xc6, 0x9c, 0x04),
+    "maize" => Color::new(0xf4, 0xd0, 0x54),
+    "faded pink" => Color::new(0xde, 0x9d, 0xac),
+    "british racing green" => Color::new(0x05, 0x48, 0x0d),
+    "sandstone" => Color::new(0xc9, 0xae, 0x74),
+    "mud brown" => Color::new(0x60, 0x46, 0x0f),
+    "light sea green" => Color::new(0x98, 0xf6, 0xb0),
+    "robin egg blue" => Color::new(0x8a, 0xf1, 0xfe),
+    "aqua marine" => Color::new(0x2e, 0xe8, 0xbb),
+    "dark sea green" => Color::new(0x11, 0x87, 0x5d),
+    "soft pink" => Color::new(0xfd, 0xb0, 0xc0),
+    "orangey brown" => Color::new(0xb1, 0x60, 0x02),
+    "cherry red" => Color::new(0xf7, 0x02, 0x2a),
+    "burnt yellow" => Color::new(0xd5, 0xab, 0x09),
+    "brownish grey" => Color::new(0x86, 0x77, 0x5f),
+    "camel" => Color::new(0xc6, 0x9f, 0x59),
+    "purplish grey" => Color::new(0x7a, 0x68, 0x7f),
+    "marine" => Color::new(0x04, 0x2e, 0x60),
+    "greyish pink" => Color::new(0xc8, 0x8d, 0x94),
+    "pale turquoise" => Color::new(0xa5, 0xfb, 0xd5),
+    "pastel yellow" => Color::new(0xff, 0xfe, 0x71),
+    "bluey purple" => Color::new(0x62, 0x41, 0xc7),
+    "canary yellow" => Color::new(0xff, 0xfe, 0x40),
+    "faded red" => Color::new(0xd3, 0x49, 0x4e),
+    "sepia" => Color::new(0x98, 0x5e, 0x2b),
+    "coffee" => Color::new(0xa6, 0x81, 0x4c),
+    "bright magenta" => Color::new(0xff, 0x08, 0xe8),
+    "mocha" => Color::new(0x9d, 0x76, 0x51),
+    "ecru" => Color::new(0xfe, 0xff, 0xca),
+    "purpleish" => Color::new(0x98, 0x56, 0x8d),
+    "cranberry" => Color::new(0x9e, 0x00, 0x3a),
+    "darkish green" => Color::new(0x28, 0x7c, 0x37),
+    "brown orange" => Color::new(0xb9, 0x69, 0x02),
+    "dusky rose" => Color::new(0xba, 0x68, 0x73),
+    "melon" => Color::new(0xff, 0x78, 0x55),
+    "sickly green" => Color::new(0x94, 0xb2, 0x1c),
+    "silver" => Color::new(0xc5, 0xc9, 0xc7),
+    "purply blue" => Color::new(0x66, 0x1a, 0xee),
+    "purpleish blue" => Color::new(0x61, 0x40, 0xef),
+    "hospital green" => Color::new(0x9b, 0xe5, 0xaa),
+    "shit brown" => Color::new(0x7b, 0x58, 0x04),
+    "mid blue" => Color::new(0x27, 0x6a, 0xb3),
+    "amber" => Color::new(0xfe, 0xb3, 0x08),
+    "easter green" => Color::new(0x8c, 0xfd, 0x7e),
+    "soft blue" => Color::new(0x64, 0x88, 0xea),
+    "cerulean blue" => Color::new(0x05, 0x6e, 0xee),
+    "golden brown" => Color::new(0xb2, 0x7a, 0x01),
+    "bright turquoise" => Color::new(0x0f, 0xfe, 0xf9),
+    "red pink" => Color::new(0xfa, 0x2a, 0x55),
+    "red purple" => Color::new(0x82, 0x07, 0x47),
+    "greyish brown" => Color::new(0x7a, 0x6a, 0x4f),
+    "vermillion" => Color::new(0xf4, 0x32, 0x0c),
+    "russet" => Color::new(0xa1, 0x39, 0x05),
+    "steel grey" => Color::new(0x6f, 0x82, 0x8a),
+    "lighter purple" => Color::new(0xa5, 0x5a, 0xf4),
+    "bright violet" => Color::new(0xad, 0x0a, 0xfd),
+    "prussian blue" => Color::new(0x00, 0x45, 0x77),
+    "slate green" => Color::new(0x65, 0x8d, 0x6d),
+    "dirty pink" => Color::new(0xca, 0x7b, 0x80),
+    "dark blue green" => Color::new(0x00, 0x52, 0x49),
+    "pine" => Color::new(0x2b, 0x5d, 0x34),
+    "yellowy green" => Color::new(0xbf, 0xf1, 0x28),
+    "dark gold" => Color::new(0xb5, 0x94, 0x10),
+    "bluish" => Color::new(0x29, 0x76, 0xbb),
+    "darkish blue" => Color::new(0x01, 0x41, 0x82),
+    "dull red" => Color::new(0xbb, 0x3f, 0x3f),
+    "pinky red" => Color::new(0xfc, 0x26, 0x47),
+    "bronze" => Color::new(0xa8, 0x79, 0x00),
+    "pale teal" => Color::new(0x82, 0xcb, 0xb2),
+    "military green" => Color::new(0x66, 0x7c, 0x3e),
+    "barbie pink" => Color::new(0xfe, 0x46, 0xa5),
+    "bubblegum pink" => Color::new(0xfe, 0x83, 0xcc),
+    "pea soup green" => Color::new(0x94, 0xa6, 0x17),
+    "dark mustard" => Color::new(0xa8, 0x89, 0x05),
+    "shit" => Color::new(0x7f, 0x5f, 0x00),
+    "medium purple" => Color::new(0x9e, 0x43, 0xa2),
+    "very dark green" => Color::new(0x06, 0x2e, 0x03),
+    "dirt" => Color::new(0x8a, 0x6e, 0x45),
+    "dusky pink" => Color::new(0xcc, 0x7a, 0x8b),
+    "red violet" => Color::new(0x9e, 0x01, 0x68),
+    "lemon yellow" => Color::new(0xfd, 0xff, 0x38),
+    "pistachio" => Color::new(0xc0, 0xfa, 0x8b),
+    "dull yellow" => Color::new(0xee, 0xdc, 0x5b),
+    "dark lime green" => Color::new(0x7e, 0xbd, 0x01),
+    "denim blue" => Color::new(0x3b, 0x5b, 0x92),
+    "teal blue" => Color::new(0x01, 0x88, 0x9f),
+    "lightish blue" => Color::new(0x3d, 0x7a, 0xfd),
+    "purpley blue" => Color::new(0x5f, 0x34, 0xe7),
+    "light indigo" => Color::new(0x6d, 0x5a, 0xcf),
+    "swamp green" => Color::new(0x74, 0x85, 0x00),
+    "brown green" => Color::new(0x70, 0x6c, 0x11),
+    "dark maroon" => Color::new(0x3c, 0x00, 0x08),
+    "hot purple" => Color::new(0xcb, 0x00, 0xf5),
+    "dark forest green" => Color::new(0x00, 0x2d, 0x04),
+    "faded blue" => Color::new(0x65, 0x8c, 0xbb),
+    "drab green" => Color::new(0x74, 0x95, 0x51),
+    "light lime green" => Color::new(0xb9, 0xff, 0x66),
+    "snot green" => Color::new(0x9d, 0xc1, 0x00),
+    "yellowish" => Color::new(0xfa, 0xee, 0x66),
+    "light blue green" => Color::new(0x7e, 0xfb, 0xb3),
+    "bordeaux" => Color::new(0x7b, 0x00, 0x2c),
+    "light mauve" => Color::new(0xc2, 0x92, 0xa1),
+    "ocean" => Color::new(0x01, 0x7b, 0x92),
+    "marigold" => Color::new(0xfc, 0xc0, 0x06),
+    "muddy green" => Color::new(0x65, 0x74, 0x32),
+    "dull orange" => Color::new(0xd8, 0x86, 0x3b),
+    "steel" => Color::new(0x73, 0x85, 0x95),
+    "electric purple" => Color::new(0xaa, 0x23, 0xff),
+    "fluorescent green" => Color::new(0x08, 0xff, 0x08),
+    "yellowish brown" => Color::new(0x9b, 0x7a, 0x01),
+    "blush" => Color::new(0xf2, 0x9e, 0x8e),
+    "soft green" => Color::new(0x6f, 0xc2, 0x76),
+    "bright orange" => Color::new(0xff, 0x5b, 0x00),
+    "lemon" => Color::new(0xfd, 0xff, 0x52),
+    "purple grey" => Color::new(0x86, 0x6f, 0x85),
+    "acid green" => Color::new(0x8f, 0xfe, 0x09),
+    "pale lavender" => Color::new(0xee, 0xcf, 0xfe),
+    "violet blue" => Color::new(0x51, 0x0a, 0xc9),
+    "light forest green" => Color::new(0x4f, 0x91, 0x53),
+    "burnt red" => Color::new(0x9f, 0x23, 0x05),
+    "khaki green" => Color::new(0x72, 0x86, 0x39),
+    "cerise" => Color::new(0xde, 0x0c, 0x62),
+    "faded purple" => Color::new(0x91, 0x6e, 0x99),
+    "apricot" => Color::new(0xff, 0xb1, 0x6d),
+    "dark olive green" => Color::new(0x3c, 0x4d, 0x03),
+    "grey brown" => Color::new(0x7f, 0x70, 0x53),
+    "green grey" => Color::new(0x77, 0x92, 0x6f),
+    "true blue" => Color::new(0x01, 0x0f, 0xcc),
+    "pale violet" => Color::new(0xce, 0xae, 0xfa),
+    "periwinkle blue" => Color::new(0x8f, 0x99, 0xfb),
+    "light sky blue" => Color::new(0xc6, 0xfc, 0xff),
+    "blurple" => Color::new(0x55, 0x39, 0xcc),
+    "green brown" => Color::new(0x54, 0x4e, 0x03),
+    "bluegreen" => Color::new(0x01, 0x7a, 0x79),
+    "bright teal" => Color::new(0x01, 0xf9, 0xc6),
+    "brownish yellow" => Color::new(0xc9, 0xb0, 0x03),
+    "pea soup" => Color::new(0x92, 0x99, 0x01),
+    "forest" => Color::new(0x0b, 0x55, 0x09),
+    "barney purple" => Color::new(0xa0, 0x04, 0x98),
+    "ultramarine" => Color::new(0x20, 0x00, 0xb1),
+    "purplish" => Color::new(0x94, 0x56, 0x8c),
+    "puke yellow" => Color::new(0xc2, 0xbe, 0x0e),
+    "bluish grey" => Color::new(0x74, 0x8b, 0x97),
+    "dark periwinkle" => Color::new(0x66, 0x5f, 0xd1),
+    "dark lilac" => Color::new(0x9c, 0x6d, 0xa5),
+    "reddish" => Color::new(0xc4, 0x42, 0x40),
+    "light maroon" => Color::new(0xa2, 0x48, 0x57),
+    "dusty purple" => Color::new(0x82, 0x5f, 0x87),
+    "terra cotta" => Color::new(0xc9, 0x64, 0x3b),
+    "avocado" => Color::new(0x90, 0xb1, 0x34),
+    "marine blue" => Color::new(0x01, 0x38, 0x6a),
+    "teal green" => Color::new(0x25, 0xa3, 0x6f),
+    "slate grey" => Color::new(0x59, 0x65, 0x6d),
+    "lighter green" => Color::new(0x75, 0xfd, 0x63),
+    "electric green" => Color::new(0x21, 0xfc, 0x0d),
+    "dusty blue" => Color::new(0x5a, 0x86, 0xad),
+    "golden yellow" => Color::new(0xfe, 0xc6, 0x15),
+    "bright yellow" => Color::new(0xff, 0xfd, 0x01),
+    "light lavender" => Color::new(0xdf, 0xc5, 0xfe),
+    "umber" => Color::new(0xb2, 0x64, 0x00),
+    "poop" => Color::new(0x7f, 0x5e, 0x00),
+    "dark peach" => Color::new(0xde, 0x7e, 0x5d),
+    "jungle green" => Color::new(0x04, 0x82, 0x43),
+    "eggshell" => Color::new(0xff, 0xff, 0xd4),
+    "denim" => Color::new(0x3b, 0x63, 0x8c),
+    "yellow brown" => Color::new(0xb7, 0x94, 0x00),
+    "dull purple" => Color::new(0x84, 0x59, 0x7e),
+    "chocolate brown" => Color::new(0x41, 0x19, 0x00),
+    "wine red" => Color::new(0x7b, 0x03, 0x23),
+    "neon blue" => Color::new(0x04, 0xd9, 0xff),
+    "dirty green" => Color::new(0x66, 0x7e, 0x2c),
+    "light tan" => Color::new(0xfb, 0xee, 0xac),
+    "ice blue" => Color::new(0xd7, 0xff, 0xfe),
+    "cadet blue" => Color::new(0x4e, 0x74, 0x96),
+    "dark mauve" => Color::new(0x87, 0x4c, 0x62),
+    "very light blue" => Color::new(0xd5, 0xff, 0xff),
+    "grey purple" => Color::new(0x82, 0x6d, 0x8c),
+    "pastel pink" => Color::new(0xff, 0xba, 0xcd),
+    "very light green" => Color::new(0xd1, 0xff, 0xbd),
+    "dark sky blue" => Color::new(0x44, 0x8e, 0xe4),
+    "evergreen" => Color::new(0x05, 0x47, 0x2a),
+    "dull pink" => Color::new(0xd5, 0x86, 0x9d),
+    "aubergine" => Color::new(0x3d, 0x07, 0x34),
+    "mahogany" => Color::new(0x4a, 0x01, 0x00),
+    "reddish orange" => Color::new(0xf8, 0x48, 0x1c),
+    "deep green" => Color::new(0x02, 0x59, 0x0f),
+    "vomit green" => Color::new(0x89, 0xa2, 0x03),
+    "purple pink" => Color::new(0xe0, 0x3f, 0xd8),
+    "dusty pink" => Color::new(0xd5, 0x8a, 0x94),
+    "faded green" => Color::new(0x7b, 0xb2, 0x74),
+    "camo green" => Color::new(0x52, 0x65, 0x25),
+    "pinky purple" => Color::new(0xc9, 0x4c, 0xbe),
+    "pink purple" => Color::new(0xdb, 0x4b, 0xda),
+    "brownish red" => Color::new(0x9e, 0x36, 0x23),
+    "dark rose" => Color::new(0xb5, 0x48, 0x5d),
+    "mud" => Color::new(0x73, 0x5c, 0x12),
+    "brownish" => Color::new(0x9c, 0x6d, 0x57),
+    "emerald green" => Color::new(0x02, 0x8f, 0x1e),
+    "pale brown" => Color::new(0xb1, 0x91, 0x6e),
+    "dull blue" => Color::new(0x49, 0x75, 0x9c),
+    "burnt umber" => Color::new(0xa0, 0x45, 0x0e),
+    "medium green" => Color::new(0x39, 0xad, 0x48),
+    "clay" => Color::new(0xb6, 0x6a, 0x50),
+    "light aqua" => Color::new(0x8c, 0xff, 0xdb),
+    "light olive green" => Color::new(0xa4, 0xbe, 0x5c),
+    "brownish orange" => Color::new(0xcb, 0x77, 0x23),
+    "dark aqua" => Color::new(0x05, 0x69, 0x6b),
+    "purplish pink" => Color::new(0xce, 0x5d, 0xae),
+    "dark salmon" => Color::new(0xc8, 0x5a, 0x53),
+    "greenish grey" => Color::new(0x96, 0xae, 0x8d),
+    "jade" => Color::new(0x1f, 0xa7, 0x74),
+    "ugly green" => Color::new(0x7a, 0x97, 0x03),
+    "dark beige" => Color::new(0xac, 0x93, 0x62),
+    "emerald" => Color::new(0x01, 0xa0, 0x49),
+    "pale red" => Color::new(0xd9, 0x54, 0x4d),
+    "light magenta" => Color::new(0xfa, 0x5f, 0xf7),
+    "sky" => Color::new(0x82, 0xca, 0xfc),
+    "light cyan" => Color::new(0xac, 0xff, 0xfc),
+    "yellow orange" => Color::new(0xfc, 0xb0, 0x01),
+    "reddish purple" => Color::new(0x91, 0x09, 0x51),
+    "reddish pink" => Color::new(0xfe, 0x2c, 0x54),
+    "orchid" => Color::new(0xc8, 0x75, 0xc4),
+    "dirty yellow" => Color::new(0xcd, 0xc5, 0x0a),
+    "orange red" => Color::new(0xfd, 0x41, 0x1e),
+    "deep red" => Color::new(0x9a, 0x02, 0x00),
+    "orange brown" => Color::new(0xbe, 0x64, 0x00),
+    "cobalt blue" => Color::new(0x03, 0x0a, 0xa7),
+    "neon pink" => Color::new(0xfe, 0x01, 0x9a),
+    "rose pink" => Color::new(0xf7, 0x87, 0x9a),
+    "greyish purple" => Color::new(0x88, 0x71, 0x91),
+    "raspberry" => Color::new(0xb0, 0x01, 0x49),
+    "aqua green" => Color::new(0x12, 0xe1, 0x93),
+    "salmon pink" => Color::new(0xfe, 0x7b, 0x7c),
+    "tangerine" => Color::new(0xff, 0x94, 0x08),
+    "brownish green" => Color::new(0x6a, 0x6e, 0x09),
+    "red brown" => Color::new(0x8b, 0x2e, 0x16),
+    "greenish brown" => Color::new(0x69, 0x61, 0x12),
+    "pumpkin" => Color::new(0xe1, 0x77, 0x01),
+    "pine green" => Color::new(0x0a, 0x48, 0x1e),
+    "charcoal" => Color::new(0x34, 0x38, 0x37),
+    "baby pink" => Color::new(0xff, 0xb7, 0xce),
+    "cornflower" => Color::new(0x6a, 0x79, 0xf7),
+    "blue violet" => Color::new(0x5d, 0x06, 0xe9),
+    "chocolate" => Color::new(0x3d, 0x1c, 0x02),
+    "greyish green" => Color::new(0x82, 0xa6, 0x7d),
+    "scarlet" => Color::new(0xbe, 0x01, 0x19),
+    "green yellow" => Color::new(0xc9, 0xff, 0x27),
+    "dark olive" => Color::new(0x37, 0x3e, 0x02),
+    "sienna" => Color::new(0xa9, 0x56, 0x1e),
+    "pastel purple" => Color::new(0xca, 0xa0, 0xff),
+    "terracotta" => Color::new(0xca, 0x66, 0x41),
+    "aqua blue" => Color::new(0x02, 0xd8, 0xe9),
+    "sage green" => Color::new(0x88, 0xb3, 0x78),
+    "blood red" => Color::new(0x98, 0x00, 0x02),
+    "deep pink" => Color::new(0xcb, 0x01, 0x62),
+    "grass" => Color::new(0x5c, 0xac, 0x2d),
+    "moss" => Color::new(0x76, 0x99, 0x58),
+    "pastel blue" => Color::new(0xa2, 0xbf, 0xfe),
+    "bluish green" => Color::new(0x10, 0xa6, 0x74),
+    "green blue" => Color::new(0x06, 0xb4, 0x8b),
+    "dark tan" => Color::new(0xaf, 0x88, 0x4a),
+    "greenish blue" => Color::new(0x0b, 0x8b, 0x87),
+    "pale orange" => Color::new(0xff, 0xa7, 0x56),
+    "vomit" => Color::new(0xa2, 0xa4, 0x15),
+    "forrest green" => Color::new(0x15, 0x44, 0x06),
+    "dark lavender" => Color::new(0x85, 0x67, 0x98),
+    "dark violet" => Color::new(0x34, 0x01, 0x3f),
+    "purple blue" => Color::new(0x63, 0x2d, 0xe9),
+    "dark cyan" => Color::new(0x0a, 0x88, 0x8a),
+    "olive drab" => Color::new(0x6f, 0x76, 0x32),
+    "pinkish" => Color::new(0xd4, 0x6a, 0x7e),
+    "cobalt" => Color::new(0x1e, 0x48, 0x8f),
+    "neon purple" => Color::new(0xbc, 0x13, 0xfe),
+    "light turquoise" => Color::new(0x7e, 0xf4, 0xcc),
+    "apple green" => Color::new(0x76, 0xcd, 0x26),
+    "dull green" => Color::new(0x74, 0xa6, 0x62),
+    "wine" => Color::new(0x80, 0x01, 0x3f),
+    "powder blue" => Color::new(0xb1, 0xd1, 0xfc),
+    "off white" => Color::new(0xff, 0xff, 0xe4),
+    "electric blue" => Color::new(0x06, 0x52, 0xff),
+    "dark turquoise" => Color::new(0x04, 0x5c, 0x5a),
+    "blue purple" => Color::new(0x57, 0x29, 0xce),
+    "azure" => Color::new(0x06, 0x9a, 0xf3),
+    "bright red" => Color::new(0xff, 0x00, 0x0d),
+    "pinkish red" => Color::new(0xf1, 0x0c, 0x45),
+    "cornflower blue" => Color::new(0x51, 0x70, 0xd7),
+    "light olive" => Color::new(0xac, 0xbf, 0x69),
+    "grape" => Color::new(0x6c, 0x34, 0x61),
+    "greyish blue" => Color::new(0x5e, 0x81, 0x9d),
+    "purplish blue" => Color::new(0x60, 0x1e, 0xf9),
+    "yellowish green" => Color::new(0xb0, 0xdd, 0x16),
+    "greenish yellow" => Color::new(0xcd, 0xfd, 0x02),
+    "medium blue" => Color::new(0x2c, 0x6f, 0xbb),
+    "dusty rose" => Color::new(0xc0, 0x73, 0x7a),
+    "light violet" => Color::new(0xd6, 0xb4, 0xfc),
+    "midnight blue" => Color::new(0x02, 0x00, 0x35),
+    "bluish purple" => Color::new(0x70, 0x3b, 0xe7),
+    "red orange" => Color::new(0xfd, 0x3c, 0x06),
+    "dark magenta" => Color::new(0x96, 0x00, 0x56),
+    "greenish" => Color::new(0x40, 0xa3, 0x68),
+    "ocean blue" => Color::new(0x03, 0x71, 0x9c),
+    "coral" => Color::new(0xfc, 0x5a, 0x50),
+    "cream" => Color::new(0xff, 0xff, 0xc2),
+    "reddish brown" => Color::new(0x7f, 0x2b, 0x0a),
+    "burnt sienna" => Color::new(0xb0, 0x4e, 0x0f),
+    "brick" => Color::new(0xa0, 0x36, 0x23),
+    "sage" => Color::new(0x87, 0xae, 0x73),
+    "grey green" => Color::new(0x78, 0x9b, 0x73),
+    "white" => Color::new(0xff, 0xff, 0xff),
+    "robin's egg blue" => Color::new(0x98, 0xef, 0xf9),
+    "moss green" => Color::new(0x65, 0x8b, 0x38),
+    "steel blue" => Color::new(0x5a, 0x7d, 0x9a),
+    "eggplant" => Color::new(0x38, 0x08, 0x35),
+    "light yellow" => Color::new(0xff, 0xfe, 0x7a),
+    "leaf green" => Color::new(0x5c, 0xa9, 0x04),
+    "light grey" => Color::new(0xd8, 0xdc, 0xd6),
+    "puke" => Color::new(0xa5, 0xa5, 0x02),
+    "pinkish purple" => Color::new(0xd6, 0x48, 0xd7),
+    "sea blue" => Color::new(0x04, 0x74, 0x95),
+    "pale purple" => Color::new(0xb7, 0x90, 0xd4),
+    "slate blue" => Color::new(0x5b, 0x7c, 0x99),
+    "blue grey" => Color::new(0x60, 0x7c, 0x8e),
+    "hunter green" => Color::new(0x0b, 0x40, 0x08),
+    "fuchsia" => Color::new(0xed, 0x0d, 0xd9),
+    "crimson" => Color::new(0x8c, 0x00, 0x0f),
+    "pale yellow" => Color::new(0xff, 0xff, 0x84),
+    "ochre" => Color::new(0xbf, 0x90, 0x05),
+    "mustard yellow" => Color::new(0xd2, 0xbd, 0x0a),
+    "light red" => Color::new(0xff, 0x47, 0x4c),
+    "cerulean" => Color::new(0x04, 0x85, 0xd1),
+    "pale pink" => Color::new(0xff, 0xcf, 0xdc),
+    "deep blue" => Color::new(0x04, 0x02, 0x73),
+    "rust" => Color::new(0xa8, 0x3c, 0x09),
+    "light teal" => Color::new(0x90, 0xe4, 0xc1),
+    "slate" => Color::new(0x51, 0x65, 0x72),
+    "goldenrod" => Color::new(0xfa, 0xc2, 0x05),
+    "dark yellow" => Color::new(0xd5, 0xb6, 0x0a),
+    "dark grey" => Color::new(0x36, 0x37, 0x37),
+    "army green" => Color::new(0x4b, 0x5d, 0x16),
+    "grey blue" => Color::new(0x6b, 0x8b, 0xa4),
+    "seafoam" => Color::new(0x80, 0xf9, 0xad),
+    "puce" => Color::new(0xa5, 0x7e, 0x52),
+    "spring green" => Color::new(0xa9, 0xf9, 0x71),
+    "dark orange" => Color::new(0xc6, 0x51, 0x02),
+    "sand" => Color::new(0xe2, 0xca, 0x76),
+    "pastel green" => Color::new(0xb0, 0xff, 0x9d),
+    "mint" => Color::new(0x9f, 0xfe, 0xb0),
+    "light orange" => Color::new(0xfd, 0xaa, 0x48),
+    "bright pink" => Color::new(0xfe, 0x01, 0xb1),
+    "chartreuse" => Color::new(0xc1, 0xf8, 0x0a),
+    "deep purple" => Color::new(0x36, 0x01, 0x3f),
+    "dark brown" => Color::new(0x34, 0x1c, 0x02),
+    "taupe" => Color::new(0xb9, 0xa2, 0x81),
+    "pea green" => Color::new(0x8e, 0xab, 0x12),
+    "puke green" => Color::new(0x9a, 0xae, 0x07),
+    "kelly green" => Color::new(0x02, 0xab, 0x2e),
+    "seafoam green" => Color::new(0x7a, 0xf9, 0xab),
+    "blue green" => Color::new(0x13, 0x7e, 0x6d),
+    "khaki" => Color::new(0xaa, 0xa6, 0x62),
+    "burgundy" => Color::new(0x61, 0x00, 0x23),
+    "dark teal" => Color::new(0x01, 0x4d, 0x4e),
+    "brick red" => Color::new(0x8f, 0x14, 0x02),
+    "royal purple" => Color::new(0x4b, 0x00, 0x6e),
+    "plum" => Color::new(0x58, 0x0f, 0x41),
+    "mint green" => Color::new(0x8f, 0xff, 0x9f),
+    "gold" => Color::new(0xdb, 0xb4, 0x0c),
+    "baby blue" => Color::new(0xa2, 0xcf, 0xfe),
+    "yellow green" => Color::new(0xc0, 0xfb, 0x2d),
+    "bright purple" => Color::new(0xbe, 0x03, 0xfd),
+    "dark red" => Color::new(0x84, 0x00, 0x00),
+    "pale blue" => Color::new(0xd0, 0xfe, 0xfe),
+    "grass green" => Color::new(0x3f, 0x9b, 0x0b),
+    "navy" => Color::new(0x01, 0x15, 0x3e),
+    "aquamarine" => Color::new(0x04, 0xd8, 0xb2),
+    "burnt orange" => Color::new(0xc0, 0x4e, 0x01),
+    "neon green" => Color::new(0x0c, 0xff, 0x0c),
+    "bright blue" => Color::new(0x01, 0x65, 0xfc),
+    "rose" => Color::new(0xcf, 0x62, 0x75),
+    "light pink" => Color::new(0xff, 0xd1, 0xdf),
+    "mustard" => Color::new(0xce, 0xb3, 0x01),
+    "indigo" => Color::new(0x38, 0x02, 0x82),
+    "lime" => Color::new(0xaa, 0xff, 0x32),
+    "sea green" => Color::new(0x53, 0xfc, 0xa1),
+    "periwinkle" => Color::new(0x8e, 0x82, 0xfe),
+    "dark pink" => Color::new(0xcb, 0x41, 0x6b),
+    "olive green" => Color::new(0x67, 0x7a, 0x04),
+    "peach" => Color::new(0xff, 0xb0, 0x7c),
+    "pale green" => Color::new(0xc7, 0xfd, 0xb5),
+    "light brown" => Color::new(0xad, 0x81, 0x50),
+    "hot pink" => Color::new(0xff, 0x02, 0x8d),
+    "black" => Color::new(0x00, 0x00, 0x00),
+    "lilac" => Color::new(0xce, 0xa2, 0xfd),
+    "navy blue" => Color::new(0x00, 0x11, 0x46),
+    "royal blue" => Color::new(0x05, 0x04, 0xaa),
+    "beige" => Color::new(0xe6, 0xda, 0xa6),
+    "salmon" => Color::new(0xff, 0x79, 0x6c),
+    "olive" => Color::new(0x6e, 0x75, 0x0e),
+    "maroon" => Color::new(0x65, 0x00, 0x21),
+    "bright green" => Color::new(0x01, 0xff, 0x07),
+    "dark purple" => Color::new(0x35, 0x06, 0x3e),
+    "mauve" => Color::new(0xae, 0x71, 0x81),
+    "forest green" => Color::new(0x06, 0x47, 0x0c),
+    "aqua" => Color::new(0x13, 0xea, 0xc9),
+    "cyan" => Color::new(0x00, 0xff, 0xff),
+    "tan" => Color::new(0xd1, 0xb2, 0x6f),
+    "dark blue" => Color::new(0x00, 0x03, 0x5b),
+    "lavender" => Color::new(0xc7, 0x9f, 0xef),
+    "turquoise" => Color::new(0x06, 0xc2, 0xac),
+    "dark green" => Color::new(0x03, 0x35, 0x00),
+    "violet" => Color::new(0x9a, 0x0e, 0xea),
+    "light purple" => Color::new(0xbf, 0x77, 0xf6),
+    "lime green" => Color::new(0x89, 0xfe, 0x05),
+    "grey" => Color::new(0x92, 0x95, 0x91),
+    "sky blue" => Color::new(0x75, 0xbb, 0xfd),
+    "yellow" => Color::new(0xff, 0xff, 0x14),
+    "magenta" => Color::new(0xc2, 0x00, 0x78),
+    "light green" => Color::new(0x96, 0xf9, 0x7b),
+    "orange" => Color::new(0xf9, 0x73, 0x06),
+    "teal" => Color::new(0x02, 0x93, 0x86),
+    "light blue" => Color::new(0x95, 0xd0, 0xfc),
+    "red" => Color::new(0xe5, 0x00, 0x00),
+    "brown" => Color::new(0x65, 0x37, 0x00),
+    "pink" => Color::new(0xff, 0x81, 0xc0),
+    "blue" => Color::new(0x03, 0x43, 0xdf),
+    "green" => Color::new(0x15, 0xb0, 0x1a),
+    "purple" => Color::new(0x7e, 0x1e, 0x9c),
+};
+
+pub(crate) static ALL: [(&str, &BuiltinColors); 4] = [
+    ("tailwind", &TAILWIND),
+    ("material", &MATERIAL),
+    ("open-color", &OPEN_COLOR),
+    ("xkcd", &XKCD),
+];
+
+/// Length of the longest color name, `blue with a hint of purple` of XKCD.
+pub(crate) const LONGEST_NAME: usize = 26;
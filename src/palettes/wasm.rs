@@ -0,0 +1,213 @@
+//! Palettes read by WebAssembly modules, for theme formats the crate doesn't know. A module gets
+//! the bytes of a file and returns its colors as JSON, so a proprietary format only needs a
+//! plugin, not a fork.
+
+use std::fmt;
+
+use wasmi::{Config, Engine, Linker, Memory, Module, Store};
+
+use crate::tokens::{parse_tokens, TokensError};
+use crate::Color;
+
+/// How many instructions, roughly, a module can run per file by default.
+const FUEL: u64 = 100_000_000;
+
+/// A palette source compiled from a WebAssembly module, in the binary or the text format.
+///
+/// The module exports its `memory` and two functions:
+///
+/// - `alloc(len: i32) -> i32`, the address of `len` bytes the input is written to.
+/// - `read_palette(address: i32, len: i32) -> i64`, which reads the input and returns where its
+///   output is, the address in the high 32 bits and the length in the low ones.
+///
+/// The output is UTF-8 JSON that [`parse_tokens`] reads, like `{"pink": "#f26ca7"}` or W3C design
+/// tokens. A module fails by trapping, like a Rust plugin does when it panics. Every file is read
+/// by a new instance of the module, so none sees the state another file left.
+///
+/// ```
+/// use contrast_checker::palettes::WasmPaletteSource;
+/// use contrast_checker::Color;
+///
+/// // A plugin whose palette is its input, written in the text format
+/// let source = WasmPaletteSource::new(
+///     br#"(module
+///         (memory (export "memory") 1)
+///         (func (export "alloc") (param i32) (result i32) i32.const 0)
+///         (func (export "read_palette") (param i32 i32) (result i64)
+///             (i64.or
+///                 (i64.shl (i64.extend_i32_u (local.get 0)) (i64.const 32))
+///                 (i64.extend_i32_u (local.get 1)))))"#,
+/// )
+/// .unwrap();
+///
+/// let colors = source.read(br##"{"pink": "#f26ca7"}"##).unwrap();
+/// assert_eq!(colors, vec![("pink".to_string(), Color::new(0xf2, 0x6c, 0xa7))]);
+/// ```
+pub struct WasmPaletteSource {
+    engine: Engine,
+    module: Module,
+    fuel: u64,
+}
+
+/// Errors returned by [`WasmPaletteSource`].
+#[derive(Debug)]
+pub enum PluginError {
+    /// The bytes aren't a valid module, or it can't be instantiated, e.g. because it has imports.
+    InvalidModule(String),
+    /// The module doesn't export the memory or one of the functions, or with another type.
+    MissingExport {
+        name: &'static str,
+    },
+    /// The module trapped or ran out of fuel.
+    Trap(String),
+    /// The input doesn't fit in the memory of the module or the output isn't in it.
+    OutOfBounds,
+    InvalidOutput(TokensError),
+}
+
+impl fmt::Display for PluginError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PluginError::InvalidModule(err) => write!(f, "invalid module: {err}"),
+            PluginError::MissingExport { name } => {
+                write!(f, "the module doesn't export {name}, see WasmPaletteSource")
+            }
+            PluginError::Trap(err) => write!(f, "the module failed: {err}"),
+            PluginError::OutOfBounds => {
+                write!(f, "the module returned an address out of its memory")
+            }
+            PluginError::InvalidOutput(err) => write!(f, "the module returned {err}"),
+        }
+    }
+}
+
+impl std::error::Error for PluginError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            PluginError::InvalidOutput(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+impl WasmPaletteSource {
+    /// Compiles a module, `wasm` can be its binary or text format.
+    pub fn new(wasm: &[u8]) -> Result<WasmPaletteSource, PluginError> {
+        let mut config = Config::default();
+        config.consume_fuel(true);
+        let engine = Engine::new(&config);
+        let module = Module::new(&engine, wasm)
+            .map_err(|err| PluginError::InvalidModule(err.to_string()))?;
+
+        Ok(WasmPaletteSource {
+            engine,
+            module,
+            fuel: FUEL,
+        })
+    }
+
+    /// How many instructions, roughly, the module can run per file before it's stopped, so a
+    /// plugin stuck in a loop fails instead of hanging the audit. 100 million by default.
+    pub fn fuel(mut self, fuel: u64) -> WasmPaletteSource {
+        self.fuel = fuel;
+        self
+    }
+
+    /// Runs the module on the bytes of a file and returns the colors of the palette, in the order
+    /// of its output.
+    pub fn read(&self, input: &[u8]) -> Result<Vec<(String, Color)>, PluginError> {
+        let trap = |err: wasmi::Error| PluginError::Trap(err.to_string());
+        let mut store = Store::new(&self.engine, ());
+        store.set_fuel(self.fuel).map_err(trap)?;
+        let instance = Linker::<()>::new(&self.engine)
+            .instantiate_and_start(&mut store, &self.module)
+            .map_err(|err| PluginError::InvalidModule(err.to_string()))?;
+
+        let memory: Memory = instance
+            .get_memory(&store, "memory")
+            .ok_or(PluginError::MissingExport { name: "memory" })?;
+        let alloc = instance
+            .get_typed_func::<i32, i32>(&store, "alloc")
+            .map_err(|_| PluginError::MissingExport { name: "alloc" })?;
+        let read_palette = instance
+            .get_typed_func::<(i32, i32), i64>(&store, "read_palette")
+            .map_err(|_| PluginError::MissingExport {
+                name: "read_palette",
+            })?;
+
+        let length = i32::try_from(input.len()).map_err(|_| PluginError::OutOfBounds)?;
+        let address = alloc.call(&mut store, length).map_err(trap)?;
+        memory
+            .write(&mut store, address as u32 as usize, input)
+            .map_err(|_| PluginError::OutOfBounds)?;
+
+        let packed = read_palette
+            .call(&mut store, (address, length))
+            .map_err(trap)? as u64;
+        let mut output = vec![0; packed as u32 as usize];
+        memory
+            .read(&store, (packed >> 32) as usize, &mut output)
+            .map_err(|_| PluginError::OutOfBounds)?;
+
+        let output = String::from_utf8_lossy(&output);
+        let file = parse_tokens(&output).map_err(PluginError::InvalidOutput)?;
+        Ok(file
+            .tokens
+            .into_iter()
+            .map(|token| (token.name, token.color))
+            .collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A plugin that returns the same palette whatever its input is.
+    const CONSTANT: &str = r##"(module
+        (memory (export "memory") 1)
+        (data (i32.const 0) "{\"text\": \"#112233\", \"surface\": \"white\"}")
+        (func (export "alloc") (param i32) (result i32) i32.const 1024)
+        (func (export "read_palette") (param i32 i32) (result i64) i64.const 39))"##;
+
+    #[test]
+    fn reads_the_output_of_every_run_with_a_new_instance() {
+        let source = WasmPaletteSource::new(CONSTANT.as_bytes()).unwrap();
+
+        for _ in 0..2 {
+            assert_eq!(
+                source.read(b"text=#112233").unwrap(),
+                vec![
+                    ("text".to_string(), Color::new(0x11, 0x22, 0x33)),
+                    ("surface".to_string(), Color::new(255, 255, 255)),
+                ]
+            );
+        }
+    }
+
+    #[test]
+    fn modules_that_loop_or_miss_exports_fail() {
+        let looping = WasmPaletteSource::new(
+            br#"(module
+                (memory (export "memory") 1)
+                (func (export "alloc") (param i32) (result i32) i32.const 0)
+                (func (export "read_palette") (param i32 i32) (result i64)
+                    (loop $forever (br $forever))
+                    i64.const 0))"#,
+        )
+        .unwrap()
+        .fuel(10_000);
+        assert!(matches!(looping.read(b""), Err(PluginError::Trap(_))));
+
+        let incomplete =
+            WasmPaletteSource::new(br#"(module (memory (export "memory") 1))"#).unwrap();
+        assert_eq!(
+            incomplete.read(b"").unwrap_err().to_string(),
+            "the module doesn't export alloc, see WasmPaletteSource"
+        );
+        assert!(matches!(
+            WasmPaletteSource::new(b"not wasm"),
+            Err(PluginError::InvalidModule(_))
+        ));
+    }
+}
@@ -0,0 +1,732 @@
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::fmt;
+use core::str::FromStr;
+
+use crate::oklab::linear_to_color;
+use crate::{Color, ColorFromHexError, GamutMapping};
+
+/// The longest input [`Color::from_css`] reads, in bytes. The longest valid colors, like
+/// `color(display-p3 ...)` with long decimals, are far shorter, so anything longer is rejected
+/// before it's parsed or copied into the error.
+pub const MAX_COLOR_LENGTH: usize = 256;
+
+/// What's wrong with the input of [`Color::from_css`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ParseColorErrorKind {
+    Empty,
+    NotAscii,
+    InvalidHexDigit,
+    InvalidHexLength,
+    UnknownColorName,
+    UnknownFunction,
+    UnknownColorSpace,
+    MissingClosingParenthesis,
+    InvalidArgumentCount,
+    InvalidNumber,
+    /// A channel of the `242,108,167` shorthand is above 255 or 100%, or negative.
+    ChannelOutOfRange,
+    /// The input is longer than [`MAX_COLOR_LENGTH`].
+    TooLong,
+}
+
+/// Errors returned by [`Color::from_css`]. They keep the input and the part of it that is wrong,
+/// so they can be shown like `"#12g456": invalid hex digit 'g' at position 3`, and
+/// [`ParseColorError::hint`] tells what the input should look like.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseColorError {
+    input: String,
+    kind: ParseColorErrorKind,
+    /// Byte offset and text of the wrong part of the input.
+    found: Option<(usize, String)>,
+}
+
+impl ParseColorError {
+    fn new(input: &str, kind: ParseColorErrorKind, found: Option<(usize, &str)>) -> Self {
+        ParseColorError {
+            input: input.to_string(),
+            kind,
+            found: found.map(|(position, found)| (position, found.to_string())),
+        }
+    }
+
+    /// The whole input, as it was passed to [`Color::from_css`]. Inputs longer than
+    /// [`MAX_COLOR_LENGTH`] are cut to its first bytes.
+    pub fn input(&self) -> &str {
+        &self.input
+    }
+
+    pub fn kind(&self) -> ParseColorErrorKind {
+        self.kind
+    }
+
+    /// Byte offset, counting from 0, where the wrong part of the input starts.
+    pub fn position(&self) -> Option<usize> {
+        self.found.as_ref().map(|(position, _)| *position)
+    }
+
+    /// The wrong part of the input, like the `g` of `#12g456` or the `x` of `rgb(1 x 3)`.
+    pub fn found(&self) -> Option<&str> {
+        self.found.as_ref().map(|(_, found)| found.as_str())
+    }
+
+    /// Describes the format that was expected.
+    pub fn hint(&self) -> &'static str {
+        match self.kind {
+            ParseColorErrorKind::Empty | ParseColorErrorKind::NotAscii => {
+                "expected a hex color like #f26ca7, a name like tomato or a function like \
+                 rgb(242 108 167)"
+            }
+            ParseColorErrorKind::InvalidHexDigit => {
+                "hex colors only use the digits 0-9 and a-f, like #f26ca7"
+            }
+            ParseColorErrorKind::InvalidHexLength => {
+                "hex colors have 3, 4, 6 or 8 digits with or without a starting #, like #f26ca7"
+            }
+            ParseColorErrorKind::UnknownColorName => {
+                "expected one of the CSS color names, like tomato or rebeccapurple"
+            }
+            ParseColorErrorKind::UnknownFunction => {
+                "expected rgb(), rgba(), hsl(), hsla(), oklab(), oklch() or color()"
+            }
+            ParseColorErrorKind::UnknownColorSpace => {
+                "color() supports display-p3, srgb and srgb-linear, like color(display-p3 1 0 0)"
+            }
+            ParseColorErrorKind::MissingClosingParenthesis => {
+                "functions end with ), like rgb(242 108 167)"
+            }
+            ParseColorErrorKind::InvalidArgumentCount => {
+                "expected 3 channels and an optional alpha value, like rgb(242 108 167 / 0.5)"
+            }
+            ParseColorErrorKind::InvalidNumber => "channels must be numbers, percentages or angles",
+            ParseColorErrorKind::ChannelOutOfRange => {
+                "channels go from 0 to 255 or from 0% to 100%, like 242,108,167 or 95%,42%,65%"
+            }
+            ParseColorErrorKind::TooLong => {
+                "colors are at most 256 bytes long, like #f26ca7 or rgb(242 108 167)"
+            }
+        }
+    }
+}
+
+impl fmt::Display for ParseColorError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "\"{}\": ", self.input)?;
+
+        let found = self.found().unwrap_or_default();
+        match self.kind {
+            ParseColorErrorKind::Empty => write!(f, "the color is empty")?,
+            ParseColorErrorKind::NotAscii => write!(f, "non ASCII character '{found}'")?,
+            ParseColorErrorKind::InvalidHexDigit => write!(f, "invalid hex digit '{found}'")?,
+            ParseColorErrorKind::InvalidHexLength => write!(f, "wrong number of hex digits")?,
+            ParseColorErrorKind::UnknownColorName => write!(f, "unknown color name")?,
+            ParseColorErrorKind::UnknownFunction => write!(f, "unknown function {found}()")?,
+            ParseColorErrorKind::UnknownColorSpace => write!(f, "unknown color space {found}")?,
+            ParseColorErrorKind::MissingClosingParenthesis => write!(f, "missing closing )")?,
+            ParseColorErrorKind::InvalidArgumentCount => write!(f, "wrong number of arguments")?,
+            ParseColorErrorKind::InvalidNumber => write!(f, "invalid number \"{found}\"")?,
+            ParseColorErrorKind::ChannelOutOfRange => {
+                write!(f, "channel \"{found}\" is out of range")?
+            }
+            ParseColorErrorKind::TooLong => write!(f, "the color is too long")?,
+        }
+
+        if let Some(position) = self.position() {
+            write!(f, " at position {position}")?;
+        }
+
+        Ok(())
+    }
+}
+
+impl core::error::Error for ParseColorError {}
+
+/// A problem found while parsing one part of the input, [`Color::from_css`] turns it into a
+/// [`ParseColorError`] that points to that part.
+struct InvalidPart<'a> {
+    kind: ParseColorErrorKind,
+    part: Option<&'a str>,
+}
+
+impl<'a> InvalidPart<'a> {
+    fn at(kind: ParseColorErrorKind, part: &'a str) -> Self {
+        InvalidPart {
+            kind,
+            part: Some(part),
+        }
+    }
+
+    /// `input` has to be the string every part was sliced from.
+    fn into_error(self, input: &str) -> ParseColorError {
+        let position = |part: &str| part.as_ptr() as usize - input.as_ptr() as usize;
+        ParseColorError::new(
+            input,
+            self.kind,
+            self.part.map(|part| (position(part), part)),
+        )
+    }
+}
+
+/// Parses a CSS number or percentage. Percentages are scaled so that 100% becomes `max`.
+fn parse_number_or_percentage(value: &str, max: f32) -> Result<f32, InvalidPart<'_>> {
+    let (number, scale) = match value.strip_suffix('%') {
+        Some(number) => (number, max / 100.0),
+        None => (value, 1.0),
+    };
+
+    match number.trim().parse::<f32>() {
+        Ok(number) if number.is_finite() => Ok(number * scale),
+        _ => Err(InvalidPart::at(ParseColorErrorKind::InvalidNumber, value)),
+    }
+}
+
+/// Splits the arguments of a CSS color function into its channels and optional alpha value.
+/// Both the legacy comma separated syntax and the modern space separated one are supported:
+/// `rgb(242, 108, 167, 0.8)` and `rgb(242 108 167 / 0.8)`.
+fn split_arguments(arguments: &str) -> Result<([&str; 3], Option<&str>), InvalidPart<'_>> {
+    let parts: Vec<&str> = if arguments.contains(',') {
+        arguments.split(',').map(str::trim).collect()
+    } else {
+        let (channels, alpha) = match arguments.split_once('/') {
+            Some((channels, alpha)) => (channels, Some(alpha.trim())),
+            None => (arguments, None),
+        };
+
+        channels.split_whitespace().chain(alpha).collect()
+    };
+
+    match parts.as_slice() {
+        [first, second, third] => Ok(([first, second, third], None)),
+        [first, second, third, alpha] => Ok(([first, second, third], Some(alpha))),
+        _ => Err(InvalidPart {
+            kind: ParseColorErrorKind::InvalidArgumentCount,
+            part: None,
+        }),
+    }
+}
+
+/// Parses `rgb(...)` and `rgba(...)`. Channels out of the 0-255 range are clamped like browsers do.
+fn parse_rgb_function(arguments: &str) -> Result<Color, InvalidPart<'_>> {
+    let (channels, alpha) = split_arguments(arguments)?;
+    let [red, green, blue] = channels;
+
+    Ok(Color::from_f32_channels(
+        parse_number_or_percentage(red, 255.0)?,
+        parse_number_or_percentage(green, 255.0)?,
+        parse_number_or_percentage(blue, 255.0)?,
+    )
+    .with_opacity(parse_alpha(alpha)?))
+}
+
+/// Parses a CSS hue, which is an angle in degrees unless it has a `deg`, `grad`, `rad` or `turn`
+/// unit. The result is in degrees but isn't wrapped to 0-360.
+fn parse_hue(value: &str) -> Result<f32, InvalidPart<'_>> {
+    let units = [
+        ("deg", 1.0),
+        ("grad", 0.9),
+        ("rad", 180.0 / core::f32::consts::PI),
+        ("turn", 360.0),
+    ];
+
+    let lowercase = value.to_ascii_lowercase();
+    let (number, scale) = units
+        .iter()
+        .find_map(|(unit, scale)| lowercase.strip_suffix(unit).map(|number| (number, *scale)))
+        .unwrap_or((lowercase.as_str(), 1.0));
+
+    match number.trim().parse::<f32>() {
+        Ok(number) if number.is_finite() => Ok(number * scale),
+        _ => Err(InvalidPart::at(ParseColorErrorKind::InvalidNumber, value)),
+    }
+}
+
+/// Parses the optional alpha value of a CSS color function, a missing alpha means opaque.
+fn parse_alpha(alpha: Option<&str>) -> Result<f32, InvalidPart<'_>> {
+    match alpha {
+        Some(alpha) => Ok(parse_number_or_percentage(alpha, 1.0)?.clamp(0.0, 1.0)),
+        None => Ok(1.0),
+    }
+}
+
+/// Parses `hsl(...)` and `hsla(...)`. Saturation and lightness are percentages, plain numbers are
+/// treated as percentages too like the modern CSS syntax allows.
+fn parse_hsl_function<'a>(arguments: &'a str) -> Result<Color, InvalidPart<'a>> {
+    let (channels, alpha) = split_arguments(arguments)?;
+    let [hue, saturation, lightness] = channels;
+    let percentage = |value: &'a str| {
+        let number = value.strip_suffix('%').unwrap_or(value);
+        parse_number_or_percentage(number, 1.0)
+            .map(|number| (number / 100.0).clamp(0.0, 1.0))
+            // Point at the whole value, with its %
+            .map_err(|_| InvalidPart::at(ParseColorErrorKind::InvalidNumber, value))
+    };
+
+    let color = Color::from_hsl(
+        parse_hue(hue)?,
+        percentage(saturation)?,
+        percentage(lightness)?,
+    );
+
+    Ok(color.with_opacity(parse_alpha(alpha)?))
+}
+
+/// Parses `oklab(...)`. Lightness is a number from 0 to 1 or a percentage, `a` and `b` are
+/// numbers or percentages where 100% is 0.4.
+fn parse_oklab_function(arguments: &str) -> Result<Color, InvalidPart<'_>> {
+    let (channels, alpha) = split_arguments(arguments)?;
+    let [lightness, a, b] = channels;
+
+    let color = Color::from_oklab(
+        parse_number_or_percentage(lightness, 1.0)?,
+        parse_number_or_percentage(a, 0.4)?,
+        parse_number_or_percentage(b, 0.4)?,
+    );
+
+    Ok(color.with_opacity(parse_alpha(alpha)?))
+}
+
+/// Parses `oklch(...)`, e.g. `oklch(0.7 0.15 340)`. Lightness is like in `oklab()`, chroma is a
+/// number or a percentage where 100% is 0.4 and the hue is an angle.
+fn parse_oklch_function(arguments: &str) -> Result<Color, InvalidPart<'_>> {
+    let (channels, alpha) = split_arguments(arguments)?;
+    let [lightness, chroma, hue] = channels;
+
+    let color = Color::from_oklch(
+        parse_number_or_percentage(lightness, 1.0)?,
+        parse_number_or_percentage(chroma, 0.4)?,
+        parse_hue(hue)?,
+    );
+
+    Ok(color.with_opacity(parse_alpha(alpha)?))
+}
+
+/// Parses `color(...)` with one of the predefined color spaces, e.g. `color(display-p3 1 0 0)`.
+/// Channels are numbers from 0 to 1 or percentages, colors outside of sRGB are mapped into it.
+fn parse_color_function(arguments: &str, mapping: GamutMapping) -> Result<Color, InvalidPart<'_>> {
+    let arguments = arguments.trim_start();
+    let (space, channels) = arguments
+        .split_once(char::is_whitespace)
+        .unwrap_or((arguments, ""));
+    let (channels, alpha) = split_arguments(channels)?;
+    let [red, green, blue] = channels;
+    let (red, green, blue) = (
+        parse_number_or_percentage(red, 1.0)?,
+        parse_number_or_percentage(green, 1.0)?,
+        parse_number_or_percentage(blue, 1.0)?,
+    );
+
+    let color = match space.to_ascii_lowercase().as_str() {
+        "display-p3" => Color::from_display_p3_with(red, green, blue, mapping),
+        "srgb" => Color::from_f32_channels(red * 255.0, green * 255.0, blue * 255.0),
+        "srgb-linear" => linear_to_color([red, green, blue]),
+        _ => {
+            return Err(InvalidPart::at(
+                ParseColorErrorKind::UnknownColorSpace,
+                space,
+            ))
+        }
+    };
+
+    Ok(color.with_opacity(parse_alpha(alpha)?))
+}
+
+/// Parses the `242,108,167` and `95%,42%,65%` shorthands, with an optional alpha like
+/// `242,108,167,0.5`. Unlike `rgb()`, which clamps like browsers do, channels out of range are an
+/// error since these are typed by hand.
+fn parse_channel_list<'a>(input: &'a str) -> Result<Color, InvalidPart<'a>> {
+    let (channels, alpha) = split_arguments(input)?;
+    let channel = |value: &'a str| {
+        let channel = parse_number_or_percentage(value, 255.0)?;
+        if (0.0..=255.0).contains(&channel) {
+            Ok(channel)
+        } else {
+            Err(InvalidPart::at(
+                ParseColorErrorKind::ChannelOutOfRange,
+                value,
+            ))
+        }
+    };
+    let [red, green, blue] = channels;
+
+    let color = Color::from_f32_channels(channel(red)?, channel(green)?, channel(blue)?);
+    match alpha {
+        Some(value) if !(0.0..=1.0).contains(&parse_number_or_percentage(value, 1.0)?) => Err(
+            InvalidPart::at(ParseColorErrorKind::ChannelOutOfRange, value),
+        ),
+        _ => Ok(color.with_opacity(parse_alpha(alpha)?)),
+    }
+}
+
+/// Finds where [`Color::from_hex`] failed in `hex`, which has to be trimmed.
+fn invalid_hex<'a>(hex: &'a str, error: &ColorFromHexError) -> InvalidPart<'a> {
+    let digits = hex.strip_prefix('#').unwrap_or(hex);
+    let first_wrong = |is_wrong: fn(&char) -> bool| {
+        digits
+            .char_indices()
+            .find(|(_, c)| is_wrong(c))
+            .map(|(index, c)| &digits[index..index + c.len_utf8()])
+    };
+
+    let (kind, part) = match error {
+        ColorFromHexError::InputIsEmpty => (ParseColorErrorKind::Empty, None),
+        ColorFromHexError::InputIsNotAscii => (
+            ParseColorErrorKind::NotAscii,
+            first_wrong(|c| !c.is_ascii()),
+        ),
+        ColorFromHexError::InvalidInputLength => (ParseColorErrorKind::InvalidHexLength, None),
+        ColorFromHexError::InvalidChannel(_) => (
+            ParseColorErrorKind::InvalidHexDigit,
+            first_wrong(|c| !c.is_ascii_hexdigit()),
+        ),
+    };
+
+    InvalidPart { kind, part }
+}
+
+impl Color {
+    /// Parses a color written the way CSS accepts it: hex like [`Color::from_hex`], or the
+    /// functional `rgb()`/`rgba()` notation with numbers or percentages, e.g.
+    /// `rgb(242, 108, 167)` or `rgb(95% 42% 65%)`, or the `hsl()`/`hsla()` notation, e.g.
+    /// `hsl(340, 82%, 59%)` or `hsl(340deg 82% 59%)`, or the `oklab()` and `oklch()`
+    /// notations, e.g. `oklch(0.7 0.15 340)`, or `color()` with the `display-p3`, `srgb` and
+    /// `srgb-linear` spaces, e.g. `color(display-p3 1 0.4 0.6)`. Named colors like `tomato` are
+    /// supported as well, see [`Color::from_name`], and so are comma separated channels like
+    /// `242,108,167` or `95%,42%,65%`, which are an error when out of range.
+    ///
+    /// Display P3 colors that sRGB can't show have their chroma reduced, like CSS does. Use
+    /// [`Color::from_css_with`] to clip them instead.
+    ///
+    /// ```
+    /// use contrast_checker::Color;
+    ///
+    /// let error = Color::from_css("#12g456").unwrap_err();
+    /// assert_eq!(error.to_string(), "\"#12g456\": invalid hex digit 'g' at position 3");
+    /// ```
+    pub fn from_css(input: &str) -> Result<Color, ParseColorError> {
+        Color::from_css_with(input, GamutMapping::default())
+    }
+
+    /// Same as [`Color::from_css`] with a choice of how wide gamut colors are mapped into sRGB.
+    pub fn from_css_with(input: &str, mapping: GamutMapping) -> Result<Color, ParseColorError> {
+        if input.len() > MAX_COLOR_LENGTH {
+            let mut end = MAX_COLOR_LENGTH;
+            while !input.is_char_boundary(end) {
+                end -= 1;
+            }
+            return Err(ParseColorError::new(
+                &input[..end],
+                ParseColorErrorKind::TooLong,
+                None,
+            ));
+        }
+        let trimmed = input.trim();
+
+        let Some((function, arguments)) = trimmed.split_once('(') else {
+            if trimmed.contains(',') {
+                log::debug!("{input:?} looks like a list of channels");
+                return parse_channel_list(trimmed).map_err(|part| part.into_error(input));
+            }
+
+            // Words that can't be hex digits are treated as names, so typos like "tomatoe"
+            // report an unknown name instead of a hex error
+            let is_name = trimmed.chars().all(|c| c.is_ascii_alphabetic())
+                && !trimmed.chars().all(|c| c.is_ascii_hexdigit());
+
+            if is_name {
+                log::debug!("{input:?} looks like a color name");
+                return Color::from_name(trimmed).ok_or_else(|| {
+                    ParseColorError::new(input, ParseColorErrorKind::UnknownColorName, None)
+                });
+            }
+
+            log::debug!("{input:?} looks like hex");
+            return Color::from_hex(trimmed)
+                .map_err(|error| invalid_hex(trimmed, &error).into_error(input));
+        };
+
+        let Some(arguments) = arguments.strip_suffix(')') else {
+            let end = input.trim_end().len();
+            return Err(InvalidPart::at(
+                ParseColorErrorKind::MissingClosingParenthesis,
+                &input[end..end],
+            )
+            .into_error(input));
+        };
+
+        let function = function.trim();
+        log::debug!("{input:?} is a {function}() function");
+        let color = match function.to_ascii_lowercase().as_str() {
+            "rgb" | "rgba" => parse_rgb_function(arguments),
+            "hsl" | "hsla" => parse_hsl_function(arguments),
+            "oklab" => parse_oklab_function(arguments),
+            "oklch" => parse_oklch_function(arguments),
+            "color" => parse_color_function(arguments, mapping),
+            _ => Err(InvalidPart::at(
+                ParseColorErrorKind::UnknownFunction,
+                function,
+            )),
+        };
+
+        color.map_err(|part| part.into_error(input))
+    }
+}
+
+/// Parses a color in any notation the crate reads: hex, CSS names and functions, and the
+/// `242,108,167` or `95%,42%,65%` shorthands. It's the same as [`Color::from_css`], and what the
+/// command line tool, token files and stylesheet scans all go through.
+///
+/// ```
+/// use contrast_checker::{parse_color, Color};
+///
+/// assert_eq!(parse_color("242,108,167"), Ok(Color::new(242, 108, 167)));
+/// assert_eq!(parse_color("100%,0%,0%"), parse_color("red"));
+/// assert!(parse_color("256,0,0").is_err());
+/// ```
+pub fn parse_color(input: &str) -> Result<Color, ParseColorError> {
+    Color::from_css(input)
+}
+
+/// Parses any notation [`Color::from_css`] accepts, so `"#336699".parse::<Color>()` works.
+impl FromStr for Color {
+    type Err = ParseColorError;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        Color::from_css(input)
+    }
+}
+
+impl TryFrom<&str> for Color {
+    type Error = ParseColorError;
+
+    fn try_from(input: &str) -> Result<Self, Self::Error> {
+        Color::from_css(input)
+    }
+}
+
+/// Every `(red, green, blue)` tuple is a valid opaque color, `Color::try_from` works with it
+/// as well through the standard blanket implementation.
+impl From<(u8, u8, u8)> for Color {
+    fn from((red, green, blue): (u8, u8, u8)) -> Self {
+        Color::new(red, green, blue)
+    }
+}
+
+/// Splits a list of colors separated by whitespace, commas or semicolons, like
+/// `#fff, rgb(0 0 0)`. Separators inside parentheses belong to the color, so functional
+/// notations are kept together, and so are comma separated channels without spaces, like
+/// `242,108,167`. [`split_colors_iter`] does the same without collecting them.
+///
+/// ```
+/// use contrast_checker::split_colors;
+///
+/// assert_eq!(
+///     split_colors("#777 rgb(255, 255, 255)"),
+///     vec!["#777", "rgb(255, 255, 255)"]
+/// );
+/// assert_eq!(split_colors("242,108,167 fff"), vec!["242,108,167", "fff"]);
+/// ```
+pub fn split_colors(input: &str) -> Vec<&str> {
+    split_colors_iter(input).collect()
+}
+
+/// Iterates over the colors of a list like [`split_colors`] does, borrowing them from `input`
+/// without allocating, for reading big files one line at a time.
+///
+/// ```
+/// use contrast_checker::split_colors_iter;
+///
+/// let mut colors = split_colors_iter("#777; hsl(0 0% 100%)");
+/// assert_eq!(colors.next(), Some("#777"));
+/// assert_eq!(colors.next(), Some("hsl(0 0% 100%)"));
+/// assert_eq!(colors.next(), None);
+/// ```
+pub fn split_colors_iter(input: &str) -> SplitColors<'_> {
+    SplitColors { input, position: 0 }
+}
+
+/// The length of the comma separated channels `input` starts with, three or four numbers or
+/// percentages without spaces like `242,108,167`, which are a single color.
+fn channel_list_len(input: &str) -> Option<usize> {
+    let len = input
+        .find(|c: char| c.is_whitespace() || c == ';')
+        .unwrap_or(input.len());
+    let channels = input[..len].split(',');
+    let is_channel = |channel: &str| {
+        !channel.is_empty()
+            && channel
+                .chars()
+                .all(|c| c.is_ascii_digit() || matches!(c, '.' | '-' | '%'))
+    };
+    let count = channels.clone().count();
+    ((3..=4).contains(&count) && { channels }.all(is_channel)).then_some(len)
+}
+
+/// The iterator returned by [`split_colors_iter`].
+#[derive(Debug, Clone)]
+pub struct SplitColors<'a> {
+    input: &'a str,
+    position: usize,
+}
+
+impl<'a> SplitColors<'a> {
+    /// The part of the input that hasn't been split yet, starting at the separator after the
+    /// last color returned.
+    pub fn rest(&self) -> &'a str {
+        &self.input[self.position..]
+    }
+}
+
+impl<'a> Iterator for SplitColors<'a> {
+    type Item = &'a str;
+
+    fn next(&mut self) -> Option<&'a str> {
+        let rest = &self.input[self.position..];
+        let mut depth = 0usize;
+        let mut start = None;
+
+        for (index, c) in rest.char_indices() {
+            match c {
+                '(' => depth += 1,
+                ')' => depth = depth.saturating_sub(1),
+                _ => {}
+            }
+
+            let is_separator = depth == 0 && (c.is_whitespace() || c == ',' || c == ';');
+            match (is_separator, start) {
+                (true, Some(color_start)) => {
+                    self.position += index;
+                    return Some(&rest[color_start..index]);
+                }
+                (false, None) => {
+                    if let Some(len) = channel_list_len(&rest[index..]) {
+                        self.position += index + len;
+                        return Some(&rest[index..index + len]);
+                    }
+                    start = Some(index);
+                }
+                _ => {}
+            }
+        }
+
+        self.position = self.input.len();
+        start.map(|color_start| &rest[color_start..])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::string::ToString;
+
+    fn error(input: &str) -> ParseColorError {
+        Color::from_css(input).unwrap_err()
+    }
+
+    #[test]
+    fn errors_point_at_the_wrong_part() {
+        let hex = error("  #12g456");
+        assert_eq!(hex.kind(), ParseColorErrorKind::InvalidHexDigit);
+        assert_eq!((hex.position(), hex.found()), (Some(5), Some("g")));
+
+        let number = error("rgb(10, 2x, 30)");
+        assert_eq!(number.kind(), ParseColorErrorKind::InvalidNumber);
+        assert_eq!((number.position(), number.found()), (Some(8), Some("2x")));
+
+        let hue = error("hsl(1zz 50% 50%)");
+        assert_eq!((hue.position(), hue.found()), (Some(4), Some("1zz")));
+
+        let function = error("lab(1 2 3)");
+        assert_eq!(function.kind(), ParseColorErrorKind::UnknownFunction);
+        assert_eq!(function.found(), Some("lab"));
+    }
+
+    #[test]
+    fn errors_without_a_position() {
+        for (input, kind) in [
+            ("", ParseColorErrorKind::Empty),
+            ("#12345", ParseColorErrorKind::InvalidHexLength),
+            ("tomatoe", ParseColorErrorKind::UnknownColorName),
+            ("rgb(1 2)", ParseColorErrorKind::InvalidArgumentCount),
+        ] {
+            let error = error(input);
+            assert_eq!(error.kind(), kind, "{input:?}");
+            assert_eq!(error.position(), None, "{input:?}");
+            assert_eq!(error.input(), input);
+        }
+    }
+
+    #[test]
+    fn parses_predefined_color_spaces() {
+        let color = |input: &str| Color::from_css(input).unwrap();
+
+        assert_eq!(color("color(srgb 1 0.4 60%)"), Color::new(255, 102, 153));
+        assert_eq!(color("color(display-p3 1 1 1)"), Color::new(255, 255, 255));
+        assert_eq!(color("color(srgb-linear 0 0 1 / 0.5)").alpha, 128);
+
+        // Outside of sRGB, clipping gives pure red and reducing chroma a lighter red
+        let p3_red = "color(display-p3 1 0 0)";
+        assert_eq!(
+            Color::from_css_with(p3_red, GamutMapping::Clip).unwrap(),
+            Color::new(255, 0, 0)
+        );
+        let mapped = color(p3_red);
+        assert_ne!(mapped, Color::new(255, 0, 0));
+        assert!((mapped.to_oklch().2 - Color::new(255, 0, 0).to_oklch().2).abs() < 5.0);
+
+        let space = error("color(rec2020 1 0 0)");
+        assert_eq!(space.kind(), ParseColorErrorKind::UnknownColorSpace);
+        assert_eq!(
+            (space.position(), space.found()),
+            (Some(6), Some("rec2020"))
+        );
+    }
+
+    #[test]
+    fn parses_comma_separated_channels() {
+        assert_eq!(parse_color(" 242, 108,167 "), Ok(Color::new(242, 108, 167)));
+        assert_eq!(parse_color("100%,50%,0%"), Ok(Color::new(255, 128, 0)));
+        assert_eq!(parse_color("0,0,0,50%").unwrap().alpha, 128);
+
+        for (input, found) in [("256,0,0", "256"), ("0,-1,0", "-1"), ("0,0,101%", "101%")] {
+            let error = error(input);
+            assert_eq!(error.kind(), ParseColorErrorKind::ChannelOutOfRange);
+            assert_eq!(error.found(), Some(found));
+        }
+        assert_eq!(
+            error("1,2").kind(),
+            ParseColorErrorKind::InvalidArgumentCount
+        );
+    }
+
+    #[test]
+    fn lists_keep_comma_separated_channels_together() {
+        // What filter reads from a file, and a pair of batch
+        assert_eq!(split_colors("242,108,167\n"), ["242,108,167"]);
+        assert_eq!(split_colors("242,108,167 fff"), ["242,108,167", "fff"]);
+        assert_eq!(
+            split_colors("0,0,0,50%;100%,50%,0%"),
+            ["0,0,0,50%", "100%,50%,0%"]
+        );
+
+        // Lists of hex colors still split, with or without spaces
+        assert_eq!(split_colors("fff,000"), ["fff", "000"]);
+        assert_eq!(split_colors("111, 222, 333"), ["111", "222", "333"]);
+        assert_eq!(split_colors("fff,000,eee"), ["fff", "000", "eee"]);
+    }
+
+    #[test]
+    fn errors_are_displayed_with_the_input() {
+        assert_eq!(
+            error("#12g456").to_string(),
+            "\"#12g456\": invalid hex digit 'g' at position 3"
+        );
+        assert_eq!(
+            error("rgb(1 2 3").to_string(),
+            "\"rgb(1 2 3\": missing closing ) at position 9"
+        );
+        assert_eq!(
+            error("#ffé").to_string(),
+            "\"#ffé\": non ASCII character 'é' at position 3"
+        );
+    }
+}
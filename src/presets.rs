@@ -0,0 +1,346 @@
+//! The role pairs of well-known theme systems and the WCAG requirement of each, so their themes
+//! can be audited without listing the pairs by hand. A preset finds its roles among the names of
+//! a palette or token file, whatever their prefix or case:
+//!
+//! ```
+//! use contrast_checker::presets::ThemePreset;
+//! use contrast_checker::ContrastContext;
+//!
+//! let names = ["md.sys.color.primary", "md.sys.color.on-primary", "md.sys.color.outline",
+//!              "md.sys.color.surface"];
+//! let pairs = ThemePreset::Material3.find_pairs(&names);
+//!
+//! let on_primary = &pairs[0];
+//! assert_eq!((on_primary.foreground, on_primary.background), (1, 0));
+//! assert_eq!(on_primary.role.context, ContrastContext::Text);
+//! // Outlines are graded as non-text contrast
+//! assert!(pairs.iter().any(|pair| pair.role.foreground == "outline"
+//!     && pair.role.context == ContrastContext::Ui));
+//! ```
+
+use alloc::string::String;
+use alloc::vec::Vec;
+
+use crate::{ContrastContext, WcagLevel};
+
+/// A foreground role used on a background role, and what it's used for.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RolePair {
+    pub foreground: &'static str,
+    pub background: &'static str,
+    pub context: ContrastContext,
+}
+
+impl RolePair {
+    /// The AA ratio the pair needs, 4.5:1 for text and 3:1 for large text and UI components.
+    pub fn minimum_ratio(&self) -> f32 {
+        // Every context has an AA requirement
+        self.context.minimum_ratio(WcagLevel::AA).unwrap_or(4.5)
+    }
+}
+
+/// A role pair found among names by [`ThemePreset::find_pairs`], with the indexes of its colors.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PresetPair {
+    pub foreground: usize,
+    pub background: usize,
+    pub role: RolePair,
+}
+
+/// A theme system whose role pairs are built in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ThemePreset {
+    /// The color roles of Material Design 3, like `on-primary` on `primary`.
+    Material3,
+    /// The theme variables of Bootstrap 5.3, like `body-color` on `body-bg`.
+    Bootstrap,
+    /// The workbench colors of a VS Code color theme, like `editor.foreground` on
+    /// `editor.background`.
+    VsCodeTheme,
+}
+
+const fn text(foreground: &'static str, background: &'static str) -> RolePair {
+    RolePair {
+        foreground,
+        background,
+        context: ContrastContext::Text,
+    }
+}
+
+const fn ui(foreground: &'static str, background: &'static str) -> RolePair {
+    RolePair {
+        foreground,
+        background,
+        context: ContrastContext::Ui,
+    }
+}
+
+/// Roles obtained from https://m3.material.io/styles/color/roles
+const MATERIAL3: &[RolePair] = &[
+    text("on-primary", "primary"),
+    text("on-primary-container", "primary-container"),
+    text("on-secondary", "secondary"),
+    text("on-secondary-container", "secondary-container"),
+    text("on-tertiary", "tertiary"),
+    text("on-tertiary-container", "tertiary-container"),
+    text("on-error", "error"),
+    text("on-error-container", "error-container"),
+    text("on-surface", "surface"),
+    text("on-surface-variant", "surface"),
+    text("on-surface", "surface-container"),
+    text("on-surface-variant", "surface-container"),
+    text("on-background", "background"),
+    text("inverse-on-surface", "inverse-surface"),
+    text("inverse-primary", "inverse-surface"),
+    text("primary", "surface"),
+    ui("outline", "surface"),
+];
+
+/// Variables obtained from https://getbootstrap.com/docs/5.3/customize/color/
+const BOOTSTRAP: &[RolePair] = &[
+    text("body-color", "body-bg"),
+    text("emphasis-color", "body-bg"),
+    text("secondary-color", "body-bg"),
+    text("link-color", "body-bg"),
+    text("primary-text-emphasis", "primary-bg-subtle"),
+    text("secondary-text-emphasis", "secondary-bg-subtle"),
+    text("success-text-emphasis", "success-bg-subtle"),
+    text("info-text-emphasis", "info-bg-subtle"),
+    text("warning-text-emphasis", "warning-bg-subtle"),
+    text("danger-text-emphasis", "danger-bg-subtle"),
+    text("light-text-emphasis", "light-bg-subtle"),
+    text("dark-text-emphasis", "dark-bg-subtle"),
+];
+
+/// Colors obtained from https://code.visualstudio.com/api/references/theme-color
+const VSCODE_THEME: &[RolePair] = &[
+    text("editor.foreground", "editor.background"),
+    text("editorLineNumber.foreground", "editor.background"),
+    text("editorLineNumber.activeForeground", "editor.background"),
+    ui("editorCursor.foreground", "editor.background"),
+    text("editorWidget.foreground", "editorWidget.background"),
+    text("sideBar.foreground", "sideBar.background"),
+    text("sideBarTitle.foreground", "sideBar.background"),
+    ui("activityBar.foreground", "activityBar.background"),
+    text("activityBarBadge.foreground", "activityBarBadge.background"),
+    text("statusBar.foreground", "statusBar.background"),
+    text("titleBar.activeForeground", "titleBar.activeBackground"),
+    text("tab.activeForeground", "tab.activeBackground"),
+    text("tab.inactiveForeground", "tab.inactiveBackground"),
+    text("panelTitle.activeForeground", "panel.background"),
+    text("terminal.foreground", "terminal.background"),
+    text("button.foreground", "button.background"),
+    text("input.foreground", "input.background"),
+    text("input.placeholderForeground", "input.background"),
+    text(
+        "list.activeSelectionForeground",
+        "list.activeSelectionBackground",
+    ),
+    text("badge.foreground", "badge.background"),
+    text("notifications.foreground", "notifications.background"),
+];
+
+/// Lowercase without the `-` and `_` separators, so `onPrimary` and `on_primary` are the same.
+fn normalize(name: &str) -> String {
+    name.chars()
+        .filter(|c| *c != '-' && *c != '_')
+        .flat_map(char::to_lowercase)
+        .collect()
+}
+
+/// What's in front of `role` when `name` ends with it, normalized, so the foreground and
+/// background of a pair can be matched in the same theme of a file with several. Dots separate
+/// segments, and the first segment of the role can also end a segment after a `-`, like
+/// `--bs-body-color`.
+fn role_prefix(name: &str, role: &str) -> Option<String> {
+    let segments: Vec<&str> = name.split('.').collect();
+    let roles: Vec<&str> = role.split('.').collect();
+    let start = segments.len().checked_sub(roles.len())?;
+    if segments[start + 1..]
+        .iter()
+        .zip(&roles[1..])
+        .any(|(segment, role)| normalize(segment) != normalize(role))
+    {
+        return None;
+    }
+
+    let first = segments[start];
+    let mut prefix = normalize(&segments[..start].join("."));
+    prefix.push('|');
+    if normalize(first) != normalize(roles[0]) {
+        let lowercase = first.to_lowercase().replace('_', "-");
+        let leftover = lowercase.strip_suffix(&roles[0].to_lowercase())?;
+        prefix.push_str(&normalize(leftover.strip_suffix('-')?));
+    }
+
+    Some(prefix)
+}
+
+impl ThemePreset {
+    pub const ALL: [ThemePreset; 3] = [
+        ThemePreset::Material3,
+        ThemePreset::Bootstrap,
+        ThemePreset::VsCodeTheme,
+    ];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            ThemePreset::Material3 => "material3",
+            ThemePreset::Bootstrap => "bootstrap",
+            ThemePreset::VsCodeTheme => "vscode-theme",
+        }
+    }
+
+    pub fn from_name(name: &str) -> Option<ThemePreset> {
+        ThemePreset::ALL
+            .into_iter()
+            .find(|preset| preset.name().eq_ignore_ascii_case(name))
+    }
+
+    /// The role pairs of the theme system, in the order they're checked.
+    pub fn role_pairs(self) -> &'static [RolePair] {
+        match self {
+            ThemePreset::Material3 => MATERIAL3,
+            ThemePreset::Bootstrap => BOOTSTRAP,
+            ThemePreset::VsCodeTheme => VSCODE_THEME,
+        }
+    }
+
+    /// The role of a name and what's in front of it, the longest role the name ends with so
+    /// `on-primary` isn't taken for `primary`.
+    fn role_of(self, name: &str) -> Option<(&'static str, String)> {
+        self.role_pairs()
+            .iter()
+            .flat_map(|pair| [pair.foreground, pair.background])
+            .filter_map(|role| Some((role, role_prefix(name, role)?)))
+            .max_by_key(|(role, _)| normalize(role).len())
+    }
+
+    /// Finds the role pairs among the names of a palette or token file. A name has a role when it
+    /// ends with it, ignoring case, `-` and `_`, so `md.sys.color.on-primary`,
+    /// `schemes.light.onPrimary` and `--bs-body-color` all work. Both colors of a pair have the
+    /// same prefix, a file with a light and a dark theme gets the pairs of each. Pairs are in the
+    /// order of [`ThemePreset::role_pairs`].
+    pub fn find_pairs<S: AsRef<str>>(self, names: &[S]) -> Vec<PresetPair> {
+        let roles: Vec<Option<(&str, String)>> = names
+            .iter()
+            .map(|name| self.role_of(name.as_ref()))
+            .collect();
+        let mut pairs = Vec::new();
+        for role in self.role_pairs() {
+            for (foreground, found) in roles.iter().enumerate() {
+                let Some((_, prefix)) = found.as_ref().filter(|(name, _)| *name == role.foreground)
+                else {
+                    continue;
+                };
+                let background = roles.iter().position(|found| {
+                    found
+                        .as_ref()
+                        .is_some_and(|(name, other)| *name == role.background && other == prefix)
+                });
+                if let Some(background) = background {
+                    pairs.push(PresetPair {
+                        foreground,
+                        background,
+                        role: *role,
+                    });
+                }
+            }
+        }
+
+        pairs
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn roles_match_whatever_the_naming() {
+        assert_eq!(
+            role_prefix("on-primary", "on-primary").as_deref(),
+            Some("|")
+        );
+        assert_eq!(
+            role_prefix("schemes.light.onPrimary", "on-primary").as_deref(),
+            Some("schemes.light|")
+        );
+        assert_eq!(
+            role_prefix("--bs-body-color", "body-color").as_deref(),
+            Some("|bs")
+        );
+        assert_eq!(
+            role_prefix(
+                "colors.editorLineNumber.foreground",
+                "editorLineNumber.foreground"
+            )
+            .as_deref(),
+            Some("colors|")
+        );
+
+        assert_eq!(
+            role_prefix("colors.diffEditor.foreground", "editor.foreground"),
+            None
+        );
+        assert_eq!(role_prefix("primary", "on-primary"), None);
+        assert_eq!(role_prefix("onprimary", "primary"), None);
+    }
+
+    #[test]
+    fn names_get_their_longest_role() {
+        let names = [
+            "--md-sys-color-on-primary",
+            "--md-sys-color-primary",
+            "--md-sys-color-on-surface",
+            "--md-sys-color-surface",
+            "--md-sys-color-inverse-on-surface",
+            "--md-sys-color-inverse-surface",
+        ];
+        let found: Vec<(&str, &str)> = ThemePreset::Material3
+            .find_pairs(&names)
+            .iter()
+            .map(|pair| (pair.role.foreground, pair.role.background))
+            .collect();
+
+        assert_eq!(
+            found,
+            [
+                ("on-primary", "primary"),
+                ("on-surface", "surface"),
+                ("inverse-on-surface", "inverse-surface"),
+                ("primary", "surface"),
+            ]
+        );
+    }
+
+    #[test]
+    fn pairs_stay_in_their_theme() {
+        let names = [
+            "schemes.light.primary",
+            "schemes.light.onPrimary",
+            "schemes.dark.primary",
+            "schemes.dark.onPrimary",
+            "schemes.dark.surface",
+        ];
+        let pairs = ThemePreset::Material3.find_pairs(&names);
+        let found: Vec<(usize, usize)> = pairs
+            .iter()
+            .map(|pair| (pair.foreground, pair.background))
+            .collect();
+
+        // on-primary in both themes, then primary on the dark surface only
+        assert_eq!(found, [(1, 0), (3, 2), (2, 4)]);
+        assert_eq!(pairs[2].role.minimum_ratio(), 4.5);
+    }
+
+    #[test]
+    fn presets_have_names() {
+        for preset in ThemePreset::ALL {
+            assert_eq!(ThemePreset::from_name(preset.name()), Some(preset));
+            assert!(!preset.role_pairs().is_empty());
+        }
+        assert_eq!(ThemePreset::from_name("tailwind"), None);
+        assert_eq!(ui("a", "b").minimum_ratio(), 3.0);
+    }
+}
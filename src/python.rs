@@ -0,0 +1,96 @@
+//! Python bindings, a `contrast_checker` module built with `maturin build --release`. Colors are
+//! passed as strings in any notation [`Color::from_css`] accepts:
+//!
+//! ```python
+//! import contrast_checker
+//!
+//! contrast_checker.contrast_ratio("#F26CA7", "white")  # 2.81...
+//! contrast_checker.parse_color("tomato")  # (255, 99, 71, 255)
+//! contrast_checker.check_compliance("#777", "white").aa_large_text  # True
+//! ```
+
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+
+use crate::Color;
+
+fn parse(input: &str) -> PyResult<Color> {
+    Color::from_css(input).map_err(|err| PyValueError::new_err(format!("invalid color {err}")))
+}
+
+/// `contrast_ratio("#F26CA7", "white")` returns the WCAG contrast ratio, from 1 to 21.
+#[pyfunction]
+fn contrast_ratio(foreground: &str, background: &str) -> PyResult<f32> {
+    Ok(crate::contrast_ratio(
+        &parse(foreground)?,
+        &parse(background)?,
+    ))
+}
+
+/// `parse_color("tomato")` returns the red, green, blue and alpha channels, from 0 to 255.
+#[pyfunction]
+fn parse_color(input: &str) -> PyResult<(u8, u8, u8, u8)> {
+    let color = parse(input)?;
+    Ok((color.red, color.green, color.blue, color.alpha))
+}
+
+/// The result of `check_compliance`, an immutable object with the ratio and every verdict.
+#[pyclass(frozen, get_all, skip_from_py_object, module = "contrast_checker")]
+#[derive(Debug, Clone, Copy)]
+struct Compliance {
+    ratio: f32,
+    aa_normal_text: bool,
+    aa_large_text: bool,
+    aaa_normal_text: bool,
+    aaa_large_text: bool,
+    indistinguishable: bool,
+}
+
+#[pymethods]
+impl Compliance {
+    fn __repr__(&self) -> String {
+        format!(
+            "Compliance(ratio={}, aa_normal_text={}, aa_large_text={}, aaa_normal_text={}, \
+             aaa_large_text={}, indistinguishable={})",
+            self.ratio,
+            python_bool(self.aa_normal_text),
+            python_bool(self.aa_large_text),
+            python_bool(self.aaa_normal_text),
+            python_bool(self.aaa_large_text),
+            python_bool(self.indistinguishable)
+        )
+    }
+}
+
+fn python_bool(value: bool) -> &'static str {
+    if value {
+        "True"
+    } else {
+        "False"
+    }
+}
+
+/// `check_compliance("#777", "white")` grades the pair against every WCAG level and text size.
+#[pyfunction]
+fn check_compliance(foreground: &str, background: &str) -> PyResult<Compliance> {
+    let report = crate::check_compliance(&parse(foreground)?, &parse(background)?);
+
+    Ok(Compliance {
+        ratio: report.ratio,
+        aa_normal_text: report.aa_normal_text,
+        aa_large_text: report.aa_large_text,
+        aaa_normal_text: report.aaa_normal_text,
+        aaa_large_text: report.aaa_large_text,
+        indistinguishable: report.indistinguishable,
+    })
+}
+
+#[pymodule]
+fn contrast_checker(module: &Bound<'_, PyModule>) -> PyResult<()> {
+    module.add_function(wrap_pyfunction!(contrast_ratio, module)?)?;
+    module.add_function(wrap_pyfunction!(parse_color, module)?)?;
+    module.add_function(wrap_pyfunction!(check_compliance, module)?)?;
+    module.add_class::<Compliance>()?;
+
+    Ok(())
+}
@@ -0,0 +1,69 @@
+use crate::{contrast_ratio, Color, TextSize, WcagLevel};
+
+pub(crate) const AA_NORMAL_TEXT: f32 = WcagLevel::AA.minimum_ratio(TextSize::Normal);
+
+/// How a single color behaves against the two extremes, pure white and pure black.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ContrastRange {
+    pub against_white: f32,
+    pub against_black: f32,
+    /// How much HSL lightness (from 0 to 1) the color can gain while still passing AA for normal
+    /// text against white. `None` when it doesn't pass against white to begin with.
+    pub lighten_headroom: Option<f32>,
+    /// How much HSL lightness (from 0 to 1) the color can lose while still passing AA for normal
+    /// text against black. `None` when it doesn't pass against black to begin with.
+    pub darken_headroom: Option<f32>,
+}
+
+impl ContrastRange {
+    /// Whether the color can be used for normal AA text in at least one of the two extremes. If
+    /// it can't, no background will ever make it pass.
+    pub fn can_host_aa_text(&self) -> bool {
+        self.against_white.max(self.against_black) >= AA_NORMAL_TEXT
+    }
+}
+
+/// Finds how far the lightness of `color` can move towards `target_lightness` while it still
+/// passes AA against `reference`. Moving towards the reference only lowers the contrast, so a
+/// binary search is enough.
+fn lightness_headroom(color: &Color, reference: &Color, target_lightness: f32) -> Option<f32> {
+    if contrast_ratio(color, reference) < AA_NORMAL_TEXT {
+        return None;
+    }
+
+    let (hue, saturation, lightness) = color.to_hsl();
+    let passes =
+        |l: f32| contrast_ratio(&Color::from_hsl(hue, saturation, l), reference) >= AA_NORMAL_TEXT;
+
+    if passes(target_lightness) {
+        return Some((target_lightness - lightness).abs());
+    }
+
+    let mut passing = lightness;
+    let mut failing = target_lightness;
+    for _ in 0..24 {
+        let middle = (passing + failing) / 2.0;
+        if passes(middle) {
+            passing = middle;
+        } else {
+            failing = middle;
+        }
+    }
+
+    Some((passing - lightness).abs())
+}
+
+/// Reports the contrast of a color against pure white and pure black, which is a quick way to
+/// tell whether a new brand color can ever be used for text.
+pub fn contrast_range(color: &Color) -> ContrastRange {
+    let white = Color::new(255, 255, 255);
+    let black = Color::new(0, 0, 0);
+
+    ContrastRange {
+        against_white: contrast_ratio(color, &white),
+        against_black: contrast_ratio(color, &black),
+        lighten_headroom: lightness_headroom(color, &white, 1.0),
+        darken_headroom: lightness_headroom(color, &black, 0.0),
+    }
+}
@@ -0,0 +1,456 @@
+mod csv;
+mod html;
+mod json;
+mod pdf;
+mod permalink;
+mod spreadsheet;
+mod table;
+mod tsv;
+
+use std::io;
+
+use crate::{
+    contrast_ratio, Color, ComplianceReport, ContrastAlgorithm, RoundingPolicy, TextSize, Wcag2,
+    WcagLevel,
+};
+
+pub use csv::{csv_field, tsv_field, CsvDialect, CsvWriter};
+pub use html::{distance_matrix_html, escape_html, matrix_html, matrix_html_with, HtmlWriter};
+pub use json::{escape_json, JsonValue, JsonWriter, Measurement, PairJson};
+pub use pdf::PdfWriter;
+pub use permalink::{percent_decode, Permalink, PermalinkError};
+pub use spreadsheet::{SpreadsheetFormat, SpreadsheetWriter};
+pub use table::{TableStyle, TableWriter};
+pub use tsv::TsvWriter;
+
+/// One checked foreground and background pair.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PairResult {
+    /// The foreground as it was written in the input, e.g. `tomato`.
+    pub foreground_input: String,
+    pub background_input: String,
+    pub foreground: Color,
+    pub background: Color,
+    /// The WCAG 2 verdicts, whatever algorithm the report uses.
+    pub compliance: ComplianceReport,
+    /// The contrast measured by the algorithm of the report, the same as the WCAG ratio unless
+    /// it uses another one.
+    pub contrast: f32,
+    /// Whether the pair reaches the threshold of the report it belongs to.
+    pub meets_threshold: bool,
+    /// How bad the contrast is by the [`SeverityLevels`] of the report, `None` when it has none
+    /// or the pair reaches all of them.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub severity: Option<Severity>,
+    /// The [`PairInput::metadata`](crate::audit::PairInput::metadata) of the pair, written after
+    /// the verdicts.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub metadata: Vec<String>,
+}
+
+impl PairResult {
+    /// Checks a pair's WCAG 2 ratio against `threshold`, grading it with the given rounding
+    /// policy.
+    pub fn new(
+        foreground_input: &str,
+        background_input: &str,
+        foreground: Color,
+        background: Color,
+        threshold: f32,
+        rounding: RoundingPolicy,
+    ) -> PairResult {
+        PairResult::with_algorithm(
+            foreground_input,
+            background_input,
+            foreground,
+            background,
+            threshold,
+            rounding,
+            &Wcag2,
+        )
+    }
+
+    /// Like [`PairResult::new`], with the contrast compared against `threshold` measured by
+    /// `algorithm`.
+    pub fn with_algorithm(
+        foreground_input: &str,
+        background_input: &str,
+        foreground: Color,
+        background: Color,
+        threshold: f32,
+        rounding: RoundingPolicy,
+        algorithm: &impl ContrastAlgorithm,
+    ) -> PairResult {
+        let ratio = contrast_ratio(&foreground, &background);
+        let contrast = algorithm.contrast(&foreground, &background);
+
+        PairResult {
+            foreground_input: foreground_input.to_string(),
+            background_input: background_input.to_string(),
+            foreground,
+            background,
+            compliance: ComplianceReport::with_rounding(ratio, rounding),
+            contrast,
+            meets_threshold: algorithm.passes(rounding.apply(contrast), threshold),
+            severity: None,
+            metadata: Vec::new(),
+        }
+    }
+}
+
+/// The results of checking several pairs against the same threshold.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct BatchReport {
+    pub threshold: f32,
+    /// Name of the [`ContrastAlgorithm`] the threshold applies to.
+    pub algorithm: String,
+    pub results: Vec<PairResult>,
+    /// The levels the pairs were classified with, which decide what fails the report instead of
+    /// the threshold when there are some.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub severities: Option<SeverityLevels>,
+}
+
+impl BatchReport {
+    /// The threshold the way reports write it, `4.5:1` for WCAG 2 ratios and like `apca 60` for
+    /// other algorithms.
+    pub fn threshold_label(&self) -> String {
+        threshold_label(&self.algorithm, self.threshold)
+    }
+
+    /// The most metadata fields a pair has, the number of columns the writers add for them.
+    pub(crate) fn metadata_columns(&self) -> usize {
+        self.results
+            .iter()
+            .map(|result| result.metadata.len())
+            .max()
+            .unwrap_or(0)
+    }
+
+    /// Whether the report uses another algorithm than WCAG 2, whose contrast the writers add
+    /// next to the ratio.
+    pub(crate) fn has_other_algorithm(&self) -> bool {
+        self.algorithm != Wcag2.name()
+    }
+
+    /// Number of pairs whose colors are indistinguishable, see
+    /// [`ComplianceReport::indistinguishable`].
+    pub fn indistinguishable(&self) -> usize {
+        self.results
+            .iter()
+            .filter(|result| result.compliance.indistinguishable)
+            .count()
+    }
+
+    /// Number of pairs that don't reach the threshold.
+    pub fn failures(&self) -> usize {
+        self.results
+            .iter()
+            .filter(|result| !result.meets_threshold)
+            .count()
+    }
+
+    /// Number of pairs that fail the report: the ones with an error severity when the report has
+    /// [`SeverityLevels`], warnings and infos being only reported, and the ones that don't reach
+    /// the threshold otherwise.
+    pub fn errors(&self) -> usize {
+        match self.severities {
+            Some(_) => self.count(Severity::Error),
+            None => self.failures(),
+        }
+    }
+
+    /// Number of pairs with the given severity.
+    pub fn count(&self, severity: Severity) -> usize {
+        self.results
+            .iter()
+            .filter(|result| result.severity == Some(severity))
+            .count()
+    }
+
+    /// A single number for the whole report, from 0 when every pair is an error to 100 when none
+    /// fails: the score goes down by the weight of every failing pair, as a share of the pairs.
+    /// Empty reports score 100.
+    pub fn score(&self, weights: &ScoreWeights) -> f32 {
+        if self.results.is_empty() {
+            return 100.0;
+        }
+        let penalty: f32 = self
+            .results
+            .iter()
+            .map(|result| match self.severities {
+                Some(_) => weights.weight(result.severity),
+                None if result.meets_threshold => 0.0,
+                None => weights.error,
+            })
+            .sum();
+
+        (100.0 * (1.0 - penalty / self.results.len() as f32)).clamp(0.0, 100.0)
+    }
+
+    /// The number of pairs of every severity, like `1 error, 2 warnings, 0 info`, when the report
+    /// has [`SeverityLevels`].
+    pub fn severity_summary(&self) -> Option<String> {
+        let severities = self.severities.as_ref()?;
+        let counts: Vec<String> = severities
+            .levels()
+            .iter()
+            .map(|(severity, _)| {
+                let count = self.count(*severity);
+                let plural = if count == 1 || *severity == Severity::Info {
+                    ""
+                } else {
+                    "s"
+                };
+                format!("{count} {}{plural}", severity.name())
+            })
+            .collect();
+        Some(counts.join(", "))
+    }
+}
+
+/// How bad the contrast of a pair is, see [`SeverityLevels`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "lowercase"))]
+pub enum Severity {
+    Info,
+    Warning,
+    Error,
+}
+
+impl Severity {
+    /// Lowercase name written in reports, like `warning`.
+    pub fn name(self) -> &'static str {
+        match self {
+            Severity::Info => "info",
+            Severity::Warning => "warning",
+            Severity::Error => "error",
+        }
+    }
+}
+
+/// Contrasts below which a pair gets a severity, measured by the algorithm of the report. A pair
+/// gets the most severe level it doesn't reach, so by default one below 3:1 is an error, one
+/// below 4.5:1 a warning and one below 7:1 an info:
+///
+/// ```
+/// use contrast_checker::audit::Audit;
+/// use contrast_checker::report::{Severity, SeverityLevels};
+///
+/// let report = Audit::new()
+///     .severities(SeverityLevels::default())
+///     .pairs_from_lines("#777 white\n#aaa white\n#666 white")
+///     .unwrap()
+///     .run()
+///     .unwrap();
+/// let severities: Vec<_> = report.results.iter().map(|result| result.severity).collect();
+///
+/// assert_eq!(
+///     severities,
+///     [Some(Severity::Warning), Some(Severity::Error), Some(Severity::Info)]
+/// );
+/// // Only errors fail the report
+/// assert_eq!(report.errors(), 1);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SeverityLevels {
+    levels: Vec<(Severity, f32)>,
+}
+
+impl Default for SeverityLevels {
+    fn default() -> Self {
+        SeverityLevels::new()
+            .level(Severity::Error, 3.0)
+            .level(Severity::Warning, 4.5)
+            .level(Severity::Info, 7.0)
+    }
+}
+
+impl SeverityLevels {
+    /// Levels without any severity, add them with [`SeverityLevels::level`].
+    pub fn new() -> SeverityLevels {
+        SeverityLevels { levels: Vec::new() }
+    }
+
+    /// Gives `severity` to the pairs below `limit`, replacing the limit it had.
+    pub fn level(mut self, severity: Severity, limit: f32) -> SeverityLevels {
+        self.levels.retain(|(level, _)| *level != severity);
+        self.levels.push((severity, limit));
+        self.levels
+            .sort_by_key(|(severity, _)| core::cmp::Reverse(*severity));
+        self
+    }
+
+    /// The severities and their limits, the most severe first.
+    pub fn levels(&self) -> &[(Severity, f32)] {
+        &self.levels
+    }
+
+    /// The most severe level whose limit `contrast`, measured by `algorithm`, doesn't reach.
+    pub fn classify(
+        &self,
+        contrast: f32,
+        algorithm: &(impl ContrastAlgorithm + ?Sized),
+    ) -> Option<Severity> {
+        self.levels
+            .iter()
+            .find(|(_, limit)| !algorithm.passes(contrast, *limit))
+            .map(|(severity, _)| *severity)
+    }
+}
+
+/// How much a failing pair takes off the score of a report, by severity. Reports without
+/// [`SeverityLevels`] count every pair below the threshold as an error.
+///
+/// ```
+/// use contrast_checker::audit::Audit;
+/// use contrast_checker::report::{ScoreWeights, SeverityLevels};
+///
+/// let report = Audit::new()
+///     .severities(SeverityLevels::default())
+///     .pairs_from_lines("#777 white\n#aaa white\n#666 white\nblack white")
+///     .unwrap()
+///     .run()
+///     .unwrap();
+///
+/// // A warning, an error, an info and a pass: 100 - (40 + 100 + 10) / 4
+/// assert_eq!(report.score(&ScoreWeights::default()), 62.5);
+/// let lenient = ScoreWeights { warning: 0.0, info: 0.0, ..ScoreWeights::default() };
+/// assert_eq!(report.score(&lenient), 75.0);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ScoreWeights {
+    pub error: f32,
+    pub warning: f32,
+    pub info: f32,
+}
+
+impl Default for ScoreWeights {
+    fn default() -> Self {
+        ScoreWeights {
+            error: 1.0,
+            warning: 0.4,
+            info: 0.1,
+        }
+    }
+}
+
+impl ScoreWeights {
+    /// The weight of a pair, 0 for the ones that pass.
+    pub fn weight(&self, severity: Option<Severity>) -> f32 {
+        match severity {
+            Some(Severity::Error) => self.error,
+            Some(Severity::Warning) => self.warning,
+            Some(Severity::Info) => self.info,
+            None => 0.0,
+        }
+    }
+}
+
+/// Every WCAG level and text size combination, in the order writers report them.
+pub const COMPLIANCE_COLUMNS: [(&str, WcagLevel, TextSize); 4] = [
+    ("AA normal text", WcagLevel::AA, TextSize::Normal),
+    ("AA large text", WcagLevel::AA, TextSize::Large),
+    ("AAA normal text", WcagLevel::AAA, TextSize::Normal),
+    ("AAA large text", WcagLevel::AAA, TextSize::Large),
+];
+
+/// A sink for reports. The crate ships writers for the output formats of the CLI, and embedders
+/// can implement it to render the structured results however they need, e.g. straight into
+/// their own UI.
+pub trait ReportWriter {
+    fn write_report(&mut self, report: &BatchReport) -> io::Result<()>;
+}
+
+/// Writes a threshold or contrast of `algorithm`, like `4.5:1` for WCAG 2 ratios and `apca 60`
+/// for the others.
+pub fn threshold_label(algorithm: &str, threshold: f32) -> String {
+    if algorithm == Wcag2.name() {
+        format!("{threshold}:1")
+    } else {
+        format!("{algorithm} {threshold}")
+    }
+}
+
+/// The verdict of a pair against the threshold of its report, `indistinguishable` stands out from
+/// ordinary failures.
+pub(crate) fn threshold_verdict(result: &PairResult) -> &'static str {
+    if result.compliance.indistinguishable {
+        "indistinguishable"
+    } else {
+        verdict(result.meets_threshold)
+    }
+}
+
+/// The severity of a pair as reports write it, empty when it has none.
+pub(crate) fn severity_name(result: &PairResult) -> &'static str {
+    result.severity.map_or("", Severity::name)
+}
+
+pub(crate) fn verdict(passes: bool) -> &'static str {
+    if passes {
+        "pass"
+    } else {
+        "fail"
+    }
+}
+
+/// The metadata of a pair padded with empty cells to `columns`, so the rows line up.
+pub(crate) fn metadata_cells(result: &PairResult, columns: usize) -> Vec<String> {
+    let mut cells = result.metadata.clone();
+    cells.resize(columns, String::new());
+    cells
+}
+
+/// The cells of a report as text, with a header row first. Shared by the plain text writers.
+pub(crate) fn text_rows(report: &BatchReport) -> Vec<Vec<String>> {
+    let mut header = vec![
+        "foreground".to_string(),
+        "background".to_string(),
+        "ratio".to_string(),
+    ];
+    if report.has_other_algorithm() {
+        header.push(report.algorithm.clone());
+    }
+    header.extend(
+        COMPLIANCE_COLUMNS
+            .iter()
+            .map(|(name, _, _)| name.to_string()),
+    );
+    header.push(report.threshold_label());
+    if report.severities.is_some() {
+        header.push("severity".to_string());
+    }
+    let metadata_columns = report.metadata_columns();
+    header.extend((1..=metadata_columns).map(|column| format!("field {column}")));
+
+    let mut rows = vec![header];
+    rows.extend(report.results.iter().map(|result| {
+        let mut row = vec![
+            result.foreground_input.clone(),
+            result.background_input.clone(),
+            format!("{:.2}", result.compliance.ratio),
+        ];
+        if report.has_other_algorithm() {
+            row.push(format!("{:.2}", result.contrast));
+        }
+        row.extend(
+            COMPLIANCE_COLUMNS.iter().map(|(_, level, size)| {
+                verdict(result.compliance.passes(*level, *size)).to_string()
+            }),
+        );
+        row.push(threshold_verdict(result).to_string());
+        if report.severities.is_some() {
+            row.push(severity_name(result).to_string());
+        }
+        row.extend(metadata_cells(result, metadata_columns));
+        row
+    }));
+
+    rows
+}
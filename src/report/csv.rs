@@ -0,0 +1,221 @@
+use std::io::{self, Write};
+
+use super::{text_rows, BatchReport, ReportWriter};
+
+/// How CSV fields are separated and decimals written. Spreadsheets in locales that write decimals
+/// with a comma, like German or French, read `;` as the separator instead.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CsvDialect {
+    pub delimiter: char,
+    pub decimal_comma: bool,
+}
+
+impl CsvDialect {
+    /// RFC 4180, fields separated by commas and decimals written with a point.
+    pub const RFC_4180: CsvDialect = CsvDialect {
+        delimiter: ',',
+        decimal_comma: false,
+    };
+
+    /// Fields separated by `;` and decimals written with a comma, like 4,48.
+    pub const DECIMAL_COMMA: CsvDialect = CsvDialect {
+        delimiter: ';',
+        decimal_comma: true,
+    };
+
+    /// Quotes a field when it has to be: fields with the delimiter, quotes or line breaks are
+    /// wrapped in quotes, doubling the quotes inside.
+    pub fn field(self, value: &str) -> String {
+        if value.contains([self.delimiter, '"', '\n', '\r']) {
+            format!("\"{}\"", value.replace('"', "\"\""))
+        } else {
+            value.to_string()
+        }
+    }
+
+    /// A ratio or contrast with two decimals.
+    pub fn decimal(self, value: f32) -> String {
+        self.localize(&format!("{value:.2}"))
+    }
+
+    /// A number already written with a decimal point, with the decimal separator of the dialect.
+    fn localize(self, number: &str) -> String {
+        if self.decimal_comma {
+            number.replace('.', ",")
+        } else {
+            number.to_string()
+        }
+    }
+}
+
+impl Default for CsvDialect {
+    fn default() -> Self {
+        CsvDialect::RFC_4180
+    }
+}
+
+/// Quotes a CSV field when it has to be, following RFC 4180, see [`CsvDialect::field`].
+pub fn csv_field(value: &str) -> String {
+    CsvDialect::RFC_4180.field(value)
+}
+
+/// Makes a value safe to use as a TSV field. TSV has no quoting, so tabs and line breaks become
+/// spaces.
+pub fn tsv_field(value: &str) -> String {
+    value.replace(['\t', '\n', '\r'], " ")
+}
+
+/// Writes a report as comma separated values with a header row, for spreadsheets.
+pub struct CsvWriter<W: Write> {
+    output: W,
+    dialect: CsvDialect,
+}
+
+impl<W: Write> CsvWriter<W> {
+    pub fn new(output: W) -> CsvWriter<W> {
+        CsvWriter {
+            output,
+            dialect: CsvDialect::default(),
+        }
+    }
+
+    pub fn dialect(mut self, dialect: CsvDialect) -> CsvWriter<W> {
+        self.dialect = dialect;
+        self
+    }
+}
+
+impl<W: Write> ReportWriter for CsvWriter<W> {
+    fn write_report(&mut self, report: &BatchReport) -> io::Result<()> {
+        // The ratio, and the contrast of another algorithm, come right after the colors
+        let numbers = if report.has_other_algorithm() {
+            2..4
+        } else {
+            2..3
+        };
+        let delimiter = self.dialect.delimiter.to_string();
+        for (index, row) in text_rows(report).iter().enumerate() {
+            let fields: Vec<String> = row
+                .iter()
+                .enumerate()
+                .map(
+                    |(column, field)| match index > 0 && numbers.contains(&column) {
+                        true => self.dialect.localize(field),
+                        false => self.dialect.field(field),
+                    },
+                )
+                .collect();
+            // RFC 4180 lines end with CRLF, which every spreadsheet reads
+            write!(self.output, "{}\r\n", fields.join(&delimiter))?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::report::PairResult;
+    use crate::{Color, RoundingPolicy};
+
+    #[test]
+    fn fields_are_quoted_when_needed() {
+        assert_eq!(csv_field("#777"), "#777");
+        assert_eq!(csv_field("rgb(0, 0, 0)"), "\"rgb(0, 0, 0)\"");
+        assert_eq!(csv_field("say \"hi\""), "\"say \"\"hi\"\"\"");
+        assert_eq!(tsv_field("a\tb"), "a b");
+    }
+
+    #[test]
+    fn csv_writer_has_a_header_row() {
+        let report = BatchReport {
+            threshold: 4.5,
+            algorithm: "wcag2".to_string(),
+            severities: None,
+            results: vec![PairResult::new(
+                "rgb(119, 119, 119)",
+                "white",
+                Color::new(0x77, 0x77, 0x77),
+                Color::new(255, 255, 255),
+                4.5,
+                RoundingPolicy::Exact,
+            )],
+        };
+        let mut output = Vec::new();
+        CsvWriter::new(&mut output).write_report(&report).unwrap();
+        let csv = String::from_utf8(output).unwrap();
+
+        assert_eq!(
+            csv,
+            "foreground,background,ratio,AA normal text,AA large text,AAA normal text,\
+             AAA large text,4.5:1\r\n\
+             \"rgb(119, 119, 119)\",white,4.48,fail,pass,fail,fail,fail\r\n"
+        );
+    }
+
+    #[test]
+    fn decimal_comma_separates_fields_with_semicolons() {
+        let report = crate::audit::Audit::new()
+            .pairs_from_lines("rgb(119,119,119) white Button")
+            .unwrap()
+            .run()
+            .unwrap();
+        let mut output = Vec::new();
+        CsvWriter::new(&mut output)
+            .dialect(CsvDialect::DECIMAL_COMMA)
+            .write_report(&report)
+            .unwrap();
+        let csv = String::from_utf8(output).unwrap();
+        let lines: Vec<&str> = csv.lines().collect();
+
+        assert!(lines[0].starts_with("foreground;background;ratio;AA normal text;"));
+        assert!(lines[0].ends_with(";4.5:1;field 1"));
+        assert_eq!(
+            lines[1],
+            "rgb(119,119,119);white;4,48;fail;pass;fail;fail;fail;Button"
+        );
+        assert_eq!(CsvDialect::DECIMAL_COMMA.field("a;b"), "\"a;b\"");
+        assert_eq!(CsvDialect::DECIMAL_COMMA.decimal(4.4789), "4,48");
+        assert_eq!(CsvDialect::RFC_4180.decimal(4.4789), "4.48");
+    }
+
+    #[test]
+    fn metadata_gets_a_column_per_field() {
+        let report = crate::audit::Audit::new()
+            .pairs_from_lines("#777 white Button JIRA-12\nblack white Header")
+            .unwrap()
+            .run()
+            .unwrap();
+        let mut output = Vec::new();
+        CsvWriter::new(&mut output).write_report(&report).unwrap();
+        let csv = String::from_utf8(output).unwrap();
+        let lines: Vec<&str> = csv.lines().collect();
+
+        assert!(lines[0].ends_with(",4.5:1,field 1,field 2"));
+        assert!(lines[1].ends_with(",fail,Button,JIRA-12"));
+        assert!(lines[2].ends_with(",pass,Header,"));
+    }
+
+    #[test]
+    fn quoted_metadata_is_written_once_escaped() {
+        let report = crate::audit::Audit::new()
+            .pairs_from_lines(r#"777,white,"Button, primary",JIRA-1,"say ""hi""" "#)
+            .unwrap()
+            .run()
+            .unwrap();
+        assert_eq!(
+            report.results[0].metadata,
+            ["Button, primary", "JIRA-1", r#"say "hi""#]
+        );
+
+        let mut output = Vec::new();
+        CsvWriter::new(&mut output).write_report(&report).unwrap();
+        let csv = String::from_utf8(output).unwrap();
+        assert!(csv
+            .lines()
+            .nth(1)
+            .unwrap()
+            .ends_with(r#","Button, primary",JIRA-1,"say ""hi""""#));
+    }
+}
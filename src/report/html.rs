@@ -0,0 +1,404 @@
+use std::io::{self, Write};
+
+use super::{
+    severity_name, threshold_label, threshold_verdict, verdict, BatchReport, PairResult, Permalink,
+    ReportWriter, COMPLIANCE_COLUMNS,
+};
+use crate::{delta_e_matrix, Color, ContrastAlgorithm, DeltaEBand, RoundingPolicy, Wcag2};
+
+const PAGE: &str = include_str!("templates/page.html");
+const SWATCH: &str = include_str!("templates/swatch.html");
+const BADGE: &str = include_str!("templates/badge.html");
+
+/// Fills the `{{name}}` placeholders of a template. Values are inserted as they are, so text
+/// coming from the input has to go through [`escape_html`] first.
+pub(crate) fn render(template: &str, values: &[(&str, &str)]) -> String {
+    let mut rendered = template.trim_end().to_string();
+    for (name, value) in values {
+        rendered = rendered.replace(&format!("{{{{{name}}}}}"), value);
+    }
+
+    rendered
+}
+
+/// Escapes text so it can be put inside HTML elements and quoted attributes.
+pub fn escape_html(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&#39;"),
+            c => escaped.push(c),
+        }
+    }
+
+    escaped
+}
+
+fn swatch(foreground: &Color, background: &Color, text: &str) -> String {
+    render(
+        SWATCH,
+        &[
+            ("foreground", &foreground.to_hex()),
+            ("background", &background.to_hex()),
+            ("text", text),
+        ],
+    )
+}
+
+fn badge(passes: bool, label: &str) -> String {
+    render(BADGE, &[("verdict", verdict(passes)), ("label", label)])
+}
+
+fn page(title: &str, summary: &str, content: &str) -> String {
+    render(
+        PAGE,
+        &[("title", title), ("summary", summary), ("content", content)],
+    )
+}
+
+/// Writes a report as a standalone HTML page, with a rendered sample of every pair and pass/fail
+/// badges, for sharing results with people who won't read a terminal.
+pub struct HtmlWriter<W: Write> {
+    output: W,
+}
+
+impl<W: Write> HtmlWriter<W> {
+    pub fn new(output: W) -> HtmlWriter<W> {
+        HtmlWriter { output }
+    }
+}
+
+/// The link to a result, with the colors as they were written when they are colors and not
+/// descriptions like the selectors of a stylesheet. WCAG 2 is left out, it's the default.
+fn permalink(report: &BatchReport, result: &PairResult) -> Permalink {
+    let written = |input: &str, color: &Color| match crate::parse_color(input) {
+        Ok(_) => input.to_string(),
+        Err(_) => color.to_hex(),
+    };
+    let mut permalink = Permalink::new(
+        &written(&result.foreground_input, &result.foreground),
+        &written(&result.background_input, &result.background),
+    )
+    .threshold(report.threshold);
+    if report.algorithm != Wcag2.name() {
+        permalink = permalink.algorithm(&report.algorithm);
+    }
+
+    permalink
+}
+
+/// The results of a report as a table, with a rendered sample of every pair. Every row has the
+/// [`Permalink`] of its pair as its id, the sample links to it.
+fn results_table(report: &BatchReport) -> String {
+    let mut table = String::from("<table>\n<tr><th>sample</th><th>foreground</th>");
+    table.push_str("<th>background</th><th>ratio</th>");
+    if report.has_other_algorithm() {
+        table.push_str(&format!("<th>{}</th>", report.algorithm));
+    }
+    for (name, _, _) in COMPLIANCE_COLUMNS {
+        table.push_str(&format!("<th>{name}</th>"));
+    }
+    table.push_str(&format!("<th>{}</th>", report.threshold_label()));
+    if report.severities.is_some() {
+        table.push_str("<th>severity</th>");
+    }
+    table.push_str("</tr>\n");
+
+    for result in &report.results {
+        let permalink = permalink(report, result).query();
+        table.push_str(&format!("<tr id=\"{}\">", escape_html(&permalink)));
+        let sample = format!("<a href=\"#{}\">Aa</a>", escape_html(&permalink));
+        table.push_str(&swatch(&result.foreground, &result.background, &sample));
+        table.push_str(&format!(
+            "<td>{}</td><td>{}</td><td class=\"ratio\">{:.2}:1</td>",
+            escape_html(&result.foreground_input),
+            escape_html(&result.background_input),
+            result.compliance.ratio
+        ));
+        if report.has_other_algorithm() {
+            table.push_str(&format!("<td class=\"ratio\">{:.2}</td>", result.contrast));
+        }
+        for (_, level, size) in COMPLIANCE_COLUMNS {
+            let passes = result.compliance.passes(level, size);
+            table.push_str(&format!("<td>{}</td>", badge(passes, verdict(passes))));
+        }
+        table.push_str(&format!(
+            "<td>{}</td>",
+            badge(result.meets_threshold, threshold_verdict(result))
+        ));
+        if report.severities.is_some() {
+            table.push_str(&format!("<td>{}</td>", severity_name(result)));
+        }
+        table.push_str("</tr>\n");
+    }
+    table.push_str("</table>");
+
+    table
+}
+
+/// The line under the title, like `1 of 2 pairs fail 4.5:1`.
+fn summary(report: &BatchReport) -> String {
+    let mut summary = format!(
+        "{} of {} pairs fail {}",
+        report.failures(),
+        report.results.len(),
+        report.threshold_label()
+    );
+    if let Some(severities) = report.severity_summary() {
+        summary.push_str(&format!("; {severities}"));
+    }
+
+    summary
+}
+
+impl<W: Write> HtmlWriter<W> {
+    /// Writes the reports of the groups of an audit, like the rules of every selector or
+    /// component of a stylesheet, as sections that collapse. The groups with failures start
+    /// expanded, the summary counts every pair.
+    pub fn write_groups(&mut self, groups: &[(&str, &BatchReport)]) -> io::Result<()> {
+        let mut content = String::new();
+        let (mut failures, mut total) = (0, 0);
+        for (name, report) in groups {
+            failures += report.failures();
+            total += report.results.len();
+            let open = if report.failures() > 0 { " open" } else { "" };
+            content.push_str(&format!(
+                "<details{open}>\n<summary><strong>{}</strong> {}</summary>\n{}\n</details>\n",
+                escape_html(name),
+                summary(report),
+                results_table(report)
+            ));
+        }
+
+        let threshold = groups.first().map_or(String::new(), |(_, report)| {
+            format!(" {}", report.threshold_label())
+        });
+        let summary = format!(
+            "{failures} of {total} pairs in {} groups fail{threshold}",
+            groups.len()
+        );
+        writeln!(
+            self.output,
+            "{}",
+            page("Contrast report", &summary, &content)
+        )
+    }
+}
+
+impl<W: Write> ReportWriter for HtmlWriter<W> {
+    fn write_report(&mut self, report: &BatchReport) -> io::Result<()> {
+        writeln!(
+            self.output,
+            "{}",
+            page("Contrast report", &summary(report), &results_table(report))
+        )
+    }
+}
+
+/// Renders the contrast of every combination of a palette as a standalone HTML page. Rows are
+/// foregrounds and columns are backgrounds, every cell shows the combination with its ratio and a
+/// badge for `threshold`. `labels` are the names shown for the colors.
+pub fn matrix_html(
+    labels: &[String],
+    colors: &[Color],
+    threshold: f32,
+    rounding: RoundingPolicy,
+) -> String {
+    matrix_html_with(labels, colors, threshold, rounding, &Wcag2)
+}
+
+/// Like [`matrix_html`], with the contrast measured by `algorithm`.
+pub fn matrix_html_with(
+    labels: &[String],
+    colors: &[Color],
+    threshold: f32,
+    rounding: RoundingPolicy,
+    algorithm: &impl ContrastAlgorithm,
+) -> String {
+    let matrix = algorithm.contrast_matrix(colors);
+
+    let mut table = String::from("<table>\n<tr><th>fg \\ bg</th>");
+    for label in labels {
+        table.push_str(&format!("<th>{}</th>", escape_html(label)));
+    }
+    table.push_str("</tr>\n");
+
+    let mut passing = 0;
+    for (index, (label, row)) in labels.iter().zip(&matrix).enumerate() {
+        table.push_str(&format!("<tr><th>{}</th>", escape_html(label)));
+        for (column, ratio) in row.iter().enumerate() {
+            if column == index {
+                table.push_str("<td>-</td>");
+                continue;
+            }
+
+            let passes = algorithm.passes(rounding.apply(*ratio), threshold);
+            passing += usize::from(passes);
+            let text = format!("{ratio:.2} {}", badge(passes, verdict(passes)));
+            table.push_str(&swatch(&colors[index], &colors[column], &text));
+        }
+        table.push_str("</tr>\n");
+    }
+    table.push_str("</table>");
+
+    let combinations = colors.len() * colors.len().saturating_sub(1);
+    let summary = format!(
+        "{passing} of {combinations} combinations reach {}",
+        threshold_label(algorithm.name(), threshold)
+    );
+    page("Palette contrast", &summary, &table)
+}
+
+/// Renders the CIEDE2000 difference of every two colors of a palette as a standalone HTML page.
+/// Every cell shows the column color on the row color with the difference and its
+/// [`DeltaEBand`], pairs that look alike are marked as failing. `labels` are the names shown for
+/// the colors.
+pub fn distance_matrix_html(labels: &[String], colors: &[Color]) -> String {
+    let matrix = delta_e_matrix(colors);
+
+    let mut table = String::from("<table>\n<tr><th></th>");
+    for label in labels {
+        table.push_str(&format!("<th>{}</th>", escape_html(label)));
+    }
+    table.push_str("</tr>\n");
+
+    let mut alike = 0;
+    for (index, (label, row)) in labels.iter().zip(&matrix).enumerate() {
+        table.push_str(&format!("<tr><th>{}</th>", escape_html(label)));
+        for (column, delta_e) in row.iter().enumerate() {
+            if column == index {
+                table.push_str("<td>-</td>");
+                continue;
+            }
+
+            let band = DeltaEBand::from_delta_e(*delta_e);
+            let distinct = band == DeltaEBand::Distinct;
+            alike += usize::from(!distinct && column > index);
+            let text = format!("{delta_e:.2} {}", badge(distinct, band.name()));
+            table.push_str(&swatch(&colors[column], &colors[index], &text));
+        }
+        table.push_str("</tr>\n");
+    }
+    table.push_str("</table>");
+
+    let pairs = colors.len() * colors.len().saturating_sub(1) / 2;
+    let summary =
+        format!("{alike} of {pairs} pairs look alike, with a CIEDE2000 difference below 2");
+    page("Palette distances", &summary, &table)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn render_fills_every_placeholder() {
+        let rendered = render(
+            "<p>{{greeting}}, {{name}}! {{name}}</p>\n",
+            &[("greeting", "Hello"), ("name", "you")],
+        );
+
+        assert_eq!(rendered, "<p>Hello, you! you</p>");
+    }
+
+    #[test]
+    fn escape_html_escapes_markup() {
+        assert_eq!(
+            escape_html(r#"<a href="x">'&'</a>"#),
+            "&lt;a href=&quot;x&quot;&gt;&#39;&amp;&#39;&lt;/a&gt;"
+        );
+    }
+
+    #[test]
+    fn templates_have_no_placeholders_left() {
+        let labels = ["white".to_string(), "<black>".to_string()];
+        let colors = [Color::new(255, 255, 255), Color::new(0, 0, 0)];
+        let html = matrix_html(&labels, &colors, 4.5, RoundingPolicy::Exact);
+
+        assert!(!html.contains("{{"));
+        assert!(html.contains("&lt;black&gt;"));
+        assert!(html.contains("background: #000000"));
+        assert!(html.contains("2 of 2 combinations reach 4.5:1"));
+    }
+
+    #[test]
+    fn distance_matrix_marks_colors_that_look_alike() {
+        let labels = [
+            "#777".to_string(),
+            "#787878".to_string(),
+            "tomato".to_string(),
+        ];
+        let colors = [
+            Color::new(0x77, 0x77, 0x77),
+            Color::new(0x78, 0x78, 0x78),
+            Color::new(0xff, 0x63, 0x47),
+        ];
+        let html = distance_matrix_html(&labels, &colors);
+
+        assert!(!html.contains("{{"));
+        assert!(html.contains("1 of 3 pairs look alike"));
+        assert!(html.contains("imperceptible"));
+        assert!(html.contains("color: #ff6347; background: #777777"));
+    }
+
+    #[test]
+    fn html_writer_renders_badges() {
+        let report = BatchReport {
+            threshold: 4.5,
+            algorithm: "wcag2".to_string(),
+            severities: None,
+            results: vec![PairResult::new(
+                "#777",
+                "white",
+                Color::new(0x77, 0x77, 0x77),
+                Color::new(255, 255, 255),
+                4.5,
+                RoundingPolicy::Exact,
+            )],
+        };
+        let mut output = Vec::new();
+        HtmlWriter::new(&mut output).write_report(&report).unwrap();
+        let html = String::from_utf8(output).unwrap();
+
+        assert!(html.starts_with("<!DOCTYPE html>"));
+        assert!(html.contains(r#"<span class="badge fail">fail</span>"#));
+        assert!(html.contains(r#"<span class="badge pass">pass</span>"#));
+        assert!(html.contains("1 of 1 pairs fail 4.5:1"));
+        assert!(html.contains(r##"<tr id="fg=%23777&amp;bg=white&amp;ratio=4.5">"##));
+        assert!(html.contains(r##"<a href="#fg=%23777&amp;bg=white&amp;ratio=4.5">Aa</a>"##));
+    }
+
+    #[test]
+    fn only_groups_with_failures_start_expanded() {
+        let report = |foreground: Color| BatchReport {
+            threshold: 4.5,
+            algorithm: "wcag2".to_string(),
+            severities: None,
+            results: vec![PairResult::new(
+                &foreground.to_hex(),
+                "white",
+                foreground,
+                Color::new(255, 255, 255),
+                4.5,
+                RoundingPolicy::Exact,
+            )],
+        };
+        let (card, button) = (
+            report(Color::new(0x77, 0x77, 0x77)),
+            report(Color::new(0, 0, 0)),
+        );
+        let mut output = Vec::new();
+        HtmlWriter::new(&mut output)
+            .write_groups(&[("card", &card), ("<button>", &button)])
+            .unwrap();
+        let html = String::from_utf8(output).unwrap();
+
+        assert!(html.contains("<details open>\n<summary><strong>card</strong> 1 of 1 pairs fail"));
+        assert!(html.contains("<details>\n<summary><strong>&lt;button&gt;</strong> 0 of 1"));
+        assert!(html.contains("1 of 2 pairs in 2 groups fail 4.5:1"));
+    }
+}
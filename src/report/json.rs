@@ -0,0 +1,393 @@
+use core::fmt;
+use std::io::{self, Write};
+
+use super::{BatchReport, PairResult, ReportWriter};
+use crate::{check, Color, ComplianceReport};
+
+/// Escapes a string so it can be written inside JSON quotes.
+pub fn escape_json(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len());
+
+    for c in value.chars() {
+        match c {
+            '"' => escaped.push_str("\\\""),
+            '\\' => escaped.push_str("\\\\"),
+            '\n' => escaped.push_str("\\n"),
+            '\r' => escaped.push_str("\\r"),
+            '\t' => escaped.push_str("\\t"),
+            c if c.is_control() => escaped.push_str(&format!("\\u{:04x}", c as u32)),
+            c => escaped.push(c),
+        }
+    }
+
+    escaped
+}
+
+/// A value of a field added to the JSON of a pair with [`PairJson::field`], written escaped so
+/// the output is always valid JSON.
+#[derive(Debug, Clone, PartialEq)]
+pub enum JsonValue {
+    Null,
+    Bool(bool),
+    /// Written like [`f32`] displays it, without a fraction when it's whole. Infinite and NaN
+    /// numbers, which JSON doesn't have, are written as `null`.
+    Number(f32),
+    Integer(i64),
+    String(String),
+    Array(Vec<JsonValue>),
+    /// Fields in the order they're written.
+    Object(Vec<(String, JsonValue)>),
+}
+
+impl JsonValue {
+    /// An object with the fields in order.
+    pub fn object<'a>(fields: impl IntoIterator<Item = (&'a str, JsonValue)>) -> JsonValue {
+        JsonValue::Object(
+            fields
+                .into_iter()
+                .map(|(name, value)| (name.to_string(), value))
+                .collect(),
+        )
+    }
+}
+
+impl fmt::Display for JsonValue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            JsonValue::Null => write!(f, "null"),
+            JsonValue::Bool(value) => write!(f, "{value}"),
+            JsonValue::Number(value) if value.is_finite() => write!(f, "{value}"),
+            JsonValue::Number(_) => write!(f, "null"),
+            JsonValue::Integer(value) => write!(f, "{value}"),
+            JsonValue::String(value) => write!(f, "\"{}\"", escape_json(value)),
+            JsonValue::Array(values) => {
+                write!(f, "[")?;
+                for (index, value) in values.iter().enumerate() {
+                    let separator = if index > 0 { "," } else { "" };
+                    write!(f, "{separator}{value}")?;
+                }
+                write!(f, "]")
+            }
+            JsonValue::Object(fields) => {
+                write!(f, "{{")?;
+                for (index, (name, value)) in fields.iter().enumerate() {
+                    let separator = if index > 0 { "," } else { "" };
+                    write!(f, "{separator}\"{}\":{value}", escape_json(name))?;
+                }
+                write!(f, "}}")
+            }
+        }
+    }
+}
+
+impl From<bool> for JsonValue {
+    fn from(value: bool) -> JsonValue {
+        JsonValue::Bool(value)
+    }
+}
+
+impl From<f32> for JsonValue {
+    fn from(value: f32) -> JsonValue {
+        JsonValue::Number(value)
+    }
+}
+
+impl From<usize> for JsonValue {
+    fn from(value: usize) -> JsonValue {
+        JsonValue::Integer(value as i64)
+    }
+}
+
+impl From<&str> for JsonValue {
+    fn from(value: &str) -> JsonValue {
+        JsonValue::String(value.to_string())
+    }
+}
+
+impl From<String> for JsonValue {
+    fn from(value: String) -> JsonValue {
+        JsonValue::String(value)
+    }
+}
+
+impl From<Vec<JsonValue>> for JsonValue {
+    fn from(values: Vec<JsonValue>) -> JsonValue {
+        JsonValue::Array(values)
+    }
+}
+
+impl<T: Into<JsonValue>> From<Option<T>> for JsonValue {
+    fn from(value: Option<T>) -> JsonValue {
+        value.map_or(JsonValue::Null, Into::into)
+    }
+}
+
+/// What the contrast of a pair is compared against, which the JSON of every pair has.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Measurement<'a> {
+    /// Name of the [`ContrastAlgorithm`](crate::ContrastAlgorithm) the contrast is measured by.
+    pub algorithm: &'a str,
+    pub contrast: f32,
+    pub threshold: f32,
+    pub meets_threshold: bool,
+}
+
+impl Measurement<'static> {
+    /// The WCAG 2 ratio of a pair compared unrounded against `threshold`.
+    pub fn wcag2(compliance: &ComplianceReport, threshold: f32) -> Measurement<'static> {
+        Measurement {
+            algorithm: "wcag2",
+            contrast: compliance.ratio,
+            threshold,
+            meets_threshold: compliance.ratio >= threshold,
+        }
+    }
+}
+
+/// The fields every pair has, which [`PairJson::field`] can't add again.
+const PAIR_FIELDS: [&str; 12] = [
+    "foreground",
+    "background",
+    "ratio",
+    "aa_normal_text",
+    "aa_large_text",
+    "aaa_normal_text",
+    "aaa_large_text",
+    "indistinguishable",
+    "algorithm",
+    "contrast",
+    "threshold",
+    "meets_threshold",
+];
+
+/// The JSON object of a checked pair, the same schema for every command that prints one, the
+/// server and the plugin host: the input colors with their normalized hex and luminance, the
+/// ratio, every WCAG verdict and the [`Measurement`], in that order. The luminances are the ones
+/// of the colors as seen on screen, which the ratio comes from, so a translucent foreground is
+/// composited over the background. Commands can add fields of their own after those.
+///
+/// ```
+/// use contrast_checker::report::{Measurement, PairJson};
+/// use contrast_checker::{check_compliance, Color};
+///
+/// let (gray, white) = (Color::new(0x77, 0x77, 0x77), Color::new(255, 255, 255));
+/// let compliance = check_compliance(&gray, &white);
+/// let measurement = Measurement::wcag2(&compliance, 4.5);
+/// let json = PairJson::new(("#777", &gray), ("white", &white), &compliance, measurement)
+///     .field("session", "brand")
+///     .to_string();
+///
+/// assert!(json.starts_with(r##"{"foreground":{"input":"#777","hex":"#777777""##));
+/// assert!(json.ends_with(r#""threshold":4.5,"meets_threshold":false,"session":"brand"}"#));
+/// ```
+#[derive(Debug, Clone)]
+pub struct PairJson<'a> {
+    foreground: (&'a str, &'a Color),
+    background: (&'a str, &'a Color),
+    compliance: &'a ComplianceReport,
+    measurement: Measurement<'a>,
+    fields: Vec<(&'static str, JsonValue)>,
+}
+
+impl<'a> PairJson<'a> {
+    /// The JSON of a pair given as the colors as written and parsed.
+    pub fn new(
+        foreground: (&'a str, &'a Color),
+        background: (&'a str, &'a Color),
+        compliance: &'a ComplianceReport,
+        measurement: Measurement<'a>,
+    ) -> PairJson<'a> {
+        PairJson {
+            foreground,
+            background,
+            compliance,
+            measurement,
+            fields: Vec::new(),
+        }
+    }
+
+    /// Adds a field after the ones every pair has, like the name of a session.
+    ///
+    /// # Panics
+    ///
+    /// If `name` is one of the fields every pair has, which would make the object ambiguous.
+    pub fn field(mut self, name: &'static str, value: impl Into<JsonValue>) -> PairJson<'a> {
+        assert!(
+            !PAIR_FIELDS.contains(&name),
+            "every pair already has a {name} field"
+        );
+        self.fields.push((name, value.into()));
+        self
+    }
+}
+
+impl fmt::Display for PairJson<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let visible = check(self.foreground.1, self.background.1);
+        let color = |(input, color): (&str, &Color), luminance: f32| {
+            JsonValue::object([
+                ("input", input.into()),
+                ("hex", color.to_hex().into()),
+                ("luminance", luminance.into()),
+            ])
+        };
+        let compliance = self.compliance;
+        let measurement = &self.measurement;
+
+        let mut fields = vec![
+            (
+                "foreground",
+                color(self.foreground, visible.foreground_luminance),
+            ),
+            (
+                "background",
+                color(self.background, visible.background_luminance),
+            ),
+            ("ratio", compliance.ratio.into()),
+            ("aa_normal_text", compliance.aa_normal_text.into()),
+            ("aa_large_text", compliance.aa_large_text.into()),
+            ("aaa_normal_text", compliance.aaa_normal_text.into()),
+            ("aaa_large_text", compliance.aaa_large_text.into()),
+            ("indistinguishable", compliance.indistinguishable.into()),
+            ("algorithm", measurement.algorithm.into()),
+            ("contrast", measurement.contrast.into()),
+            ("threshold", measurement.threshold.into()),
+            ("meets_threshold", measurement.meets_threshold.into()),
+        ];
+        fields.extend(
+            self.fields
+                .iter()
+                .map(|(name, value)| (*name, value.clone())),
+        );
+
+        write!(f, "{}", JsonValue::object(fields))
+    }
+}
+
+fn result_json(report: &BatchReport, result: &PairResult) -> String {
+    let measurement = Measurement {
+        algorithm: &report.algorithm,
+        contrast: result.contrast,
+        threshold: report.threshold,
+        meets_threshold: result.meets_threshold,
+    };
+    let mut json = PairJson::new(
+        (&result.foreground_input, &result.foreground),
+        (&result.background_input, &result.background),
+        &result.compliance,
+        measurement,
+    );
+    // Classified reports give every pair a severity, null for the ones without
+    if report.severities.is_some() {
+        json = json.field("severity", result.severity.map(|severity| severity.name()));
+    }
+    let metadata: Vec<JsonValue> = result
+        .metadata
+        .iter()
+        .map(|field| field.as_str().into())
+        .collect();
+
+    json.field("metadata", metadata).to_string()
+}
+
+/// Writes a report as a single JSON object, for scripts and CI pipelines.
+pub struct JsonWriter<W: Write> {
+    output: W,
+}
+
+impl<W: Write> JsonWriter<W> {
+    pub fn new(output: W) -> JsonWriter<W> {
+        JsonWriter { output }
+    }
+}
+
+impl<W: Write> ReportWriter for JsonWriter<W> {
+    fn write_report(&mut self, report: &BatchReport) -> io::Result<()> {
+        let results: Vec<String> = report
+            .results
+            .iter()
+            .map(|result| result_json(report, result))
+            .collect();
+        let severities = match &report.severities {
+            Some(severities) => {
+                let counts: Vec<String> = severities
+                    .levels()
+                    .iter()
+                    .map(|(severity, _)| {
+                        format!(r#""{}":{}"#, severity.name(), report.count(*severity))
+                    })
+                    .collect();
+                format!(
+                    r#","errors":{},"severities":{{{}}}"#,
+                    report.errors(),
+                    counts.join(",")
+                )
+            }
+            None => String::new(),
+        };
+
+        writeln!(
+            self.output,
+            r#"{{"algorithm":"{}","threshold":{},"failures":{}{severities},"results":[{}]}}"#,
+            escape_json(&report.algorithm),
+            report.threshold,
+            report.failures(),
+            results.join(",")
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::check_compliance;
+
+    #[test]
+    fn luminances_are_the_ones_seen_on_screen() {
+        let foreground = Color::from_hex("#00000080").unwrap();
+        let background = Color::new(255, 255, 255);
+        let compliance = check_compliance(&foreground, &background);
+        let json = PairJson::new(
+            ("#00000080", &foreground),
+            ("fff", &background),
+            &compliance,
+            Measurement::wcag2(&compliance, 4.5),
+        )
+        .to_string();
+
+        assert!(json.starts_with(
+            r##"{"foreground":{"input":"#00000080","hex":"#00000080","luminance":0.2122"##
+        ));
+        assert!(json.contains(r##""background":{"input":"fff","hex":"#ffffff","luminance":1}"##));
+    }
+
+    #[test]
+    fn values_are_escaped() {
+        let value = JsonValue::object([
+            ("name", "say \"hi\"".into()),
+            ("ratio", f32::NAN.into()),
+            (
+                "items",
+                vec![JsonValue::Integer(1), None::<bool>.into()].into(),
+            ),
+        ]);
+        assert_eq!(
+            value.to_string(),
+            r#"{"name":"say \"hi\"","ratio":null,"items":[1,null]}"#
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "every pair already has a ratio field")]
+    fn fields_every_pair_has_cant_be_added() {
+        let white = Color::new(255, 255, 255);
+        let compliance = check_compliance(&white, &white);
+        let _ = PairJson::new(
+            ("white", &white),
+            ("white", &white),
+            &compliance,
+            Measurement::wcag2(&compliance, 4.5),
+        )
+        .field("ratio", 1.0);
+    }
+}
@@ -0,0 +1,426 @@
+use std::io::{self, Write};
+
+use super::{
+    threshold_verdict, BatchReport, PairResult, ReportWriter, Severity, COMPLIANCE_COLUMNS,
+};
+use crate::compliance_gap;
+use crate::contrast::visible_colors;
+
+/// A4 in landscape, in points.
+const PAGE_WIDTH: f32 = 842.0;
+const PAGE_HEIGHT: f32 = 595.0;
+const MARGIN: f32 = 40.0;
+const ROW_HEIGHT: f32 = 30.0;
+const HINT_HEIGHT: f32 = 12.0;
+/// Inputs longer than this are cut so they don't run into the next column.
+const MAX_INPUT: usize = 24;
+
+const PASS: [f32; 3] = [0.1, 0.46, 0.22];
+const FAIL: [f32; 3] = [0.72, 0.11, 0.11];
+const GRAY: [f32; 3] = [0.4, 0.4, 0.4];
+const BLACK: [f32; 3] = [0.0, 0.0, 0.0];
+
+/// Where the columns of a row start, the badges of the four WCAG verdicts follow the contrast.
+const SAMPLE_X: f32 = MARGIN;
+const FOREGROUND_X: f32 = 110.0;
+const BACKGROUND_X: f32 = 250.0;
+const RATIO_X: f32 = 390.0;
+const CONTRAST_X: f32 = 450.0;
+const BADGES_X: f32 = 510.0;
+const BADGE_WIDTH: f32 = 48.0;
+const THRESHOLD_X: f32 = 726.0;
+const THRESHOLD_WIDTH: f32 = 70.0;
+
+/// Writes text of the content stream as a PDF string. The fonts use WinAnsiEncoding, which
+/// matches Latin-1 for the printable characters, so the others become `?`.
+fn pdf_string(text: &str) -> Vec<u8> {
+    let mut string = vec![b'('];
+    for c in text.chars() {
+        match c {
+            '(' | ')' | '\\' => string.extend([b'\\', c as u8]),
+            ' '..='~' | '\u{a0}'..='\u{ff}' => string.push(c as u32 as u8),
+            _ => string.push(b'?'),
+        }
+    }
+    string.push(b')');
+    string
+}
+
+/// Shortens an input to [`MAX_INPUT`] characters.
+fn shorten(input: &str) -> String {
+    if input.chars().count() <= MAX_INPUT {
+        input.to_string()
+    } else {
+        let start: String = input.chars().take(MAX_INPUT - 3).collect();
+        format!("{start}...")
+    }
+}
+
+/// The drawing operators of one page.
+#[derive(Default)]
+struct Page {
+    content: Vec<u8>,
+}
+
+impl Page {
+    fn rectangle(&mut self, x: f32, y: f32, width: f32, height: f32, [r, g, b]: [f32; 3]) {
+        self.content.extend(
+            format!("{r:.3} {g:.3} {b:.3} rg {x:.1} {y:.1} {width:.1} {height:.1} re f\n")
+                .as_bytes(),
+        );
+    }
+
+    fn text(&mut self, x: f32, y: f32, size: f32, bold: bool, [r, g, b]: [f32; 3], text: &str) {
+        let font = if bold { "F2" } else { "F1" };
+        self.content.extend(
+            format!("BT {r:.3} {g:.3} {b:.3} rg /{font} {size} Tf {x:.1} {y:.1} Td ").as_bytes(),
+        );
+        self.content.extend(pdf_string(text));
+        self.content.extend(b" Tj ET\n");
+    }
+
+    /// A colored label, green when `passes`.
+    fn badge(&mut self, x: f32, y: f32, width: f32, passes: bool, label: &str) {
+        self.rectangle(x, y, width, 14.0, if passes { PASS } else { FAIL });
+        self.text(x + 4.0, y + 4.0, 7.0, true, [1.0; 3], label);
+    }
+}
+
+/// Writes a report as a paginated PDF, with a summary on the first page, a rendered sample,
+/// badges for every verdict and a remediation hint under every failing pair, for handing to
+/// people who won't open a terminal or an HTML file. The PDF is written with the standard
+/// Helvetica fonts, so inputs outside Latin-1 show up as `?`.
+pub struct PdfWriter<W: Write> {
+    output: W,
+}
+
+impl<W: Write> PdfWriter<W> {
+    pub fn new(output: W) -> PdfWriter<W> {
+        PdfWriter { output }
+    }
+}
+
+/// The summary lines of the first page.
+fn summary(report: &BatchReport) -> Vec<String> {
+    let total = report.results.len();
+    let failures = report.failures();
+    let mut lines = vec![format!(
+        "{total} pairs checked against {}: {} pass, {failures} fail",
+        report.threshold_label(),
+        total - failures
+    )];
+    match report.indistinguishable() {
+        0 => {}
+        count => lines.push(format!(
+            "{count} of them indistinguishable, their colors can't be told apart"
+        )),
+    }
+    if let Some(severities) = report.severity_summary() {
+        lines.push(format!("{severities}, only errors fail the report"));
+    }
+
+    let ratios = || report.results.iter().map(|result| result.compliance.ratio);
+    if let Some(worst) = report
+        .results
+        .iter()
+        .min_by(|a, b| a.compliance.ratio.total_cmp(&b.compliance.ratio))
+    {
+        let average = ratios().sum::<f32>() / total as f32;
+        lines.push(format!(
+            "lowest ratio {:.2}:1 for {} on {}, {:.2}:1 on average, {:.2}:1 at best",
+            worst.compliance.ratio,
+            worst.foreground_input,
+            worst.background_input,
+            average,
+            ratios().fold(f32::NEG_INFINITY, f32::max)
+        ));
+    }
+
+    lines
+}
+
+fn header(page: &mut Page, report: &BatchReport, y: f32) {
+    let mut columns = vec![
+        (SAMPLE_X, "sample".to_string()),
+        (FOREGROUND_X, "foreground".to_string()),
+        (BACKGROUND_X, "background".to_string()),
+        (RATIO_X, "ratio".to_string()),
+    ];
+    if report.has_other_algorithm() {
+        columns.push((CONTRAST_X, report.algorithm.clone()));
+    }
+    columns.push((BADGES_X, "WCAG 2".to_string()));
+    columns.push((THRESHOLD_X, report.threshold_label()));
+
+    for (x, label) in columns {
+        page.text(x, y, 8.0, true, GRAY, &label);
+    }
+}
+
+fn row(page: &mut Page, report: &BatchReport, result: &PairResult, hint: Option<&str>, y: f32) {
+    let (foreground, background) = visible_colors(&result.foreground, &result.background);
+    page.rectangle(SAMPLE_X, y, 60.0, 22.0, background.normalized());
+    // Outlined so light backgrounds don't blend into the page
+    page.content
+        .extend(format!("0.8 G 0.5 w {SAMPLE_X:.1} {y:.1} 60.0 22.0 re S\n").as_bytes());
+    page.text(
+        SAMPLE_X + 20.0,
+        y + 6.0,
+        12.0,
+        true,
+        foreground.normalized(),
+        "Aa",
+    );
+
+    page.text(
+        FOREGROUND_X,
+        y + 8.0,
+        9.0,
+        false,
+        BLACK,
+        &shorten(&result.foreground_input),
+    );
+    page.text(
+        BACKGROUND_X,
+        y + 8.0,
+        9.0,
+        false,
+        BLACK,
+        &shorten(&result.background_input),
+    );
+    page.text(
+        RATIO_X,
+        y + 8.0,
+        9.0,
+        true,
+        BLACK,
+        &format!("{:.2}:1", result.compliance.ratio),
+    );
+    if report.has_other_algorithm() {
+        page.text(
+            CONTRAST_X,
+            y + 8.0,
+            9.0,
+            false,
+            BLACK,
+            &format!("{:.2}", result.contrast),
+        );
+    }
+
+    for (index, (name, level, size)) in COMPLIANCE_COLUMNS.iter().enumerate() {
+        let x = BADGES_X + index as f32 * (BADGE_WIDTH + 6.0);
+        let label = name.replace(" normal text", "").replace(" text", "");
+        page.badge(
+            x,
+            y + 4.0,
+            BADGE_WIDTH,
+            result.compliance.passes(*level, *size),
+            &label,
+        );
+    }
+    // The severity of a failing pair says more than the verdict
+    page.badge(
+        THRESHOLD_X,
+        y + 4.0,
+        THRESHOLD_WIDTH,
+        result.meets_threshold,
+        result
+            .severity
+            .map_or(threshold_verdict(result), Severity::name),
+    );
+
+    if let Some(hint) = hint {
+        page.text(FOREGROUND_X, y - 6.0, 7.5, false, GRAY, hint);
+    }
+}
+
+/// Lays the report out on pages.
+fn pages(report: &BatchReport) -> Vec<Page> {
+    let mut pages = vec![Page::default()];
+    let mut y = PAGE_HEIGHT - MARGIN - 18.0;
+    pages[0].text(MARGIN, y, 18.0, true, BLACK, "Contrast report");
+    for line in summary(report) {
+        y -= 16.0;
+        pages[0].text(MARGIN, y, 10.0, false, BLACK, &line);
+    }
+    y -= 30.0;
+    header(&mut pages[0], report, y);
+
+    for result in &report.results {
+        // Hints are about WCAG 2 ratios, like the ones of the table
+        let hint = (!result.meets_threshold && !report.has_other_algorithm())
+            .then(|| compliance_gap(&result.foreground, &result.background, report.threshold))
+            .flatten()
+            .map(|gap| gap.hint());
+        let height = ROW_HEIGHT + if hint.is_some() { HINT_HEIGHT } else { 0.0 };
+
+        if y - height < MARGIN + 20.0 {
+            pages.push(Page::default());
+            y = PAGE_HEIGHT - MARGIN;
+            header(pages.last_mut().unwrap(), report, y);
+        }
+        y -= height;
+        let page = pages.last_mut().unwrap();
+        row(
+            page,
+            report,
+            result,
+            hint.as_deref(),
+            y + height - ROW_HEIGHT,
+        );
+    }
+
+    let count = pages.len();
+    for (index, page) in pages.iter_mut().enumerate() {
+        page.text(
+            PAGE_WIDTH - MARGIN - 50.0,
+            MARGIN - 10.0,
+            8.0,
+            false,
+            GRAY,
+            &format!("page {} of {count}", index + 1),
+        );
+    }
+
+    pages
+}
+
+/// Puts the objects of a PDF together with the cross-reference table that points at them. Object
+/// numbers start at 1 in the order of `objects`.
+fn document(objects: &[Vec<u8>]) -> Vec<u8> {
+    let mut pdf = b"%PDF-1.4\n".to_vec();
+    let mut offsets = Vec::new();
+    for (index, object) in objects.iter().enumerate() {
+        offsets.push(pdf.len());
+        pdf.extend(format!("{} 0 obj\n", index + 1).as_bytes());
+        pdf.extend(object);
+        pdf.extend(b"\nendobj\n");
+    }
+
+    let xref = pdf.len();
+    pdf.extend(format!("xref\n0 {}\n0000000000 65535 f \n", objects.len() + 1).as_bytes());
+    for offset in offsets {
+        pdf.extend(format!("{offset:010} 00000 n \n").as_bytes());
+    }
+    pdf.extend(
+        format!(
+            "trailer\n<< /Size {} /Root 1 0 R >>\nstartxref\n{xref}\n%%EOF\n",
+            objects.len() + 1
+        )
+        .as_bytes(),
+    );
+
+    pdf
+}
+
+impl<W: Write> ReportWriter for PdfWriter<W> {
+    fn write_report(&mut self, report: &BatchReport) -> io::Result<()> {
+        let pages = pages(report);
+
+        // 1 is the catalog, 2 the page tree, 3 and 4 the fonts, then a page and its content
+        let kids: Vec<String> = (0..pages.len())
+            .map(|index| format!("{} 0 R", 5 + 2 * index))
+            .collect();
+        let mut objects = vec![
+            b"<< /Type /Catalog /Pages 2 0 R >>".to_vec(),
+            format!(
+                "<< /Type /Pages /Kids [{}] /Count {} >>",
+                kids.join(" "),
+                pages.len()
+            )
+            .into_bytes(),
+            b"<< /Type /Font /Subtype /Type1 /BaseFont /Helvetica /Encoding /WinAnsiEncoding >>"
+                .to_vec(),
+            b"<< /Type /Font /Subtype /Type1 /BaseFont /Helvetica-Bold /Encoding /WinAnsiEncoding >>"
+                .to_vec(),
+        ];
+        for (index, page) in pages.iter().enumerate() {
+            objects.push(
+                format!(
+                    "<< /Type /Page /Parent 2 0 R /MediaBox [0 0 {PAGE_WIDTH} {PAGE_HEIGHT}] \
+                     /Resources << /Font << /F1 3 0 R /F2 4 0 R >> >> /Contents {} 0 R >>",
+                    6 + 2 * index
+                )
+                .into_bytes(),
+            );
+            let mut stream = format!("<< /Length {} >>\nstream\n", page.content.len()).into_bytes();
+            stream.extend(&page.content);
+            stream.extend(b"endstream");
+            objects.push(stream);
+        }
+
+        self.output.write_all(&document(&objects))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Color, RoundingPolicy};
+
+    fn report(pairs: usize) -> BatchReport {
+        BatchReport {
+            threshold: 4.5,
+            algorithm: "wcag2".to_string(),
+            severities: None,
+            results: (0..pairs)
+                .map(|_| {
+                    PairResult::new(
+                        "#777",
+                        "white",
+                        Color::new(0x77, 0x77, 0x77),
+                        Color::new(255, 255, 255),
+                        4.5,
+                        RoundingPolicy::Exact,
+                    )
+                })
+                .collect(),
+        }
+    }
+
+    fn write(report: &BatchReport) -> Vec<u8> {
+        let mut output = Vec::new();
+        PdfWriter::new(&mut output).write_report(report).unwrap();
+        output
+    }
+
+    #[test]
+    fn pdf_string_escapes_delimiters() {
+        assert_eq!(pdf_string(r"a(b)\c"), br"(a\(b\)\\c)".to_vec());
+        assert_eq!(pdf_string("café ✓"), b"(caf\xe9 ?)".to_vec());
+    }
+
+    #[test]
+    fn cross_references_point_at_the_objects() {
+        let pdf = write(&report(1));
+        let text = String::from_utf8_lossy(&pdf);
+        let xref = text.find("xref\n").unwrap();
+        let startxref: usize = text
+            .split("startxref\n")
+            .nth(1)
+            .unwrap()
+            .lines()
+            .next()
+            .unwrap()
+            .parse()
+            .unwrap();
+        assert_eq!(startxref, xref);
+
+        for (number, line) in text[xref..].lines().skip(3).take(6).enumerate() {
+            let offset: usize = line[..10].parse().unwrap();
+            assert!(text[offset..].starts_with(&format!("{} 0 obj", number + 1)));
+        }
+        assert!(text.contains("(needs +0.02 contrast;"));
+        assert!(text.contains("(1 pairs checked against 4.5:1: 0 pass, 1 fail)"));
+    }
+
+    #[test]
+    fn long_reports_get_more_pages() {
+        let text = String::from_utf8_lossy(&write(&report(40))).into_owned();
+        let pages = text.matches("/Type /Page /Parent").count();
+
+        assert!(pages > 1);
+        assert!(text.contains(&format!("/Count {pages}")));
+        assert!(text.contains(&format!("(page {pages} of {pages})")));
+    }
+}
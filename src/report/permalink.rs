@@ -0,0 +1,198 @@
+use std::fmt;
+
+/// The colors and options of a check, written as the fragment of a link like
+/// `#fg=%23777&bg=white&algorithm=apca&ratio=60`, so opening it reproduces the comparison. The
+/// fragment has the format of a query string, the server takes the same parameters.
+///
+/// ```
+/// use contrast_checker::report::Permalink;
+///
+/// let permalink = Permalink::new("#777", "white").algorithm("apca").threshold(60.0);
+/// assert_eq!(permalink.fragment(), "#fg=%23777&bg=white&algorithm=apca&ratio=60");
+///
+/// let parsed = Permalink::parse("http://localhost:8080/#fg=%23777&bg=white&algorithm=apca&ratio=60");
+/// assert_eq!(parsed, Ok(permalink));
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct Permalink {
+    /// The foreground as it was written, e.g. `tomato` or `#777`.
+    pub foreground: String,
+    pub background: String,
+    /// The name of the algorithm, like [`ContrastAlgorithm::name`](crate::ContrastAlgorithm::name),
+    /// WCAG 2 when missing.
+    pub algorithm: Option<String>,
+    /// The threshold compared against, the default of the algorithm when missing.
+    pub threshold: Option<f32>,
+}
+
+/// Errors returned by [`Permalink::parse`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PermalinkError {
+    /// The `fg` or `bg` parameter is missing.
+    MissingColor { name: &'static str },
+    /// The `ratio` parameter isn't a number.
+    InvalidThreshold { value: String },
+}
+
+impl fmt::Display for PermalinkError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PermalinkError::MissingColor { name } => {
+                let role = if *name == "fg" {
+                    "foreground"
+                } else {
+                    "background"
+                };
+                write!(f, "missing the {name} parameter with the {role} color")
+            }
+            PermalinkError::InvalidThreshold { value } => {
+                write!(f, "the ratio parameter \"{value}\" is not a number")
+            }
+        }
+    }
+}
+
+impl std::error::Error for PermalinkError {}
+
+/// Encodes everything but the unreserved characters of URLs, so `#`, `&` and `=` in color names
+/// or functions don't end the value.
+pub(crate) fn percent_encode(value: &str) -> String {
+    let mut encoded = String::with_capacity(value.len());
+    for byte in value.bytes() {
+        if byte.is_ascii_alphanumeric() || matches!(byte, b'-' | b'.' | b'_' | b'~') {
+            encoded.push(byte as char);
+        } else {
+            encoded.push_str(&format!("%{byte:02X}"));
+        }
+    }
+
+    encoded
+}
+
+/// Decodes a query string value, e.g. `%23fff` into `#fff` and `+` into a space.
+pub fn percent_decode(value: &str) -> String {
+    let mut bytes = Vec::with_capacity(value.len());
+    let mut rest = value.as_bytes();
+
+    while let Some((&byte, tail)) = rest.split_first() {
+        let decoded = match byte {
+            b'+' => Some((b' ', tail)),
+            b'%' => tail
+                .get(..2)
+                .and_then(|hex| u8::from_str_radix(std::str::from_utf8(hex).ok()?, 16).ok())
+                .map(|decoded| (decoded, &tail[2..])),
+            _ => None,
+        };
+        let (byte, tail) = decoded.unwrap_or((byte, tail));
+        bytes.push(byte);
+        rest = tail;
+    }
+
+    String::from_utf8_lossy(&bytes).into_owned()
+}
+
+impl Permalink {
+    pub fn new(foreground: &str, background: &str) -> Permalink {
+        Permalink {
+            foreground: foreground.to_string(),
+            background: background.to_string(),
+            algorithm: None,
+            threshold: None,
+        }
+    }
+
+    pub fn algorithm(mut self, name: &str) -> Permalink {
+        self.algorithm = Some(name.to_string());
+        self
+    }
+
+    pub fn threshold(mut self, threshold: f32) -> Permalink {
+        self.threshold = Some(threshold);
+        self
+    }
+
+    /// The parameters, like a query string without the `?`.
+    pub fn query(&self) -> String {
+        let mut query = format!(
+            "fg={}&bg={}",
+            percent_encode(&self.foreground),
+            percent_encode(&self.background)
+        );
+        if let Some(algorithm) = &self.algorithm {
+            query.push_str(&format!("&algorithm={}", percent_encode(algorithm)));
+        }
+        if let Some(threshold) = self.threshold {
+            query.push_str(&format!("&ratio={threshold}"));
+        }
+
+        query
+    }
+
+    /// The fragment of the link, with its `#`.
+    pub fn fragment(&self) -> String {
+        format!("#{}", self.query())
+    }
+
+    /// Reads a permalink back from a whole link, its fragment or a query string. Unknown
+    /// parameters are ignored, so links can carry more.
+    pub fn parse(link: &str) -> Result<Permalink, PermalinkError> {
+        let query = match link.split_once('#') {
+            Some((_, fragment)) => fragment,
+            None => link.split_once('?').map_or(link, |(_, query)| query),
+        };
+        let value = |name: &str| {
+            query.split('&').find_map(|parameter| {
+                let (key, value) = parameter.split_once('=')?;
+                (key == name).then(|| percent_decode(value))
+            })
+        };
+        let color = |name: &'static str| value(name).ok_or(PermalinkError::MissingColor { name });
+
+        let threshold = value("ratio")
+            .map(|ratio| {
+                ratio
+                    .parse()
+                    .map_err(|_| PermalinkError::InvalidThreshold { value: ratio })
+            })
+            .transpose()?;
+        Ok(Permalink {
+            foreground: color("fg")?,
+            background: color("bg")?,
+            algorithm: value("algorithm"),
+            threshold,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn fragments_round_trip_functions_and_names() {
+        let permalink = Permalink::new("rgb(0 0 0 / 50%)", "light goldenrod & co").threshold(4.5);
+
+        assert_eq!(
+            permalink.fragment(),
+            "#fg=rgb%280%200%200%20%2F%2050%25%29&bg=light%20goldenrod%20%26%20co&ratio=4.5"
+        );
+        assert_eq!(Permalink::parse(&permalink.fragment()), Ok(permalink));
+    }
+
+    #[test]
+    fn parse_reads_query_strings_and_reports_what_is_missing() {
+        let permalink = Permalink::parse("/contrast?fg=%23fff&bg=black&extra=1").unwrap();
+        assert_eq!(permalink, Permalink::new("#fff", "black"));
+
+        assert_eq!(
+            Permalink::parse("#fg=fff").unwrap_err().to_string(),
+            "missing the bg parameter with the background color"
+        );
+        assert_eq!(
+            Permalink::parse("#fg=fff&bg=000&ratio=AA"),
+            Err(PermalinkError::InvalidThreshold {
+                value: "AA".to_string()
+            })
+        );
+    }
+}
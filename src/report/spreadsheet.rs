@@ -0,0 +1,483 @@
+use std::io::{self, Write};
+
+use super::{
+    metadata_cells, severity_name, BatchReport, PairResult, ReportWriter, COMPLIANCE_COLUMNS,
+};
+
+/// The file formats of [`SpreadsheetWriter`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SpreadsheetFormat {
+    /// Office Open XML, what Excel saves.
+    Xlsx,
+    /// OpenDocument, what LibreOffice saves.
+    Ods,
+}
+
+/// A cell of a row: text, a number or a boolean, which spreadsheets keep apart so rules like
+/// "ratio below 4.5" or "AA normal text is FALSE" work without converting anything.
+enum Cell {
+    Text(String),
+    Number(f32),
+    Boolean(bool),
+}
+
+/// Writes reports as an XLSX or ODS workbook with one sheet per report, like one per file or
+/// theme. Ratios, thresholds and shortfalls are numbers and verdicts are booleans, so columns can
+/// be filtered and conditionally formatted as they are. The workbook is only written by
+/// [`SpreadsheetWriter::write_sheets`], [`ReportWriter::write_report`] writes a single sheet.
+pub struct SpreadsheetWriter<W: Write> {
+    output: W,
+    format: SpreadsheetFormat,
+}
+
+impl<W: Write> SpreadsheetWriter<W> {
+    pub fn new(output: W, format: SpreadsheetFormat) -> SpreadsheetWriter<W> {
+        SpreadsheetWriter { output, format }
+    }
+
+    /// Writes a workbook with a sheet for every named report. Names are made valid for sheets,
+    /// at most 31 characters without `[]:*?/\`, and unique.
+    pub fn write_sheets(&mut self, sheets: &[(&str, &BatchReport)]) -> io::Result<()> {
+        let mut names: Vec<String> = Vec::new();
+        for (name, _) in sheets {
+            let name = sheet_name(name, &names);
+            names.push(name);
+        }
+        let tables: Vec<(&str, Vec<Vec<Cell>>)> = names
+            .iter()
+            .zip(sheets)
+            .map(|(name, (_, report))| (name.as_str(), rows(report)))
+            .collect();
+
+        let files = match self.format {
+            SpreadsheetFormat::Xlsx => xlsx(&tables),
+            SpreadsheetFormat::Ods => ods(&tables),
+        };
+        self.output.write_all(&zip(&files))
+    }
+}
+
+impl<W: Write> ReportWriter for SpreadsheetWriter<W> {
+    fn write_report(&mut self, report: &BatchReport) -> io::Result<()> {
+        self.write_sheets(&[("results", report)])
+    }
+}
+
+/// A sheet name spreadsheets accept, numbered when `taken` already has it.
+fn sheet_name(name: &str, taken: &[String]) -> String {
+    let cleaned: String = name
+        .chars()
+        .map(|c| if "[]:*?/\\".contains(c) { '_' } else { c })
+        .collect();
+    let cleaned = match cleaned.trim().trim_matches('\'') {
+        "" => "results".to_string(),
+        cleaned => cleaned.to_string(),
+    };
+
+    let mut candidate: String = cleaned.chars().take(31).collect();
+    let mut number = 2;
+    while taken
+        .iter()
+        .any(|name| name.eq_ignore_ascii_case(&candidate))
+    {
+        let suffix = format!(" ({number})");
+        let start: String = cleaned.chars().take(31 - suffix.len()).collect();
+        candidate = format!("{start}{suffix}");
+        number += 1;
+    }
+
+    candidate
+}
+
+fn header(report: &BatchReport, metadata_columns: usize) -> Vec<Cell> {
+    let mut header: Vec<String> = [
+        "foreground",
+        "background",
+        "foreground hex",
+        "background hex",
+        "ratio",
+    ]
+    .map(String::from)
+    .to_vec();
+    if report.has_other_algorithm() {
+        header.push(report.algorithm.clone());
+    }
+    header.extend(
+        COMPLIANCE_COLUMNS
+            .iter()
+            .map(|(name, _, _)| name.to_string()),
+    );
+    header.extend([
+        "threshold".to_string(),
+        "meets threshold".to_string(),
+        "shortfall".to_string(),
+        "indistinguishable".to_string(),
+    ]);
+    if report.severities.is_some() {
+        header.push("severity".to_string());
+    }
+    header.extend((1..=metadata_columns).map(|column| format!("field {column}")));
+
+    header.into_iter().map(Cell::Text).collect()
+}
+
+fn row(report: &BatchReport, result: &PairResult, metadata_columns: usize) -> Vec<Cell> {
+    let mut row = vec![
+        Cell::Text(result.foreground_input.clone()),
+        Cell::Text(result.background_input.clone()),
+        Cell::Text(result.foreground.to_hex()),
+        Cell::Text(result.background.to_hex()),
+        Cell::Number(result.compliance.ratio),
+    ];
+    if report.has_other_algorithm() {
+        row.push(Cell::Number(result.contrast));
+    }
+    row.extend(
+        COMPLIANCE_COLUMNS
+            .iter()
+            .map(|(_, level, size)| Cell::Boolean(result.compliance.passes(*level, *size))),
+    );
+    // Compared by magnitude, like APCA, so the shortfall is the same for both polarities
+    let shortfall = if result.meets_threshold {
+        0.0
+    } else {
+        report.threshold - result.contrast.abs()
+    };
+    row.extend([
+        Cell::Number(report.threshold),
+        Cell::Boolean(result.meets_threshold),
+        Cell::Number(shortfall.max(0.0)),
+        Cell::Boolean(result.compliance.indistinguishable),
+    ]);
+    if report.severities.is_some() {
+        row.push(Cell::Text(severity_name(result).to_string()));
+    }
+    row.extend(
+        metadata_cells(result, metadata_columns)
+            .into_iter()
+            .map(Cell::Text),
+    );
+
+    row
+}
+
+fn rows(report: &BatchReport) -> Vec<Vec<Cell>> {
+    let metadata_columns = report.metadata_columns();
+    let mut rows = vec![header(report, metadata_columns)];
+    rows.extend(
+        report
+            .results
+            .iter()
+            .map(|result| row(report, result, metadata_columns)),
+    );
+    rows
+}
+
+fn escape_xml(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            // Control characters aren't allowed in XML 1.0, even escaped
+            c if c.is_control() && !matches!(c, '\t' | '\n' | '\r') => escaped.push('\u{fffd}'),
+            c => escaped.push(c),
+        }
+    }
+
+    escaped
+}
+
+/// The column letters of a cell reference, `A` to `Z`, then `AA` and so on.
+fn column_name(mut index: usize) -> String {
+    let mut name = Vec::new();
+    loop {
+        name.push(b'A' + (index % 26) as u8);
+        if index < 26 {
+            break;
+        }
+        index = index / 26 - 1;
+    }
+    name.reverse();
+    String::from_utf8(name).unwrap()
+}
+
+const XML_DECLARATION: &str = r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?>"#;
+
+fn xlsx(sheets: &[(&str, Vec<Vec<Cell>>)]) -> Vec<(String, Vec<u8>)> {
+    let mut content_types = format!(
+        r#"{XML_DECLARATION}<Types xmlns="http://schemas.openxmlformats.org/package/2006/content-types"><Default Extension="rels" ContentType="application/vnd.openxmlformats-package.relationships+xml"/><Default Extension="xml" ContentType="application/xml"/><Override PartName="/xl/workbook.xml" ContentType="application/vnd.openxmlformats-officedocument.spreadsheetml.sheet.main+xml"/>"#
+    );
+    let mut workbook = format!(
+        r#"{XML_DECLARATION}<workbook xmlns="http://schemas.openxmlformats.org/spreadsheetml/2006/main" xmlns:r="http://schemas.openxmlformats.org/officeDocument/2006/relationships"><sheets>"#
+    );
+    let mut relationships = format!(
+        r#"{XML_DECLARATION}<Relationships xmlns="http://schemas.openxmlformats.org/package/2006/relationships">"#
+    );
+    let mut files = vec![(
+        "_rels/.rels".to_string(),
+        format!(
+            r#"{XML_DECLARATION}<Relationships xmlns="http://schemas.openxmlformats.org/package/2006/relationships"><Relationship Id="rId1" Type="http://schemas.openxmlformats.org/officeDocument/2006/relationships/officeDocument" Target="xl/workbook.xml"/></Relationships>"#
+        )
+        .into_bytes(),
+    )];
+
+    for (index, (name, rows)) in sheets.iter().enumerate() {
+        let number = index + 1;
+        content_types.push_str(&format!(
+            r#"<Override PartName="/xl/worksheets/sheet{number}.xml" ContentType="application/vnd.openxmlformats-officedocument.spreadsheetml.worksheet+xml"/>"#
+        ));
+        workbook.push_str(&format!(
+            r#"<sheet name="{}" sheetId="{number}" r:id="rId{number}"/>"#,
+            escape_xml(name)
+        ));
+        relationships.push_str(&format!(
+            r#"<Relationship Id="rId{number}" Type="http://schemas.openxmlformats.org/officeDocument/2006/relationships/worksheet" Target="worksheets/sheet{number}.xml"/>"#
+        ));
+
+        // The header row stays visible while scrolling
+        let mut sheet = format!(
+            r#"{XML_DECLARATION}<worksheet xmlns="http://schemas.openxmlformats.org/spreadsheetml/2006/main"><sheetViews><sheetView workbookViewId="0"><pane ySplit="1" topLeftCell="A2" activePane="bottomLeft" state="frozen"/></sheetView></sheetViews><sheetData>"#
+        );
+        for (row_index, row) in rows.iter().enumerate() {
+            sheet.push_str(&format!(r#"<row r="{}">"#, row_index + 1));
+            for (column, cell) in row.iter().enumerate() {
+                let reference = format!("{}{}", column_name(column), row_index + 1);
+                sheet.push_str(&match cell {
+                    Cell::Text(text) => format!(
+                        r#"<c r="{reference}" t="inlineStr"><is><t>{}</t></is></c>"#,
+                        escape_xml(text)
+                    ),
+                    Cell::Number(number) => format!(r#"<c r="{reference}"><v>{number}</v></c>"#),
+                    Cell::Boolean(value) => format!(
+                        r#"<c r="{reference}" t="b"><v>{}</v></c>"#,
+                        u8::from(*value)
+                    ),
+                });
+            }
+            sheet.push_str("</row>");
+        }
+        sheet.push_str("</sheetData></worksheet>");
+        files.push((
+            format!("xl/worksheets/sheet{number}.xml"),
+            sheet.into_bytes(),
+        ));
+    }
+
+    content_types.push_str("</Types>");
+    workbook.push_str("</sheets></workbook>");
+    relationships.push_str("</Relationships>");
+    files.splice(
+        0..0,
+        [(
+            "[Content_Types].xml".to_string(),
+            content_types.into_bytes(),
+        )],
+    );
+    files.push(("xl/workbook.xml".to_string(), workbook.into_bytes()));
+    files.push((
+        "xl/_rels/workbook.xml.rels".to_string(),
+        relationships.into_bytes(),
+    ));
+
+    files
+}
+
+const ODS_MIMETYPE: &str = "application/vnd.oasis.opendocument.spreadsheet";
+
+fn ods(sheets: &[(&str, Vec<Vec<Cell>>)]) -> Vec<(String, Vec<u8>)> {
+    let mut content = format!(
+        r#"{XML_DECLARATION}<office:document-content xmlns:office="urn:oasis:names:tc:opendocument:xmlns:office:1.0" xmlns:table="urn:oasis:names:tc:opendocument:xmlns:table:1.0" xmlns:text="urn:oasis:names:tc:opendocument:xmlns:text:1.0" office:version="1.2"><office:body><office:spreadsheet>"#
+    );
+
+    for (name, rows) in sheets {
+        content.push_str(&format!(
+            r#"<table:table table:name="{}">"#,
+            escape_xml(name)
+        ));
+        for row in rows {
+            content.push_str("<table:table-row>");
+            for cell in row {
+                content.push_str(&match cell {
+                    Cell::Text(text) => format!(
+                        r#"<table:table-cell office:value-type="string"><text:p>{}</text:p></table:table-cell>"#,
+                        escape_xml(text)
+                    ),
+                    Cell::Number(number) => format!(
+                        r#"<table:table-cell office:value-type="float" office:value="{number}"><text:p>{number}</text:p></table:table-cell>"#
+                    ),
+                    Cell::Boolean(value) => format!(
+                        r#"<table:table-cell office:value-type="boolean" office:boolean-value="{value}"><text:p>{}</text:p></table:table-cell>"#,
+                        if *value { "TRUE" } else { "FALSE" }
+                    ),
+                });
+            }
+            content.push_str("</table:table-row>");
+        }
+        content.push_str("</table:table>");
+    }
+    content.push_str("</office:spreadsheet></office:body></office:document-content>");
+
+    let manifest = format!(
+        r#"{XML_DECLARATION}<manifest:manifest xmlns:manifest="urn:oasis:names:tc:opendocument:xmlns:manifest:1.0" manifest:version="1.2"><manifest:file-entry manifest:full-path="/" manifest:version="1.2" manifest:media-type="{ODS_MIMETYPE}"/><manifest:file-entry manifest:full-path="content.xml" manifest:media-type="text/xml"/></manifest:manifest>"#
+    );
+
+    // The mimetype has to come first, so tools can tell the format from the first bytes
+    vec![
+        ("mimetype".to_string(), ODS_MIMETYPE.as_bytes().to_vec()),
+        ("content.xml".to_string(), content.into_bytes()),
+        ("META-INF/manifest.xml".to_string(), manifest.into_bytes()),
+    ]
+}
+
+/// The CRC-32 of the zip format, the one of IEEE 802.3.
+fn crc32(bytes: &[u8]) -> u32 {
+    let mut crc = !0u32;
+    for &byte in bytes {
+        crc ^= u32::from(byte);
+        for _ in 0..8 {
+            crc = if crc & 1 == 1 {
+                (crc >> 1) ^ 0xedb8_8320
+            } else {
+                crc >> 1
+            };
+        }
+    }
+    !crc
+}
+
+/// A zip archive of the files, stored without compression, which both formats allow. The
+/// modification times are all 1980-01-01, the earliest the format can write.
+fn zip(files: &[(String, Vec<u8>)]) -> Vec<u8> {
+    const DOS_DATE: u16 = 1 << 5 | 1;
+    let mut archive = Vec::new();
+    let mut directory = Vec::new();
+
+    for (name, contents) in files {
+        let offset = archive.len() as u32;
+        let crc = crc32(contents);
+        let size = contents.len() as u32;
+        // Version 2.0, no flags, stored, the time and the date
+        let common = |header: &mut Vec<u8>| {
+            header.extend(20u16.to_le_bytes());
+            header.extend(0u16.to_le_bytes());
+            header.extend(0u16.to_le_bytes());
+            header.extend(0u16.to_le_bytes());
+            header.extend(DOS_DATE.to_le_bytes());
+            header.extend(crc.to_le_bytes());
+            header.extend(size.to_le_bytes());
+            header.extend(size.to_le_bytes());
+            header.extend((name.len() as u16).to_le_bytes());
+            header.extend(0u16.to_le_bytes());
+        };
+
+        archive.extend(0x0403_4b50u32.to_le_bytes());
+        common(&mut archive);
+        archive.extend(name.as_bytes());
+        archive.extend(contents);
+
+        directory.extend(0x0201_4b50u32.to_le_bytes());
+        directory.extend(20u16.to_le_bytes());
+        common(&mut directory);
+        // No comment, disk 0, no attributes, then where the local header is
+        directory.extend(0u16.to_le_bytes());
+        directory.extend(0u16.to_le_bytes());
+        directory.extend(0u16.to_le_bytes());
+        directory.extend(0u32.to_le_bytes());
+        directory.extend(offset.to_le_bytes());
+        directory.extend(name.as_bytes());
+    }
+
+    let directory_offset = archive.len() as u32;
+    let count = files.len() as u16;
+    archive.extend(&directory);
+    archive.extend(0x0605_4b50u32.to_le_bytes());
+    archive.extend(0u16.to_le_bytes());
+    archive.extend(0u16.to_le_bytes());
+    archive.extend(count.to_le_bytes());
+    archive.extend(count.to_le_bytes());
+    archive.extend((directory.len() as u32).to_le_bytes());
+    archive.extend(directory_offset.to_le_bytes());
+    archive.extend(0u16.to_le_bytes());
+
+    archive
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Color, RoundingPolicy};
+
+    fn report() -> BatchReport {
+        BatchReport {
+            threshold: 4.5,
+            algorithm: "wcag2".to_string(),
+            severities: None,
+            results: vec![PairResult::new(
+                "#777",
+                "<white>",
+                Color::new(0x77, 0x77, 0x77),
+                Color::new(255, 255, 255),
+                4.5,
+                RoundingPolicy::Exact,
+            )],
+        }
+    }
+
+    #[test]
+    fn crc32_matches_the_check_value() {
+        assert_eq!(crc32(b"123456789"), 0xcbf4_3926);
+    }
+
+    #[test]
+    fn sheet_names_are_valid_and_unique() {
+        let taken = ["themes_light".to_string()];
+        assert_eq!(sheet_name("themes/light", &[]), "themes_light");
+        assert_eq!(sheet_name("themes/light", &taken), "themes_light (2)");
+        assert_eq!(sheet_name("", &[]), "results");
+        assert_eq!(sheet_name(&"x".repeat(40), &[]).len(), 31);
+    }
+
+    #[test]
+    fn column_names_continue_after_z() {
+        assert_eq!(column_name(0), "A");
+        assert_eq!(column_name(25), "Z");
+        assert_eq!(column_name(26), "AA");
+        assert_eq!(column_name(27), "AB");
+    }
+
+    #[test]
+    fn xlsx_has_a_sheet_per_report() {
+        let (light, dark) = (report(), report());
+        let mut output = Vec::new();
+        SpreadsheetWriter::new(&mut output, SpreadsheetFormat::Xlsx)
+            .write_sheets(&[("light", &light), ("dark", &dark)])
+            .unwrap();
+        let text = String::from_utf8_lossy(&output);
+
+        assert!(output.starts_with(b"PK\x03\x04"));
+        assert!(text.contains(r#"<sheet name="light" sheetId="1" r:id="rId1"/>"#));
+        assert!(text.contains(r#"<sheet name="dark" sheetId="2" r:id="rId2"/>"#));
+        assert!(text.contains("<t>&lt;white&gt;</t>"));
+        // AA normal text fails and AA large text passes
+        assert!(text.contains(r#"<c r="F2" t="b"><v>0</v></c><c r="G2" t="b"><v>1</v></c>"#));
+    }
+
+    #[test]
+    fn ods_starts_with_its_mimetype() {
+        let mut output = Vec::new();
+        SpreadsheetWriter::new(&mut output, SpreadsheetFormat::Ods)
+            .write_report(&report())
+            .unwrap();
+
+        // The name of the first entry starts at 30, its contents right after
+        assert_eq!(&output[30..38], b"mimetype");
+        assert_eq!(
+            &output[38..38 + ODS_MIMETYPE.len()],
+            ODS_MIMETYPE.as_bytes()
+        );
+        assert!(String::from_utf8_lossy(&output).contains(r#"<table:table table:name="results">"#));
+    }
+}
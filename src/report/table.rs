@@ -0,0 +1,167 @@
+use std::io::{self, Write};
+
+use super::{text_rows, BatchReport, PairResult, ReportWriter, COMPLIANCE_COLUMNS};
+use crate::compliance_gap;
+
+/// How a [`TableWriter`] shows the verdicts.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum TableStyle {
+    /// A column of pass and fail for every WCAG level and the threshold.
+    #[default]
+    Columns,
+    /// A line of `✅ AA` and `❌ AAA` badges for every pair, for commit statuses and narrow
+    /// terminals.
+    Badges,
+    /// The badges as `[PASS] AA` and `[FAIL] AAA`, for terminals and logs without emoji.
+    AsciiBadges,
+}
+
+impl TableStyle {
+    fn badge(self, passes: bool, label: &str) -> String {
+        let mark = match (self, passes) {
+            (TableStyle::AsciiBadges, true) => "[PASS]",
+            (TableStyle::AsciiBadges, false) => "[FAIL]",
+            (_, true) => "✅",
+            (_, false) => "❌",
+        };
+        format!("{mark} {label}")
+    }
+}
+
+/// Writes a report as aligned columns followed by a summary line, for reading in a terminal. With
+/// WCAG 2 ratios every failing pair then gets a hint saying how far it is from passing.
+///
+/// ```
+/// use contrast_checker::audit::Audit;
+/// use contrast_checker::report::{ReportWriter, TableStyle, TableWriter};
+///
+/// let report = Audit::new().pairs_from_lines("#777 white").unwrap().run().unwrap();
+/// let mut output = Vec::new();
+/// TableWriter::new(&mut output)
+///     .style(TableStyle::AsciiBadges)
+///     .write_report(&report)
+///     .unwrap();
+/// let output = String::from_utf8(output).unwrap();
+/// assert!(output.starts_with("#777 on white  4.48:1  [FAIL] AA  [PASS] AA large"));
+/// ```
+pub struct TableWriter<W: Write> {
+    output: W,
+    style: TableStyle,
+}
+
+impl<W: Write> TableWriter<W> {
+    pub fn new(output: W) -> TableWriter<W> {
+        TableWriter {
+            output,
+            style: TableStyle::Columns,
+        }
+    }
+
+    /// Shows the verdicts as columns, the default, or as badges.
+    pub fn style(mut self, style: TableStyle) -> TableWriter<W> {
+        self.style = style;
+        self
+    }
+
+    /// The badges of a pair: its ratio, then the WCAG levels and the threshold.
+    fn badges(&self, report: &BatchReport, result: &PairResult) -> Vec<String> {
+        let mut cells = vec![format!("{:.2}:1", result.compliance.ratio)];
+        if report.has_other_algorithm() {
+            cells.push(format!("{} {:.2}", report.algorithm, result.contrast));
+        }
+        cells.extend(COMPLIANCE_COLUMNS.iter().map(|(name, level, size)| {
+            // "AA normal text" is just AA and "AA large text" AA large
+            let label = name.trim_end_matches(" text").trim_end_matches(" normal");
+            self.style
+                .badge(result.compliance.passes(*level, *size), label)
+        }));
+        let threshold = if result.compliance.indistinguishable {
+            format!("{} indistinguishable", report.threshold_label())
+        } else {
+            report.threshold_label()
+        };
+        cells.push(self.style.badge(result.meets_threshold, &threshold));
+        if let Some(severity) = result.severity {
+            cells.push(severity.name().to_string());
+        }
+        cells.extend(result.metadata.iter().cloned());
+        cells
+    }
+}
+
+impl<W: Write> ReportWriter for TableWriter<W> {
+    fn write_report(&mut self, report: &BatchReport) -> io::Result<()> {
+        let rows = match self.style {
+            TableStyle::Columns => text_rows(report),
+            TableStyle::Badges | TableStyle::AsciiBadges => report
+                .results
+                .iter()
+                .map(|result| {
+                    let pair =
+                        format!("{} on {}", result.foreground_input, result.background_input);
+                    let mut row = vec![pair];
+                    row.extend(self.badges(report, result));
+                    row
+                })
+                .collect(),
+        };
+
+        let mut widths = vec![0; rows.iter().map(Vec::len).max().unwrap_or(0)];
+        for row in &rows {
+            for (width, cell) in widths.iter_mut().zip(row) {
+                *width = (*width).max(cell.chars().count());
+            }
+        }
+
+        for row in &rows {
+            let cells: Vec<String> = row
+                .iter()
+                .zip(&widths)
+                .map(|(cell, width)| format!("{cell:<width$}"))
+                .collect();
+            writeln!(self.output, "{}", cells.join("  ").trim_end())?;
+        }
+
+        if self.style == TableStyle::Columns {
+            writeln!(self.output)?;
+        }
+        write!(
+            self.output,
+            "{} of {} pairs fail {}",
+            report.failures(),
+            report.results.len(),
+            report.threshold_label()
+        )?;
+        match report.indistinguishable() {
+            0 => {}
+            count => write!(self.output, ", {count} of them indistinguishable")?,
+        }
+        match report.severity_summary() {
+            Some(summary) => writeln!(self.output, "; {summary}")?,
+            None => writeln!(self.output)?,
+        }
+
+        if report.has_other_algorithm() || self.style != TableStyle::Columns {
+            return Ok(());
+        }
+        for result in report
+            .results
+            .iter()
+            .filter(|result| !result.meets_threshold)
+        {
+            if let Some(gap) =
+                compliance_gap(&result.foreground, &result.background, report.threshold)
+            {
+                writeln!(
+                    self.output,
+                    "  {} on {}: {}",
+                    result.foreground_input,
+                    result.background_input,
+                    gap.hint()
+                )?;
+            }
+        }
+
+        Ok(())
+    }
+}
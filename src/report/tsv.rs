@@ -0,0 +1,25 @@
+use std::io::{self, Write};
+
+use super::{text_rows, tsv_field, BatchReport, ReportWriter};
+
+/// Writes a report as tab separated values with a header row.
+pub struct TsvWriter<W: Write> {
+    output: W,
+}
+
+impl<W: Write> TsvWriter<W> {
+    pub fn new(output: W) -> TsvWriter<W> {
+        TsvWriter { output }
+    }
+}
+
+impl<W: Write> ReportWriter for TsvWriter<W> {
+    fn write_report(&mut self, report: &BatchReport) -> io::Result<()> {
+        for row in text_rows(report) {
+            let fields: Vec<String> = row.iter().map(|field| tsv_field(field)).collect();
+            writeln!(self.output, "{}", fields.join("\t"))?;
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,75 @@
+use alloc::vec::Vec;
+
+use crate::range::AA_NORMAL_TEXT;
+use crate::{contrast_ratio, Color};
+
+/// One color of a scale generated by [`tint_scale`], with how it does as text on white and black.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ScaleStep {
+    pub color: Color,
+    pub against_white: f32,
+    pub against_black: f32,
+    /// Whether the step is the base color itself.
+    pub is_base: bool,
+}
+
+impl ScaleStep {
+    fn new(color: Color, is_base: bool) -> ScaleStep {
+        ScaleStep {
+            against_white: contrast_ratio(&color, &Color::new(255, 255, 255)),
+            against_black: contrast_ratio(&color, &Color::new(0, 0, 0)),
+            color,
+            is_base,
+        }
+    }
+
+    /// Whether the step can be used for normal text on white (or white text on it).
+    pub fn aa_on_white(&self) -> bool {
+        self.against_white >= AA_NORMAL_TEXT
+    }
+
+    /// Whether the step can be used for normal text on black (or black text on it).
+    pub fn aa_on_black(&self) -> bool {
+        self.against_black >= AA_NORMAL_TEXT
+    }
+}
+
+// Lightest and darkest steps of a scale, in OKLCH lightness. Pure white and black are left out
+// because they wouldn't carry any of the hue.
+const LIGHTEST: f32 = 0.97;
+const DARKEST: f32 = 0.25;
+
+/// Generates `steps` tints and shades of `base`, from lightest to darkest, like the 50-900 scales
+/// of design systems. The steps are evenly spaced in OKLCH lightness and keep the hue and chroma
+/// of the base color (chroma is reduced where sRGB can't reach it), so they look like the same
+/// color at different lightness levels.
+///
+/// The step closest in lightness to the base color is replaced by the base color itself, so a
+/// brand color is always part of its scale.
+pub fn tint_scale(base: &Color, steps: usize) -> Vec<ScaleStep> {
+    let (base_lightness, chroma, hue) = base.to_oklch();
+    let lightness_at = |index: usize| {
+        if steps == 1 {
+            base_lightness
+        } else {
+            LIGHTEST - (LIGHTEST - DARKEST) * index as f32 / (steps - 1) as f32
+        }
+    };
+
+    let closest = (0..steps).min_by(|first, second| {
+        let distance = |index: usize| (lightness_at(index) - base_lightness).abs();
+        distance(*first).total_cmp(&distance(*second))
+    });
+
+    (0..steps)
+        .map(|index| {
+            if Some(index) == closest {
+                ScaleStep::new(base.with_opacity(1.0), true)
+            } else {
+                let color = Color::from_oklch(lightness_at(index), chroma, hue);
+                ScaleStep::new(color, false)
+            }
+        })
+        .collect()
+}
@@ -0,0 +1,588 @@
+//! Best-effort extraction of text and background colors from CSS, SCSS, Less and SVG, so existing
+//! stylesheets and icons can be audited without copying values around. The styles of CSS-in-JS
+//! libraries are read from JavaScript and TypeScript, those of Vue and Svelte components from their
+//! `<style>` blocks, and with the `postcss` feature the rules can also come from a PostCSS AST.
+//!
+//! Sources are untrusted, the extractors never panic on one, however broken or cut off it is.
+
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::ops::Range;
+
+use crate::{split_colors, Color};
+
+mod css_in_js;
+#[cfg(feature = "postcss")]
+mod postcss;
+mod sfc;
+
+pub use css_in_js::css_in_js_rules;
+#[cfg(feature = "postcss")]
+pub use postcss::{postcss_rules, PostcssError, PostcssRule};
+pub use sfc::sfc_rules;
+
+/// A CSS rule that declares a text color, a background color or both.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CssRule {
+    /// The selector, with whitespace collapsed, e.g. `.button:hover`. Nested SCSS and Less rules
+    /// get the selector of their parent, so `&:hover` inside `.button` is `.button:hover`.
+    pub selector: String,
+    /// Line where the selector starts, counting from 1.
+    pub line: usize,
+    /// Value of the last `color` declaration, with SCSS and Less variables resolved.
+    pub foreground: Option<String>,
+    /// Value of the last `background-color` declaration, or the color found in a `background`
+    /// shorthand, with variables resolved.
+    pub background: Option<String>,
+    /// The variable the foreground was read from, like `$text` or `@text`.
+    pub foreground_variable: Option<String>,
+    /// The variable the background was read from.
+    pub background_variable: Option<String>,
+    /// Where the foreground is written in the source, as a byte range, so tools can rewrite it.
+    /// It's the variable when the value comes from one.
+    pub foreground_span: Option<Range<usize>>,
+    /// Where the background color is written in the source, as a byte range.
+    pub background_span: Option<Range<usize>>,
+}
+
+/// Where `part` starts in `outer`, which it has to be sliced from.
+fn offset_in(outer: &str, part: &str) -> usize {
+    part.as_ptr() as usize - outer.as_ptr() as usize
+}
+
+/// Spaces as long as `text` in bytes, with its new lines kept, so replacing it keeps both the
+/// byte offsets and the line numbers of what follows.
+fn blank(text: &str) -> String {
+    text.chars()
+        .flat_map(|c| {
+            let (filler, count) = if c == '\n' {
+                ('\n', 1)
+            } else {
+                (' ', c.len_utf8())
+            };
+            core::iter::repeat_n(filler, count)
+        })
+        .collect()
+}
+
+/// Replaces comments with spaces, keeping new lines so line numbers stay the same, and byte
+/// offsets too. The `//` line
+/// comments of SCSS and Less are removed too, except where they're part of a URL like
+/// `url(//cdn.example.com/a.png)`.
+fn strip_comments(source: &str) -> String {
+    let mut stripped = String::with_capacity(source.len());
+    let mut rest = source;
+
+    let next_comment = |rest: &str| {
+        let block = rest.find("/*");
+        let line = rest
+            .match_indices("//")
+            .find(|(index, _)| !rest[..*index].ends_with([':', '(', '"', '\'']))
+            .map(|(index, _)| index);
+        match (block, line) {
+            (Some(block), Some(line)) => Some(block.min(line)),
+            (block, line) => block.or(line),
+        }
+    };
+
+    while let Some(start) = next_comment(rest) {
+        stripped.push_str(&rest[..start]);
+        let comment_end = if rest[start..].starts_with("/*") {
+            rest[start + 2..]
+                .find("*/")
+                .map_or(rest.len(), |end| start + 2 + end + 2)
+        } else {
+            rest[start..]
+                .find('\n')
+                .map_or(rest.len(), |end| start + end)
+        };
+        stripped.push_str(&blank(&rest[start..comment_end]));
+        rest = &rest[comment_end..];
+    }
+    stripped.push_str(rest);
+
+    stripped
+}
+
+/// Finds the color in a `background` shorthand like `url(bg.png) no-repeat #fff`, or the
+/// variable that is probably one, like `$surface`. A shorthand that's only a `var()` is one too.
+fn shorthand_color(value: &str) -> Option<&str> {
+    if value.starts_with("var(") && value.ends_with(')') {
+        return Some(value);
+    }
+    split_colors(value).into_iter().find(|token| {
+        Color::from_css(token).is_ok() || token.starts_with('$') || token.starts_with('@')
+    })
+}
+
+/// A value of a declaration and where it is in the body of its rule.
+type Value = (String, Range<usize>);
+
+/// Reads the declarations of a rule, returns the text and background colors if there are any.
+fn parse_declarations(body: &str) -> (Option<Value>, Option<Value>) {
+    let mut foreground = None;
+    let mut background = None;
+    let value_of = |value: &str| {
+        let start = offset_in(body, value);
+        Some((value.to_string(), start..start + value.len()))
+    };
+
+    for declaration in body.split(';') {
+        let Some((property, value)) = declaration.split_once(':') else {
+            continue;
+        };
+        let value = value.trim();
+        let value = value.strip_suffix("!important").unwrap_or(value).trim();
+
+        match property.trim().to_ascii_lowercase().as_str() {
+            "color" => foreground = value_of(value),
+            "background-color" => background = value_of(value),
+            "background" => {
+                if let Some(color) = shorthand_color(value) {
+                    background = value_of(color);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    (foreground, background)
+}
+
+/// Reads a SCSS variable like `$text: #333 !default` or a Less one like `@text: #333`.
+fn variable_declaration(statement: &str) -> Option<(String, String)> {
+    let statement = statement.trim();
+    let (name, value) = statement.split_once(':')?;
+    let name = name.trim_end();
+    let identifier = name.strip_prefix('$').or_else(|| name.strip_prefix('@'))?;
+    if identifier.is_empty()
+        || !identifier
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_')
+    {
+        return None;
+    }
+
+    let value = value.trim();
+    let value = value.strip_suffix("!default").unwrap_or(value);
+    let value = value.strip_suffix("!global").unwrap_or(value);
+    Some((name.to_string(), value.trim().to_string()))
+}
+
+/// Blanks the nested blocks of a body, and the selectors in front of them, so only the
+/// declarations of the rule itself are left where they were.
+fn own_declarations(body: &str) -> String {
+    let mut own = String::with_capacity(body.len());
+    let mut depth = 0;
+    let mut statement_start = 0;
+
+    for (index, c) in body.char_indices() {
+        let text = &body[index..index + c.len_utf8()];
+        match c {
+            '{' => {
+                if depth == 0 {
+                    own.truncate(statement_start);
+                    own.push_str(&blank(&body[statement_start..index]));
+                }
+                own.push(' ');
+                depth += 1;
+            }
+            '}' => {
+                depth -= 1;
+                own.push(' ');
+                if depth == 0 {
+                    statement_start = own.len();
+                }
+            }
+            _ if depth == 0 => {
+                own.push_str(text);
+                if c == ';' {
+                    statement_start = own.len();
+                }
+            }
+            _ => own.push_str(&blank(text)),
+        }
+    }
+
+    own
+}
+
+/// How deep rules can be nested in SCSS, Less and CSS-in-JS, the rules nested deeper are skipped.
+pub const MAX_NESTING_DEPTH: usize = 32;
+
+/// The longest selector a nested rule gets, longer ones keep their own selector instead of the
+/// expanded one. Every `&` copies the parent selector, so without a limit a few levels of `&&&&`
+/// grow a short source into megabytes of selector.
+pub const MAX_SELECTOR_LENGTH: usize = 1024;
+
+/// The selector of a nested rule, with `&` replaced by the parent one. Rules inside at-rules like
+/// `@media` keep their own selector, and so do those whose selector would get longer than
+/// [`MAX_SELECTOR_LENGTH`].
+fn nested_selector(parent: Option<&str>, selector: String) -> String {
+    match parent {
+        Some(parent) if !parent.starts_with('@') => {
+            let copies = selector.matches('&').count().max(1);
+            let length = selector.len() - copies.min(selector.len()) + copies * (parent.len() + 1);
+            if length > MAX_SELECTOR_LENGTH {
+                selector
+            } else if selector.contains('&') {
+                selector.replace('&', parent)
+            } else {
+                format!("{parent} {selector}")
+            }
+        }
+        _ => selector,
+    }
+}
+
+/// Collects the rules of a block, going into nested blocks like `@media` ones. `first_line` is
+/// the line the block starts at, `offset` the byte it starts at, `parent` the selector of the rule
+/// the block belongs to and `depth` how many blocks it is nested in.
+fn collect_rules(
+    source: &str,
+    first_line: usize,
+    offset: usize,
+    parent: Option<&str>,
+    depth: usize,
+    rules: &mut Vec<CssRule>,
+    variables: &mut Vec<(String, String)>,
+) {
+    let mut line = first_line;
+    let mut prelude_start = 0;
+    let mut prelude_line = None;
+    let mut chars = source.char_indices();
+
+    while let Some((index, c)) = chars.next() {
+        match c {
+            '\n' => line += 1,
+            ';' | '}' => {
+                // Statements like @import don't have a block, variables are statements too
+                if c == ';' {
+                    variables.extend(variable_declaration(&source[prelude_start..index]));
+                }
+                prelude_start = index + 1;
+                prelude_line = None;
+            }
+            '{' => {
+                let selector = source[prelude_start..index]
+                    .split_whitespace()
+                    .collect::<Vec<_>>()
+                    .join(" ");
+                let selector = nested_selector(parent, selector);
+                let selector_line = prelude_line.unwrap_or(line);
+                let body_line = line;
+
+                let mut open = 1;
+                let mut body_end = source.len();
+                let mut nested = false;
+                for (index, c) in chars.by_ref() {
+                    match c {
+                        '\n' => line += 1,
+                        '{' => {
+                            open += 1;
+                            nested = true;
+                        }
+                        '}' => {
+                            open -= 1;
+                            if open == 0 {
+                                body_end = index;
+                                break;
+                            }
+                        }
+                        _ => {}
+                    }
+                }
+
+                let body = &source[index + 1..body_end];
+                let body_offset = offset + index + 1;
+                let (foreground, background) = if nested {
+                    parse_declarations(&own_declarations(body))
+                } else {
+                    parse_declarations(body)
+                };
+                if foreground.is_some() || background.is_some() {
+                    let span =
+                        |range: Range<usize>| body_offset + range.start..body_offset + range.end;
+                    let (foreground, foreground_span) = foreground.unzip();
+                    let (background, background_span) = background.unzip();
+                    rules.push(CssRule {
+                        selector: selector.clone(),
+                        line: selector_line,
+                        foreground,
+                        background,
+                        foreground_variable: None,
+                        background_variable: None,
+                        foreground_span: foreground_span.map(span),
+                        background_span: background_span.map(span),
+                    });
+                }
+                if nested && depth < MAX_NESTING_DEPTH {
+                    collect_rules(
+                        body,
+                        body_line,
+                        body_offset,
+                        Some(&selector),
+                        depth + 1,
+                        rules,
+                        variables,
+                    );
+                }
+
+                prelude_start = body_end + 1;
+                prelude_line = None;
+            }
+            c if !c.is_whitespace() && prelude_line.is_none() => prelude_line = Some(line),
+            _ => {}
+        }
+    }
+}
+
+/// Replaces a value that is a variable with the value of the variable, following variables that
+/// point to other variables. Returns the first variable name when there was one.
+fn resolve_variable(value: &mut Option<String>, variables: &[(String, String)]) -> Option<String> {
+    let first = value.clone()?;
+    let mut name = None;
+
+    // Bounded so variables pointing at each other don't loop forever
+    for _ in 0..16 {
+        let current = value.as_deref()?;
+        let Some((_, resolved)) = variables.iter().rev().find(|(name, _)| name == current) else {
+            break;
+        };
+        name.get_or_insert_with(|| first.clone());
+        *value = Some(resolved.clone());
+    }
+
+    name
+}
+
+/// Extracts every rule that sets `color`, `background-color` or a `background` with a color.
+/// Values are returned as written, so they may be things like `var(--text)` that aren't colors.
+///
+/// SCSS and Less are read too: `$name: value` and `@name: value` variables are resolved when a
+/// declaration is only a variable, and nested rules are found with their full selector.
+///
+/// ```
+/// use contrast_checker::scan::css_rules;
+///
+/// let css = ".button {\n  color: #777;\n  background: url(a.png) white;\n}";
+/// let rules = css_rules(css);
+///
+/// assert_eq!(rules[0].selector, ".button");
+/// assert_eq!(rules[0].line, 1);
+/// assert_eq!(rules[0].foreground.as_deref(), Some("#777"));
+/// assert_eq!(rules[0].background.as_deref(), Some("white"));
+/// assert_eq!(&css[rules[0].foreground_span.clone().unwrap()], "#777");
+///
+/// let scss = css_rules("$text: #777;\n.card {\n  background: white;\n  &:hover { color: $text; }\n}");
+///
+/// assert_eq!(scss[0].selector, ".card");
+/// assert_eq!(scss[1].selector, ".card:hover");
+/// assert_eq!(scss[1].foreground.as_deref(), Some("#777"));
+/// assert_eq!(scss[1].foreground_variable.as_deref(), Some("$text"));
+/// ```
+pub fn css_rules(source: &str) -> Vec<CssRule> {
+    let mut rules = Vec::new();
+    let mut variables = Vec::new();
+    collect_rules(
+        &strip_comments(source),
+        1,
+        0,
+        None,
+        0,
+        &mut rules,
+        &mut variables,
+    );
+
+    for rule in &mut rules {
+        rule.foreground_variable = resolve_variable(&mut rule.foreground, &variables);
+        rule.background_variable = resolve_variable(&mut rule.background, &variables);
+    }
+
+    rules
+}
+
+/// The component a selector styles, to group the findings of big stylesheets: the block of the
+/// first BEM class, the prefix of the first utility class, or else the first id or element of the
+/// selector. SVG element names like `rect#badge` work the same way.
+///
+/// ```
+/// use contrast_checker::scan::component_name;
+///
+/// assert_eq!(component_name(".card__title--active:hover"), "card");
+/// assert_eq!(component_name("body .text-gray-500"), "text");
+/// assert_eq!(component_name("#header > a"), "header");
+/// assert_eq!(component_name("h1 + p"), "h1");
+/// ```
+pub fn component_name(selector: &str) -> String {
+    let is_name = |c: char| c.is_alphanumeric() || c == '-' || c == '_' || c == '\\';
+    let after = |marker: char| {
+        selector.find(marker).map(|start| {
+            let rest = &selector[start + 1..];
+            &rest[..rest.find(|c| !is_name(c)).unwrap_or(rest.len())]
+        })
+    };
+
+    if let Some(class) = after('.').filter(|class| !class.is_empty()) {
+        // A BEM block ends at its element or modifier, a utility at its first dash
+        let end = ["__", "--", "-"]
+            .iter()
+            .find_map(|separator| class.find(separator).filter(|end| *end > 0))
+            .unwrap_or(class.len());
+        return class[..end].to_string();
+    }
+    if let Some(id) = after('#').filter(|id| !id.is_empty()) {
+        return id.to_string();
+    }
+
+    let element = selector.trim_start();
+    let element = &element[..element.find(|c| !is_name(c)).unwrap_or(element.len())];
+    if element.is_empty() {
+        selector.trim().to_string()
+    } else {
+        element.to_string()
+    }
+}
+
+/// An SVG element that paints with a `fill` or a `stroke`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SvgElement {
+    /// The tag, followed by the id when there's one, e.g. `rect#badge`.
+    pub name: String,
+    /// Line where the element starts, counting from 1.
+    pub line: usize,
+    /// Value of `fill`, from the attribute or the `style` of the element.
+    pub fill: Option<String>,
+    /// Value of `stroke`, from the attribute or the `style` of the element.
+    pub stroke: Option<String>,
+}
+
+/// Reads the `name="value"` attributes of a tag, `tag` is what follows the name of the element.
+fn attributes(tag: &str) -> Vec<(&str, &str)> {
+    let mut attributes = Vec::new();
+    let mut rest = tag;
+
+    while let Some(equals) = rest.find('=') {
+        let name = rest[..equals].split_whitespace().last().unwrap_or("");
+        let value_start = rest[equals + 1..].trim_start();
+        let Some(quote) = value_start
+            .chars()
+            .next()
+            .filter(|c| *c == '"' || *c == '\'')
+        else {
+            break;
+        };
+        let value = &value_start[1..];
+        let Some(end) = value.find(quote) else {
+            break;
+        };
+
+        attributes.push((name, &value[..end]));
+        rest = &value[end + 1..];
+    }
+
+    attributes
+}
+
+/// Extracts every element with a `fill` or a `stroke`, set as attributes or in a `style`
+/// attribute, which wins like it does in browsers. `none` counts as not painting, and values are
+/// returned as written otherwise, so they may be things like `url(#gradient)`. Inherited paint,
+/// like a `fill` on a `<g>`, isn't applied to the children.
+///
+/// ```
+/// use contrast_checker::scan::svg_elements;
+///
+/// let elements = svg_elements(r##"<svg>
+///   <circle id="dot" fill="#f26ca7" stroke="white"/>
+///   <path style="fill: none; stroke: #777"/>
+/// </svg>"##);
+///
+/// assert_eq!(elements[0].name, "circle#dot");
+/// assert_eq!(elements[0].fill.as_deref(), Some("#f26ca7"));
+/// assert_eq!(elements[1].line, 3);
+/// assert_eq!(elements[1].fill, None);
+/// assert_eq!(elements[1].stroke.as_deref(), Some("#777"));
+/// ```
+pub fn svg_elements(source: &str) -> Vec<SvgElement> {
+    let mut elements = Vec::new();
+    let mut rest = source;
+    let mut line = 1;
+
+    while let Some(start) = rest.find('<') {
+        line += rest[..start].matches('\n').count();
+        rest = &rest[start..];
+
+        // Comments, doctypes, processing instructions and closing tags don't paint
+        let end = if rest.starts_with("<!--") {
+            rest.find("-->").map_or(rest.len(), |end| end + 3)
+        } else {
+            tag_end(rest)
+        };
+        // Tags cut off at the end of the source have no `>`
+        let tag = rest[1..end].strip_suffix('>').unwrap_or(&rest[1..end]);
+        let is_element = tag.starts_with(|c: char| c.is_ascii_alphabetic());
+
+        if is_element {
+            let name_end = tag
+                .find(|c: char| c.is_whitespace() || c == '/' || c == '>')
+                .unwrap_or(tag.len());
+            let mut element = SvgElement {
+                name: tag[..name_end].to_string(),
+                line,
+                fill: None,
+                stroke: None,
+            };
+
+            let mut style = None;
+            for (name, value) in attributes(&tag[name_end..]) {
+                match name {
+                    "fill" => element.fill = Some(value.trim().to_string()),
+                    "stroke" => element.stroke = Some(value.trim().to_string()),
+                    "id" => element.name = format!("{}#{value}", element.name),
+                    "style" => style = Some(value),
+                    _ => {}
+                }
+            }
+            for declaration in style.unwrap_or("").split(';') {
+                match declaration.split_once(':') {
+                    Some((property, value)) if property.trim() == "fill" => {
+                        element.fill = Some(value.trim().to_string());
+                    }
+                    Some((property, value)) if property.trim() == "stroke" => {
+                        element.stroke = Some(value.trim().to_string());
+                    }
+                    _ => {}
+                }
+            }
+
+            element.fill = element.fill.filter(|fill| fill != "none");
+            element.stroke = element.stroke.filter(|stroke| stroke != "none");
+            if element.fill.is_some() || element.stroke.is_some() {
+                elements.push(element);
+            }
+        }
+
+        line += rest[..end].matches('\n').count();
+        rest = &rest[end..];
+    }
+
+    elements
+}
+
+/// Length of the tag at the start of `source` up to and including its `>`, skipping the ones in
+/// quoted attribute values.
+fn tag_end(source: &str) -> usize {
+    let mut quote = None;
+
+    for (index, c) in source.char_indices() {
+        match (c, quote) {
+            ('"' | '\'', None) => quote = Some(c),
+            (c, Some(open)) if c == open => quote = None,
+            ('>', None) => return index + 1,
+            _ => {}
+        }
+    }
+
+    source.len()
+}
@@ -0,0 +1,548 @@
+//! CSS-in-JS, the styles of styled-components and emotion written in JavaScript and TypeScript:
+//! tagged template literals like ``styled.h1`color: #777;` `` and object styles like
+//! `css({ color: '#777' })`.
+
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::ops::Range;
+
+use super::{
+    blank, collect_rules, nested_selector, offset_in, own_declarations, parse_declarations,
+    resolve_variable, shorthand_color, CssRule, MAX_NESTING_DEPTH,
+};
+
+/// Blanks the comments of JavaScript, leaving strings and template literals alone, so offsets and
+/// line numbers stay the same.
+fn strip_js_comments(source: &str) -> String {
+    let mut stripped = String::with_capacity(source.len());
+    let bytes = source.as_bytes();
+    // Where `${` opened code inside a template literal, by depth of the braces of the code
+    let mut templates: Vec<usize> = Vec::new();
+    let mut braces = 0;
+    let mut index = 0;
+
+    while index < source.len() {
+        let rest = &source[index..];
+        let in_template = templates.last().is_some_and(|depth| *depth == usize::MAX);
+        let length = if in_template {
+            match bytes[index] {
+                b'`' => {
+                    templates.pop();
+                    1
+                }
+                b'\\' => 2,
+                b'$' if rest.starts_with("${") => {
+                    templates.pop();
+                    templates.push(braces);
+                    braces += 1;
+                    2
+                }
+                _ => 1,
+            }
+        } else {
+            match bytes[index] {
+                b'/' if rest.starts_with("//") => {
+                    let end = rest.find('\n').unwrap_or(rest.len());
+                    stripped.push_str(&blank(&rest[..end]));
+                    index += end;
+                    continue;
+                }
+                b'/' if rest.starts_with("/*") => {
+                    let end = rest.find("*/").map_or(rest.len(), |end| end + 2);
+                    stripped.push_str(&blank(&rest[..end]));
+                    index += end;
+                    continue;
+                }
+                quote @ (b'"' | b'\'') => {
+                    let mut end = 1;
+                    while end < rest.len() && !matches!(rest.as_bytes()[end], b'\n') {
+                        match rest.as_bytes()[end] {
+                            b'\\' => end += 1,
+                            byte if byte == quote => {
+                                end += 1;
+                                break;
+                            }
+                            _ => {}
+                        }
+                        end += 1;
+                    }
+                    end.min(rest.len())
+                }
+                b'`' => {
+                    templates.push(usize::MAX);
+                    1
+                }
+                b'{' => {
+                    braces += 1;
+                    1
+                }
+                b'}' => {
+                    braces = braces.saturating_sub(1);
+                    // The end of the code of a `${`, back to the template
+                    if templates.last() == Some(&braces) {
+                        templates.pop();
+                        templates.push(usize::MAX);
+                    }
+                    1
+                }
+                _ => 1,
+            }
+        };
+
+        let mut end = (index + length).min(source.len());
+        while !source.is_char_boundary(end) {
+            end += 1;
+        }
+        stripped.push_str(&source[index..end]);
+        index = end;
+    }
+
+    stripped
+}
+
+/// Line of a byte of the source, counting from 1.
+fn line_of(source: &str, offset: usize) -> usize {
+    source[..offset].matches('\n').count() + 1
+}
+
+/// Where the bracket opened at `start` closes, skipping strings and template literals.
+fn closing(code: &str, start: usize) -> Option<usize> {
+    let bytes = code.as_bytes();
+    let mut depth = 0;
+    let mut index = start;
+    while index < bytes.len() {
+        match bytes[index] {
+            b'{' | b'(' | b'[' => depth += 1,
+            b'}' | b')' | b']' => {
+                depth -= 1;
+                if depth == 0 {
+                    return Some(index);
+                }
+            }
+            quote @ (b'"' | b'\'' | b'`') => {
+                index += 1;
+                while index < bytes.len() && bytes[index] != quote {
+                    index += if bytes[index] == b'\\' { 2 } else { 1 };
+                }
+            }
+            _ => {}
+        }
+        index += 1;
+    }
+
+    None
+}
+
+/// The expression a template literal or a call at `end` is tagged with, like `styled.h1`,
+/// `styled(Button).attrs({})` or `css`.
+fn tag_before(code: &str, end: usize) -> &str {
+    let bytes = code.as_bytes();
+    let mut start = end;
+    let mut depth = 0;
+    while start > 0 {
+        let byte = bytes[start - 1];
+        match byte {
+            b')' | b']' | b'}' => depth += 1,
+            b'(' | b'[' | b'{' if depth > 0 => depth -= 1,
+            _ if depth > 0 => {}
+            b'.' | b'_' | b'$' => {}
+            _ if byte.is_ascii_alphanumeric() => {}
+            _ => break,
+        }
+        start -= 1;
+    }
+
+    code[start..end].trim()
+}
+
+/// Whether an expression makes styles of styled-components or emotion.
+fn is_style_tag(tag: &str) -> bool {
+    let styled = tag == "styled" || tag.starts_with("styled.") || tag.starts_with("styled(");
+    // The props of .attrs() aren't styles, the template after it is
+    (styled && !tag.ends_with(".attrs"))
+        || matches!(tag, "css" | "createGlobalStyle" | "injectGlobal")
+}
+
+/// The name of the constant a style is assigned to, like `Title` in
+/// ``const Title = styled.h1`...` ``, or else the tag.
+fn style_name<'a>(code: &'a str, tag_start: usize, tag: &'a str) -> &'a str {
+    let before = code[..tag_start].trim_end();
+    let Some(before) = before.strip_suffix('=') else {
+        return tag;
+    };
+    let before = before.trim_end();
+    let name_start = before
+        .char_indices()
+        .rev()
+        .find(|(_, c)| !(c.is_alphanumeric() || *c == '_' || *c == '$'))
+        .map_or(0, |(index, c)| index + c.len_utf8());
+    let name = &before[name_start..];
+    if name.is_empty() {
+        tag
+    } else {
+        name
+    }
+}
+
+/// The string constants of the file, like `const muted = '#777'`, which styles interpolate.
+fn string_constants(code: &str) -> Vec<(String, String)> {
+    let mut constants = Vec::new();
+    for keyword in ["const ", "let ", "var "] {
+        for (start, _) in code.match_indices(keyword) {
+            let rest = &code[start + keyword.len()..];
+            let Some((name, value)) = rest.split_once('=') else {
+                continue;
+            };
+            let name = name.trim();
+            let value = value.trim_start();
+            let Some(quote) = value
+                .chars()
+                .next()
+                .filter(|c| matches!(c, '\'' | '"' | '`'))
+            else {
+                continue;
+            };
+            let Some(end) = value[1..].find(quote) else {
+                continue;
+            };
+            if !name.is_empty()
+                && name
+                    .chars()
+                    .all(|c| c.is_alphanumeric() || c == '_' || c == '$')
+            {
+                constants.push((format!("${name}"), value[1..end + 1].to_string()));
+            }
+        }
+    }
+
+    constants
+}
+
+/// The body of a template literal with its `${...}` interpolations: an interpolated identifier
+/// becomes a variable like `$muted`, which has no braces to confuse the CSS rules, and anything
+/// else is blanked.
+fn template_body(code: &str, range: Range<usize>) -> String {
+    let template = &code[range];
+    let mut body = String::with_capacity(template.len());
+    let mut index = 0;
+    while let Some(start) = template[index..].find("${").map(|start| index + start) {
+        body.push_str(&template[index..start]);
+        let (end, expression) = match closing(template, start + 1) {
+            Some(close) => (close + 1, &template[start + 2..close]),
+            // Cut off at the end of the source
+            None => (template.len(), &template[start + 2..]),
+        };
+        let expression = expression.trim();
+        if expression
+            .chars()
+            .all(|c| c.is_alphanumeric() || c == '_' || c == '$')
+            && !expression.is_empty()
+        {
+            // Padded so the offsets after it stay the same
+            body.push_str(&format!("${expression}"));
+            body.push_str(&" ".repeat(end - start - expression.len() - 1));
+        } else {
+            body.push_str(&blank(&template[start..end]));
+        }
+        index = end;
+    }
+    body.push_str(&template[index..]);
+
+    body
+}
+
+/// The value of a property of an object literal, a string with where its text is or an
+/// identifier that may be a constant.
+fn object_value(code: &str, start: usize, end: usize) -> Option<(String, Range<usize>)> {
+    let raw = &code[start..end];
+    let value = raw.trim();
+    let value_start = start + (raw.len() - raw.trim_start().len());
+    match value.chars().next()? {
+        quote @ ('\'' | '"' | '`') if value.len() >= 2 && value.ends_with(quote) => {
+            let text = &value[1..value.len() - 1];
+            Some((
+                text.to_string(),
+                value_start + 1..value_start + 1 + text.len(),
+            ))
+        }
+        _ if value
+            .chars()
+            .all(|c| c.is_alphanumeric() || c == '_' || c == '$') =>
+        {
+            Some((format!("${value}"), value_start..value_start + value.len()))
+        }
+        _ => None,
+    }
+}
+
+/// Collects the rules of an object style whose `{` is at `open`: the properties of the object
+/// itself, then the objects nested under selectors like `'&:hover'`, down to
+/// [`MAX_NESTING_DEPTH`].
+fn object_rules(code: &str, open: usize, selector: &str, depth: usize, rules: &mut Vec<CssRule>) {
+    let Some(close) = closing(code, open) else {
+        return;
+    };
+    let (mut foreground, mut background) = (None, None);
+    let mut nested = Vec::new();
+
+    let mut index = open + 1;
+    while index < close {
+        // The key, an identifier or a string, up to its colon. Spreads and shorthands have none
+        let key_start = index + (code[index..close].len() - code[index..close].trim_start().len());
+        // Quoted keys like '&:hover' have colons of their own
+        let key_end = match code.as_bytes().get(key_start) {
+            Some(b'"' | b'\'' | b'`') => {
+                closing_value(code, key_start).map_or(close, |end| end + 1)
+            }
+            _ => key_start,
+        };
+        let Some(colon) = code[key_end.min(close)..close]
+            .find([':', ','])
+            .map(|colon| key_end.min(close) + colon)
+        else {
+            break;
+        };
+        if code.as_bytes()[colon] == b',' {
+            index = colon + 1;
+            continue;
+        }
+        let key = code[index..colon]
+            .trim()
+            .trim_matches(|c| matches!(c, '\'' | '"' | '`'))
+            .to_string();
+
+        // The value, up to the comma at this depth
+        let mut end = colon + 1;
+        while end < close && code.as_bytes()[end] != b',' {
+            end = match code.as_bytes()[end] {
+                b'{' | b'(' | b'[' | b'"' | b'\'' | b'`' => {
+                    closing_value(code, end).unwrap_or(close) + 1
+                }
+                _ => end + 1,
+            };
+        }
+        let end = end.min(close);
+
+        let value_start =
+            colon + 1 + (code[colon + 1..end].len() - code[colon + 1..end].trim_start().len());
+        if code[value_start..end].starts_with('{') {
+            nested.push((key, value_start));
+        } else {
+            match key.as_str() {
+                "color" => foreground = object_value(code, colon + 1, end),
+                "backgroundColor" | "background-color" => {
+                    background = object_value(code, colon + 1, end);
+                }
+                "background" => {
+                    if let Some((value, span)) = object_value(code, colon + 1, end) {
+                        if let Some(color) = shorthand_color(&value) {
+                            let offset = span.start + offset_in(&value, color);
+                            background = Some((color.to_string(), offset..offset + color.len()));
+                        }
+                    }
+                }
+                _ => {}
+            }
+        }
+        index = end + 1;
+    }
+
+    if foreground.is_some() || background.is_some() {
+        let (foreground, foreground_span) = foreground.unzip();
+        let (background, background_span) = background.unzip();
+        rules.push(CssRule {
+            selector: selector.to_string(),
+            line: line_of(code, open),
+            foreground,
+            background,
+            foreground_variable: None,
+            background_variable: None,
+            foreground_span,
+            background_span,
+        });
+    }
+    if depth < MAX_NESTING_DEPTH {
+        for (key, open) in nested {
+            let selector = nested_selector(Some(selector), key);
+            object_rules(code, open, &selector, depth + 1, rules);
+        }
+    }
+}
+
+/// Where a string or bracket that starts a value at `start` ends.
+fn closing_value(code: &str, start: usize) -> Option<usize> {
+    let quote = code.as_bytes()[start];
+    if matches!(quote, b'"' | b'\'' | b'`') {
+        let mut index = start + 1;
+        while index < code.len() && code.as_bytes()[index] != quote {
+            index += if code.as_bytes()[index] == b'\\' {
+                2
+            } else {
+                1
+            };
+        }
+        // Past the end when the source ends with a backslash
+        Some(index.min(code.len()))
+    } else {
+        closing(code, start)
+    }
+}
+
+/// The name of the JSX element an attribute at `index` belongs to, like `<p>`.
+fn jsx_element(code: &str, index: usize) -> String {
+    let start = code[..index].rfind('<').map_or(index, |start| start + 1);
+    let name_end = code[start..]
+        .find(|c: char| !(c.is_alphanumeric() || matches!(c, '.' | '_' | '-' | ':')))
+        .map_or(code.len(), |end| start + end);
+    format!("<{}>", &code[start..name_end])
+}
+
+/// Extracts the rules of the styled-components and emotion styles of a JavaScript or TypeScript
+/// file, like [`css_rules`](super::css_rules) does for stylesheets. The rules are named after the
+/// constant they're assigned to, with nested selectors like `&:hover` appended. Interpolated
+/// string constants are resolved and reported as the variable, as are constants used as values
+/// of object styles, other interpolations can't be known without running the code.
+///
+/// Inline styles of JSX, `style={{ color: '#888' }}`, bypass every stylesheet, so they're
+/// extracted too and named after their element, like `<p>`.
+///
+/// ```
+/// use contrast_checker::scan::css_in_js_rules;
+///
+/// let source = r#"
+/// const muted = '#777';
+/// const Title = styled.h1`
+///   color: ${muted};
+///   background: white;
+///   &:hover { color: ${(props) => props.theme.accent}; }
+/// `;
+/// const Badge = styled.span({ color: 'tomato', backgroundColor: '#fff' });
+/// const Hint = () => <p style={{ color: '#888', backgroundColor: '#f5f5f5' }}>Saved</p>;
+/// "#;
+/// let rules = css_in_js_rules(source);
+///
+/// assert_eq!(rules[0].selector, "Title");
+/// assert_eq!(rules[0].line, 3);
+/// assert_eq!(rules[0].foreground.as_deref(), Some("#777"));
+/// assert_eq!(rules[0].foreground_variable.as_deref(), Some("${muted}"));
+/// assert_eq!(rules[0].background.as_deref(), Some("white"));
+/// assert_eq!(rules[1].selector, "Badge");
+/// assert_eq!(&source[rules[1].foreground_span.clone().unwrap()], "tomato");
+/// assert_eq!(rules[2].selector, "<p>");
+/// assert_eq!(rules[2].background.as_deref(), Some("#f5f5f5"));
+/// ```
+pub fn css_in_js_rules(source: &str) -> Vec<CssRule> {
+    let code = strip_js_comments(source);
+    let constants = string_constants(&code);
+    let mut rules = Vec::new();
+
+    let mut index = 0;
+    while index < code.len() {
+        // Everything this looks for is ASCII, the rest of multibyte characters is skipped
+        if !code.is_char_boundary(index) {
+            index += 1;
+            continue;
+        }
+        let byte = code.as_bytes()[index];
+        if matches!(byte, b'"' | b'\'') {
+            // Strings don't span lines, the apostrophes of JSX text don't start one
+            let line_end = code[index..]
+                .find('\n')
+                .map_or(code.len(), |end| index + end);
+            index = closing_value(&code, index).map_or(code.len(), |end| end + 1);
+            index = index.min(line_end);
+            continue;
+        }
+        if code[index..].starts_with("style={") && code[..index].ends_with(char::is_whitespace) {
+            let open = index + "style={".len();
+            let open = open + (code[open..].len() - code[open..].trim_start().len());
+            if code[open..].starts_with('{') {
+                object_rules(&code, open, &jsx_element(&code, index), 0, &mut rules);
+            }
+            index = open;
+            continue;
+        }
+        if !matches!(byte, b'`' | b'(') {
+            index += 1;
+            continue;
+        }
+        let tag = tag_before(&code, index);
+        if tag.is_empty() || !is_style_tag(tag) {
+            index = if byte == b'`' {
+                closing_value(&code, index).map_or(code.len(), |end| end + 1)
+            } else {
+                index + 1
+            };
+            continue;
+        }
+        let tag_start = index - (code[..index].len() - code[..index].trim_end().len()) - tag.len();
+        let name = style_name(&code, tag_start, tag).to_string();
+
+        if byte == b'`' {
+            let Some(end) = closing_value(&code, index) else {
+                break;
+            };
+            let body = template_body(&code, index + 1..end);
+            let line = line_of(&code, index);
+            let mut variables = Vec::new();
+            let (foreground, background) = parse_declarations(&own_declarations(&body));
+            if foreground.is_some() || background.is_some() {
+                let span = |range: Range<usize>| index + 1 + range.start..index + 1 + range.end;
+                let (foreground, foreground_span) = foreground.unzip();
+                let (background, background_span) = background.unzip();
+                rules.push(CssRule {
+                    selector: name.clone(),
+                    line,
+                    foreground,
+                    background,
+                    foreground_variable: None,
+                    background_variable: None,
+                    foreground_span: foreground_span.map(span),
+                    background_span: background_span.map(span),
+                });
+            }
+            // createGlobalStyle has no component, its rules keep their own selectors
+            let parent =
+                (tag != "createGlobalStyle" && tag != "injectGlobal").then_some(name.as_str());
+            collect_rules(
+                &body,
+                line,
+                index + 1,
+                parent,
+                0,
+                &mut rules,
+                &mut variables,
+            );
+            index = end + 1;
+        } else {
+            let open = index + 1 + (code[index + 1..].len() - code[index + 1..].trim_start().len());
+            if code[open..].starts_with('{') {
+                object_rules(&code, open, &name, 0, &mut rules);
+            }
+            index += 1;
+        }
+    }
+
+    // Values that were only code are gone
+    for rule in &mut rules {
+        if rule.foreground.as_deref() == Some("") {
+            (rule.foreground, rule.foreground_span) = (None, None);
+        }
+        if rule.background.as_deref() == Some("") {
+            (rule.background, rule.background_span) = (None, None);
+        }
+    }
+    rules.retain(|rule| rule.foreground.is_some() || rule.background.is_some());
+
+    // Variables are named the way they're interpolated
+    let interpolation = |variable: String| format!("${{{}}}", &variable[1..]);
+    for rule in &mut rules {
+        rule.foreground_variable =
+            resolve_variable(&mut rule.foreground, &constants).map(interpolation);
+        rule.background_variable =
+            resolve_variable(&mut rule.background, &constants).map(interpolation);
+    }
+
+    rules
+}
@@ -0,0 +1,202 @@
+//! Stylesheets already parsed by [PostCSS](https://postcss.org), read from the JSON of
+//! `root.toJSON()`, so pipelines hand over their AST instead of having the stylesheet parsed again
+//! with another idea of the syntax. Rules nested with postcss-nested or postcss-scss get their full
+//! selector, and `$name` (postcss-scss) and `@name` (postcss-less) variables are resolved.
+
+use std::fmt;
+
+use serde_json::Value;
+
+use super::{nested_selector, resolve_variable, shorthand_color, CssRule};
+
+/// A rule of a PostCSS AST and the file PostCSS read it from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PostcssRule {
+    /// The rule, without spans since the AST isn't the text of the stylesheet.
+    pub rule: CssRule,
+    /// The `file` of the input the rule comes from, when PostCSS was given one.
+    pub file: Option<String>,
+}
+
+/// Errors returned by [`postcss_rules`].
+#[derive(Debug)]
+pub enum PostcssError {
+    InvalidJson(serde_json::Error),
+    /// The JSON isn't a node with a `type`, like the `root` of a stylesheet.
+    NotANode,
+}
+
+impl fmt::Display for PostcssError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PostcssError::InvalidJson(err) => write!(f, "invalid JSON: {err}"),
+            PostcssError::NotANode => write!(f, "expected a PostCSS node, like the root"),
+        }
+    }
+}
+
+impl std::error::Error for PostcssError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            PostcssError::InvalidJson(err) => Some(err),
+            PostcssError::NotANode => None,
+        }
+    }
+}
+
+fn text<'a>(node: &'a Value, key: &str) -> &'a str {
+    node.get(key).and_then(Value::as_str).unwrap_or_default()
+}
+
+/// What a walk through the AST collects.
+struct Walk {
+    files: Vec<Option<String>>,
+    rules: Vec<PostcssRule>,
+    variables: Vec<(String, String)>,
+}
+
+impl Walk {
+    fn nodes(&mut self, node: &Value, parent: Option<&str>) {
+        for child in node
+            .get("nodes")
+            .and_then(Value::as_array)
+            .into_iter()
+            .flatten()
+        {
+            match text(child, "type") {
+                "rule" => self.rule(child, parent),
+                // postcss-less writes `@name: value` as an at-rule
+                "atrule" if child.get("variable").and_then(Value::as_bool) == Some(true) => {
+                    let value = text(child, "value").trim();
+                    self.variables
+                        .push((format!("@{}", text(child, "name")), value.to_string()));
+                }
+                "atrule" => {
+                    let name = format!("@{}", text(child, "name"));
+                    self.nodes(child, Some(&name));
+                }
+                "decl" if text(child, "prop").starts_with('$') => {
+                    let value = text(child, "value").trim();
+                    let value = value.strip_suffix("!default").unwrap_or(value);
+                    let value = value.strip_suffix("!global").unwrap_or(value);
+                    self.variables
+                        .push((text(child, "prop").to_string(), value.trim().to_string()));
+                }
+                _ => self.nodes(child, parent),
+            }
+        }
+    }
+
+    fn rule(&mut self, node: &Value, parent: Option<&str>) {
+        let selector = text(node, "selector")
+            .split_whitespace()
+            .collect::<Vec<_>>()
+            .join(" ");
+        let selector = nested_selector(parent, selector);
+
+        let (mut foreground, mut background) = (None, None);
+        for declaration in node
+            .get("nodes")
+            .and_then(Value::as_array)
+            .into_iter()
+            .flatten()
+        {
+            if text(declaration, "type") != "decl" {
+                continue;
+            }
+            let value = text(declaration, "value").trim();
+            match text(declaration, "prop").to_ascii_lowercase().as_str() {
+                "color" => foreground = Some(value.to_string()),
+                "background-color" => background = Some(value.to_string()),
+                "background" => {
+                    if let Some(color) = shorthand_color(value) {
+                        background = Some(color.to_string());
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        if foreground.is_some() || background.is_some() {
+            let source = node.get("source");
+            let start = source.and_then(|source| source.get("start"));
+            let input = source
+                .and_then(|source| source.get("inputId"))
+                .and_then(Value::as_u64);
+            self.rules.push(PostcssRule {
+                rule: CssRule {
+                    selector: selector.clone(),
+                    line: start
+                        .and_then(|start| start.get("line"))
+                        .and_then(Value::as_u64)
+                        .unwrap_or(1) as usize,
+                    foreground,
+                    background,
+                    foreground_variable: None,
+                    background_variable: None,
+                    foreground_span: None,
+                    background_span: None,
+                },
+                file: input.and_then(|input| self.files.get(input as usize).cloned().flatten()),
+            });
+        }
+
+        self.nodes(node, Some(&selector));
+    }
+}
+
+/// Extracts the rules of a PostCSS AST that set a text or background color, like
+/// [`css_rules`](super::css_rules) does for the text of a stylesheet. Lines come from the
+/// `source` of every rule.
+///
+/// ```
+/// use contrast_checker::scan::postcss_rules;
+///
+/// let ast = r##"{"type":"root","nodes":[{"type":"rule","selector":".card",
+///   "source":{"start":{"line":3,"column":1},"inputId":0},
+///   "nodes":[{"type":"decl","prop":"color","value":"#777"},
+///            {"type":"decl","prop":"background","value":"white url(bg.png)"}]}],
+///   "inputs":[{"file":"/app/card.css"}]}"##;
+/// let rules = postcss_rules(ast).unwrap();
+///
+/// assert_eq!(rules[0].rule.selector, ".card");
+/// assert_eq!(rules[0].rule.line, 3);
+/// assert_eq!(rules[0].rule.background.as_deref(), Some("white"));
+/// assert_eq!(rules[0].file.as_deref(), Some("/app/card.css"));
+/// ```
+pub fn postcss_rules(json: &str) -> Result<Vec<PostcssRule>, PostcssError> {
+    let root: Value = serde_json::from_str(json).map_err(PostcssError::InvalidJson)?;
+    if root.get("type").and_then(Value::as_str).is_none() {
+        return Err(PostcssError::NotANode);
+    }
+
+    let files = root
+        .get("inputs")
+        .and_then(Value::as_array)
+        .into_iter()
+        .flatten()
+        .map(|input| input.get("file").and_then(Value::as_str).map(String::from))
+        .collect();
+    let mut walk = Walk {
+        files,
+        rules: Vec::new(),
+        variables: Vec::new(),
+    };
+    if text(&root, "type") == "rule" {
+        walk.rule(&root, None);
+    } else {
+        walk.nodes(&root, None);
+    }
+
+    let Walk {
+        mut rules,
+        variables,
+        ..
+    } = walk;
+    for PostcssRule { rule, .. } in &mut rules {
+        rule.foreground_variable = resolve_variable(&mut rule.foreground, &variables);
+        rule.background_variable = resolve_variable(&mut rule.background, &variables);
+    }
+
+    Ok(rules)
+}
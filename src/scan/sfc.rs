@@ -0,0 +1,186 @@
+//! Single-file components of Vue and Svelte, whose styles are in `<style>` blocks next to the
+//! markup and the script.
+
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::ops::Range;
+
+use super::{attributes, blank, css_rules, tag_end, CssRule};
+
+/// The languages of `<style lang="...">` blocks that can be read like CSS. Stylus and the indented
+/// Sass syntax have no braces, so their blocks are skipped.
+const LANGUAGES: [&str; 4] = ["css", "scss", "less", "postcss"];
+
+/// Where the contents of the `<style>` blocks of a component are, between the tags.
+fn style_blocks(source: &str) -> Vec<Range<usize>> {
+    let mut blocks = Vec::new();
+    let mut index = 0;
+
+    while let Some(start) = source[index..].find('<').map(|start| index + start) {
+        let rest = &source[start..];
+        if rest.starts_with("<!--") {
+            index = start + rest.find("-->").map_or(rest.len(), |end| end + 3);
+            continue;
+        }
+
+        let end = start + tag_end(rest);
+        index = end;
+        let Some(tag) = rest.strip_prefix("<style") else {
+            continue;
+        };
+        if !tag.starts_with(|c: char| c.is_whitespace() || c == '>') {
+            continue;
+        }
+
+        let close = source[end..]
+            .find("</style")
+            .map_or(source.len(), |close| end + close);
+        let readable = attributes(&source[start + 6..end])
+            .into_iter()
+            .find(|(name, _)| *name == "lang")
+            .is_none_or(|(_, lang)| LANGUAGES.contains(&lang));
+        if readable {
+            blocks.push(end..close);
+        }
+        index = close;
+    }
+
+    blocks
+}
+
+/// The custom properties a component sets: the ones declared in its styles, then the ones its
+/// markup passes to components as style props like `<Bar --fill="#777" />` or sets with
+/// `style:--fill="#777"` directives, later ones winning. Values that are expressions, like
+/// `--fill={color}`, can't be known.
+fn custom_properties(styles: &str, markup: &str) -> Vec<(String, String)> {
+    let mut properties = Vec::new();
+    for declaration in styles.split([';', '{', '}']) {
+        if let Some((name, value)) = declaration.trim().split_once(':') {
+            if name.starts_with("--") {
+                properties.push((name.trim_end().to_string(), value.trim().to_string()));
+            }
+        }
+    }
+
+    for (start, _) in markup.match_indices("--") {
+        let attribute = markup[..start].ends_with(|c: char| c.is_whitespace())
+            || markup[..start].ends_with("style:");
+        let rest = &markup[start..];
+        let name_end = rest[2..]
+            .find(|c: char| !(c.is_ascii_alphanumeric() || c == '-' || c == '_'))
+            .map_or(rest.len(), |end| end + 2);
+        let Some(value) = rest[name_end..].strip_prefix('=') else {
+            continue;
+        };
+        let Some(quote) = value.chars().next().filter(|c| matches!(c, '"' | '\'')) else {
+            continue;
+        };
+        if let (true, Some(end)) = (attribute && name_end > 2, value[1..].find(quote)) {
+            properties.push((
+                rest[..name_end].to_string(),
+                value[1..end + 1].trim().to_string(),
+            ));
+        }
+    }
+
+    properties
+}
+
+/// Replaces a value like `var(--text)` or `var(--text, #777)` with the value of the custom
+/// property, or its fallback when the component doesn't set it. Returns the first property.
+fn resolve_custom_property(
+    value: &mut Option<String>,
+    properties: &[(String, String)],
+) -> Option<String> {
+    let mut first = None;
+
+    // Bounded like variables, properties can point at each other
+    for _ in 0..16 {
+        let Some(inner) = value
+            .as_deref()
+            .and_then(|value| value.strip_prefix("var("))
+            .and_then(|inner| inner.strip_suffix(')'))
+        else {
+            break;
+        };
+        let (name, fallback) = match inner.split_once(',') {
+            Some((name, fallback)) => (name.trim(), Some(fallback.trim())),
+            None => (inner.trim(), None),
+        };
+        let resolved = properties
+            .iter()
+            .rev()
+            .find(|(property, _)| property == name)
+            .map(|(_, value)| value.as_str())
+            .or(fallback);
+        let Some(resolved) = resolved else {
+            break;
+        };
+        first.get_or_insert_with(|| name.to_string());
+        *value = Some(resolved.to_string());
+    }
+
+    first
+}
+
+/// Extracts the rules of the `<style>` blocks of a Vue or Svelte component, `scoped` and `module`
+/// ones included, like [`css_rules`](super::css_rules) does for a stylesheet. Everything else is
+/// blanked before the blocks are read, so lines and spans are those of the component file.
+///
+/// `var()` is resolved with the custom properties of the component: those its styles declare and
+/// those the markup sets, as Svelte style props (`<Bar --fill="#777" />`) or `style:--fill`
+/// directives, so the property is reported as the variable.
+///
+/// ```
+/// use contrast_checker::scan::sfc_rules;
+///
+/// let source = r#"<template>
+///   <h1 class="title">{{ title }}</h1>
+/// </template>
+///
+/// <style scoped lang="scss">
+/// $muted: #777;
+/// .title { color: $muted; }
+/// </style>
+/// "#;
+/// let rules = sfc_rules(source);
+///
+/// assert_eq!(rules[0].selector, ".title");
+/// assert_eq!(rules[0].line, 7);
+/// assert_eq!(rules[0].foreground.as_deref(), Some("#777"));
+/// assert_eq!(&source[rules[0].foreground_span.clone().unwrap()], "$muted");
+///
+/// let svelte = sfc_rules(r##"<p class="hint" style:--hint="#aaa">Saved</p>
+/// <style>
+///   .hint { color: var(--hint, #666); background: var(--surface, white); }
+/// </style>"##);
+///
+/// assert_eq!(svelte[0].foreground.as_deref(), Some("#aaa"));
+/// assert_eq!(svelte[0].foreground_variable.as_deref(), Some("--hint"));
+/// assert_eq!(svelte[0].background.as_deref(), Some("white"));
+/// ```
+pub fn sfc_rules(source: &str) -> Vec<CssRule> {
+    let mut styles = String::with_capacity(source.len());
+    let mut markup = String::with_capacity(source.len());
+    let mut position = 0;
+    for block in style_blocks(source) {
+        styles.push_str(&blank(&source[position..block.start]));
+        styles.push_str(&source[block.clone()]);
+        markup.push_str(&source[position..block.start]);
+        markup.push_str(&blank(&source[block.clone()]));
+        position = block.end;
+    }
+    styles.push_str(&blank(&source[position..]));
+    markup.push_str(&source[position..]);
+
+    let properties = custom_properties(&styles, &markup);
+    let mut rules = css_rules(&styles);
+    for rule in &mut rules {
+        let foreground = resolve_custom_property(&mut rule.foreground, &properties);
+        let background = resolve_custom_property(&mut rule.background, &properties);
+        rule.foreground_variable = rule.foreground_variable.take().or(foreground);
+        rule.background_variable = rule.background_variable.take().or(background);
+    }
+
+    rules
+}
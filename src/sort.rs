@@ -0,0 +1,116 @@
+use alloc::vec::Vec;
+
+use crate::{contrast_ratio, relative_luminance, Color};
+
+/// Indexes of `colors` from the darkest to the lightest by relative luminance, colors with the
+/// same luminance keep their order. [`sort_by_luminance`] sorts the colors themselves.
+pub fn luminance_order(colors: &[Color]) -> Vec<usize> {
+    let luminances: Vec<f32> = colors.iter().map(relative_luminance).collect();
+    let mut order: Vec<usize> = (0..colors.len()).collect();
+    order.sort_by(|first, second| luminances[*first].total_cmp(&luminances[*second]));
+    order
+}
+
+/// Indexes of `colors` from the highest to the lowest contrast against `reference`, colors with
+/// the same ratio keep their order. Translucent colors are composited like [`contrast_ratio`]
+/// does, as foregrounds over the reference.
+pub fn contrast_order(colors: &[Color], reference: &Color) -> Vec<usize> {
+    let ratios: Vec<f32> = colors
+        .iter()
+        .map(|color| contrast_ratio(color, reference))
+        .collect();
+    let mut order: Vec<usize> = (0..colors.len()).collect();
+    order.sort_by(|first, second| ratios[*second].total_cmp(&ratios[*first]));
+    order
+}
+
+/// Sorts a palette from the darkest to the lightest color by relative luminance, which orders
+/// colors of different hues the way they look on a grayscale screen.
+///
+/// ```
+/// use contrast_checker::{sort_by_luminance, Color};
+///
+/// let mut palette = [
+///     Color::new(0xff, 0xff, 0x00),
+///     Color::new(0x00, 0x00, 0xff),
+///     Color::new(0xff, 0x00, 0x00),
+/// ];
+/// sort_by_luminance(&mut palette);
+///
+/// // Blue is darker than red, which is darker than yellow
+/// assert_eq!(palette.map(|color| color.to_hex()), ["#0000ff", "#ff0000", "#ffff00"]);
+/// ```
+pub fn sort_by_luminance(colors: &mut [Color]) {
+    colors
+        .sort_by(|first, second| relative_luminance(first).total_cmp(&relative_luminance(second)));
+}
+
+/// Sorts colors from the highest to the lowest contrast against `reference`, the text colors that
+/// work best on a background first.
+///
+/// ```
+/// use contrast_checker::{sort_by_contrast, Color};
+///
+/// let white = Color::new(255, 255, 255);
+/// let mut candidates = [
+///     Color::new(0x99, 0x99, 0x99),
+///     Color::new(0x00, 0x00, 0x00),
+///     Color::new(0x55, 0x55, 0x55),
+/// ];
+/// sort_by_contrast(&mut candidates, &white);
+///
+/// assert_eq!(candidates.map(|color| color.to_hex()), ["#000000", "#555555", "#999999"]);
+/// ```
+pub fn sort_by_contrast(colors: &mut [Color], reference: &Color) {
+    colors.sort_by(|first, second| {
+        contrast_ratio(second, reference).total_cmp(&contrast_ratio(first, reference))
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn gray(value: u8) -> Color {
+        Color::new(value, value, value)
+    }
+
+    #[test]
+    fn orders_match_the_sorts() {
+        let palette = [
+            gray(0x80),
+            gray(0x10),
+            Color::new(0xf2, 0x6c, 0xa7),
+            gray(0xf0),
+        ];
+
+        let mut sorted = palette;
+        sort_by_luminance(&mut sorted);
+        let ordered: Vec<Color> = luminance_order(&palette)
+            .into_iter()
+            .map(|index| palette[index])
+            .collect();
+        assert_eq!(ordered, sorted);
+        assert_eq!(luminance_order(&palette), [1, 0, 2, 3]);
+
+        let reference = gray(0x20);
+        let mut sorted = palette;
+        sort_by_contrast(&mut sorted, &reference);
+        let ordered: Vec<Color> = contrast_order(&palette, &reference)
+            .into_iter()
+            .map(|index| palette[index])
+            .collect();
+        assert_eq!(ordered, sorted);
+        assert_eq!(contrast_order(&palette, &reference), [3, 2, 0, 1]);
+    }
+
+    #[test]
+    fn ties_keep_their_order() {
+        let white = gray(0xff);
+        let black = gray(0);
+        let palette = [white, black, gray(0x77), white];
+        assert_eq!(luminance_order(&palette), [1, 2, 0, 3]);
+        assert_eq!(contrast_order(&[black, black], &white), [0, 1]);
+        assert!(luminance_order(&[]).is_empty());
+    }
+}
@@ -0,0 +1,315 @@
+//! Reads [source maps](https://sourcemaps.info/spec.html) of compiled stylesheets, so findings in
+//! built CSS can point at the SCSS or Less that developers actually edit:
+//!
+//! ```
+//! use contrast_checker::source_map::SourceMap;
+//!
+//! // `.card__title` on line 1 of the CSS comes from line 12 of card.scss
+//! let map = SourceMap::parse(r#"{"version":3,"sources":["card.scss"],"mappings":"AAWA"}"#)
+//!     .unwrap();
+//! let original = map.original(1, 0).unwrap();
+//!
+//! assert_eq!((original.source, original.line), ("card.scss", 12));
+//! ```
+//!
+//! Index maps, with `sections` instead of `mappings`, aren't supported.
+
+use std::fmt;
+
+use serde_json::Value;
+
+/// A mapping of a generated position to the position it was compiled from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Mapping {
+    column: usize,
+    source: usize,
+    line: usize,
+    source_column: usize,
+}
+
+/// Where a position of the generated file comes from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct OriginalPosition<'a> {
+    /// The source file, with the `sourceRoot` of the map in front.
+    pub source: &'a str,
+    /// Line in the source, counting from 1.
+    pub line: usize,
+    /// Column in the source, counting from 0.
+    pub column: usize,
+}
+
+/// The mappings of a source map, by line of the generated file.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SourceMap {
+    sources: Vec<String>,
+    lines: Vec<Vec<Mapping>>,
+}
+
+/// Errors returned by [`SourceMap::parse`].
+#[derive(Debug)]
+pub enum SourceMapError {
+    InvalidJson(serde_json::Error),
+    /// The map isn't version 3, the only one in use.
+    UnsupportedVersion,
+    /// The map is an index map, or misses `sources` or `mappings`.
+    MissingField(&'static str),
+    /// `mappings` isn't valid Base64 VLQ, at this byte.
+    InvalidMappings {
+        offset: usize,
+    },
+}
+
+impl fmt::Display for SourceMapError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SourceMapError::InvalidJson(err) => write!(f, "invalid JSON: {err}"),
+            SourceMapError::UnsupportedVersion => {
+                write!(f, "only version 3 source maps are supported")
+            }
+            SourceMapError::MissingField(field) => write!(f, "missing {field}"),
+            SourceMapError::InvalidMappings { offset } => {
+                write!(f, "invalid mappings at byte {offset}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for SourceMapError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            SourceMapError::InvalidJson(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+/// The value of a Base64 digit.
+fn base64_digit(byte: u8) -> Option<u32> {
+    match byte {
+        b'A'..=b'Z' => Some(u32::from(byte - b'A')),
+        b'a'..=b'z' => Some(u32::from(byte - b'a') + 26),
+        b'0'..=b'9' => Some(u32::from(byte - b'0') + 52),
+        b'+' => Some(62),
+        b'/' => Some(63),
+        _ => None,
+    }
+}
+
+/// Decodes the fields of a segment of `mappings`, every field a Base64 VLQ: 5 bits per digit,
+/// least significant first, with the sixth bit set on every digit but the last and the sign in
+/// the lowest bit of the value.
+fn segment_fields(segment: &str) -> Option<Vec<i64>> {
+    let mut fields = Vec::new();
+    let (mut value, mut shift) = (0i64, 0);
+    for byte in segment.bytes() {
+        let digit = base64_digit(byte)?;
+        value += i64::from(digit & 31) << shift;
+        if digit & 32 != 0 {
+            shift += 5;
+            // Fields are 32 bit values
+            if shift > 30 {
+                return None;
+            }
+            continue;
+        }
+
+        fields.push(if value & 1 == 1 {
+            -(value >> 1)
+        } else {
+            value >> 1
+        });
+        (value, shift) = (0, 0);
+    }
+
+    (shift == 0).then_some(fields)
+}
+
+impl SourceMap {
+    /// Reads a version 3 source map.
+    pub fn parse(json: &str) -> Result<SourceMap, SourceMapError> {
+        let map: Value = serde_json::from_str(json).map_err(SourceMapError::InvalidJson)?;
+        if map.get("version").and_then(Value::as_u64) != Some(3) {
+            return Err(SourceMapError::UnsupportedVersion);
+        }
+
+        let root = map
+            .get("sourceRoot")
+            .and_then(Value::as_str)
+            .filter(|root| !root.is_empty())
+            .map(|root| format!("{}/", root.trim_end_matches('/')))
+            .unwrap_or_default();
+        let sources = map
+            .get("sources")
+            .and_then(Value::as_array)
+            .ok_or(SourceMapError::MissingField("sources"))?
+            .iter()
+            .map(|source| format!("{root}{}", source.as_str().unwrap_or_default()))
+            .collect();
+        let mappings = map
+            .get("mappings")
+            .and_then(Value::as_str)
+            .ok_or(SourceMapError::MissingField("mappings"))?;
+
+        // Every field but the generated column is relative to the previous segment of the file
+        let (mut source, mut line, mut source_column) = (0i64, 0i64, 0i64);
+        let mut lines = Vec::new();
+        let mut offset = 0;
+        for generated in mappings.split(';') {
+            let mut column = 0i64;
+            let mut segments = Vec::new();
+            for segment in generated.split(',') {
+                let start = offset;
+                let invalid = || SourceMapError::InvalidMappings { offset: start };
+                offset += segment.len() + 1;
+                if segment.is_empty() {
+                    continue;
+                }
+
+                let fields = segment_fields(segment).ok_or_else(invalid)?;
+                column += fields[0];
+                // Segments with a single field map to nothing
+                if let [_, source_delta, line_delta, column_delta, ..] = fields[..] {
+                    source += source_delta;
+                    line += line_delta;
+                    source_column += column_delta;
+                    segments.push(Mapping {
+                        column: usize::try_from(column).map_err(|_| invalid())?,
+                        source: usize::try_from(source).map_err(|_| invalid())?,
+                        line: usize::try_from(line).map_err(|_| invalid())?,
+                        source_column: usize::try_from(source_column).map_err(|_| invalid())?,
+                    });
+                }
+            }
+            segments.sort_by_key(|mapping| mapping.column);
+            lines.push(segments);
+        }
+
+        Ok(SourceMap { sources, lines })
+    }
+
+    /// Where the generated position comes from, `line` counting from 1 and `column` from 0. It's
+    /// the closest mapping of the line at or before the column, or the first one of the line when
+    /// the column is before all of them.
+    pub fn original(&self, line: usize, column: usize) -> Option<OriginalPosition<'_>> {
+        let mappings = self.lines.get(line.checked_sub(1)?)?;
+        let mapping = mappings
+            .iter()
+            .rev()
+            .find(|mapping| mapping.column <= column)
+            .or(mappings.first())?;
+
+        Some(OriginalPosition {
+            source: self.sources.get(mapping.source)?,
+            line: mapping.line + 1,
+            column: mapping.source_column,
+        })
+    }
+}
+
+/// Where the source map of a stylesheet is, from its `sourceMappingURL` comment.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SourceMapUrl<'a> {
+    /// A map inlined as a `data:` URL, decoded.
+    Inline(String),
+    /// A file or URL, relative to the stylesheet.
+    File(&'a str),
+}
+
+/// Decodes Base64, skipping whitespace and stopping at the padding.
+fn decode_base64(text: &str) -> Option<Vec<u8>> {
+    let mut bytes = Vec::new();
+    let (mut buffer, mut bits) = (0u32, 0);
+    for byte in text.bytes().filter(|byte| !byte.is_ascii_whitespace()) {
+        if byte == b'=' {
+            break;
+        }
+        buffer = (buffer << 6) | base64_digit(byte)?;
+        bits += 6;
+        if bits >= 8 {
+            bits -= 8;
+            bytes.push((buffer >> bits) as u8);
+            buffer &= (1 << bits) - 1;
+        }
+    }
+
+    Some(bytes)
+}
+
+/// Finds the `/*# sourceMappingURL=... */` comment of a stylesheet, the last one when there are
+/// several. Inline maps are decoded, `None` is also returned for `data:` URLs that aren't Base64
+/// JSON.
+///
+/// ```
+/// use contrast_checker::source_map::{source_map_url, SourceMapUrl};
+///
+/// let css = ".a{color:#777}\n/*# sourceMappingURL=app.css.map */\n";
+/// assert_eq!(source_map_url(css), Some(SourceMapUrl::File("app.css.map")));
+///
+/// let inline = "/*# sourceMappingURL=data:application/json;base64,eyJ2ZXJzaW9uIjozfQ== */";
+/// assert_eq!(
+///     source_map_url(inline),
+///     Some(SourceMapUrl::Inline(r#"{"version":3}"#.to_string()))
+/// );
+/// ```
+pub fn source_map_url(css: &str) -> Option<SourceMapUrl<'_>> {
+    const MARKER: &str = "sourceMappingURL=";
+    let start = css.rfind(MARKER)? + MARKER.len();
+    let rest = &css[start..];
+    let url = rest[..rest.find("*/").unwrap_or(rest.len())].trim();
+
+    match url.strip_prefix("data:") {
+        Some(data) => {
+            let (media_type, encoded) = data.split_once(',')?;
+            if !media_type.starts_with("application/json") || !media_type.ends_with(";base64") {
+                return None;
+            }
+            String::from_utf8(decode_base64(encoded)?)
+                .ok()
+                .map(SourceMapUrl::Inline)
+        }
+        None => (!url.is_empty()).then_some(SourceMapUrl::File(url)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn vlq_fields_have_a_sign_and_continuations() {
+        // D is -1, 2H is 123 over two digits
+        assert_eq!(segment_fields("AADC"), Some(vec![0, 0, -1, 1]));
+        assert_eq!(segment_fields("2H"), Some(vec![123]));
+        assert_eq!(segment_fields("g"), None);
+        assert_eq!(segment_fields("A*"), None);
+    }
+
+    #[test]
+    fn mappings_are_relative_to_the_previous_segment() {
+        // Line 1: column 0 from a.scss:1, column 10 from b.scss:3. Line 2: column 4,
+        // from b.scss again
+        let map = SourceMap::parse(
+            r#"{"version":3,"sourceRoot":"src/","sources":["a.scss","b.scss"],"mappings":"AAAA,UCEA;IAAA"}"#,
+        )
+        .unwrap();
+
+        assert_eq!(map.original(1, 3).unwrap().source, "src/a.scss");
+        let second = map.original(1, 12).unwrap();
+        assert_eq!((second.source, second.line), ("src/b.scss", 3));
+        // Before the first mapping of the line the first one is used
+        assert_eq!(map.original(2, 0).unwrap().line, 3);
+        assert_eq!(map.original(3, 0), None);
+    }
+
+    #[test]
+    fn parse_rejects_other_versions_and_bad_mappings() {
+        assert!(matches!(
+            SourceMap::parse(r#"{"version":2,"sources":[],"mappings":""}"#),
+            Err(SourceMapError::UnsupportedVersion)
+        ));
+        assert!(matches!(
+            SourceMap::parse(r#"{"version":3,"sources":["a"],"mappings":"AAAA,!"}"#),
+            Err(SourceMapError::InvalidMappings { offset: 5 })
+        ));
+    }
+}
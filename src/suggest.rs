@@ -0,0 +1,358 @@
+use alloc::format;
+use alloc::string::String;
+use alloc::vec::Vec;
+
+#[cfg(not(any(feature = "std", test)))]
+use crate::math::Float;
+
+use crate::{check, contrast_ratio, relative_luminance, Color};
+
+/// A foreground proposed by [`suggest_foreground`].
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Suggestion {
+    pub color: Color,
+    pub ratio: f32,
+}
+
+/// Moves the OKLCH lightness of `color` towards `target_lightness` until it `passes`, returning
+/// the first passing color, if any. The hue is kept, and so is the chroma unless the lighter or
+/// darker color can't have that much of it in sRGB.
+fn walk_lightness(
+    color: &Color,
+    passes: &impl Fn(&Color) -> bool,
+    target_lightness: f32,
+) -> Option<Color> {
+    let (lightness, chroma, hue) = color.to_oklch();
+    let color_at = |l: f32| Color {
+        alpha: color.alpha,
+        ..Color::from_oklch(l, chroma, hue)
+    };
+
+    let mut passing = color_at(target_lightness);
+    if !passes(&passing) {
+        return None;
+    }
+
+    // The contrast can first go down while moving towards the target, if the color crosses the
+    // luminance of the other one, but once it passes it keeps passing. That makes the passing
+    // side of the walk contiguous, so a binary search finds where it starts.
+    let mut passing_lightness = target_lightness;
+    let mut failing_lightness = lightness;
+    for _ in 0..24 {
+        let middle = (passing_lightness + failing_lightness) / 2.0;
+        let color = color_at(middle);
+
+        if passes(&color) {
+            passing_lightness = middle;
+            passing = color;
+        } else {
+            failing_lightness = middle;
+        }
+    }
+
+    Some(passing)
+}
+
+/// Tries both lighter and darker versions of `color` and returns the passing one with the
+/// smallest OKLCH lightness change. `passes` has to keep passing once it does when moving towards
+/// white or black, which the contrast against one color does.
+pub(crate) fn closest_lightness(color: &Color, passes: impl Fn(&Color) -> bool) -> Option<Color> {
+    let (lightness, _, _) = color.to_oklch();
+    let lighter = walk_lightness(color, &passes, 1.0);
+    let darker = walk_lightness(color, &passes, 0.0);
+
+    match (lighter, darker) {
+        (Some(lighter), Some(darker)) => {
+            let lighter_change = lighter.to_oklch().0 - lightness;
+            let darker_change = lightness - darker.to_oklch().0;
+
+            if lighter_change < darker_change {
+                Some(lighter)
+            } else {
+                Some(darker)
+            }
+        }
+        (lighter, darker) => lighter.or(darker),
+    }
+}
+
+/// Suggests the closest foreground that reaches `ratio` against `background`, by only changing
+/// the perceptual lightness of `foreground` (L in OKLCH). Both lighter and darker colors are tried
+/// and the one with the smallest lightness change wins. Unlike HSL lightness, equal changes of
+/// OKLCH lightness look equally big for every hue, so the suggestion stays close to the original.
+///
+/// Returns the foreground unchanged when it already passes, and `None` when no lightness reaches
+/// the ratio, e.g. for ratios above 21 or backgrounds that are mid gray and a high target.
+pub fn suggest_foreground(
+    foreground: &Color,
+    background: &Color,
+    ratio: f32,
+) -> Option<Suggestion> {
+    let current_ratio = contrast_ratio(foreground, background);
+    if current_ratio >= ratio {
+        return Some(Suggestion {
+            color: *foreground,
+            ratio: current_ratio,
+        });
+    }
+
+    let fix = foreground_fix(foreground, background, ratio)?;
+    Some(Suggestion {
+        color: fix.color,
+        ratio: fix.ratio,
+    })
+}
+
+/// A change of lightness to one color of a pair that makes it pass, see [`ComplianceGap`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct LightnessFix {
+    /// The changed color.
+    pub color: Color,
+    /// Ratio of the pair with the changed color.
+    pub ratio: f32,
+    /// How much the OKLCH lightness changed, from -1 to 1, negative when the color got darker.
+    pub oklch_lightness_change: f32,
+    /// The same change measured as HSL lightness, from -1 to 1.
+    pub hsl_lightness_change: f32,
+    /// The same change measured as CIELAB L*, from -100 to 100.
+    pub lab_lightness_change: f32,
+}
+
+impl LightnessFix {
+    fn new(original: &Color, color: Color, ratio: f32) -> LightnessFix {
+        LightnessFix {
+            color,
+            ratio,
+            oklch_lightness_change: color.to_oklch().0 - original.to_oklch().0,
+            hsl_lightness_change: color.to_hsl().2 - original.to_hsl().2,
+            lab_lightness_change: color.to_lab().0 - original.to_lab().0,
+        }
+    }
+
+    /// Describes the fix of `role`, like "darken foreground by ~12 L*". The change is rounded
+    /// up, so applying it reaches the ratio.
+    fn describe(&self, role: &str) -> String {
+        let (verb, change) = if self.lab_lightness_change < 0.0 {
+            ("darken", -self.lab_lightness_change)
+        } else {
+            ("lighten", self.lab_lightness_change)
+        };
+        // Rounded up without ceil(), which the no_std math doesn't have
+        format!("{verb} {role} by ~{} L*", -(-change).floor())
+    }
+}
+
+/// How far a pair is from a contrast ratio, and the smallest changes that close the gap. Tools
+/// can apply one of the fixes to update a design token automatically.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ComplianceGap {
+    pub required_ratio: f32,
+    pub achieved_ratio: f32,
+    /// Closest foreground, by OKLCH lightness, that passes on the same background.
+    pub foreground_fix: Option<LightnessFix>,
+    /// Closest background, by OKLCH lightness, that passes under the same foreground.
+    pub background_fix: Option<LightnessFix>,
+}
+
+impl ComplianceGap {
+    /// How much ratio is missing, e.g. 1.69 for a pair at 2.81:1 that needs 4.5:1.
+    pub fn missing_ratio(&self) -> f32 {
+        self.required_ratio - self.achieved_ratio
+    }
+
+    /// A line saying how far the pair is from passing and what closes the gap, in CIELAB L*
+    /// because it's what design tools show:
+    ///
+    /// ```
+    /// use contrast_checker::{compliance_gap, Color};
+    ///
+    /// let gap = compliance_gap(&Color::new(0x99, 0x99, 0x99), &Color::new(0x44, 0x44, 0x44), 4.5);
+    /// assert_eq!(
+    ///     gap.unwrap().hint(),
+    ///     "needs +1.08 contrast; lighten foreground by ~9 L* or darken background by ~9 L*"
+    /// );
+    ///
+    /// // White can't get any lighter, and a dark background would flip the pair
+    /// let gap = compliance_gap(&Color::new(0x77, 0x77, 0x77), &Color::new(255, 255, 255), 4.5);
+    /// assert_eq!(gap.unwrap().hint(), "needs +0.02 contrast; darken foreground by ~1 L*");
+    /// ```
+    pub fn hint(&self) -> String {
+        let fixes: Vec<String> = [
+            (self.foreground_fix, "foreground"),
+            (self.background_fix, "background"),
+        ]
+        .iter()
+        .filter_map(|(fix, role)| fix.map(|fix| fix.describe(role)))
+        .collect();
+
+        let missing = format!("needs +{:.2} contrast", self.missing_ratio());
+        if fixes.is_empty() {
+            format!("{missing}; no lightness of either color reaches it")
+        } else {
+            format!("{missing}; {}", fixes.join(" or "))
+        }
+    }
+}
+
+/// Reports how far a pair is from `required_ratio`, `None` when it already reaches it. The fixes
+/// only change the OKLCH lightness of one of the colors, like [`suggest_foreground`] does, and
+/// keep the color on its side of the other one: dark text on a light background stays dark on
+/// light. They are `None` when no lightness of that color on that side reaches the ratio.
+///
+/// ```
+/// use contrast_checker::{compliance_gap, Color};
+///
+/// let pink = Color::new(0xf2, 0x6c, 0xa7);
+/// let white = Color::new(255, 255, 255);
+///
+/// let gap = compliance_gap(&pink, &white, 4.5).unwrap();
+/// assert!((gap.missing_ratio() - 1.69).abs() < 0.01);
+///
+/// let fix = gap.foreground_fix.unwrap();
+/// assert!(fix.ratio >= 4.5 && fix.oklch_lightness_change < 0.0);
+/// assert!(compliance_gap(&Color::new(0, 0, 0), &white, 4.5).is_none());
+/// ```
+pub fn compliance_gap(
+    foreground: &Color,
+    background: &Color,
+    required_ratio: f32,
+) -> Option<ComplianceGap> {
+    let achieved_ratio = contrast_ratio(foreground, background);
+    if achieved_ratio >= required_ratio {
+        return None;
+    }
+
+    Some(ComplianceGap {
+        required_ratio,
+        achieved_ratio,
+        foreground_fix: foreground_fix(foreground, background, required_ratio),
+        background_fix: background_fix(foreground, background, required_ratio),
+    })
+}
+
+/// Whether a changed pair reaches `ratio` and is still the way round the original pair is, light
+/// on dark or dark on light. Fixes that flip it, like turning the white background of gray text
+/// into black, would redesign the pair rather than fix it. Pairs as light as each other can go
+/// either way.
+fn keeps_polarity_and_passes(
+    original: (&Color, &Color),
+    changed: (&Color, &Color),
+    ratio: f32,
+) -> bool {
+    let before = check(original.0, original.1);
+    let after = check(changed.0, changed.1);
+    after.ratio >= ratio
+        && (after.polarity == before.polarity
+            || before.foreground_luminance == before.background_luminance)
+}
+
+/// The closest foreground by OKLCH lightness that reaches `ratio` on `background`, keeping which
+/// color of the pair is lighter.
+fn foreground_fix(foreground: &Color, background: &Color, ratio: f32) -> Option<LightnessFix> {
+    closest_lightness(foreground, |color| {
+        keeps_polarity_and_passes((foreground, background), (color, background), ratio)
+    })
+    .map(|color| LightnessFix::new(foreground, color, contrast_ratio(&color, background)))
+}
+
+/// The closest background by OKLCH lightness that reaches `ratio` under `foreground`, keeping
+/// which color of the pair is lighter.
+fn background_fix(foreground: &Color, background: &Color, ratio: f32) -> Option<LightnessFix> {
+    closest_lightness(background, |color| {
+        keeps_polarity_and_passes((foreground, background), (foreground, color), ratio)
+    })
+    .map(|color| LightnessFix::new(background, color, contrast_ratio(foreground, &color)))
+}
+
+/// The smallest OKLCH lightness change to each color of a pair that reaches a ratio, see
+/// [`required_lightness_change`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct LightnessDelta {
+    /// Change to the foreground alone, from -1 to 1 and negative when it has to get darker.
+    /// `None` when no lightness of the foreground reaches the ratio.
+    pub foreground: Option<f32>,
+    /// Change to the background alone, the same way.
+    pub background: Option<f32>,
+}
+
+/// Finds how much the OKLCH lightness of each color of a pair has to change, on its own, for the
+/// pair to reach `target_ratio`. Both changes are 0 when it already does. These are the changes
+/// of the fixes of [`compliance_gap`], for showing next to lightness sliders or in a hint.
+///
+/// ```
+/// use contrast_checker::{required_lightness_change, Color};
+///
+/// let (gray, white) = (Color::new(0x77, 0x77, 0x77), Color::new(255, 255, 255));
+///
+/// let delta = required_lightness_change(&gray, &white, 4.5);
+/// // Barely darker text is enough, while white can't get lighter and going dark would turn the
+/// // pair into light text on a dark background
+/// assert!(delta.foreground.unwrap() > -0.01);
+/// assert_eq!(delta.background, None);
+///
+/// let passing = required_lightness_change(&gray, &white, 3.0);
+/// assert_eq!((passing.foreground, passing.background), (Some(0.0), Some(0.0)));
+/// ```
+pub fn required_lightness_change(
+    foreground: &Color,
+    background: &Color,
+    target_ratio: f32,
+) -> LightnessDelta {
+    if contrast_ratio(foreground, background) >= target_ratio {
+        return LightnessDelta {
+            foreground: Some(0.0),
+            background: Some(0.0),
+        };
+    }
+
+    let change = |fix: Option<LightnessFix>| fix.map(|fix| fix.oklch_lightness_change);
+    LightnessDelta {
+        foreground: change(foreground_fix(foreground, background, target_ratio)),
+        background: change(background_fix(foreground, background, target_ratio)),
+    }
+}
+
+/// Finds colors with the same relative luminance as `color` but a different hue, for replacing a
+/// decorative color without changing the contrast of the layout around it. The saturation is
+/// kept and the hues are spread evenly around the color wheel, starting next to the original
+/// one, so `count` colors are returned. Grays don't have a hue to change, so nothing is returned
+/// for them.
+///
+/// Channels are rounded to whole values, so the luminance can differ very slightly from the
+/// original, the contrast ratio between them stays at 1.0 when rounded to two decimals.
+pub fn luminance_matches(color: &Color, count: usize) -> Vec<Color> {
+    let (hue, saturation, _) = color.to_hsl();
+    if saturation == 0.0 {
+        return Vec::new();
+    }
+
+    let target = relative_luminance(color);
+    let step = 360.0 / (count + 1) as f32;
+
+    (1..=count)
+        .map(|index| {
+            let hue = hue + step * index as f32;
+
+            // Luminance only grows with HSL lightness, so a binary search finds the match
+            let mut darker = 0.0;
+            let mut lighter = 1.0;
+            for _ in 0..24 {
+                let middle = (darker + lighter) / 2.0;
+                if relative_luminance(&Color::from_hsl(hue, saturation, middle)) < target {
+                    darker = middle;
+                } else {
+                    lighter = middle;
+                }
+            }
+
+            Color {
+                alpha: color.alpha,
+                ..Color::from_hsl(hue, saturation, (darker + lighter) / 2.0)
+            }
+        })
+        .collect()
+}
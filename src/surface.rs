@@ -0,0 +1,72 @@
+use alloc::vec::Vec;
+
+use crate::{check, Color, ContrastReport};
+
+/// A background made of layers, like the elevation overlays of Material Design or the acrylic
+/// and mica layers of Fluent: an opaque base and translucent overlays drawn on it in order. The
+/// text is checked against the flattened result, which is what is actually on screen.
+///
+/// ```
+/// use contrast_checker::{Color, SurfaceStack};
+///
+/// // Material's dark theme lifts surfaces with 8% white at elevation 8dp
+/// let surface = SurfaceStack::new(Color::new(0x12, 0x12, 0x12))
+///     .overlay(Color::new(255, 255, 255).with_opacity(0.08));
+///
+/// assert_eq!(surface.flatten().to_hex(), "#252525");
+/// assert!(surface.check(&Color::new(0xe0, 0xe0, 0xe0)).ratio > 11.0);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SurfaceStack {
+    /// The bottom layer. A translucent base is drawn on a white page, like translucent
+    /// backgrounds everywhere else.
+    pub base: Color,
+    /// Drawn over the base in order, the last one ends up on top.
+    pub overlays: Vec<Color>,
+}
+
+impl SurfaceStack {
+    /// A stack with only a base.
+    pub fn new(base: Color) -> SurfaceStack {
+        SurfaceStack {
+            base,
+            overlays: Vec::new(),
+        }
+    }
+
+    /// Adds a layer on top of the stack, its alpha is its opacity.
+    pub fn overlay(mut self, color: Color) -> SurfaceStack {
+        self.overlays.push(color);
+        self
+    }
+
+    /// The surface after each overlay is drawn, starting with the base on its page, so the last
+    /// one is [`SurfaceStack::flatten`].
+    pub fn layers(&self) -> Vec<Color> {
+        let mut surface = self.base.composite_over(&Color::new(255, 255, 255));
+        let mut layers = Vec::with_capacity(self.overlays.len() + 1);
+        layers.push(surface);
+
+        for overlay in &self.overlays {
+            surface = overlay.composite_over(&surface);
+            layers.push(surface);
+        }
+
+        layers
+    }
+
+    /// The opaque color the whole stack is seen as.
+    pub fn flatten(&self) -> Color {
+        self.overlays.iter().fold(
+            self.base.composite_over(&Color::new(255, 255, 255)),
+            |surface, overlay| overlay.composite_over(&surface),
+        )
+    }
+
+    /// Checks a text color drawn on top of the stack, a translucent text is composited over the
+    /// flattened surface.
+    pub fn check(&self, text: &Color) -> ContrastReport {
+        check(text, &self.flatten())
+    }
+}
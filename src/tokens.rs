@@ -0,0 +1,480 @@
+//! Reads the colors of design token files, so a design system can be audited from its source of
+//! truth. Both the [W3C design tokens format](https://tr.designtokens.org/format/) and plain JSON
+//! objects of names to colors are supported:
+//!
+//! ```json
+//! {
+//!   "base": { "$type": "color", "pink": { "$value": "#f26ca7" } },
+//!   "text": { "accent": { "$value": "{base.pink}" } },
+//!   "surface": "white",
+//!   "$pairs": [["text.accent", "surface"]]
+//! }
+//! ```
+//!
+//! Token names are the path of keys joined with dots, like `base.pink`. Aliases like
+//! `{base.pink}` are resolved, even through other aliases. The optional `$pairs` array at the root
+//! declares which foreground and background tokens are used together.
+
+use std::collections::HashMap;
+use std::fmt;
+
+use serde_json::{Map, Value};
+
+use crate::{contrast_ratio, Color, ParseColorError, RoundingPolicy};
+
+/// How many aliases [`parse_tokens`] follows from a token before giving up, so a file with a long
+/// chain of aliases can't make it quadratic. Real design systems alias two or three levels deep.
+pub const MAX_ALIAS_DEPTH: usize = 32;
+
+/// A token whose value is a color.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ColorToken {
+    /// Path of the token, e.g. `text.primary`.
+    pub name: String,
+    pub color: Color,
+}
+
+/// The color tokens of a file and the pairs it declares, in the order they're written.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct TokenFile {
+    pub tokens: Vec<ColorToken>,
+    /// Foreground and background token names from `$pairs`.
+    pub pairs: Vec<(String, String)>,
+}
+
+impl TokenFile {
+    pub fn get(&self, name: &str) -> Option<&ColorToken> {
+        self.tokens.iter().find(|token| token.name == name)
+    }
+
+    /// Every combination of two tokens once, the token declared first is the foreground.
+    pub fn combinations(&self) -> Vec<(&ColorToken, &ColorToken)> {
+        let tokens = &self.tokens;
+        (0..tokens.len())
+            .flat_map(|first| {
+                (first + 1..tokens.len()).map(move |second| (&tokens[first], &tokens[second]))
+            })
+            .collect()
+    }
+
+    /// The pairs declared in `$pairs`, or every combination when the file doesn't declare any.
+    pub fn checked_pairs(&self) -> Vec<(&ColorToken, &ColorToken)> {
+        if self.pairs.is_empty() {
+            return self.combinations();
+        }
+
+        self.pairs
+            .iter()
+            // parse_tokens checks that the tokens of every pair exist
+            .filter_map(|(foreground, background)| self.get(foreground).zip(self.get(background)))
+            .collect()
+    }
+}
+
+/// How the contrast of a pair changed between two versions of a token file, see [`diff_tokens`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct PairChange {
+    pub foreground: String,
+    pub background: String,
+    pub old_ratio: f32,
+    pub new_ratio: f32,
+}
+
+impl PairChange {
+    /// Whether the new colors have less contrast than the old ones.
+    pub fn dropped(&self) -> bool {
+        self.new_ratio < self.old_ratio
+    }
+
+    /// Whether the pair reached `threshold` before the change and doesn't anymore.
+    pub fn newly_fails(&self, threshold: f32, rounding: RoundingPolicy) -> bool {
+        rounding.apply(self.old_ratio) >= threshold && rounding.apply(self.new_ratio) < threshold
+    }
+}
+
+/// Compares the contrast of the pairs two versions of a token file have in common, to review
+/// changes to a design system. The pairs are the ones the new file checks (its `$pairs`, or every
+/// combination), and pairs with a token missing from the old file are left out since there's
+/// nothing to compare them with.
+///
+/// ```
+/// use contrast_checker::tokens::{diff_tokens, parse_tokens};
+/// use contrast_checker::RoundingPolicy;
+///
+/// let old = parse_tokens(r##"{ "text": "#595959", "surface": "white" }"##).unwrap();
+/// let new = parse_tokens(r##"{ "text": "#777777", "surface": "white" }"##).unwrap();
+///
+/// let changes = diff_tokens(&old, &new);
+/// assert!(changes[0].dropped());
+/// assert!(changes[0].newly_fails(4.5, RoundingPolicy::Exact));
+/// ```
+pub fn diff_tokens(old: &TokenFile, new: &TokenFile) -> Vec<PairChange> {
+    new.checked_pairs()
+        .into_iter()
+        .filter_map(|(foreground, background)| {
+            let old_foreground = old.get(&foreground.name)?;
+            let old_background = old.get(&background.name)?;
+
+            Some(PairChange {
+                foreground: foreground.name.clone(),
+                background: background.name.clone(),
+                old_ratio: contrast_ratio(&old_foreground.color, &old_background.color),
+                new_ratio: contrast_ratio(&foreground.color, &background.color),
+            })
+        })
+        .collect()
+}
+
+/// Errors returned by [`parse_tokens`].
+#[derive(Debug)]
+pub enum TokensError {
+    InvalidJson(serde_json::Error),
+    /// The root of the file isn't an object.
+    NotAnObject,
+    /// A token of type color, or one referenced by a pair, has a value that isn't a color.
+    InvalidColor {
+        token: String,
+        value: String,
+        error: ParseColorError,
+    },
+    UnknownAlias {
+        token: String,
+        alias: String,
+    },
+    /// Following the aliases of a token leads back to it.
+    CircularAlias {
+        token: String,
+    },
+    /// A token is an alias of an alias, and so on, more than [`MAX_ALIAS_DEPTH`] times.
+    TooManyAliases {
+        token: String,
+    },
+    /// `$pairs` isn't an array of `[foreground, background]` token names.
+    InvalidPairs,
+    /// A pair references a token that doesn't exist or isn't a color.
+    UnknownPairToken {
+        name: String,
+    },
+}
+
+impl fmt::Display for TokensError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TokensError::InvalidJson(err) => write!(f, "invalid JSON: {err}"),
+            TokensError::NotAnObject => write!(f, "expected a JSON object of tokens"),
+            TokensError::InvalidColor { token, error, .. } => {
+                write!(f, "token {token}: invalid color {error}")
+            }
+            TokensError::UnknownAlias { token, alias } => {
+                write!(
+                    f,
+                    "token {token}: alias {{{alias}}} doesn't match any token"
+                )
+            }
+            TokensError::CircularAlias { token } => {
+                write!(f, "token {token}: its aliases lead back to it")
+            }
+            TokensError::TooManyAliases { token } => {
+                write!(f, "token {token}: more than {MAX_ALIAS_DEPTH} aliases deep")
+            }
+            TokensError::InvalidPairs => {
+                write!(
+                    f,
+                    "$pairs must be an array of [foreground, background] names"
+                )
+            }
+            TokensError::UnknownPairToken { name } => {
+                write!(f, "$pairs: {name} isn't a color token")
+            }
+        }
+    }
+}
+
+impl std::error::Error for TokensError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            TokensError::InvalidJson(err) => Some(err),
+            TokensError::InvalidColor { error, .. } => Some(error),
+            _ => None,
+        }
+    }
+}
+
+/// A token as written in the file, before aliases are resolved.
+struct RawToken {
+    name: String,
+    value: String,
+    kind: Option<String>,
+}
+
+/// Collects the tokens of a group. W3C tokens are objects with a `$value`, a `$type` on a group
+/// applies to everything inside it. Plain strings are tokens too, for simple name to color files.
+fn collect_tokens(
+    group: &Map<String, Value>,
+    path: &str,
+    kind: Option<&str>,
+    tokens: &mut Vec<RawToken>,
+) {
+    let kind = group.get("$type").and_then(Value::as_str).or(kind);
+
+    for (key, value) in group {
+        if key.starts_with('$') {
+            continue;
+        }
+
+        let name = if path.is_empty() {
+            key.clone()
+        } else {
+            format!("{path}.{key}")
+        };
+        match value {
+            Value::String(value) => tokens.push(RawToken {
+                name,
+                value: value.clone(),
+                kind: kind.map(str::to_string),
+            }),
+            Value::Object(object) => match object.get("$value") {
+                // Composite values like shadows are objects, they never are colors we can read
+                Some(Value::String(value)) => tokens.push(RawToken {
+                    name,
+                    value: value.clone(),
+                    kind: object
+                        .get("$type")
+                        .and_then(Value::as_str)
+                        .or(kind)
+                        .map(str::to_string),
+                }),
+                Some(_) => {}
+                None => collect_tokens(object, &name, kind, tokens),
+            },
+            _ => {}
+        }
+    }
+}
+
+/// Returns the name inside an alias like `{base.pink}`.
+fn alias_target(value: &str) -> Option<&str> {
+    value.trim().strip_prefix('{')?.strip_suffix('}')
+}
+
+/// Follows the aliases of a token until a value that isn't an alias.
+fn resolve<'a>(
+    token: &'a RawToken,
+    by_name: &HashMap<&str, &'a RawToken>,
+) -> Result<&'a RawToken, TokensError> {
+    let mut current = token;
+    let mut visited = vec![token.name.as_str()];
+
+    while let Some(alias) = alias_target(&current.value) {
+        current = by_name
+            .get(alias)
+            .ok_or_else(|| TokensError::UnknownAlias {
+                token: current.name.clone(),
+                alias: alias.to_string(),
+            })?;
+        if visited.contains(&current.name.as_str()) {
+            return Err(TokensError::CircularAlias {
+                token: token.name.clone(),
+            });
+        }
+        if visited.len() > MAX_ALIAS_DEPTH {
+            return Err(TokensError::TooManyAliases {
+                token: token.name.clone(),
+            });
+        }
+        visited.push(&current.name);
+    }
+
+    Ok(current)
+}
+
+fn parse_pairs(pairs: &Value) -> Result<Vec<(String, String)>, TokensError> {
+    let name = |value: &Value| {
+        let name = value.as_str()?;
+        Some(alias_target(name).unwrap_or(name).to_string())
+    };
+
+    pairs
+        .as_array()
+        .ok_or(TokensError::InvalidPairs)?
+        .iter()
+        .map(|pair| match pair.as_array().map(Vec::as_slice) {
+            Some([foreground, background]) => name(foreground).zip(name(background)),
+            _ => None,
+        })
+        .collect::<Option<_>>()
+        .ok_or(TokensError::InvalidPairs)
+}
+
+/// Reads the color tokens of a design token file.
+///
+/// Tokens with a `$type` other than `color` are skipped, and so are tokens without a type whose
+/// value isn't a color, like `16px`. A token of type `color` with an invalid value is an error.
+///
+/// ```
+/// use contrast_checker::tokens::parse_tokens;
+///
+/// let file = parse_tokens(r##"{
+///     "brand": { "$type": "color", "pink": { "$value": "#f26ca7" } },
+///     "text": { "accent": "{brand.pink}", "size": "16px" },
+///     "$pairs": [["text.accent", "brand.pink"]]
+/// }"##).unwrap();
+///
+/// assert_eq!(file.tokens.len(), 2);
+/// assert_eq!(file.get("text.accent").unwrap().color.to_hex(), "#f26ca7");
+/// assert_eq!(file.pairs, [("text.accent".to_string(), "brand.pink".to_string())]);
+/// ```
+pub fn parse_tokens(json: &str) -> Result<TokenFile, TokensError> {
+    let root: Value = serde_json::from_str(json).map_err(TokensError::InvalidJson)?;
+    let root = root.as_object().ok_or(TokensError::NotAnObject)?;
+
+    let mut raw_tokens = Vec::new();
+    collect_tokens(root, "", None, &mut raw_tokens);
+    let by_name: HashMap<&str, &RawToken> = raw_tokens
+        .iter()
+        .map(|token| (token.name.as_str(), token))
+        .collect();
+
+    let mut tokens = Vec::new();
+    for token in &raw_tokens {
+        let resolved = resolve(token, &by_name)?;
+        let kind = token.kind.as_deref().or(resolved.kind.as_deref());
+        match (kind, Color::from_css(&resolved.value)) {
+            (Some("color") | None, Ok(color)) => tokens.push(ColorToken {
+                name: token.name.clone(),
+                color,
+            }),
+            (Some("color"), Err(error)) => {
+                return Err(TokensError::InvalidColor {
+                    token: token.name.clone(),
+                    value: resolved.value.clone(),
+                    error,
+                })
+            }
+            _ => {}
+        }
+    }
+
+    let mut file = TokenFile {
+        tokens,
+        pairs: Vec::new(),
+    };
+    if let Some(pairs) = root.get("$pairs") {
+        file.pairs = parse_pairs(pairs)?;
+    }
+    for (foreground, background) in &file.pairs {
+        for name in [foreground, background] {
+            if file.get(name).is_none() {
+                return Err(TokensError::UnknownPairToken { name: name.clone() });
+            }
+        }
+    }
+
+    Ok(file)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn resolves_aliases_of_aliases() {
+        let file = parse_tokens(
+            r##"{
+                "base": { "gray": { "$value": "#777", "$type": "color" } },
+                "text": { "muted": { "$value": "{text.secondary}" }, "secondary": "{base.gray}" }
+            }"##,
+        )
+        .unwrap();
+
+        let names: Vec<_> = file
+            .tokens
+            .iter()
+            .map(|token| token.name.as_str())
+            .collect();
+        assert_eq!(names, ["base.gray", "text.muted", "text.secondary"]);
+        assert_eq!(
+            file.get("text.muted").unwrap().color,
+            Color::new(0x77, 0x77, 0x77)
+        );
+    }
+
+    #[test]
+    fn skips_tokens_that_are_not_colors() {
+        let file = parse_tokens(
+            r##"{
+                "size": { "$type": "dimension", "small": { "$value": "red" } },
+                "space": "16px",
+                "shadow": { "$value": { "blur": "4px" } },
+                "opacity": 0.5,
+                "accent": "tomato"
+            }"##,
+        )
+        .unwrap();
+
+        assert_eq!(file.tokens.len(), 1);
+        assert_eq!(file.tokens[0].name, "accent");
+    }
+
+    #[test]
+    fn diffs_the_pairs_both_files_have() {
+        let old = parse_tokens(
+            r##"{ "text": "#222", "muted": "#595959", "surface": "white", "$pairs": [] }"##,
+        )
+        .unwrap();
+        let new = parse_tokens(
+            r##"{
+                "text": "#000", "muted": "#777", "surface": "white", "accent": "#f26ca7",
+                "$pairs": [["text", "surface"], ["muted", "surface"], ["accent", "surface"]]
+            }"##,
+        )
+        .unwrap();
+
+        let changes = diff_tokens(&old, &new);
+        let names: Vec<_> = changes
+            .iter()
+            .map(|change| change.foreground.as_str())
+            .collect();
+        assert_eq!(names, ["text", "muted"]);
+        assert!(!changes[0].dropped());
+        assert!(changes[1].dropped());
+        assert!(changes[1].newly_fails(4.5, RoundingPolicy::Exact));
+        assert!(!changes[1].newly_fails(3.0, RoundingPolicy::Exact));
+    }
+
+    #[test]
+    fn reports_invalid_files() {
+        let error = |json: &str| parse_tokens(json).unwrap_err();
+
+        assert!(matches!(error("[1]"), TokensError::NotAnObject));
+        assert!(matches!(error("{"), TokensError::InvalidJson(_)));
+        assert!(matches!(
+            error(r#"{ "a": { "$type": "color", "$value": "nope" } }"#),
+            TokensError::InvalidColor { .. }
+        ));
+        assert!(matches!(
+            error(r#"{ "a": "{b}" }"#),
+            TokensError::UnknownAlias { alias, .. } if alias == "b"
+        ));
+        assert!(matches!(
+            error(r#"{ "a": "{b}", "b": "{a}" }"#),
+            TokensError::CircularAlias { .. }
+        ));
+        let chain: Vec<String> = (0..=MAX_ALIAS_DEPTH)
+            .map(|index| format!(r#""t{index}": "{{t{}}}""#, index + 1))
+            .chain([format!(r#""t{}": "red""#, MAX_ALIAS_DEPTH + 1)])
+            .collect();
+        assert!(matches!(
+            error(&format!("{{ {} }}", chain.join(", "))),
+            TokensError::TooManyAliases { token } if token == "t0"
+        ));
+        assert!(matches!(
+            error(r#"{ "a": "red", "$pairs": [["a"]] }"#),
+            TokensError::InvalidPairs
+        ));
+        assert!(matches!(
+            error(r#"{ "a": "red", "$pairs": [["a", "{b}"]] }"#),
+            TokensError::UnknownPairToken { name } if name == "b"
+        ));
+    }
+}
@@ -0,0 +1,216 @@
+#[cfg(not(any(feature = "std", test)))]
+use crate::math::Float;
+use crate::{contrast_ratio, Color};
+
+/// WCAG 2.1 conformance levels that have contrast requirements.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum WcagLevel {
+    AA,
+    AAA,
+}
+
+/// Text size categories used by WCAG. Large text is at least 18pt, or 14pt and bold.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum TextSize {
+    Normal,
+    Large,
+}
+
+impl WcagLevel {
+    /// Minimum contrast ratio required by the level, obtained from
+    /// https://www.w3.org/TR/WCAG21/#contrast-minimum and https://www.w3.org/TR/WCAG21/#contrast-enhanced
+    pub const fn minimum_ratio(self, size: TextSize) -> f32 {
+        match (self, size) {
+            (WcagLevel::AA, TextSize::Normal) => 4.5,
+            (WcagLevel::AA, TextSize::Large) => 3.0,
+            (WcagLevel::AAA, TextSize::Normal) => 7.0,
+            (WcagLevel::AAA, TextSize::Large) => 4.5,
+        }
+    }
+}
+
+/// What a pair of colors is used for, which decides the WCAG success criterion that applies and
+/// so the thresholds.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ContrastContext {
+    /// Text below 18pt, or below 14pt when bold.
+    #[default]
+    Text,
+    /// Text of at least 18pt, or 14pt and bold.
+    LargeText,
+    /// Graphical objects and the boundaries and states of UI components, like input borders,
+    /// focus rings and icons.
+    Ui,
+}
+
+/// A WCAG 2.1 success criterion with a contrast requirement.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct SuccessCriterion {
+    /// The number of the criterion, e.g. `1.4.3`.
+    pub number: &'static str,
+    pub name: &'static str,
+    pub url: &'static str,
+}
+
+const CONTRAST_MINIMUM: SuccessCriterion = SuccessCriterion {
+    number: "1.4.3",
+    name: "Contrast (Minimum)",
+    url: "https://www.w3.org/TR/WCAG21/#contrast-minimum",
+};
+
+const CONTRAST_ENHANCED: SuccessCriterion = SuccessCriterion {
+    number: "1.4.6",
+    name: "Contrast (Enhanced)",
+    url: "https://www.w3.org/TR/WCAG21/#contrast-enhanced",
+};
+
+pub(crate) const NON_TEXT_CONTRAST: SuccessCriterion = SuccessCriterion {
+    number: "1.4.11",
+    name: "Non-text Contrast",
+    url: "https://www.w3.org/TR/WCAG21/#non-text-contrast",
+};
+
+/// The WCAG 2.2 criterion whose 3:1 between the focused and unfocused states applies to focus
+/// indicators at AAA, see [`FocusIndicator`](crate::FocusIndicator).
+pub(crate) const FOCUS_APPEARANCE: SuccessCriterion = SuccessCriterion {
+    number: "2.4.13",
+    name: "Focus Appearance",
+    url: "https://www.w3.org/TR/WCAG22/#focus-appearance",
+};
+
+impl ContrastContext {
+    pub fn name(self) -> &'static str {
+        match self {
+            ContrastContext::Text => "text",
+            ContrastContext::LargeText => "large text",
+            ContrastContext::Ui => "UI components",
+        }
+    }
+
+    /// The success criterion that sets the requirement of the level, `None` when the level has
+    /// none for this context. Non-text contrast is only required at AA.
+    pub const fn criterion(self, level: WcagLevel) -> Option<SuccessCriterion> {
+        match (self, level) {
+            (ContrastContext::Ui, WcagLevel::AA) => Some(NON_TEXT_CONTRAST),
+            (ContrastContext::Ui, WcagLevel::AAA) => None,
+            (_, WcagLevel::AA) => Some(CONTRAST_MINIMUM),
+            (_, WcagLevel::AAA) => Some(CONTRAST_ENHANCED),
+        }
+    }
+
+    /// Minimum contrast ratio required by the level, `None` when the level has no requirement for
+    /// this context. The UI component threshold is obtained from
+    /// https://www.w3.org/TR/WCAG21/#non-text-contrast
+    pub const fn minimum_ratio(self, level: WcagLevel) -> Option<f32> {
+        match self {
+            ContrastContext::Text => Some(level.minimum_ratio(TextSize::Normal)),
+            ContrastContext::LargeText => Some(level.minimum_ratio(TextSize::Large)),
+            ContrastContext::Ui => match level {
+                WcagLevel::AA => Some(3.0),
+                WcagLevel::AAA => None,
+            },
+        }
+    }
+}
+
+/// Which value is compared against the thresholds when grading a ratio. WCAG ratios are usually
+/// reported with two decimals but tools disagree on whether that's what gets graded, so a ratio of
+/// 4.4956 can be shown as "4.50" and still fail AA.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum RoundingPolicy {
+    /// Compare the unrounded ratio. This is the default because WCAG doesn't allow rounding up:
+    /// https://www.w3.org/WAI/WCAG21/Understanding/contrast-minimum.html
+    #[default]
+    Exact,
+    /// Compare the ratio rounded to two decimals, which is how it's usually displayed.
+    TwoDecimals,
+}
+
+impl RoundingPolicy {
+    /// Returns the value that should be compared against the thresholds.
+    pub fn apply(self, ratio: f32) -> f32 {
+        match self {
+            RoundingPolicy::Exact => ratio,
+            RoundingPolicy::TwoDecimals => (ratio * 100.0).round() / 100.0,
+        }
+    }
+}
+
+/// Ratios below this are reported as indistinguishable: the colors are the same or so close that
+/// text would be invisible, which a bare 1.00:1 doesn't make obvious.
+pub const INDISTINGUISHABLE_RATIO: f32 = 1.1;
+
+/// Pass/fail results of a contrast ratio for every WCAG level and text size.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ComplianceReport {
+    pub ratio: f32,
+    pub aa_normal_text: bool,
+    pub aa_large_text: bool,
+    pub aaa_normal_text: bool,
+    pub aaa_large_text: bool,
+    /// The ratio is below [`INDISTINGUISHABLE_RATIO`], like for identical colors.
+    pub indistinguishable: bool,
+}
+
+impl ComplianceReport {
+    /// Grades an already computed contrast ratio, using [`RoundingPolicy::Exact`].
+    pub fn from_ratio(ratio: f32) -> ComplianceReport {
+        ComplianceReport::with_rounding(ratio, RoundingPolicy::Exact)
+    }
+
+    /// Grades an already computed contrast ratio with the given rounding policy. The stored ratio
+    /// is always the unrounded one.
+    pub fn with_rounding(ratio: f32, rounding: RoundingPolicy) -> ComplianceReport {
+        let graded_ratio = rounding.apply(ratio);
+        let passes = |level: WcagLevel, size| graded_ratio >= level.minimum_ratio(size);
+
+        ComplianceReport {
+            ratio,
+            aa_normal_text: passes(WcagLevel::AA, TextSize::Normal),
+            aa_large_text: passes(WcagLevel::AA, TextSize::Large),
+            aaa_normal_text: passes(WcagLevel::AAA, TextSize::Normal),
+            aaa_large_text: passes(WcagLevel::AAA, TextSize::Large),
+            indistinguishable: graded_ratio < INDISTINGUISHABLE_RATIO,
+        }
+    }
+
+    pub fn passes(&self, level: WcagLevel, size: TextSize) -> bool {
+        match (level, size) {
+            (WcagLevel::AA, TextSize::Normal) => self.aa_normal_text,
+            (WcagLevel::AA, TextSize::Large) => self.aa_large_text,
+            (WcagLevel::AAA, TextSize::Normal) => self.aaa_normal_text,
+            (WcagLevel::AAA, TextSize::Large) => self.aaa_large_text,
+        }
+    }
+
+    /// Whether the ratio meets the level for a context, `None` when the level has no requirement
+    /// for it.
+    pub fn passes_in(&self, level: WcagLevel, context: ContrastContext) -> Option<bool> {
+        match context {
+            ContrastContext::Text => Some(self.passes(level, TextSize::Normal)),
+            ContrastContext::LargeText => Some(self.passes(level, TextSize::Large)),
+            // Same 3:1 threshold as AA large text
+            ContrastContext::Ui => match level {
+                WcagLevel::AA => Some(self.aa_large_text),
+                WcagLevel::AAA => None,
+            },
+        }
+    }
+
+    /// The highest level passed for the given text size, if any.
+    pub fn highest_level(&self, size: TextSize) -> Option<WcagLevel> {
+        [WcagLevel::AAA, WcagLevel::AA]
+            .into_iter()
+            .find(|level| self.passes(*level, size))
+    }
+}
+
+/// Computes the contrast ratio of a pair and grades it against every WCAG 2.1 level.
+pub fn check_compliance(foreground: &Color, background: &Color) -> ComplianceReport {
+    ComplianceReport::from_ratio(contrast_ratio(foreground, background))
+}
@@ -0,0 +1,150 @@
+//! The parsers read untrusted input in batch, scan and serve, so they must return an error for
+//! anything they can't read instead of panicking. The fuzz targets in `fuzz/` go further, these run
+//! with every `cargo test`.
+
+use contrast_checker::scan::{
+    css_in_js_rules, css_rules, sfc_rules, svg_elements, MAX_NESTING_DEPTH, MAX_SELECTOR_LENGTH,
+};
+use contrast_checker::{
+    parse_color, split_colors, Color, GamutMapping, ParseColorErrorKind, MAX_COLOR_LENGTH,
+};
+use proptest::prelude::*;
+
+/// Strings made of the characters colors and stylesheets are written with, which reach deeper
+/// into the parsers than uniformly random ones.
+fn css_like() -> impl Strategy<Value = String> {
+    proptest::string::string_regex(r#"[#a-fA-F0-9 ,;:%./()\-{}<>"'`=$@*\nrgbhslokcdpéä]{0,80}"#)
+        .unwrap()
+}
+
+proptest! {
+    #[test]
+    fn parsing_colors_never_panics(input in any::<String>()) {
+        let _ = parse_color(&input);
+    }
+
+    #[test]
+    fn parsing_css_like_colors_never_panics(input in css_like()) {
+        if let Ok(color) = parse_color(&input) {
+            prop_assert_eq!(parse_color(&color.to_hex()), Ok(color));
+        }
+        for color in split_colors(&input) {
+            let _ = parse_color(color);
+        }
+    }
+
+    #[test]
+    fn scanning_never_panics(source in css_like()) {
+        let _ = css_rules(&source);
+        let _ = css_in_js_rules(&source);
+        let _ = sfc_rules(&source);
+        let _ = svg_elements(&source);
+    }
+
+    #[cfg(feature = "tokens")]
+    #[test]
+    fn parsing_tokens_never_panics(json in r#"[{}\[\]":,a-z0-9#$ ]{0,80}"#) {
+        let _ = contrast_checker::tokens::parse_tokens(&json);
+    }
+}
+
+#[test]
+fn long_inputs_are_rejected_before_parsing() {
+    let long = format!("rgb({}1 2 3)", " ".repeat(MAX_COLOR_LENGTH));
+    let error = parse_color(&long).unwrap_err();
+    assert_eq!(error.kind(), ParseColorErrorKind::TooLong);
+    assert_eq!(error.input().len(), MAX_COLOR_LENGTH);
+
+    // Cut at a character boundary
+    let error = parse_color(&"é".repeat(MAX_COLOR_LENGTH)).unwrap_err();
+    assert_eq!(error.input(), "é".repeat(MAX_COLOR_LENGTH / 2));
+}
+
+#[test]
+fn nested_selectors_stay_short() {
+    let css = format!("{}color: #777;{}", ".a { && { ".repeat(8), "} ".repeat(16));
+    let rules = css_rules(&css);
+    assert!(rules
+        .iter()
+        .all(|rule| rule.selector.len() <= MAX_SELECTOR_LENGTH));
+    assert_eq!(rules.last().unwrap().selector, "&&");
+
+    // Inputs the scan fuzz target ran out of memory on
+    for source in [
+        include_str!("../fuzz/artifacts/scan/oom-c31cbe82b4e9aa00c30593150976bfd9b126f04b"),
+        include_str!("../fuzz/artifacts/scan/oom-ca996151abde4c6a2c36c24366ca1753b6e4e94b"),
+        include_str!("../fuzz/artifacts/scan/oom-de2d6e8898302cd68e4d7552b0b02bf25229cd1e"),
+        include_str!("../fuzz/artifacts/scan/oom-e0fbb683d3fe4d42b265c94a94470b1211597bbf"),
+    ] {
+        for rule in css_rules(source)
+            .into_iter()
+            .chain(css_in_js_rules(source))
+            .chain(sfc_rules(source))
+        {
+            assert!(rule.selector.len() <= MAX_SELECTOR_LENGTH);
+        }
+    }
+}
+
+#[test]
+fn deeply_nested_rules_are_skipped() {
+    let depth = MAX_NESTING_DEPTH + 8;
+    let css = format!(
+        "{}color: #777;{}",
+        ".a { ".repeat(depth),
+        "} ".repeat(depth)
+    );
+    assert!(css_rules(&css).is_empty());
+
+    let depth = MAX_NESTING_DEPTH;
+    let css = format!(
+        "{}color: #777;{}",
+        ".a { ".repeat(depth),
+        "} ".repeat(depth)
+    );
+    assert_eq!(css_rules(&css).len(), 1);
+}
+
+/// Replays what the fuzz targets found, `fuzz/artifacts/<target>/*`, through the same calls as
+/// the target. Inputs that aren't UTF-8 are skipped like the targets skip them.
+#[test]
+fn fuzz_artifacts_are_handled() {
+    let artifacts = std::path::Path::new(env!("CARGO_MANIFEST_DIR")).join("fuzz/artifacts");
+    let mut replayed = 0;
+    for target in std::fs::read_dir(artifacts).unwrap() {
+        let target = target.unwrap().path();
+        let name = target.file_name().unwrap().to_str().unwrap().to_string();
+        for artifact in std::fs::read_dir(&target).unwrap() {
+            let bytes = std::fs::read(artifact.unwrap().path()).unwrap();
+            let Ok(input) = std::str::from_utf8(&bytes) else {
+                continue;
+            };
+            match name.as_str() {
+                "parse_color" => {
+                    let _ = parse_color(input);
+                    let _ = Color::from_css_with(input, GamutMapping::Clip);
+                    for color in split_colors(input) {
+                        let _ = parse_color(color);
+                    }
+                }
+                #[cfg(feature = "tokens")]
+                "parse_tokens" => {
+                    if let Ok(file) = contrast_checker::tokens::parse_tokens(input) {
+                        let _ = file.checked_pairs();
+                    }
+                }
+                "scan" => {
+                    let _ = css_rules(input);
+                    let _ = css_in_js_rules(input);
+                    let _ = sfc_rules(input);
+                    let _ = svg_elements(input);
+                    #[cfg(feature = "postcss")]
+                    let _ = contrast_checker::scan::postcss_rules(input);
+                }
+                _ => continue,
+            }
+            replayed += 1;
+        }
+    }
+    assert!(replayed > 0);
+}
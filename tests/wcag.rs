@@ -0,0 +1,224 @@
+//! Invariants of the WCAG math checked on random colors, and known values from the WCAG
+//! documents and other checkers.
+
+use contrast_checker::{
+    check, check_compliance, compliance_gap, const_contrast_ratio, contrast_matrix, contrast_ratio,
+    relative_luminance, relative_luminance_batch, Color, ComplianceReport, Component,
+    ComponentRole, ContrastAlgorithm, Luminance, RoundingPolicy, TextSize, Wcag2, WcagLevel, Weber,
+};
+use proptest::prelude::*;
+
+fn opaque_color() -> impl Strategy<Value = Color> {
+    any::<(u8, u8, u8)>().prop_map(|(red, green, blue)| Color::new(red, green, blue))
+}
+
+fn assert_close(actual: f32, expected: f32, tolerance: f32) {
+    assert!(
+        (actual - expected).abs() <= tolerance,
+        "expected {expected}, got {actual}"
+    );
+}
+
+proptest! {
+    #[test]
+    fn contrast_is_symmetric(first in opaque_color(), second in opaque_color()) {
+        prop_assert_eq!(contrast_ratio(&first, &second), contrast_ratio(&second, &first));
+    }
+
+    #[test]
+    fn ratio_is_between_1_and_21(first in opaque_color(), second in opaque_color()) {
+        let ratio = contrast_ratio(&first, &second);
+        prop_assert!((1.0..=21.0).contains(&ratio), "{ratio}");
+    }
+
+    #[test]
+    fn a_color_has_no_contrast_with_itself(color in opaque_color()) {
+        prop_assert_eq!(contrast_ratio(&color, &color), 1.0);
+    }
+
+    #[test]
+    fn luminance_is_between_0_and_1(color in opaque_color()) {
+        let luminance = relative_luminance(&color);
+        prop_assert!((0.0..=1.0).contains(&luminance), "{luminance}");
+    }
+
+    #[test]
+    fn luminance_is_monotone_in_each_channel(
+        (red, green, blue) in any::<(u8, u8, u8)>(),
+        channel in 0..3usize,
+    ) {
+        let mut channels = [red, green, blue];
+        prop_assume!(channels[channel] < 255);
+
+        let before = relative_luminance(&Color::new(channels[0], channels[1], channels[2]));
+        channels[channel] += 1;
+        let after = relative_luminance(&Color::new(channels[0], channels[1], channels[2]));
+        prop_assert!(after > before, "{before} then {after}");
+    }
+
+    #[test]
+    fn every_way_of_computing_the_ratio_agrees(first in opaque_color(), second in opaque_color()) {
+        let ratio = contrast_ratio(&first, &second);
+
+        let cached = Luminance::of(&first).contrast_ratio(Luminance::of(&second));
+        prop_assert!((cached - ratio).abs() < 1e-5);
+        let matrix = contrast_matrix(&[first, second]);
+        prop_assert!((matrix[0][1] - ratio).abs() < 1e-5);
+        let constant = const_contrast_ratio(
+            [first.red, first.green, first.blue],
+            [second.red, second.green, second.blue],
+        );
+        prop_assert_eq!(constant, ratio);
+
+        prop_assert_eq!(Wcag2.contrast(&first, &second), ratio);
+        prop_assert!((Weber.contrast(&first, &second) + 1.0 - ratio).abs() < 1e-4);
+
+        let report = check(&first, &second);
+        prop_assert_eq!(report.ratio, ratio);
+        prop_assert_eq!(report.foreground_luminance, relative_luminance(&first));
+    }
+
+    #[test]
+    fn batch_luminance_matches_every_color(colors in prop::collection::vec(opaque_color(), 0..700)) {
+        let one_by_one: Vec<f32> = colors.iter().map(relative_luminance).collect();
+        prop_assert_eq!(relative_luminance_batch(&colors), one_by_one);
+    }
+
+    #[test]
+    fn higher_levels_imply_lower_ones(ratio in 1.0f32..=21.0) {
+        let report = ComplianceReport::from_ratio(ratio);
+
+        prop_assert!(!report.aaa_normal_text || report.aa_normal_text);
+        prop_assert!(!report.aaa_large_text || report.aa_large_text);
+        prop_assert!(!report.aa_normal_text || report.aa_large_text);
+        prop_assert_eq!(report.aaa_large_text, report.aa_normal_text);
+    }
+
+    #[test]
+    fn gap_fixes_reach_the_ratio(
+        foreground in opaque_color(),
+        background in opaque_color(),
+        required in 1.5f32..=7.0,
+    ) {
+        let Some(gap) = compliance_gap(&foreground, &background, required) else {
+            prop_assert!(contrast_ratio(&foreground, &background) >= required);
+            return Ok(());
+        };
+
+        prop_assert!(gap.missing_ratio() > 0.0);
+        for fix in [gap.foreground_fix, gap.background_fix].into_iter().flatten() {
+            prop_assert!(fix.ratio >= required, "{fix:?}");
+        }
+        if let Some(fix) = gap.foreground_fix {
+            prop_assert_eq!(contrast_ratio(&fix.color, &background), fix.ratio);
+        }
+
+        // The fixes never swap which color is lighter
+        let before = check(&foreground, &background);
+        if before.foreground_luminance != before.background_luminance {
+            let fixed = [
+                gap.foreground_fix.map(|fix| check(&fix.color, &background)),
+                gap.background_fix.map(|fix| check(&foreground, &fix.color)),
+            ];
+            for after in fixed.into_iter().flatten() {
+                prop_assert_eq!(after.polarity, before.polarity);
+            }
+        }
+    }
+
+    #[test]
+    fn hex_round_trips(color in opaque_color(), alpha in any::<u8>()) {
+        let color = Color { alpha, ..color };
+        prop_assert_eq!(Color::from_css(&color.to_hex()), Ok(color));
+    }
+}
+
+#[test]
+fn black_and_white_are_21_to_1() {
+    let ratio = contrast_ratio(&Color::new(0, 0, 0), &Color::new(255, 255, 255));
+    assert_eq!(ratio, 21.0);
+    assert!(check_compliance(&Color::new(0, 0, 0), &Color::new(255, 255, 255)).aaa_normal_text);
+}
+
+#[test]
+fn primary_colors_have_the_luminance_coefficients() {
+    // Coefficients obtained from https://www.w3.org/TR/WCAG21/#dfn-relative-luminance
+    assert_close(relative_luminance(&Color::new(255, 0, 0)), 0.2126, 1e-6);
+    assert_close(relative_luminance(&Color::new(0, 255, 0)), 0.7152, 1e-6);
+    assert_close(relative_luminance(&Color::new(0, 0, 255)), 0.0722, 1e-6);
+    assert_close(
+        relative_luminance(&Color::new(128, 128, 128)),
+        0.215_860_5,
+        1e-6,
+    );
+}
+
+#[test]
+fn grays_around_the_aa_threshold_on_white() {
+    let white = Color::new(255, 255, 255);
+
+    // #767676 is the lightest gray that passes AA on white, #777777 is the classic near miss
+    let passing = contrast_ratio(&Color::new(0x76, 0x76, 0x76), &white);
+    assert_close(passing, 4.54, 0.005);
+    assert!(ComplianceReport::from_ratio(passing).aa_normal_text);
+
+    let failing = contrast_ratio(&Color::new(0x77, 0x77, 0x77), &white);
+    assert_close(failing, 4.48, 0.005);
+    let report = ComplianceReport::from_ratio(failing);
+    assert!(!report.aa_normal_text);
+    assert!(report.aa_large_text);
+}
+
+#[test]
+fn known_pairs_from_other_checkers() {
+    let ratio = |foreground: &str, background: &str| {
+        contrast_ratio(
+            &Color::from_css(foreground).unwrap(),
+            &Color::from_css(background).unwrap(),
+        )
+    };
+
+    assert_close(ratio("#f26ca7", "white"), 2.81, 0.005);
+    assert_close(ratio("#f26ca7", "black"), 7.48, 0.005);
+    assert_close(ratio("#949494", "white"), 3.03, 0.005);
+    assert_close(ratio("#0000ff", "white"), 8.59, 0.005);
+    assert_close(ratio("red", "green"), 1.28, 0.005);
+}
+
+#[test]
+fn identical_and_near_identical_colors_are_indistinguishable() {
+    let white = Color::new(255, 255, 255);
+    assert!(check_compliance(&white, &white).indistinguishable);
+    assert!(check_compliance(&Color::new(0xf8, 0xf8, 0xf8), &white).indistinguishable);
+    assert!(!check_compliance(&Color::new(0xee, 0xee, 0xee), &white).indistinguishable);
+}
+
+#[test]
+fn components_check_each_role_against_its_criterion() {
+    let white = Color::new(255, 255, 255);
+    // #949494 is enough for a border but not for text
+    let gray = Color::new(0x94, 0x94, 0x94);
+    let report = Component::new(white)
+        .text(gray)
+        .border(gray)
+        .check(WcagLevel::AA);
+    assert!(!report.passes());
+
+    let failures: Vec<_> = report.failures().map(|check| check.role).collect();
+    assert_eq!(failures, [ComponentRole::Text]);
+    assert_eq!(report.checks[1].criterion.number, "1.4.11");
+    assert!(Component::new(white)
+        .text(gray)
+        .large_text(true)
+        .check(WcagLevel::AA)
+        .passes());
+}
+
+#[test]
+fn ratios_are_not_rounded_up_by_default() {
+    let exact = ComplianceReport::from_ratio(4.4956);
+    assert!(!exact.passes(WcagLevel::AA, TextSize::Normal));
+
+    let rounded = ComplianceReport::with_rounding(4.4956, RoundingPolicy::TwoDecimals);
+    assert!(rounded.passes(WcagLevel::AA, TextSize::Normal));
+}
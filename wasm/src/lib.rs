@@ -0,0 +1,50 @@
+//! JavaScript bindings for the contrast checker, built with `wasm-pack build wasm`. Colors are
+//! passed as strings in any notation [`Color::from_css`] accepts.
+
+use contrast_checker::{check_compliance, Color};
+use wasm_bindgen::prelude::*;
+
+fn parse(input: &str) -> Result<Color, JsError> {
+    Color::from_css(input).map_err(|err| JsError::new(&format!("invalid color {err}")))
+}
+
+/// `contrastRatio("#F26CA7", "white")` returns the WCAG contrast ratio, from 1 to 21.
+#[wasm_bindgen(js_name = contrastRatio)]
+pub fn contrast_ratio(foreground: &str, background: &str) -> Result<f32, JsError> {
+    Ok(contrast_checker::contrast_ratio(
+        &parse(foreground)?,
+        &parse(background)?,
+    ))
+}
+
+/// The result of `checkCompliance`, a plain object with camel case fields on the JS side.
+#[wasm_bindgen]
+#[derive(Debug, Clone, Copy)]
+pub struct Compliance {
+    pub ratio: f32,
+    #[wasm_bindgen(js_name = aaNormalText)]
+    pub aa_normal_text: bool,
+    #[wasm_bindgen(js_name = aaLargeText)]
+    pub aa_large_text: bool,
+    #[wasm_bindgen(js_name = aaaNormalText)]
+    pub aaa_normal_text: bool,
+    #[wasm_bindgen(js_name = aaaLargeText)]
+    pub aaa_large_text: bool,
+    /// The colors are the same or too close for text to be seen.
+    pub indistinguishable: bool,
+}
+
+/// `checkCompliance("#777", "white")` grades the pair against every WCAG level and text size.
+#[wasm_bindgen(js_name = checkCompliance)]
+pub fn check_compliance_js(foreground: &str, background: &str) -> Result<Compliance, JsError> {
+    let report = check_compliance(&parse(foreground)?, &parse(background)?);
+
+    Ok(Compliance {
+        ratio: report.ratio,
+        aa_normal_text: report.aa_normal_text,
+        aa_large_text: report.aa_large_text,
+        aaa_normal_text: report.aaa_normal_text,
+        aaa_large_text: report.aaa_large_text,
+        indistinguishable: report.indistinguishable,
+    })
+}